@@ -8,9 +8,9 @@
 //! their payment information, and lastly use the intent to create a charge.
 
 use stripe::{
-    AttachPaymentMethod, CardDetailsParams, Client, CreateCustomer, CreatePaymentIntent,
-    CreatePaymentMethod, CreatePaymentMethodCardUnion, Currency, Customer, PaymentIntent,
-    PaymentIntentConfirmParams, PaymentMethod, PaymentMethodTypeFilter, UpdatePaymentIntent,
+    AttachPaymentMethod, CardDetailsParams, Client, ConfirmPaymentIntent, CreateCustomer,
+    CreatePaymentIntent, CreatePaymentMethod, CreatePaymentMethodCardUnion, Currency, Customer,
+    PaymentIntent, PaymentMethod, PaymentMethodTypeFilter, UpdatePaymentIntent,
 };
 
 #[tokio::main]
@@ -105,13 +105,10 @@ async fn main() {
 
     println!("updated payment intent with status '{}'", payment_intent.status);
 
-    let payment_intent = PaymentIntent::confirm(
-        &client,
-        &payment_intent.id,
-        PaymentIntentConfirmParams { ..Default::default() },
-    )
-    .await
-    .unwrap();
+    let payment_intent =
+        PaymentIntent::confirm(&client, &payment_intent.id, ConfirmPaymentIntent::new())
+            .await
+            .unwrap();
 
     println!("completed payment intent with status {}", payment_intent.status);
 }
@@ -14,13 +14,16 @@
 
 use std::net::SocketAddr;
 
-use axum::{routing::post, Json, Router};
-use stripe::Event;
+use axum::{routing::post, Router};
+use stripe::{StripeEvent, WebhookConfig};
 
 #[tokio::main]
 async fn main() {
-    // build our application with a route
-    let app = Router::new().route("/stripe_webhooks", post(root));
+    // build our application with a route, the extractor
+    // reads the webhook secret from the router state
+    let app = Router::new()
+        .route("/stripe_webhooks", post(root))
+        .with_state(WebhookConfig::new("whsec_xxxxx"));
 
     // run our app with hyper
     // `axum::Server` is a re-export of `hyper::Server`
@@ -29,7 +32,7 @@ async fn main() {
     axum::Server::bind(&addr).serve(app.into_make_service()).await.unwrap();
 }
 
-// basic handler that parses stripe webhooks
-async fn root(Json(event): Json<Event>) {
+// basic handler that receives verified stripe webhooks
+async fn root(StripeEvent(event): StripeEvent) {
     println!("received event '{}' ({}) ", event.type_, event.id);
 }
@@ -10,11 +10,13 @@ use openapiv3::{
 };
 use tracing::trace;
 
+use crate::mappings::{hand_written_methods, nested_collections};
 use crate::spec::{
     as_any_of_first_item_title, as_data_array_item, as_enum_strings, as_first_enum_value,
     as_object_enum_name, as_object_properties, as_object_type, err_schema_expected,
     find_param_by_name, get_id_param, get_ok_response, get_ok_response_schema,
-    get_request_form_parameters, non_path_ref_params, ExpansionResources,
+    get_request_form_parameters, get_stripe_operations, non_path_ref_params, ExpansionResources,
+    StripeOperation,
 };
 use crate::util::{print_doc_from_schema, write_serde_rename};
 use crate::{
@@ -276,6 +278,33 @@ pub fn gen_multitype_params(
     }
 }
 
+/// The id and rust type of the objects returned by a list, which type its cursors and
+/// let it implement `Paginable`.
+///
+/// These are known for `list`, which returns the object itself, and for nested collections
+/// such as `Account::list_persons`. Other lists such as `Quote::list_line_items` return
+/// another object whose id type is not known here, so their cursors are plain strings.
+fn listed_type(
+    state: &mut FileGenerator,
+    meta: &Metadata,
+    params: &InferredParams,
+) -> Option<(String, String)> {
+    match (params.method.as_str(), &params.list_of) {
+        (_, Some(list_of)) => {
+            let (id_type, _) = meta.schema_to_id_type(list_of)?;
+            let rust_type = meta.schema_to_rust_type(list_of);
+            state.use_ids.insert(id_type.clone());
+            state.use_resources.insert(rust_type.clone());
+            Some((id_type, rust_type))
+        }
+        ("list", None) => {
+            let (id_type, _) = meta.schema_to_id_type(&state.name)?;
+            Some((id_type, meta.schema_to_rust_type(&state.name)))
+        }
+        _ => None,
+    }
+}
+
 #[tracing::instrument(skip_all)]
 pub fn gen_inferred_params(
     out: &mut String,
@@ -284,18 +313,19 @@ pub fn gen_inferred_params(
     shared_objects: &mut BTreeSet<FileGenerator>,
 ) {
     let object = state.name.clone();
-    let id_type = meta.schema_to_id_type(&object);
     let struct_name = meta.schema_to_rust_type(&object);
 
     for (_, params) in state.inferred_parameters.clone() {
         let params_schema = params.rust_type.to_snake_case();
+        let listed = listed_type(state, meta, &params);
 
         // Derive Default when no param is required
         let can_derive_default =
             params.parameters.iter().all(|param| !param.parameter_data_ref().required);
 
         out.push('\n');
-        out.push_str(&format!("/// The parameters for `{}::{}`.\n", struct_name, params.method));
+        let owner = params.owner.as_deref().unwrap_or(&struct_name);
+        out.push_str(&format!("/// The parameters for `{}::{}`.\n", owner, params.method));
 
         if can_derive_default {
             out.push_str("#[derive(Clone, Debug, Serialize, Default)]\n");
@@ -372,8 +402,7 @@ pub fn gen_inferred_params(
                 }
                 "ending_before" => {
                     print_doc(out);
-                    let cursor_type =
-                        id_type.as_ref().map(|(x, _)| x.as_str()).unwrap_or("&'a str");
+                    let cursor_type = listed.as_ref().map_or("&'a str", |(id, _)| id.as_str());
                     initializers.push(("ending_before".into(), cursor_type.into(), false));
                     if required {
                         panic!("unexpected \"required\" `ending_before` parameter");
@@ -383,8 +412,7 @@ pub fn gen_inferred_params(
                 }
                 "starting_after" => {
                     print_doc(out);
-                    let cursor_type =
-                        id_type.as_ref().map(|(x, _)| x.as_str()).unwrap_or("&'a str");
+                    let cursor_type = listed.as_ref().map_or("&'a str", |(id, _)| id.as_str());
                     initializers.push(("starting_after".into(), cursor_type.into(), false));
                     if required {
                         panic!("unexpected \"required\" `starting_after` parameter");
@@ -558,14 +586,14 @@ pub fn gen_inferred_params(
         out.push_str("}\n");
 
         // we implement paginate on lists that have an Id
-        if let ("list", Some(_)) = (params.method.as_str(), &id_type) {
+        if let Some((_, listed_struct)) = &listed {
             state.use_params.insert("Paginable");
 
             out.push_str("impl Paginable for ");
             out.push_str(&params.rust_type);
             out.push_str("<'_> {\n");
             out.push_str("    type O = ");
-            out.push_str(&struct_name);
+            out.push_str(listed_struct);
             out.push_str(";\n");
             out.push_str(
                 "    fn set_last(&mut self, item: Self::O) {
@@ -1295,13 +1323,12 @@ pub fn gen_impl_requests(
 ) -> Option<String> {
     let name = state.name.clone();
     let object = &name;
-    let requests = meta.requests.get(object)?;
     let rust_struct = meta.schema_to_rust_type(object);
     trace!("impl {} {{ ... }}", rust_struct);
 
     let mut methods = BTreeMap::new();
 
-    for path in requests {
+    for path in meta.requests.get(object).into_iter().flatten() {
         // Unwrapped is safe here to avoid dealing with an `Option` since these paths come
         // from the spec already
         let request = meta
@@ -1339,6 +1366,8 @@ pub fn gen_impl_requests(
                 };
                 let params = InferredParams {
                     method: "list".into(),
+                    owner: None,
+                    list_of: None,
                     rust_type: params_name.clone(),
                     parameters: non_path_ref_params(get_request),
                 };
@@ -1425,6 +1454,8 @@ pub fn gen_impl_requests(
                 let params_name = format!("Create{}", rust_struct);
                 let params = InferredParams {
                     method: "create".into(),
+                    owner: None,
+                    list_of: None,
                     rust_type: params_name.clone(),
                     parameters: create_parameters,
                 };
@@ -1459,6 +1490,8 @@ pub fn gen_impl_requests(
                 let params_name = format!("Update{}", rust_struct);
                 let params = InferredParams {
                     method: "update".into(),
+                    owner: None,
+                    list_of: None,
                     rust_type: params_name.clone(),
                     parameters: update_parameters,
                 };
@@ -1538,18 +1571,350 @@ pub fn gen_impl_requests(
         }
     }
 
-    if methods.is_empty() {
-        None
-    } else {
-        // Add imports
-        state.use_config.insert("Client");
-        state.use_config.insert("Response");
+    let mut test_helpers = BTreeMap::new();
+    gen_custom_requests(state, meta, object_id, &mut methods, &mut test_helpers);
+
+    if methods.is_empty() && test_helpers.is_empty() {
+        return None;
+    }
+
+    // Add imports
+    state.use_config.insert("Client");
+    state.use_config.insert("Response");
 
-        // Output the impl block
-        Some(format!(
+    // Output the impl block
+    let mut out = String::new();
+    if !methods.is_empty() {
+        out.push_str(&format!(
             "impl {} {{\n{}\n}}\n",
             rust_struct,
             methods.values().map(String::as_str).collect::<Vec<_>>().join("\n")
-        ))
+        ));
+    }
+    if !test_helpers.is_empty() {
+        if !out.is_empty() {
+            out.push('\n');
+        }
+        let helpers = test_helpers_type(&rust_struct);
+        writedoc!(
+            &mut out,
+            r#"
+            /// Test mode requests that simulate the lifecycle of a [`{rust_struct}`].
+            ///
+            /// Stripe rejects these requests in live mode.
+            #[derive(Clone, Copy, Debug)]
+            pub struct {helpers};
+
+            impl {helpers} {{
+            {methods}
+            }}
+        "#,
+            methods = test_helpers.values().map(String::as_str).collect::<Vec<_>>().join("\n")
+        )
+        .unwrap();
+    }
+    Some(out)
+}
+
+/// The type that holds the test mode requests of an object, such as `TestHelpersRefund`.
+fn test_helpers_type(rust_struct: &str) -> String {
+    format!("TestHelpers{}", rust_struct)
+}
+
+/// Generate the methods for the actions and nested collections of an object, such as
+/// `Invoice::finalize_invoice` or `Quote::list_line_items`, from its `x-stripeOperations`.
+///
+/// Requests under `/v1/test_helpers`, such as `TestHelpersRefund::expire`, are collected
+/// separately in `test_helpers` so that they are not mixed with the live mode api.
+fn gen_custom_requests(
+    state: &mut FileGenerator,
+    meta: &Metadata,
+    object_id: Option<&str>,
+    methods: &mut BTreeMap<MethodTypes, String>,
+    test_helpers: &mut BTreeMap<MethodTypes, String>,
+) {
+    let object = state.name.clone();
+    let rust_struct = meta.schema_to_rust_type(&object);
+    let hand_written = hand_written_methods();
+    let nested = nested_collections();
+
+    // the requests of the collections nested under this object come first, so that they
+    // take precedence over any request of this object for the same path
+    let mut operations = Vec::new();
+    for (child, (parent, singular)) in &nested {
+        if *parent == object {
+            let ops = stripe_operations(meta, child).into_iter();
+            operations.extend(
+                ops.filter(|op| is_nested_under(meta, parent, &op.path))
+                    .map(|op| (op, Some((*child, *singular)))),
+            );
+        }
+    }
+    let nested_paths = operations
+        .iter()
+        .map(|(op, _)| (op.path.clone(), op.operation.clone()))
+        .collect::<BTreeSet<_>>();
+    for op in stripe_operations(meta, &object) {
+        // these are generated on the parent, see above
+        let is_nested = nested
+            .get(object.as_str())
+            .map_or(false, |(parent, _)| is_nested_under(meta, parent, &op.path));
+        if !is_nested && !nested_paths.contains(&(op.path.clone(), op.operation.clone())) {
+            operations.push((op, None));
+        }
+    }
+
+    for (op, nested) in operations {
+        let path = op.path.trim_start_matches("/v1");
+        let method_name = match nested {
+            // `/accounts/{account}/persons` gives `list_persons`, `create_person` and so on
+            Some(_) if op.method_type == "list" => {
+                format!("list_{}", path.split('/').nth(3).unwrap_or_default())
+            }
+            Some((_, singular)) => format!("{}_{}", op.method_name, singular),
+            None => op.method_name.clone(),
+        };
+        let method_type = match op.method_type.as_str() {
+            // search results are paginated differently to lists
+            "search" => continue,
+            _ if nested.is_some() => MethodTypes::Custom(method_name.clone()),
+            "list" => MethodTypes::List,
+            "create" => MethodTypes::Create,
+            "retrieve" => MethodTypes::Retrieve,
+            "update" => MethodTypes::Update,
+            "delete" => MethodTypes::Delete,
+            _ => MethodTypes::Custom(method_name.clone()),
+        };
+        // test clocks are themselves a test helper, so their requests are not set apart
+        let is_test_helper =
+            path.starts_with("/test_helpers/") && !object.starts_with("test_helpers.");
+        let generated = if is_test_helper { &*test_helpers } else { &*methods };
+
+        // the standard requests of most objects were generated above, this covers
+        // the rest along with the standard requests of namespaced objects
+        if generated.contains_key(&method_type)
+            || hand_written.contains(&(object.as_str(), method_name.as_str()))
+        {
+            continue;
+        }
+
+        let operation = match meta.spec.get_operation(&op.path, &op.operation) {
+            Some(operation) => operation,
+            None => continue,
+        };
+        if !err_schema_expected(operation) {
+            continue; // skip generating this unusual request (for now...)
+        }
+        let return_type = match gen_custom_return_type(state, meta, operation) {
+            Some(return_type) => return_type,
+            None => {
+                tracing::warn!(
+                    "unhandled response for {rust_struct}::{}: {} {}",
+                    method_name,
+                    op.operation.to_uppercase(),
+                    op.path
+                );
+                continue;
+            }
+        };
+
+        // Path parameters become arguments, typed by the id they refer to where it is known.
+        // The last one is this object's id, unless the request lists or creates a child.
+        // In a nested collection they are this object's id and then the nested object's.
+        let owned_param = match method_type {
+            MethodTypes::List | MethodTypes::Create => None,
+            _ => path.rsplit('/').find(|segment| segment.starts_with('{')),
+        };
+        let mut args = String::new();
+        let mut format_args = Vec::new();
+        let mut url = String::new();
+        for segment in path.split('/').skip(1) {
+            url.push('/');
+            let param = match segment.strip_prefix('{').and_then(|s| s.strip_suffix('}')) {
+                Some(param) => param,
+                None => {
+                    url.push_str(segment);
+                    continue;
+                }
+            };
+            url.push_str("{}");
+            let (arg, arg_type) = match (nested, meta.schema_to_id_type(param), object_id) {
+                (Some(_), _, Some(object_id)) if format_args.is_empty() => {
+                    ("id".to_string(), object_id.to_string())
+                }
+                (Some((child, singular)), _, _) if meta.schema_to_id_type(child).is_some() => {
+                    let (id_type, _) = meta.schema_to_id_type(child).unwrap();
+                    (singular.to_string(), id_type)
+                }
+                (_, Some((id_type, _)), Some(object_id)) if id_type == object_id => {
+                    ("id".to_string(), id_type)
+                }
+                (_, Some((id_type, _)), _) => (param.to_snake_case(), id_type),
+                (_, None, Some(object_id)) if owned_param == Some(segment) => {
+                    ("id".to_string(), object_id.to_string())
+                }
+                (_, None, _) => (param.to_snake_case(), "str".to_string()),
+            };
+            if arg_type != "str" {
+                state.use_ids.insert(arg_type.clone());
+            }
+            write!(args, ", {}: &{}", arg, arg_type).unwrap();
+            format_args.push(arg);
+        }
+
+        let parameters = match op.operation.as_str() {
+            "post" => get_request_form_parameters(operation).unwrap_or_default(),
+            _ => non_path_ref_params(operation)
+                .into_iter()
+                .filter(|p| matches!(p, Parameter::Query { .. }))
+                .collect(),
+        };
+        // a request whose only parameter is `expand` takes it directly, like `retrieve`
+        let expand_only = op.operation == "get"
+            && parameters.len() == 1
+            && parameters[0].parameter_data_ref().name == "expand";
+        let params_name = if parameters.is_empty() || expand_only {
+            None
+        } else {
+            let params_name = custom_params_name(&method_name, &rust_struct);
+            if state.inferred_parameters.contains_key(&params_name.to_snake_case()) {
+                tracing::warn!("duplicate params {params_name} for {rust_struct}: {}", op.path);
+                continue;
+            }
+            let params = InferredParams {
+                method: method_name.clone(),
+                owner: is_test_helper.then(|| test_helpers_type(&rust_struct)),
+                list_of: nested
+                    .filter(|_| op.method_type == "list")
+                    .map(|(child, _)| child.to_string()),
+                rust_type: params_name.clone(),
+                parameters,
+            };
+            state.inferred_parameters.insert(params_name.to_snake_case(), params);
+            Some(params_name)
+        };
+
+        // Form params are passed by value like `create` and `update`, query params by
+        // reference like `list`
+        let (client_fn, params_arg) = match (op.operation.as_str(), &params_name) {
+            ("get", None) if expand_only => ("get_query", ", expand: &[&str]".to_string()),
+            ("get", None) => ("get", String::new()),
+            ("get", Some(params)) => ("get_query", format!(", params: &{}<'_>", params)),
+            ("post", None) => ("post", String::new()),
+            ("post", Some(params)) => ("post_form", format!(", params: {}<'_>", params)),
+            ("delete", None) => ("delete", String::new()),
+            ("delete", Some(params)) => ("delete_query", format!(", params: &{}<'_>", params)),
+            _ => continue,
+        };
+        let url = if format_args.is_empty() {
+            format!("\"{}\"", url)
+        } else {
+            format!("&format!(\"{}\", {})", url, format_args.join(", "))
+        };
+        let params_expr = if expand_only {
+            state.use_params.insert("Expand");
+            ", &Expand { expand }"
+        } else if params_name.is_some() {
+            ", &params"
+        } else {
+            ""
+        };
+
+        let mut out = String::new();
+        out.push('\n');
+        if let Some(doc_comment) = &operation.description {
+            print_doc_comment(&mut out, doc_comment, 1);
+        }
+        writedoc!(
+            &mut out,
+            r#"
+            pub fn {method_name}(client: &Client{args}{params_arg}) -> Response<{return_type}> {{
+                client.{client_fn}({url}{params_expr})
+            }}
+        "#
+        )
+        .unwrap();
+        if is_test_helper {
+            test_helpers.insert(method_type, out);
+        } else {
+            methods.insert(method_type, out);
+        }
+    }
+}
+
+/// The `x-stripeOperations` of a schema, if it has any.
+fn stripe_operations(meta: &Metadata, schema: &str) -> Vec<StripeOperation> {
+    match meta.spec.component_schemas().get(schema).and_then(|s| s.as_item()) {
+        Some(schema) => get_stripe_operations(schema),
+        None => Vec::new(),
+    }
+}
+
+/// Whether `path` is a collection nested under an object of `parent`, such as
+/// `/v1/transfers/{id}/reversals` under `transfer`.
+fn is_nested_under(meta: &Metadata, parent: &str, path: &str) -> bool {
+    let segments = path.trim_start_matches("/v1/").split('/').collect::<Vec<_>>();
+    segments.len() > 2
+        && segments[1].starts_with('{')
+        && meta.requests.get(parent).map_or(false, |paths| paths.contains(path))
+}
+
+/// The rust type returned by a custom request, such as `Invoice` or `List<QuoteLineItem>`.
+fn gen_custom_return_type(
+    state: &mut FileGenerator,
+    meta: &Metadata,
+    operation: &openapiv3::Operation,
+) -> Option<String> {
+    match get_ok_response_schema(operation)? {
+        ReferenceOr::Reference { reference } => {
+            let schema = reference.trim_start_matches("#/components/schemas/");
+            if let Some(deleted) = schema.strip_prefix("deleted_") {
+                let (id_type, _) = meta.schema_to_id_type(deleted)?;
+                state.use_params.insert("Deleted");
+                state.use_ids.insert(id_type.clone());
+                Some(format!("Deleted<{}>", id_type))
+            } else {
+                let rust_type = meta.schema_to_rust_type(schema);
+                state.use_resources.insert(rust_type.clone());
+                Some(rust_type)
+            }
+        }
+        ReferenceOr::Item(schema) if as_object_enum_name(schema).as_deref() == Some("list") => {
+            match as_data_array_item(as_object_type(schema)?)? {
+                ReferenceOr::Reference { reference } => {
+                    let schema = reference.trim_start_matches("#/components/schemas/");
+                    let rust_type = meta.schema_to_rust_type(schema);
+                    state.use_params.insert("List");
+                    state.use_resources.insert(rust_type.clone());
+                    Some(format!("List<{}>", rust_type))
+                }
+                ReferenceOr::Item(_) => None,
+            }
+        }
+        // binary responses such as quote pdfs are not handled by the client
+        ReferenceOr::Item(_) => None,
+    }
+}
+
+/// The name of the parameters for a custom request, such as `FinalizeInvoice` for
+/// `Invoice::finalize_invoice` or `ListQuoteLineItems` for `Quote::list_line_items`.
+///
+/// Method names often repeat (the end of) the object name, as in
+/// `TreasuryOutboundPayment::return_outbound_payment`, which is not repeated here.
+fn custom_params_name(method_name: &str, rust_struct: &str) -> String {
+    match method_name {
+        "list" if rust_struct.ends_with('y') => {
+            format!("List{}ies", &rust_struct[0..rust_struct.len() - 1])
+        }
+        "list" => format!("List{}s", rust_struct),
+        _ => match method_name.split_once('_') {
+            Some((verb, rest)) if rust_struct.to_snake_case().ends_with(rest) => {
+                format!("{}{}", verb.to_camel_case(), rust_struct)
+            }
+            Some((verb, rest)) => {
+                format!("{}{}{}", verb.to_camel_case(), rust_struct, rest.to_camel_case())
+            }
+            None => format!("{}{}", method_name.to_camel_case(), rust_struct),
+        },
     }
 }
@@ -1,4 +1,4 @@
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, BTreeSet};
 
 pub fn id_renames() -> BTreeMap<&'static str, &'static str> {
     BTreeMap::from([
@@ -26,6 +26,8 @@ pub fn object_mappings() -> ObjectMap {
         ("account_capabilities_tax_reporting_us_1099_k", "capability_status"),
         ("account_capabilities_tax_reporting_us_1099_misc", "capability_status"),
         ("account_capabilities_transfers", "capability_status"),
+        // `capability.status` has more states than the account capabilities above
+        ("capability_status", "account_capability_status"),
         ("account_branding_settings", "branding_settings"),
         ("account_card_payments_settings", "card_payments_settings"),
         ("account_dashboard_settings", "dashboard_settings"),
@@ -371,6 +373,7 @@ pub fn field_mappings() -> FieldMap {
         (("create_customer", "source"), ("PaymentSourceParams", "Option<PaymentSourceParams>")),
         (("update_customer", "source"), ("PaymentSourceParams", "Option<PaymentSourceParams>")),
         (("update_customer", "trial_end"), ("Scheduled", "Option<Scheduled>")),
+        (("create_customer_tax_id", "type"), ("", "TaxIdType")),
 
         // Config for `invoice` params
         (("list_invoices", "billing"), ("", "Option<CollectionMethod>")),
@@ -545,3 +548,49 @@ pub fn field_mappings() -> FieldMap {
         (("create_webhook_endpoint", "api_version"), ("ApiVersion", "Option<ApiVersion>")),
     ])
 }
+
+/// Collections nested under another object, such as the persons of an account, whose
+/// requests are generated on that object as `Account::list_persons`, `Account::create_person`
+/// and so on, rather than on the nested object itself.
+///
+/// Maps the nested schema to its parent and the singular used in the method names.
+pub fn nested_collections() -> BTreeMap<&'static str, (&'static str, &'static str)> {
+    BTreeMap::from([
+        ("capability", ("account", "capability")),
+        ("customer_balance_transaction", ("customer", "balance_transaction")),
+        ("fee_refund", ("application_fee", "refund")),
+        ("person", ("account", "person")),
+        ("tax_id", ("customer", "tax_id")),
+        ("transfer_reversal", ("transfer", "reversal")),
+    ])
+}
+
+/// Methods that are implemented by hand in the `*_ext.rs` files, and so must not
+/// be generated from the `x-stripeOperations` of their object.
+pub fn hand_written_methods() -> BTreeSet<(&'static str, &'static str)> {
+    BTreeSet::from([
+        ("balance", "retrieve"),
+        ("bank_account", "delete"),
+        ("bank_account", "update"),
+        ("bank_account", "verify"),
+        ("card", "delete"),
+        ("card", "update"),
+        ("charge", "capture"),
+        ("checkout.session", "expire"),
+        ("checkout.session", "retrieve"),
+        ("credit_note", "void_credit_note"),
+        ("customer", "create_balance_transaction"),
+        ("customer", "list_balance_transactions"),
+        ("customer", "retrieve_balance_transaction"),
+        ("customer", "update_balance_transaction"),
+        ("invoice", "upcoming"),
+        ("invoice", "upcoming_lines"),
+        ("login_link", "create"),
+        ("payment_method", "attach"),
+        ("payment_method", "detach"),
+        ("payout", "cancel"),
+        ("quote", "pdf"),
+        ("subscription", "cancel"),
+        ("usage_record", "create"),
+    ])
+}
@@ -1,9 +1,10 @@
+use std::borrow::Borrow;
 use std::collections::{BTreeMap, BTreeSet};
 use std::fs::write;
 use std::path::Path;
 
 use heck::{CamelCase, SnakeCase};
-use openapiv3::{ReferenceOr, SchemaKind};
+use openapiv3::{ReferenceOr, Schema, SchemaKind, Type};
 use tracing::trace;
 
 use crate::spec::{as_object_properties, Spec};
@@ -73,22 +74,7 @@ impl<'a> Metadata<'a> {
                 }
             }
             for schema_or_ref in properties.values() {
-                match schema_or_ref {
-                    ReferenceOr::Reference { reference } => {
-                        let dep = reference.trim_start_matches("#/components/schemas/");
-                        dependents.entry(dep).or_default().insert(schema_name);
-                    }
-                    ReferenceOr::Item(schema) => {
-                        if let SchemaKind::AnyOf { any_of } = &schema.schema_kind {
-                            for ty in any_of {
-                                if let ReferenceOr::Reference { reference } = ty {
-                                    let dep = reference.trim_start_matches("#/components/schemas/");
-                                    dependents.entry(dep).or_default().insert(schema_name);
-                                }
-                            }
-                        }
-                    }
-                }
+                add_dependent(&mut dependents, schema_name, schema_or_ref);
             }
         }
 
@@ -194,6 +180,46 @@ impl<'a> Metadata<'a> {
     }
 }
 
+/// Record `schema_name` as a dependent of the schemas a field refers to.
+///
+/// References inside arrays, unions and inline objects count too: a schema that is only
+/// used as the items of a list in several objects is still shared between them, and must
+/// get its own file rather than be inlined into each of them.
+fn add_dependent<'a, T: Borrow<Schema>>(
+    dependents: &mut BTreeMap<&'a str, BTreeSet<&'a str>>,
+    schema_name: &'a str,
+    field: &'a ReferenceOr<T>,
+) {
+    let schema = match field {
+        ReferenceOr::Reference { reference } => {
+            let dep = reference.trim_start_matches("#/components/schemas/");
+            dependents.entry(dep).or_default().insert(schema_name);
+            return;
+        }
+        ReferenceOr::Item(schema) => schema.borrow(),
+    };
+    match &schema.schema_kind {
+        SchemaKind::Type(Type::Array(array)) => {
+            if let Some(items) = &array.items {
+                add_dependent(dependents, schema_name, items);
+            }
+        }
+        SchemaKind::Type(Type::Object(object)) => {
+            for property in object.properties.values() {
+                add_dependent(dependents, schema_name, property);
+            }
+        }
+        SchemaKind::AnyOf { any_of: variants }
+        | SchemaKind::OneOf { one_of: variants }
+        | SchemaKind::AllOf { all_of: variants } => {
+            for variant in variants {
+                add_dependent(dependents, schema_name, variant);
+            }
+        }
+        _ => {}
+    }
+}
+
 /// given a spec and a set of objects in that spec, metadatas a
 /// map with the requests to implement for each of the types in the spec
 pub fn metadata_requests<'a>(
@@ -40,6 +40,39 @@ impl Spec {
     pub fn get_request_unwrapped(&self, path: &str) -> &ReferenceOr<PathItem> {
         self.0.paths.paths.get(path).expect("Path not found")
     }
+
+    /// Return the operation for an http method (`get`, `post` or `delete`) on a path, if any
+    pub fn get_operation(&self, path: &str, method: &str) -> Option<&Operation> {
+        let item = self.0.paths.paths.get(path)?.as_item()?;
+        match method {
+            "get" => item.get.as_ref(),
+            "post" => item.post.as_ref(),
+            "delete" => item.delete.as_ref(),
+            _ => None,
+        }
+    }
+}
+
+/// An entry of the `x-stripeOperations` extension, which lists the requests
+/// that stripe's own client libraries expose as methods of an object.
+#[derive(Debug, Clone, serde::Deserialize)]
+pub struct StripeOperation {
+    pub method_name: String,
+    /// `list`, `create`, `retrieve`, `update`, `delete` or `custom`.
+    pub method_type: String,
+    /// The http method, in lowercase.
+    pub operation: String,
+    pub path: String,
+}
+
+/// Untyped equivalent: `schema["x-stripeOperations"]`
+pub fn get_stripe_operations(schema: &Schema) -> Vec<StripeOperation> {
+    schema
+        .schema_data
+        .extensions
+        .get("x-stripeOperations")
+        .and_then(|ops| serde_json::from_value(ops.clone()).ok())
+        .unwrap_or_default()
 }
 
 pub fn as_object_type(schema: &Schema) -> Option<&ObjectType> {
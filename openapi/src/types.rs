@@ -35,6 +35,12 @@ pub struct InferredStruct {
 #[derive(Clone, Debug, PartialEq)]
 pub struct InferredParams {
     pub method: String,
+    /// The type the method is implemented on when it is not the object itself,
+    /// such as `TestHelpersRefund` for `TestHelpersRefund::expire`.
+    pub owner: Option<String>,
+    /// The schema of the objects listed by a nested collection, such as `person` for
+    /// `Account::list_persons`, whose ids are then used as the cursors of the list.
+    pub list_of: Option<String>,
     pub rust_type: String,
     pub parameters: Vec<Parameter>,
 }
@@ -52,4 +58,6 @@ pub enum MethodTypes {
     Retrieve,
     Update,
     Delete,
+    /// An action or nested collection from the object's `x-stripeOperations`, by method name.
+    Custom(String),
 }
@@ -1,12 +1,13 @@
 use std::future::{self, Future};
 use std::pin::Pin;
+use std::sync::Arc;
 
 use async_std::task::sleep;
 use http_types::{Request, StatusCode};
 use serde::de::DeserializeOwned;
 
 use crate::client::request_strategy::{Outcome, RequestStrategy};
-use crate::error::{ErrorResponse, StripeError};
+use crate::error::{BodyCapture, StripeError};
 
 pub type Response<T> = Pin<Box<dyn Future<Output = Result<T, StripeError>> + Send>>;
 
@@ -25,12 +26,19 @@ pub(crate) fn err<T: Send + 'static>(err: StripeError) -> Response<T> {
 #[derive(Clone)]
 pub struct AsyncStdClient {
     client: surf::Client,
+    capture: Arc<BodyCapture>,
 }
 
 impl AsyncStdClient {
     /// Creates a new client pointed to `https://api.stripe.com/`
     pub fn new() -> Self {
-        Self { client: surf::Client::new() }
+        Self { client: surf::Client::new(), capture: Arc::new(BodyCapture::default()) }
+    }
+
+    /// Set how response bodies are kept when they cannot be deserialized.
+    pub fn with_body_capture(mut self, capture: BodyCapture) -> Self {
+        self.capture = Arc::new(capture);
+        self
     }
 
     pub fn execute<T: DeserializeOwned + Send + 'static>(
@@ -42,11 +50,24 @@ impl AsyncStdClient {
         // N.B. Client is send sync; cloned clients share the same pool.
         let client = self.client.clone();
         let strategy = strategy.clone();
+        let capture = self.capture.clone();
+        let path = request.url().path().to_string();
+
+        Box::pin(async move {
+            let (status, bytes) = send_inner(&client, request, &strategy, &capture).await?;
+            capture.deserialize(&bytes, status, &path)
+        })
+    }
+
+    /// Like `execute`, but returns the raw body of a successful response.
+    pub fn execute_bytes(&self, request: Request, strategy: &RequestStrategy) -> Response<Vec<u8>> {
+        let client = self.client.clone();
+        let strategy = strategy.clone();
+        let capture = self.capture.clone();
 
         Box::pin(async move {
-            let bytes = send_inner(&client, request, &strategy).await?;
-            let json_deserializer = &mut serde_json::Deserializer::from_slice(&bytes);
-            serde_path_to_error::deserialize(json_deserializer).map_err(StripeError::from)
+            let (_, bytes) = send_inner(&client, request, &strategy, &capture).await?;
+            Ok(bytes)
         })
     }
 }
@@ -55,7 +76,8 @@ async fn send_inner(
     client: &surf::Client,
     mut request: Request,
     strategy: &RequestStrategy,
-) -> Result<Vec<u8>, StripeError> {
+    capture: &BodyCapture,
+) -> Result<(u16, Vec<u8>), StripeError> {
     let mut tries = 0;
     let mut last_status: Option<StatusCode> = None;
     let mut last_retry_header: Option<bool> = None;
@@ -67,6 +89,7 @@ async fn send_inner(
         request.insert_header("Idempotency-Key", key);
     }
 
+    let path = request.url().path().to_string();
     let body = request.body_bytes().await?;
 
     loop {
@@ -101,20 +124,14 @@ async fn send_inner(
 
                 if !status.is_success() {
                     tries += 1;
-                    let json_deserializer = &mut serde_json::Deserializer::from_slice(&bytes);
-                    last_error = serde_path_to_error::deserialize(json_deserializer)
-                        .map(|mut e: ErrorResponse| {
-                            e.error.http_status = status.into();
-                            StripeError::from(e.error)
-                        })
-                        .unwrap_or_else(StripeError::from);
+                    last_error = capture.deserialize_error(&bytes, status.into(), &path, retry);
                     last_status = Some(status);
                     last_retry_header = retry;
 
                     continue;
                 }
 
-                Ok(bytes)
+                Ok((status.into(), bytes))
             }
         };
     }
@@ -1,5 +1,6 @@
 use std::future::{self, Future};
 use std::pin::Pin;
+use std::sync::Arc;
 
 use http_types::{Request, StatusCode};
 use hyper::http;
@@ -8,7 +9,7 @@ use serde::de::DeserializeOwned;
 use tokio::time::sleep;
 
 use crate::client::request_strategy::{Outcome, RequestStrategy};
-use crate::error::{ErrorResponse, StripeError};
+use crate::error::{BodyCapture, StripeError};
 
 #[cfg(feature = "hyper-rustls-native")]
 mod connector {
@@ -74,15 +75,23 @@ pub(crate) fn err<T: Send + 'static>(err: StripeError) -> Response<T> {
 #[derive(Clone)]
 pub struct TokioClient {
     client: HttpClient,
+    capture: Arc<BodyCapture>,
 }
 
 impl TokioClient {
     pub fn new() -> Self {
         Self {
             client: hyper::Client::builder().pool_max_idle_per_host(0).build(connector::create()),
+            capture: Arc::new(BodyCapture::default()),
         }
     }
 
+    /// Set how response bodies are kept when they cannot be deserialized.
+    pub fn with_body_capture(mut self, capture: BodyCapture) -> Self {
+        self.capture = Arc::new(capture);
+        self
+    }
+
     pub fn execute<T: DeserializeOwned + Send + 'static>(
         &self,
         request: Request,
@@ -92,11 +101,24 @@ impl TokioClient {
         // N.B. Client is send sync; cloned clients share the same pool.
         let client = self.client.clone();
         let strategy = strategy.clone();
+        let capture = self.capture.clone();
+        let path = request.url().path().to_string();
+
+        Box::pin(async move {
+            let (status, bytes) = send_inner(&client, request, &strategy, &capture).await?;
+            capture.deserialize(&bytes, status, &path)
+        })
+    }
+
+    /// Like `execute`, but returns the raw body of a successful response.
+    pub fn execute_bytes(&self, request: Request, strategy: &RequestStrategy) -> Response<Vec<u8>> {
+        let client = self.client.clone();
+        let strategy = strategy.clone();
+        let capture = self.capture.clone();
 
         Box::pin(async move {
-            let bytes = send_inner(&client, request, &strategy).await?;
-            let json_deserializer = &mut serde_json::Deserializer::from_slice(&bytes);
-            serde_path_to_error::deserialize(json_deserializer).map_err(StripeError::from)
+            let (_, bytes) = send_inner(&client, request, &strategy, &capture).await?;
+            Ok(bytes.to_vec())
         })
     }
 }
@@ -105,7 +127,8 @@ async fn send_inner(
     client: &HttpClient,
     mut request: Request,
     strategy: &RequestStrategy,
-) -> Result<hyper::body::Bytes, StripeError> {
+    capture: &BodyCapture,
+) -> Result<(u16, hyper::body::Bytes), StripeError> {
     let mut tries = 0;
     let mut last_status: Option<StatusCode> = None;
     let mut last_retry_header: Option<bool> = None;
@@ -117,6 +140,7 @@ async fn send_inner(
         request.insert_header("Idempotency-Key", key);
     }
 
+    let path = request.url().path().to_string();
     let body = request.body_bytes().await?;
 
     loop {
@@ -153,19 +177,13 @@ async fn send_inner(
 
                 if !status.is_success() {
                     tries += 1;
-                    let json_deserializer = &mut serde_json::Deserializer::from_slice(&bytes);
-                    last_error = serde_path_to_error::deserialize(json_deserializer)
-                        .map(|mut e: ErrorResponse| {
-                            e.error.http_status = status.into();
-                            StripeError::from(e.error)
-                        })
-                        .unwrap_or_else(StripeError::from);
+                    last_error = capture.deserialize_error(&bytes, status.into(), &path, retry);
                     last_status = Some(status.into());
                     last_retry_header = retry;
                     continue;
                 }
 
-                Ok(bytes)
+                Ok((status.into(), bytes))
             }
         };
     }
@@ -190,7 +208,7 @@ mod tests {
     use super::convert_request;
     use super::TokioClient;
     use crate::client::request_strategy::RequestStrategy;
-    use crate::StripeError;
+    use crate::{BodyCapture, StripeError};
 
     const TEST_URL: &str = "https://api.stripe.com/v1/";
 
@@ -292,6 +310,55 @@ mod tests {
         }
     }
 
+    #[tokio::test]
+    async fn card_error_objects() {
+        let client = TokioClient::new();
+        let server = MockServer::start_async().await;
+
+        let mock = server.mock(|when, then| {
+            when.method(POST).path("/v1/payment_intents/pi_123/confirm");
+            then.status(402).body(
+                r#"{
+                  "error": {
+                    "type": "card_error",
+                    "code": "card_declined",
+                    "decline_code": "insufficient_funds",
+                    "param": "payment_method",
+                    "doc_url": "https://stripe.com/docs/error-codes/card-declined",
+                    "request_log_url": "https://dashboard.stripe.com/test/logs/req_123",
+                    "payment_intent": {
+                      "id": "pi_123", "object": "payment_intent", "amount": 100,
+                      "amount_capturable": 0, "amount_received": 0,
+                      "capture_method": "automatic", "confirmation_method": "automatic",
+                      "created": 1, "currency": "usd", "livemode": false, "metadata": {},
+                      "payment_method_types": ["card"], "status": "requires_payment_method"
+                    }
+                  }
+                }"#,
+            );
+        });
+
+        let req =
+            Request::post(Url::parse(&server.url("/v1/payment_intents/pi_123/confirm")).unwrap());
+        let res = client.execute::<()>(req, &RequestStrategy::Once).await;
+
+        mock.assert_hits_async(1).await;
+
+        match res {
+            Err(StripeError::Stripe(x)) => {
+                assert_eq!(x.http_status, 402);
+                assert_eq!(x.param.as_deref(), Some("payment_method"));
+                assert_eq!(
+                    x.request_log_url.as_deref(),
+                    Some("https://dashboard.stripe.com/test/logs/req_123")
+                );
+                assert_eq!(x.payment_intent.unwrap().id.as_str(), "pi_123");
+                assert!(x.setup_intent.is_none());
+            }
+            _ => panic!("Expected stripe error {:?}", res),
+        }
+    }
+
     #[tokio::test]
     async fn nice_serde_error() {
         use serde::Deserialize;
@@ -327,13 +394,52 @@ mod tests {
         mock.assert_hits_async(1).await;
 
         match res {
-            Err(StripeError::JSONSerialize(err)) => {
-                println!("Error: {:?} Path: {:?}", err.inner(), err.path().to_string())
+            Err(StripeError::Deserialize(err)) => {
+                assert_eq!(err.error.path().to_string(), "name");
+                assert_eq!(err.http_status, 200);
+                assert_eq!(err.request_path, "/v1/odd_data");
+                assert!(err.body_text().contains(r#""name": 10"#));
             }
             _ => panic!("Expected stripe error {:?}", res),
         }
     }
 
+    #[tokio::test]
+    async fn captured_body_is_redacted_and_capped() {
+        let server = MockServer::start_async().await;
+        let mock = server.mock(|when, then| {
+            when.method(GET).path("/v1/payment_intents/pi_123");
+            then.status(200).body(r#"{"id": "pi_123", "client_secret": "pi_123_secret_456"}"#);
+        });
+
+        let req = Request::get(Url::parse(&server.url("/v1/payment_intents/pi_123")).unwrap());
+        let res = TokioClient::new().execute::<u32>(req, &RequestStrategy::Once).await;
+        match res {
+            Err(StripeError::Deserialize(err)) => {
+                assert!(err.body_text().contains(r#""client_secret":"[redacted]""#));
+                assert!(!err.body_text().contains("secret_456"));
+                assert!(!err.truncated);
+            }
+            _ => panic!("Expected stripe error {:?}", res),
+        }
+
+        let capture = BodyCapture { max_bytes: 8, ..Default::default() };
+        let req = Request::get(Url::parse(&server.url("/v1/payment_intents/pi_123")).unwrap());
+        let res = TokioClient::new()
+            .with_body_capture(capture)
+            .execute::<u32>(req, &RequestStrategy::Once)
+            .await;
+        match res {
+            Err(StripeError::Deserialize(err)) => {
+                assert_eq!(err.body.len(), 8);
+                assert!(err.truncated);
+            }
+            _ => panic!("Expected stripe error {:?}", res),
+        }
+
+        mock.assert_hits_async(2).await;
+    }
+
     #[tokio::test]
     async fn retry_header() {
         let client = TokioClient::new();
@@ -351,7 +457,46 @@ mod tests {
         let res = client.execute::<()>(req, &RequestStrategy::Retry(5)).await;
 
         hello_mock.assert_hits_async(1).await;
-        assert!(res.is_err());
+        assert!(!res.unwrap_err().is_retryable());
+    }
+
+    #[tokio::test]
+    async fn retry_header_with_error_body() {
+        let client = TokioClient::new();
+
+        // Start a lightweight mock server.
+        let server = MockServer::start_async().await;
+
+        // Create a mock on the server.
+        let hello_mock = server.mock(|when, then| {
+            when.method(GET).path("/server-errors");
+            then.status(500)
+                .header("Stripe-Should-Retry", "false")
+                .body(r#"{"error": {"type": "api_error"}}"#);
+        });
+
+        let req = Request::get(Url::parse(&server.url("/server-errors")).unwrap());
+        let res = client.execute::<()>(req, &RequestStrategy::Retry(5)).await;
+
+        hello_mock.assert_hits_async(1).await;
+        match res {
+            Err(err @ StripeError::Stripe(_)) => assert!(!err.is_retryable()),
+            _ => panic!("Expected stripe error {:?}", res),
+        }
+    }
+
+    #[tokio::test]
+    async fn network_error() {
+        let client = TokioClient::new();
+
+        // nothing listens on the discard port
+        let req = Request::get(Url::parse("http://127.0.0.1:9/v1/customers").unwrap());
+        let res = client.execute::<()>(req, &RequestStrategy::Once).await;
+
+        match res {
+            Err(err @ StripeError::Transport(_)) => assert!(err.is_network()),
+            _ => panic!("Expected transport error {:?}", res),
+        }
     }
 
     #[tokio::test]
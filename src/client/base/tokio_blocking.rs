@@ -5,7 +5,7 @@ use serde::de::DeserializeOwned;
 
 use crate::client::base::tokio::TokioClient;
 use crate::client::request_strategy::RequestStrategy;
-use crate::error::StripeError;
+use crate::error::{BodyCapture, StripeError};
 
 /// The delay after which the blocking `Client` will assume the request has failed.
 const DEFAULT_TIMEOUT: Duration = Duration::from_secs(30);
@@ -34,6 +34,12 @@ impl TokioBlockingClient {
         TokioBlockingClient::from_async(TokioClient::new())
     }
 
+    /// Set how response bodies are kept when they cannot be deserialized.
+    pub fn with_body_capture(mut self, capture: BodyCapture) -> Self {
+        self.inner = self.inner.with_body_capture(capture);
+        self
+    }
+
     fn from_async(inner: TokioClient) -> TokioBlockingClient {
         let runtime = tokio::runtime::Builder::new_current_thread()
             .enable_io()
@@ -48,7 +54,15 @@ impl TokioBlockingClient {
         request: Request,
         strategy: &RequestStrategy,
     ) -> Response<T> {
-        let future = self.inner.execute(request, strategy);
+        self.block_on(self.inner.execute(request, strategy))
+    }
+
+    /// Like `execute`, but returns the raw body of a successful response.
+    pub fn execute_bytes(&self, request: Request, strategy: &RequestStrategy) -> Response<Vec<u8>> {
+        self.block_on(self.inner.execute_bytes(request, strategy))
+    }
+
+    fn block_on<T>(&self, future: crate::client::base::tokio::Response<T>) -> Response<T> {
         match self.runtime.block_on(async {
             // N.B. The `tokio::time::timeout` must be called from within a running async
             //      context or else it will panic (it registers with the thread-local timer).
@@ -29,6 +29,8 @@ mod base {
     feature = "runtime-blocking-rustls-webpki"
 ))]
 pub(crate) mod config {
+    pub(crate) use std::thread::sleep;
+
     pub(crate) use super::base::tokio_blocking::{err, ok};
     pub use super::base::tokio_blocking::{Response, TokioBlockingClient as BaseClient};
 }
@@ -41,12 +43,14 @@ pub(crate) mod config {
 pub(crate) mod config {
     pub(crate) use super::base::tokio::{err, ok};
     pub use super::base::tokio::{Response, TokioClient as BaseClient};
+    pub(crate) use tokio::time::sleep;
 }
 
 #[cfg(feature = "runtime-async-std-surf")]
 pub(crate) mod config {
     pub(crate) use super::base::async_std::{err, ok};
     pub use super::base::async_std::{AsyncStdClient as BaseClient, Response};
+    pub(crate) use async_std::task::sleep;
 }
 
 pub use config::BaseClient;
@@ -6,7 +6,7 @@ use crate::{
     config::err,
     generated::core::version::VERSION,
     params::AppInfo,
-    AccountId, ApplicationId, Headers, StripeError,
+    AccountId, ApplicationId, BodyCapture, Headers, StripeError,
 };
 
 static USER_AGENT: &str = concat!("Stripe/v1 RustBindings/", env!("CARGO_PKG_VERSION"));
@@ -19,6 +19,8 @@ pub struct Client {
     strategy: RequestStrategy,
     app_info: Option<AppInfo>,
     api_base: Url,
+    files_base: Url,
+    connect_base: Url,
     api_root: String,
 }
 
@@ -26,10 +28,15 @@ impl Client {
     /// Create a new account with the given secret key.
     pub fn new(secret_key: impl Into<String>) -> Self {
         Self::from_url("https://api.stripe.com/", secret_key)
+            .with_files_url("https://files.stripe.com/")
+            .with_connect_url("https://connect.stripe.com/")
     }
 
     /// Create a new account pointed at a specific URL. This is useful for testing.
+    ///
+    /// Requests to the files api, such as pdf downloads, and to Connect OAuth are sent to the same URL.
     pub fn from_url<'a>(url: impl Into<&'a str>, secret_key: impl Into<String>) -> Self {
+        let api_base = Url::parse(url.into()).expect("invalid url");
         Client {
             client: BaseClient::new(),
             secret_key: secret_key.into(),
@@ -41,11 +48,33 @@ impl Client {
             },
             strategy: RequestStrategy::Once,
             app_info: None,
-            api_base: Url::parse(url.into()).expect("invalid url"),
+            files_base: api_base.clone(),
+            connect_base: api_base.clone(),
+            api_base,
             api_root: "v1".to_string(),
         }
     }
 
+    /// Set the URL requests to the files api are sent to.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if `url` is not a valid URL.
+    pub fn with_files_url<'a>(mut self, url: impl Into<&'a str>) -> Self {
+        self.files_base = Url::parse(url.into()).expect("invalid url");
+        self
+    }
+
+    /// Set the URL Connect OAuth requests are sent to.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if `url` is not a valid URL.
+    pub fn with_connect_url<'a>(mut self, url: impl Into<&'a str>) -> Self {
+        self.connect_base = Url::parse(url.into()).expect("invalid url");
+        self
+    }
+
     /// Set the client id for the client.
     pub fn with_client_id(mut self, id: ApplicationId) -> Self {
         self.headers.client_id = Some(id);
@@ -64,6 +93,14 @@ impl Client {
         self
     }
 
+    /// Set how response bodies are kept when they cannot be deserialized.
+    ///
+    /// See [`BodyCapture`] for the defaults.
+    pub fn with_body_capture(mut self, capture: BodyCapture) -> Self {
+        self.client = self.client.with_body_capture(capture);
+        self
+    }
+
     /// Set the application info for the client.
     ///
     /// It is recommended that applications set this so that
@@ -100,6 +137,12 @@ impl Client {
         self.client.execute::<T>(self.create_request(Method::Get, url), &self.strategy)
     }
 
+    /// Make a `GET` http request to the files api, returning the raw response body
+    pub fn get_file_bytes(&self, path: &str) -> Response<Vec<u8>> {
+        let url = self.files_url(path);
+        self.client.execute_bytes(self.create_request(Method::Get, url), &self.strategy)
+    }
+
     /// Make a `DELETE` http request with just a path
     pub fn delete<T: DeserializeOwned + Send + 'static>(&self, path: &str) -> Response<T> {
         let url = self.url(path);
@@ -131,7 +174,23 @@ impl Client {
         path: &str,
         form: F,
     ) -> Response<T> {
-        let url = self.url(path);
+        self.post_form_to(self.url(path), form)
+    }
+
+    /// Make a `POST` http request with urlencoded body to Connect OAuth
+    pub fn post_connect_form<T: DeserializeOwned + Send + 'static, F: Serialize>(
+        &self,
+        path: &str,
+        form: F,
+    ) -> Response<T> {
+        self.post_form_to(self.connect_url(path), form)
+    }
+
+    fn post_form_to<T: DeserializeOwned + Send + 'static, F: Serialize>(
+        &self,
+        url: Url,
+        form: F,
+    ) -> Response<T> {
         let mut req = self.create_request(Method::Post, url);
 
         let mut params_buffer = Vec::new();
@@ -150,15 +209,52 @@ impl Client {
         self.client.execute::<T>(req, &self.strategy)
     }
 
+    /// Make a `POST` http request to the files api with a raw body
+    pub fn post_file_bytes<T: DeserializeOwned + Send + 'static>(
+        &self,
+        path: &str,
+        content_type: &str,
+        body: Vec<u8>,
+    ) -> Response<T> {
+        let mut req = self.create_request(Method::Post, self.files_url(path));
+        req.set_body(Body::from_bytes(body));
+        req.insert_header("content-type", content_type);
+        self.client.execute::<T>(req, &self.strategy)
+    }
+
     fn url(&self, path: &str) -> Url {
         let mut url = self.api_base.clone();
         url.set_path(&format!("{}/{}", self.api_root, path.trim_start_matches('/')));
         url
     }
 
+    fn files_url(&self, path: &str) -> Url {
+        let mut url = self.files_base.clone();
+        url.set_path(&format!("{}/{}", self.api_root, path.trim_start_matches('/')));
+        url
+    }
+
+    fn connect_url(&self, path: &str) -> Url {
+        let mut url = self.connect_base.clone();
+        url.set_path(path);
+        url
+    }
+
+    /// The URL of a Connect OAuth page, such as `/oauth/authorize`, with url query parameters.
+    #[cfg(feature = "connect")]
+    pub(crate) fn connect_url_with_params<P: Serialize>(
+        &self,
+        path: &str,
+        params: P,
+    ) -> Result<Url, StripeError> {
+        Self::with_params(self.connect_url(path), params)
+    }
+
     fn url_with_params<P: Serialize>(&self, path: &str, params: P) -> Result<Url, StripeError> {
-        let mut url = self.url(path);
+        Self::with_params(self.url(path), params)
+    }
 
+    fn with_params<P: Serialize>(mut url: Url, params: P) -> Result<Url, StripeError> {
         let mut params_buffer = Vec::new();
         let qs_ser = &mut serde_qs::Serializer::new(&mut params_buffer);
         serde_path_to_error::serialize(&params, qs_ser).map_err(StripeError::from)?;
@@ -228,4 +324,24 @@ mod test {
             )
         );
     }
+
+    #[cfg(feature = "async")]
+    #[tokio::test]
+    async fn file_bytes_use_the_files_url() {
+        use httpmock::{Method::GET, MockServer};
+
+        let api = MockServer::start_async().await;
+        let files = MockServer::start_async().await;
+        let client =
+            Client::from_url(&*api.url("/"), "sk_test_12345").with_files_url(&*files.url("/"));
+
+        let mock = files.mock(|when, then| {
+            when.method(GET).path("/v1/quotes/qt_123/pdf");
+            then.status(200).body("%PDF-1.4");
+        });
+
+        let pdf = client.get_file_bytes("/quotes/qt_123/pdf").await.unwrap();
+        assert_eq!(pdf, b"%PDF-1.4");
+        mock.assert_hits(1);
+    }
 }
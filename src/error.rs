@@ -1,37 +1,330 @@
+use std::borrow::Cow;
 use std::num::ParseIntError;
 
-use serde::{Deserialize, Serialize};
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Deserializer, Serialize};
 use thiserror::Error;
 
+use crate::ids::TestHelpersTestClockId;
+use crate::lenient::Lenient;
 use crate::params::to_snakecase;
+use crate::resources::{
+    PaymentIntent, PaymentMethod, PaymentSource, SetupIntent, TestHelpersTestClockStatus,
+};
 
 /// An error encountered when communicating with the Stripe API.
 #[derive(Debug, Error)]
+#[non_exhaustive]
 pub enum StripeError {
     #[error("error reported by stripe: {0}")]
-    Stripe(#[from] RequestError),
+    Stripe(Box<RequestError>),
+    #[error("error reported by stripe connect: {0}")]
+    OAuth(#[from] OAuthError),
+    #[error("{0}")]
+    TestClock(#[from] TestClockError),
     #[error("error serializing or deserializing a querystring: {0}")]
     QueryStringSerialize(#[from] serde_path_to_error::Error<serde_qs::Error>),
     #[error("error serializing or deserializing a request")]
     JSONSerialize(#[from] serde_path_to_error::Error<serde_json::Error>),
+    #[error("{0}")]
+    Deserialize(Box<DeserializeError>),
     #[error("attempted to access an unsupported version of the api")]
     UnsupportedVersion,
     #[error("error communicating with stripe: {0}")]
     ClientError(String),
+    #[error("error communicating with stripe: {0}")]
+    Transport(#[from] TransportError),
     #[error("timeout communicating with stripe")]
     Timeout,
 }
 
+impl StripeError {
+    /// Whether the request may succeed if it is sent again.
+    ///
+    /// Stripe's `Stripe-Should-Retry` header is respected when it was sent.
+    /// Otherwise conflicts, rate limits, server errors and network errors are retryable.
+    pub fn is_retryable(&self) -> bool {
+        match self {
+            StripeError::Stripe(err) => retryable_status(err.http_status, err.should_retry),
+            // a failed response whose body was empty or not an error, such as from a proxy
+            StripeError::Deserialize(err) if !(200..300).contains(&err.http_status) => {
+                retryable_status(err.http_status, err.should_retry)
+            }
+            StripeError::Transport(_) | StripeError::Timeout => true,
+            _ => false,
+        }
+    }
+
+    /// Whether the idempotency key was reused with different parameters,
+    /// or is in use by a request that is still being processed.
+    pub fn is_idempotency_conflict(&self) -> bool {
+        matches!(
+            self,
+            StripeError::Stripe(err) if err.error_type == ErrorType::IdempotencyError
+                || err.code == Some(ErrorCode::IdempotencyKeyInUse)
+        )
+    }
+
+    /// Whether the api key is missing, invalid or expired.
+    pub fn is_authentication(&self) -> bool {
+        matches!(
+            self,
+            StripeError::Stripe(err) if err.error_type == ErrorType::Authentication
+                || err.http_status == 401
+        )
+    }
+
+    /// Whether too many requests were made too quickly.
+    pub fn is_rate_limited(&self) -> bool {
+        matches!(
+            self,
+            StripeError::Stripe(err) if err.error_type == ErrorType::RateLimit
+                || err.http_status == 429
+        )
+    }
+
+    /// Whether a card was declined or its details were invalid.
+    ///
+    /// The reason is available in the error's `code` and `decline_code`.
+    pub fn is_card_decline(&self) -> bool {
+        matches!(self, StripeError::Stripe(err) if err.error_type == ErrorType::Card)
+    }
+
+    /// Whether stripe could not be reached, or did not respond in time.
+    pub fn is_network(&self) -> bool {
+        matches!(self, StripeError::Transport(_) | StripeError::Timeout)
+    }
+}
+
+fn retryable_status(http_status: u16, should_retry: Option<bool>) -> bool {
+    match should_retry {
+        Some(should_retry) => should_retry,
+        None => matches!(http_status, 409 | 429) || http_status >= 500,
+    }
+}
+
+impl From<RequestError> for StripeError {
+    fn from(err: RequestError) -> StripeError {
+        StripeError::Stripe(Box::new(err))
+    }
+}
+
 #[cfg(feature = "hyper")]
 impl From<hyper::Error> for StripeError {
     fn from(err: hyper::Error) -> StripeError {
-        StripeError::ClientError(err.to_string())
+        StripeError::Transport(TransportError::Hyper(err))
     }
 }
 
 impl From<http_types::Error> for StripeError {
     fn from(err: http_types::Error) -> StripeError {
-        StripeError::ClientError(err.to_string())
+        StripeError::Transport(TransportError::HttpTypes(err))
+    }
+}
+
+/// An error from the http client used to communicate with stripe.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum TransportError {
+    #[cfg(feature = "hyper")]
+    Hyper(hyper::Error),
+    HttpTypes(http_types::Error),
+}
+
+impl std::fmt::Display for TransportError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            #[cfg(feature = "hyper")]
+            TransportError::Hyper(err) => err.fmt(f),
+            TransportError::HttpTypes(err) => err.fmt(f),
+        }
+    }
+}
+
+impl std::error::Error for TransportError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            #[cfg(feature = "hyper")]
+            TransportError::Hyper(err) => Some(err),
+            TransportError::HttpTypes(err) => Some(err.as_ref()),
+        }
+    }
+}
+
+/// A response from stripe that could not be deserialized.
+#[derive(Error)]
+#[error("error deserializing the response to {request_path} ({http_status}): {error}")]
+pub struct DeserializeError {
+    /// The underlying error, with the path of the field that failed.
+    #[source]
+    pub error: serde_path_to_error::Error<serde_json::Error>,
+    /// The HTTP status in the response.
+    pub http_status: u16,
+    /// The path of the request, such as `/v1/customers/cus_123`.
+    pub request_path: String,
+    /// The response body, redacted and capped as configured by a [`BodyCapture`].
+    pub body: Vec<u8>,
+    /// Whether the body was longer than the captured part.
+    pub truncated: bool,
+    /// The value of the `Stripe-Should-Retry` header, if the request failed and it was sent.
+    pub should_retry: Option<bool>,
+}
+
+impl DeserializeError {
+    /// The captured body as text.
+    pub fn body_text(&self) -> Cow<'_, str> {
+        String::from_utf8_lossy(&self.body)
+    }
+}
+
+impl std::fmt::Debug for DeserializeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("DeserializeError")
+            .field("error", &self.error)
+            .field("http_status", &self.http_status)
+            .field("request_path", &self.request_path)
+            .field("body", &self.body_text())
+            .field("truncated", &self.truncated)
+            .field("should_retry", &self.should_retry)
+            .finish()
+    }
+}
+
+/// How much of a response body is kept in a [`DeserializeError`].
+///
+/// By default up to 64 KiB are kept, and the values of fields that usually hold
+/// secrets, such as `client_secret`, are replaced with `[redacted]`.
+#[derive(Clone, Debug)]
+pub struct BodyCapture {
+    /// The maximum number of bytes to keep.
+    pub max_bytes: usize,
+    /// The names of json fields whose values are redacted, at any depth.
+    pub redacted_fields: Vec<String>,
+}
+
+impl Default for BodyCapture {
+    fn default() -> Self {
+        BodyCapture {
+            max_bytes: 64 * 1024,
+            redacted_fields: [
+                "client_secret",
+                "secret",
+                "account_number",
+                "id_number",
+                "ssn_last_4",
+            ]
+            .iter()
+            .map(|field| field.to_string())
+            .collect(),
+        }
+    }
+}
+
+impl BodyCapture {
+    /// Keep no part of the body.
+    pub fn disabled() -> Self {
+        BodyCapture { max_bytes: 0, redacted_fields: Vec::new() }
+    }
+
+    /// Also redact the values of these fields.
+    pub fn with_redacted_fields(
+        mut self,
+        fields: impl IntoIterator<Item = impl Into<String>>,
+    ) -> Self {
+        self.redacted_fields.extend(fields.into_iter().map(Into::into));
+        self
+    }
+
+    /// Deserialize a response body, capturing it on failure.
+    pub(crate) fn deserialize<T: DeserializeOwned>(
+        &self,
+        body: &[u8],
+        http_status: u16,
+        request_path: &str,
+    ) -> Result<T, StripeError> {
+        let json_deserializer = &mut serde_json::Deserializer::from_slice(body);
+        serde_path_to_error::deserialize(json_deserializer).map_err(|error| {
+            let (body, truncated) = self.capture(body);
+            StripeError::Deserialize(Box::new(DeserializeError {
+                error,
+                http_status,
+                request_path: request_path.to_string(),
+                body,
+                truncated,
+                should_retry: None,
+            }))
+        })
+    }
+
+    /// Deserialize the body of a failed response, which holds either an api or an oauth error.
+    pub(crate) fn deserialize_error(
+        &self,
+        body: &[u8],
+        http_status: u16,
+        request_path: &str,
+        should_retry: Option<bool>,
+    ) -> StripeError {
+        match self.deserialize::<ErrorResponse>(body, http_status, request_path) {
+            Ok(ErrorResponse { mut error }) => {
+                error.http_status = http_status;
+                error.should_retry = should_retry;
+                StripeError::Stripe(Box::new(error))
+            }
+            Err(err) => match serde_json::from_slice::<OAuthError>(body) {
+                Ok(mut error) => {
+                    error.http_status = http_status;
+                    StripeError::OAuth(error)
+                }
+                Err(_) => match err {
+                    StripeError::Deserialize(mut err) => {
+                        err.should_retry = should_retry;
+                        StripeError::Deserialize(err)
+                    }
+                    err => err,
+                },
+            },
+        }
+    }
+
+    fn capture(&self, body: &[u8]) -> (Vec<u8>, bool) {
+        if self.max_bytes == 0 {
+            return (Vec::new(), !body.is_empty());
+        }
+        let mut body = match serde_json::from_slice::<serde_json::Value>(body) {
+            // only re-encode the body when something was redacted
+            Ok(mut value) => match self.redact(&mut value) {
+                true => serde_json::to_vec(&value).unwrap_or_default(),
+                false => body.to_vec(),
+            },
+            Err(_) => body.to_vec(),
+        };
+        let truncated = body.len() > self.max_bytes;
+        body.truncate(self.max_bytes);
+        (body, truncated)
+    }
+
+    /// Redact the configured fields, returning whether any were found.
+    fn redact(&self, value: &mut serde_json::Value) -> bool {
+        let mut redacted = false;
+        match value {
+            serde_json::Value::Object(map) => {
+                for (key, value) in map.iter_mut() {
+                    if self.redacted_fields.iter().any(|field| field == key) && !value.is_null() {
+                        *value = serde_json::Value::String("[redacted]".into());
+                        redacted = true;
+                    } else {
+                        redacted |= self.redact(value);
+                    }
+                }
+            }
+            serde_json::Value::Array(values) => {
+                for value in values {
+                    redacted |= self.redact(value);
+                }
+            }
+            _ => {}
+        }
+        redacted
     }
 }
 
@@ -71,94 +364,327 @@ impl std::fmt::Display for ErrorType {
 }
 
 /// The list of possible values for a RequestError's code.
-#[derive(Clone, Copy, Debug, Deserialize, Serialize, Eq, PartialEq, Hash)]
+///
+/// For more details see <https://stripe.com/docs/error-codes>.
+#[derive(Clone, Debug, Deserialize, Serialize, Eq, PartialEq, Hash)]
 #[serde(rename_all = "snake_case")]
 #[non_exhaustive]
 pub enum ErrorCode {
     AccountAlreadyExists,
+    AccountClosed,
     AccountCountryInvalidAddress,
+    AccountErrorCountryChangeRequiresAdditionalSteps,
+    AccountInformationMismatch,
     AccountInvalid,
     AccountNumberInvalid,
+    AcssDebitSessionIncomplete,
     AlipayUpgradeRequired,
     AmountTooLarge,
     AmountTooSmall,
     ApiKeyExpired,
+    ApplicationFeesNotAllowed,
+    AuthenticationRequired,
     BalanceInsufficient,
+    BalanceInvalidParameter,
+    BankAccountBadRoutingNumbers,
+    BankAccountDeclined,
     BankAccountExists,
+    BankAccountRestricted,
     BankAccountUnusable,
     BankAccountUnverified,
     BankAccountVerificationFailed,
+    BillingInvalidMandate,
     BitcoinUpgradeRequired,
+    CaptureChargeAuthorizationExpired,
+    CaptureUnauthorizedPayment,
+    CardDeclineRateLimitExceeded,
     CardDeclined,
+    CardholderPhoneNumberRequired,
     ChargeAlreadyCaptured,
     ChargeAlreadyRefunded,
     ChargeDisputed,
+    ChargeExceedsSourceLimit,
     ChargeExpiredForCapture,
+    ChargeInvalidParameter,
+    ChargeNotRefundable,
+    ClearingCodeUnsupported,
+    CountryCodeInvalid,
     CountryUnsupported,
     CouponExpired,
+    CustomerMaxPaymentMethods,
     CustomerMaxSubscriptions,
+    DebitNotAuthorized,
     EmailInvalid,
     ExpiredCard,
+    FinancialConnectionsAccountInactive,
+    FinancialConnectionsNoSuccessfulTransactionRefresh,
     IdempotencyKeyInUse,
     IncorrectAddress,
     IncorrectCvc,
     IncorrectNumber,
     IncorrectZip,
+    InstantPayoutsConfigDisabled,
+    InstantPayoutsCurrencyDisabled,
+    InstantPayoutsLimitExceeded,
     InstantPayoutsUnsupported,
+    InsufficientFunds,
+    IntentInvalidState,
+    IntentVerificationMethodMissing,
     InvalidCardType,
+    InvalidCharacters,
     InvalidChargeAmount,
     InvalidCvc,
     InvalidExpiryMonth,
     InvalidExpiryYear,
     InvalidNumber,
     InvalidSourceUsage,
+    InvalidTaxLocation,
     InvoiceNoCustomerLineItems,
+    InvoiceNoPaymentMethodTypes,
     InvoiceNoSubscriptionLineItems,
     InvoiceNotEditable,
+    InvoiceOnBehalfOfNotEditable,
+    InvoicePaymentIntentRequiresAction,
     InvoiceUpcomingNone,
     LivemodeMismatch,
+    LockTimeout,
     Missing,
+    NoAccount,
+    NotAllowedOnStandardAccount,
     OrderCreationFailed,
     OrderRequiredSettings,
     OrderStatusInvalid,
     OrderUpstreamTimeout,
     OutOfInventory,
+    OwnershipDeclarationNotAllowed,
     ParameterInvalidEmpty,
     ParameterInvalidInteger,
     ParameterInvalidStringBlank,
     ParameterInvalidStringEmpty,
     ParameterMissing,
     ParameterUnknown,
-    PaymentMethodUnactivated,
+    ParametersExclusive,
+    PaymentIntentActionRequired,
+    PaymentIntentAuthenticationFailure,
+    PaymentIntentIncompatiblePaymentMethod,
+    PaymentIntentInvalidParameter,
+    PaymentIntentKonbiniRejectedConfirmationNumber,
+    PaymentIntentMandateInvalid,
+    PaymentIntentPaymentAttemptExpired,
+    PaymentIntentPaymentAttemptFailed,
     PaymentIntentUnexpectedState,
+    PaymentMethodBankAccountAlreadyVerified,
+    PaymentMethodBankAccountBlocked,
+    PaymentMethodBillingDetailsAddressMissing,
+    PaymentMethodConfigurationFailures,
+    PaymentMethodCurrencyMismatch,
+    PaymentMethodCustomerDecline,
+    PaymentMethodInvalidParameter,
+    PaymentMethodInvalidParameterTestmode,
+    PaymentMethodMicrodepositFailed,
+    PaymentMethodMicrodepositVerificationAmountsInvalid,
+    PaymentMethodMicrodepositVerificationAmountsMismatch,
+    PaymentMethodMicrodepositVerificationAttemptsExceeded,
+    PaymentMethodMicrodepositVerificationDescriptorCodeMismatch,
+    PaymentMethodMicrodepositVerificationTimeout,
+    PaymentMethodNotAvailable,
+    PaymentMethodProviderDecline,
+    PaymentMethodProviderTimeout,
+    PaymentMethodUnactivated,
+    PaymentMethodUnexpectedState,
+    PaymentMethodUnsupportedType,
+    PayoutReconciliationNotReady,
+    PayoutsLimitExceeded,
     PayoutsNotAllowed,
+    PlatformAccountRequired,
     PlatformApiKeyExpired,
     PostalCodeInvalid,
     ProcessingError,
     ProductInactive,
+    ProgressiveOnboardingLimitExceeded,
     RateLimit,
+    ReferToCustomer,
+    RefundDisputedPayment,
     ResourceAlreadyExists,
     ResourceMissing,
+    ReturnIntentAlreadyProcessed,
     RoutingNumberInvalid,
     SecretKeyRequired,
     SepaUnsupportedAccount,
+    SetupAttemptFailed,
+    SetupIntentAuthenticationFailure,
+    SetupIntentInvalidParameter,
+    SetupIntentMandateInvalid,
+    SetupIntentSetupAttemptExpired,
+    SetupIntentUnexpectedState,
     ShippingCalculationFailed,
     SkuInactive,
     StateUnsupported,
+    StatusTransitionInvalid,
     TaxIdInvalid,
     TaxesCalculationFailed,
+    TerminalLocationCountryUnsupported,
+    TerminalReaderBusy,
+    TerminalReaderOffline,
+    TerminalReaderTimeout,
     TestmodeChargesOnly,
     TlsVersionUnsupported,
     TokenAlreadyUsed,
+    TokenCardNetworkInvalid,
     TokenInUse,
+    TransferSourceBalanceParametersMismatch,
     TransfersNotAllowed,
     UpstreamOrderCreationFailed,
     UrlInvalid,
+    /// A code that this version of the library does not know about.
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ErrorCode {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}", to_snakecase(&format!("{:?}", self)))
+        match self {
+            ErrorCode::Unknown(code) => f.write_str(code),
+            code => write!(f, "{}", to_snakecase(&format!("{:?}", code))),
+        }
+    }
+}
+
+/// The list of possible values for a RequestError's decline code.
+///
+/// For more details see <https://stripe.com/docs/declines/codes>.
+#[derive(Clone, Debug, Deserialize, Serialize, Eq, PartialEq, Hash)]
+#[serde(rename_all = "snake_case")]
+#[non_exhaustive]
+pub enum DeclineCode {
+    AuthenticationRequired,
+    ApproveWithId,
+    CallIssuer,
+    CardNotSupported,
+    CardVelocityExceeded,
+    CurrencyNotSupported,
+    DoNotHonor,
+    DoNotTryAgain,
+    DuplicateTransaction,
+    ExpiredCard,
+    Fraudulent,
+    GenericDecline,
+    IncorrectCvc,
+    IncorrectNumber,
+    IncorrectPin,
+    IncorrectZip,
+    InsufficientFunds,
+    InvalidAccount,
+    InvalidAmount,
+    InvalidCvc,
+    InvalidExpiryMonth,
+    InvalidExpiryYear,
+    InvalidNumber,
+    InvalidPin,
+    IssuerNotAvailable,
+    LostCard,
+    MerchantBlacklist,
+    NewAccountInformationAvailable,
+    NoActionTaken,
+    NotPermitted,
+    OfflinePinRequired,
+    OnlineOrOfflinePinRequired,
+    PickupCard,
+    PinTryExceeded,
+    ProcessingError,
+    ReenterTransaction,
+    RestrictedCard,
+    RevocationOfAllAuthorizations,
+    RevocationOfAuthorization,
+    SecurityViolation,
+    ServiceNotAllowed,
+    StolenCard,
+    StopPaymentOrder,
+    TestmodeDecline,
+    TransactionNotAllowed,
+    TryAgainLater,
+    WithdrawalCountLimitExceeded,
+    /// A code that this version of the library does not know about.
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+impl DeclineCode {
+    /// Whether the same payment may succeed if it is attempted again later.
+    pub fn is_retryable(&self) -> bool {
+        matches!(
+            self,
+            DeclineCode::ApproveWithId
+                | DeclineCode::CardVelocityExceeded
+                | DeclineCode::InsufficientFunds
+                | DeclineCode::IssuerNotAvailable
+                | DeclineCode::ProcessingError
+                | DeclineCode::ReenterTransaction
+                | DeclineCode::TryAgainLater
+                | DeclineCode::WithdrawalCountLimitExceeded
+        )
+    }
+
+    /// Whether the card was declined because it is suspected to be fraudulent.
+    ///
+    /// Stripe recommends not revealing this to the customer, but
+    /// showing them the message of a generic decline instead.
+    pub fn is_fraud(&self) -> bool {
+        matches!(
+            self,
+            DeclineCode::Fraudulent
+                | DeclineCode::LostCard
+                | DeclineCode::MerchantBlacklist
+                | DeclineCode::PickupCard
+                | DeclineCode::RestrictedCard
+                | DeclineCode::SecurityViolation
+                | DeclineCode::StolenCard
+        )
+    }
+
+    /// Whether the customer can resolve the decline themselves, by correcting
+    /// their card details, authenticating or contacting their card issuer.
+    pub fn is_customer_actionable(&self) -> bool {
+        matches!(
+            self,
+            DeclineCode::AuthenticationRequired
+                | DeclineCode::CallIssuer
+                | DeclineCode::CardNotSupported
+                | DeclineCode::CardVelocityExceeded
+                | DeclineCode::CurrencyNotSupported
+                | DeclineCode::DoNotHonor
+                | DeclineCode::DoNotTryAgain
+                | DeclineCode::ExpiredCard
+                | DeclineCode::GenericDecline
+                | DeclineCode::IncorrectCvc
+                | DeclineCode::IncorrectNumber
+                | DeclineCode::IncorrectPin
+                | DeclineCode::IncorrectZip
+                | DeclineCode::InsufficientFunds
+                | DeclineCode::InvalidAccount
+                | DeclineCode::InvalidCvc
+                | DeclineCode::InvalidExpiryMonth
+                | DeclineCode::InvalidExpiryYear
+                | DeclineCode::InvalidNumber
+                | DeclineCode::InvalidPin
+                | DeclineCode::NewAccountInformationAvailable
+                | DeclineCode::NotPermitted
+                | DeclineCode::OfflinePinRequired
+                | DeclineCode::OnlineOrOfflinePinRequired
+                | DeclineCode::PinTryExceeded
+                | DeclineCode::ServiceNotAllowed
+                | DeclineCode::TransactionNotAllowed
+                | DeclineCode::WithdrawalCountLimitExceeded
+        )
+    }
+}
+
+impl std::fmt::Display for DeclineCode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DeclineCode::Unknown(code) => f.write_str(code),
+            code => write!(f, "{}", to_snakecase(&format!("{:?}", code))),
+        }
     }
 }
 
@@ -172,6 +698,10 @@ pub struct RequestError {
     #[serde(skip_deserializing)]
     pub http_status: u16,
 
+    /// The `Stripe-Should-Retry` header in the response, if it was sent.
+    #[serde(skip_deserializing)]
+    pub should_retry: Option<bool>,
+
     /// The type of error returned.
     #[serde(rename = "type")]
     pub error_type: ErrorType,
@@ -186,10 +716,146 @@ pub struct RequestError {
 
     /// For card errors resulting from a bank decline, a string indicating the
     /// bank's reason for the decline if they provide one.
-    pub decline_code: Option<String>,
+    pub decline_code: Option<DeclineCode>,
+
+    /// For card errors resulting from a bank decline, a code indicating
+    /// whether and how the payment should be retried.
+    pub advice_code: Option<String>,
+
+    /// If the error is parameter-specific, the parameter related to the error.
+    ///
+    /// For example, you can use this to display a message near the correct form field.
+    pub param: Option<String>,
+
+    /// A URL to more information about the error code reported.
+    pub doc_url: Option<String>,
+
+    /// A URL to the request log entry in your dashboard.
+    pub request_log_url: Option<String>,
 
     /// The ID of the failed charge, if applicable.
     pub charge: Option<String>,
+
+    /// The PaymentIntent object for errors returned on a request involving a PaymentIntent.
+    #[serde(default, deserialize_with = "lenient_object")]
+    pub payment_intent: Option<Box<PaymentIntent>>,
+
+    /// The SetupIntent object for errors returned on a request involving a SetupIntent.
+    #[serde(default, deserialize_with = "lenient_object")]
+    pub setup_intent: Option<Box<SetupIntent>>,
+
+    /// The PaymentMethod object for errors returned on a request involving a PaymentMethod.
+    #[serde(default, deserialize_with = "lenient_object")]
+    pub payment_method: Option<Box<PaymentMethod>>,
+
+    /// If the error is specific to the type of payment method, the payment method type that had a problem.
+    ///
+    /// This field is only populated for invoice-related errors.
+    pub payment_method_type: Option<String>,
+
+    /// The source object for errors returned on a request involving a source.
+    ///
+    /// This is `source` in the response, renamed so as not to be mistaken for the error's source.
+    #[serde(rename = "source", default, deserialize_with = "lenient_object")]
+    pub payment_source: Option<Box<PaymentSource>>,
+}
+
+/// Leniently deserialize an object embedded in an error, so that a field this crate does
+/// not know about does not hide the error itself. Objects that cannot be read are `None`.
+fn lenient_object<'de, D, T>(deserializer: D) -> Result<Option<Box<T>>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: DeserializeOwned,
+{
+    let value = Option::<serde_json::Value>::deserialize(deserializer)?;
+    Ok(value.and_then(|value| Lenient::from_value(value).ok()).map(|object| Box::new(object.value)))
+}
+
+/// An error reported by stripe's Connect OAuth endpoints.
+///
+/// For more details see <https://stripe.com/docs/connect/oauth-reference#post-token-errors>.
+#[derive(Debug, Default, Deserialize, Error)]
+#[error("{error} ({http_status}) with description: {description:?}")]
+pub struct OAuthError {
+    /// The HTTP status in the response.
+    #[serde(skip_deserializing)]
+    pub http_status: u16,
+
+    /// The type of error returned.
+    pub error: OAuthErrorCode,
+
+    /// A human-readable description of the error.
+    #[serde(rename = "error_description")]
+    pub description: Option<String>,
+}
+
+/// The list of possible values for an OAuthError's `error`.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+#[non_exhaustive]
+pub enum OAuthErrorCode {
+    /// The user denied the authorization request.
+    AccessDenied,
+    /// The `client_id` does not belong to the platform, or the account is not connected to it.
+    InvalidClient,
+    /// The authorization code or refresh token is invalid, expired or already used.
+    InvalidGrant,
+    /// A parameter is missing or invalid.
+    InvalidRequest,
+    /// The requested scope is invalid.
+    InvalidScope,
+    /// The `grant_type` is not `authorization_code` or `refresh_token`.
+    UnsupportedGrantType,
+    /// The `response_type` is not `code`.
+    UnsupportedResponseType,
+    /// An error this library does not know about.
+    #[serde(other)]
+    Unknown,
+}
+
+impl Default for OAuthErrorCode {
+    fn default() -> Self {
+        OAuthErrorCode::Unknown
+    }
+}
+
+impl std::fmt::Display for OAuthErrorCode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", to_snakecase(&format!("{:?}", self)))
+    }
+}
+
+/// A test clock that stripe failed to advance, or that was still advancing when
+/// [`TestHelpersTestClock::advance_and_wait`](crate::TestHelpersTestClock::advance_and_wait)
+/// gave up on it.
+#[derive(Debug, Error)]
+#[error("test clock {id} {kind} ({status})")]
+pub struct TestClockError {
+    /// The id of the clock.
+    pub id: TestHelpersTestClockId,
+    /// Why the clock is not ready.
+    pub kind: TestClockErrorKind,
+    /// The status the clock was left in.
+    pub status: TestHelpersTestClockStatus,
+}
+
+/// Why a test clock is not ready, see [`TestClockError`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum TestClockErrorKind {
+    /// Stripe failed to advance the clock.
+    Failed,
+    /// The clock was still advancing after the most retrievals allowed.
+    StillAdvancing,
+}
+
+impl std::fmt::Display for TestClockErrorKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TestClockErrorKind::Failed => f.write_str("failed to advance"),
+            TestClockErrorKind::StillAdvancing => f.write_str("is still advancing"),
+        }
+    }
 }
 
 /// The structure of the json body when an error is included in
@@ -201,6 +867,7 @@ pub struct ErrorResponse {
 
 /// An error encountered when communicating with the Stripe API webhooks.
 #[derive(Debug, Error)]
+#[non_exhaustive]
 pub enum WebhookError {
     #[error("invalid key length")]
     BadKey,
@@ -212,4 +879,131 @@ pub enum WebhookError {
     BadTimestamp(i64),
     #[error("error parsing event object")]
     BadParse(#[from] serde_json::Error),
+    #[error("{0}")]
+    ApiVersionMismatch(Box<ApiVersionMismatch>),
+}
+
+/// An event rendered by a different api version than the one this crate was generated for.
+///
+/// The raw payload is kept, so that the event can still be handled as untyped json.
+#[derive(Debug, Error)]
+#[error("event rendered with api version {event_version}, expected {expected_version}")]
+pub struct ApiVersionMismatch {
+    /// The `api_version` of the event.
+    pub event_version: String,
+    pub expected_version: crate::ApiVersion,
+    /// The original event payload.
+    pub payload: String,
+    /// The error deserializing the event, if it could not be deserialized.
+    #[source]
+    pub source: Option<serde_json::Error>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        BodyCapture, DeclineCode, ErrorCode, ErrorResponse, ErrorType, RequestError, StripeError,
+    };
+
+    #[test]
+    fn unknown_codes() {
+        let body = r#"{"error": {"type": "card_error", "code": "card_declined",
+            "decline_code": "card_not_activated"}}"#;
+        let err = serde_json::from_str::<ErrorResponse>(body).unwrap().error;
+        assert_eq!(err.code, Some(ErrorCode::CardDeclined));
+        let decline_code = err.decline_code.unwrap();
+        assert_eq!(decline_code, DeclineCode::Unknown("card_not_activated".to_string()));
+        assert_eq!(decline_code.to_string(), "card_not_activated");
+
+        let body = r#"{"error": {"type": "invalid_request_error", "code": "brand_new_code"}}"#;
+        let err = serde_json::from_str::<ErrorResponse>(body).unwrap().error;
+        assert_eq!(err.code, Some(ErrorCode::Unknown("brand_new_code".to_string())));
+    }
+
+    #[test]
+    fn lenient_related_objects() {
+        let body = r#"{"error": {"type": "card_error", "code": "card_declined",
+            "payment_intent": {
+              "id": "pi_123", "object": "payment_intent", "amount": 100,
+              "amount_capturable": 0, "amount_received": 0,
+              "capture_method": "automatic", "confirmation_method": "automatic",
+              "created": 1, "currency": "usd", "livemode": false, "metadata": {},
+              "payment_method_types": ["card"], "status": "requires_payment_method",
+              "setup_future_usage": "brand_new_usage"
+            },
+            "setup_intent": {"id": "seti_123", "status": "brand_new_status"}}}"#;
+        let err = serde_json::from_str::<ErrorResponse>(body).unwrap().error;
+        assert_eq!(err.code, Some(ErrorCode::CardDeclined));
+        let payment_intent = err.payment_intent.unwrap();
+        assert_eq!(payment_intent.id.as_str(), "pi_123");
+        assert!(payment_intent.setup_future_usage.is_none());
+        assert!(err.setup_intent.is_none());
+        assert!(err.payment_method.is_none());
+    }
+
+    #[test]
+    fn code_display_matches_serde() {
+        for code in [
+            ErrorCode::PaymentIntentAuthenticationFailure,
+            ErrorCode::PaymentMethodMicrodepositVerificationDescriptorCodeMismatch,
+            ErrorCode::TestmodeChargesOnly,
+        ] {
+            assert_eq!(serde_json::to_value(&code).unwrap(), code.to_string());
+        }
+        let code = DeclineCode::OnlineOrOfflinePinRequired;
+        assert_eq!(serde_json::to_value(&code).unwrap(), code.to_string());
+    }
+
+    #[test]
+    fn classify_declines() {
+        assert!(DeclineCode::TryAgainLater.is_retryable());
+        assert!(!DeclineCode::StolenCard.is_retryable());
+        assert!(DeclineCode::StolenCard.is_fraud());
+        assert!(!DeclineCode::StolenCard.is_customer_actionable());
+        assert!(DeclineCode::IncorrectCvc.is_customer_actionable());
+        assert!(!DeclineCode::Unknown("new".to_string()).is_customer_actionable());
+    }
+
+    #[test]
+    fn classify_errors() {
+        let err = |error_type, http_status, should_retry| {
+            StripeError::Stripe(Box::new(RequestError {
+                error_type,
+                http_status,
+                should_retry,
+                ..Default::default()
+            }))
+        };
+
+        assert!(err(ErrorType::Api, 500, None).is_retryable());
+        assert!(!err(ErrorType::Api, 500, Some(false)).is_retryable());
+        assert!(err(ErrorType::InvalidRequest, 400, Some(true)).is_retryable());
+        assert!(!err(ErrorType::InvalidRequest, 400, None).is_retryable());
+        assert!(err(ErrorType::RateLimit, 429, None).is_rate_limited());
+        assert!(err(ErrorType::Authentication, 401, None).is_authentication());
+        assert!(err(ErrorType::IdempotencyError, 400, None).is_idempotency_conflict());
+        assert!(err(ErrorType::Card, 402, None).is_card_decline());
+        assert!(!err(ErrorType::Card, 402, None).is_network());
+        assert!(StripeError::Timeout.is_network());
+        assert!(StripeError::Timeout.is_retryable());
+    }
+
+    #[test]
+    fn classify_unreadable_errors() {
+        let capture = BodyCapture::default();
+        let err = |body: &[u8], http_status, should_retry| {
+            capture.deserialize_error(body, http_status, "/v1/customers", should_retry)
+        };
+
+        assert!(matches!(err(b"", 503, None), StripeError::Deserialize(_)));
+        assert!(err(b"", 503, None).is_retryable());
+        assert!(err(b"<html>Bad Gateway</html>", 502, None).is_retryable());
+        assert!(!err(b"", 503, Some(false)).is_retryable());
+        assert!(err(b"", 400, Some(true)).is_retryable());
+        assert!(!err(b"", 400, None).is_retryable());
+        assert!(!capture
+            .deserialize::<serde_json::Value>(b"", 200, "/v1/customers")
+            .unwrap_err()
+            .is_retryable());
+    }
 }
@@ -491,6 +491,7 @@ def_id!(
         TransferReversal(TransferReversalId),
     }
 );
+def_id!(CapabilityId: String); // N.B. A capability id is the name of the capability, eg. `card_payments`
 def_id!(CardId, "card_");
 def_id!(CardTokenId, "tok_");
 def_id!(ChargeId, "ch_" | "py_"); // TODO: Understand (and then document) why "py_" is a valid charge id
@@ -506,8 +507,21 @@ def_id!(DiscountId, "di_");
 def_id!(DisputeId, "dp_" | "du_");
 def_id!(EphemeralKeyId, "ephkey_");
 def_id!(EventId, "evt_");
+def_id!(
+    enum ExternalAccountId {
+        #[default]
+        BankAccount(BankAccountId),
+        Card(CardId),
+    }
+);
 def_id!(FileId, "file_");
 def_id!(FileLinkId, "link_");
+def_id!(FinancialConnectionsAccountId, "fca_");
+def_id!(FinancialConnectionsAccountOwnerId, "fcaowner_");
+def_id!(FinancialConnectionsAccountOwnershipId, "fcaowns_");
+def_id!(FinancialConnectionsSessionId, "fcsess_");
+def_id!(IdentityVerificationReportId, "vr_");
+def_id!(IdentityVerificationSessionId, "vs_");
 def_id!(InvoiceId, "in_", { _ });
 def_id!(InvoiceItemId, "ii_");
 def_id!(InvoiceLineItemIdWebhook, "il_");
@@ -556,6 +570,9 @@ def_id!(PriceId, "price_");
 def_id!(ProductId: String); // N.B. A product id can be user-provided so can be any arbitrary string
 def_id!(PromotionCodeId, "promo_");
 def_id!(QuoteId, "qt_");
+def_id!(RadarEarlyFraudWarningId, "issfr_");
+def_id!(RadarValueListId, "rsl_");
+def_id!(RadarValueListItemId, "rsli_");
 def_id!(RecipientId: String); // FIXME: This doesn't seem to be documented yet
 def_id!(RefundId, "re_" | "pyr_");
 def_id!(ReserveTransactionId, "rtx");
@@ -570,10 +587,14 @@ def_id!(SubscriptionId, "sub_");
 def_id!(SubscriptionItemId, "si_");
 def_id!(SubscriptionLineId, "sli_");
 def_id!(SubscriptionScheduleId, "sub_sched_");
+def_id!(TaxCalculationId, "taxcalc_");
+def_id!(TaxCalculationLineItemId, "tax_li_");
 def_id!(TaxIdId, "txi_");
 def_id!(TaxCodeId, "txcd_");
 def_id!(TaxDeductedAtSourceId, "itds");
 def_id!(TaxRateId, "txr_");
+def_id!(TaxTransactionId, "tax_");
+def_id!(TaxTransactionLineItemId, "tax_li_");
 def_id!(TerminalConfigurationId, "tmc_");
 def_id!(TerminalLocationId, "tml_");
 def_id!(TerminalReaderId, "tmr_");
@@ -588,6 +609,16 @@ def_id!(
 def_id!(TopupId, "tu_");
 def_id!(TransferId, "tr_");
 def_id!(TransferReversalId, "trr_");
+def_id!(TreasuryCreditReversalId, "credrev_");
+def_id!(TreasuryDebitReversalId, "debrev_");
+def_id!(TreasuryFinancialAccountId, "fa_");
+def_id!(TreasuryInboundTransferId, "ibt_");
+def_id!(TreasuryOutboundPaymentId, "obp_");
+def_id!(TreasuryOutboundTransferId, "obt_");
+def_id!(TreasuryReceivedCreditId, "rc_");
+def_id!(TreasuryReceivedDebitId, "rd_");
+def_id!(TreasuryTransactionId, "trxn_");
+def_id!(TreasuryTransactionEntryId, "trxne_");
 def_id!(UsageRecordId, "mbur_");
 def_id!(UsageRecordSummaryId, "urs_" | "sis_");
 def_id!(WebhookEndpointId, "we_");
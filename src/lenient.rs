@@ -0,0 +1,166 @@
+use serde::de::{DeserializeOwned, Error as _};
+use serde::{Deserialize, Deserializer};
+use serde_json::Value;
+use serde_path_to_error::Segment;
+
+/// The most fields that are skipped before giving up.
+const MAX_SKIPPED: usize = 64;
+
+/// A value deserialized leniently, along with what had to be ignored to do so.
+///
+/// Any request can be made lenient by asking the client for a `Lenient<T>` instead of a `T`:
+///
+/// ```rust,ignore
+/// let customer: Lenient<Customer> = client.get("/customers/cus_123").await?;
+/// for field in &customer.skipped {
+///     log::warn!("skipped {}: {}", field.path, field.reason);
+/// }
+/// ```
+///
+/// Fields that fail to deserialize, for example because stripe returned an enum
+/// value this crate does not know about, are removed and recorded in `skipped`. If a
+/// removed field is required, the object containing it is removed in turn, up to the
+/// nearest optional field. Fields that are not part of `T` are recorded in `unknown`.
+#[derive(Clone, Debug)]
+pub struct Lenient<T> {
+    pub value: T,
+    /// Fields that could not be deserialized, and were removed.
+    pub skipped: Vec<SkippedField>,
+    /// The paths of fields that are not part of `T`.
+    pub unknown: Vec<String>,
+}
+
+/// A field that was removed by a [`Lenient`] deserialization.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SkippedField {
+    /// The path of the field, such as `invoice_settings.default_payment_method`.
+    pub path: String,
+    /// Why the field could not be deserialized.
+    pub reason: String,
+}
+
+impl<T: DeserializeOwned> Lenient<T> {
+    /// Leniently deserialize a json value.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the value is still invalid once every
+    /// failing field has been removed, for example because the top level is invalid.
+    pub fn from_value(
+        mut value: Value,
+    ) -> Result<Self, serde_path_to_error::Error<serde_json::Error>> {
+        let mut skipped = Vec::new();
+        loop {
+            let mut unknown = Vec::new();
+            let mut track = |path: serde_ignored::Path<'_>| unknown.push(path.to_string());
+            let deserializer = serde_ignored::Deserializer::new(&value, &mut track);
+            let err = match serde_path_to_error::deserialize(deserializer) {
+                Ok(value) => return Ok(Lenient { value, skipped, unknown }),
+                Err(err) => err,
+            };
+
+            let segments = err.path().iter().collect::<Vec<_>>();
+            if skipped.len() >= MAX_SKIPPED || !remove(&mut value, &segments) {
+                return Err(err);
+            }
+            skipped.push(SkippedField {
+                path: err.path().to_string(),
+                reason: err.inner().to_string(),
+            });
+        }
+    }
+}
+
+impl<'de, T: DeserializeOwned> Deserialize<'de> for Lenient<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = Value::deserialize(deserializer)?;
+        Lenient::from_value(value).map_err(D::Error::custom)
+    }
+}
+
+/// Remove the value at `path`, returning whether there was one.
+fn remove(value: &mut Value, path: &[&Segment]) -> bool {
+    let (last, parents) = match path.split_last() {
+        Some(split) => split,
+        None => return false,
+    };
+    let mut parent = value;
+    for segment in parents {
+        parent = match (parent, segment) {
+            (Value::Object(map), Segment::Map { key }) => match map.get_mut(key) {
+                Some(value) => value,
+                None => return false,
+            },
+            (Value::Array(values), Segment::Seq { index }) => match values.get_mut(*index) {
+                Some(value) => value,
+                None => return false,
+            },
+            _ => return false,
+        };
+    }
+    match (parent, last) {
+        (Value::Object(map), Segment::Map { key }) => map.remove(key).is_some(),
+        (Value::Array(values), Segment::Seq { index }) if *index < values.len() => {
+            values.remove(*index);
+            true
+        }
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde::Deserialize;
+
+    use super::Lenient;
+
+    #[derive(Debug, Deserialize, PartialEq)]
+    #[serde(rename_all = "snake_case")]
+    enum Status {
+        Active,
+    }
+
+    #[derive(Debug, Deserialize)]
+    struct Item {
+        id: String,
+        status: Status,
+    }
+
+    #[derive(Debug, Deserialize)]
+    struct Parent {
+        id: String,
+        status: Option<Status>,
+        item: Option<Item>,
+        items: Vec<Item>,
+    }
+
+    #[test]
+    fn skips_invalid_fields() {
+        let value = serde_json::json!({
+            "id": "par_123",
+            "status": "archived",
+            "item": {"id": "itm_1", "status": "archived"},
+            "items": [{"id": "itm_2", "status": "active"}, {"id": "itm_3", "status": "paused"}],
+            "extra": true,
+        });
+
+        let parent = Lenient::<Parent>::from_value(value).unwrap();
+        assert_eq!(parent.value.id, "par_123");
+        assert_eq!(parent.value.status, None);
+        assert!(parent.value.item.is_none());
+        assert_eq!(parent.value.items.len(), 1);
+        assert_eq!(parent.value.items[0].id, "itm_2");
+        assert_eq!(parent.value.items[0].status, Status::Active);
+
+        let mut skipped = parent.skipped.iter().map(|s| s.path.as_str()).collect::<Vec<_>>();
+        skipped.sort_unstable();
+        assert_eq!(skipped, vec!["item", "item.status", "items[1]", "items[1].status", "status"]);
+        assert_eq!(parent.unknown, vec!["extra"]);
+    }
+
+    #[test]
+    fn fails_when_nothing_can_be_skipped() {
+        let value = serde_json::json!({"status": "active", "items": []});
+        assert!(Lenient::<Parent>::from_value(value).is_err());
+    }
+}
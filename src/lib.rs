@@ -64,8 +64,11 @@
 mod client;
 mod error;
 mod ids;
+mod lenient;
 mod params;
 mod resources;
+#[cfg(feature = "webhook-events")]
+mod webhooks;
 
 // N.B. Ideally we would support both a blocking client and
 //      an async client without a feature flag, but the originally
@@ -75,9 +78,16 @@ mod resources;
 // See https://github.com/wyyerd/stripe-rs/issues/24#issuecomment-451514187
 // See https://github.com/rust-lang/rust/issues/44265
 pub use crate::client::*;
-pub use crate::error::{ErrorCode, ErrorType, RequestError, StripeError, WebhookError};
+pub use crate::error::{
+    ApiVersionMismatch, BodyCapture, DeclineCode, DeserializeError, ErrorCode, ErrorType,
+    OAuthError, OAuthErrorCode, RequestError, StripeError, TestClockError, TestClockErrorKind,
+    TransportError, WebhookError,
+};
 pub use crate::ids::*;
+pub use crate::lenient::{Lenient, SkippedField};
 pub use crate::params::{
     Expandable, Headers, IdOrCreate, List, Metadata, Object, RangeBounds, RangeQuery, Timestamp,
 };
 pub use crate::resources::*;
+#[cfg(feature = "webhook-events")]
+pub use crate::webhooks::*;
@@ -18,11 +18,13 @@ mod core {
     pub mod balance_transaction_ext;
     pub mod charge_ext;
     pub mod customer_ext;
+    pub mod dispute_ext;
+    pub mod file_ext;
     pub mod payment_intent_ext;
     pub mod payment_source;
     pub mod payout_ext;
     pub mod placeholders;
-    pub mod setup_intent_ext;
+    pub mod test_helpers_test_clock_ext;
     pub mod token_ext;
 }
 
@@ -34,6 +36,8 @@ mod payment {
     pub mod source_ext;
 }
 
+#[cfg(feature = "events")]
+mod event_version;
 #[cfg(feature = "events")]
 mod webhook_events;
 
@@ -44,6 +48,7 @@ mod billing {
     pub mod customer_balance_transaction_ext;
     pub mod invoice_ext;
     pub mod line_item_ext;
+    pub mod quote_ext;
     pub mod subscription_ext;
     pub mod usage_record_ext;
 }
@@ -57,7 +62,9 @@ mod checkout {
 #[path = "resources"]
 #[cfg(feature = "connect")]
 mod connect {
+    pub mod account_requirements_ext;
     pub mod login_links_ext;
+    pub mod oauth;
 }
 
 #[path = "resources"]
@@ -86,6 +93,8 @@ mod orders {
 #[cfg(feature = "webhook-endpoints")]
 mod webhook_endpoints {
     pub mod webhook_endpoint_ext;
+    #[cfg(feature = "events")]
+    pub mod webhook_endpoint_reconcile;
 }
 
 #[rustfmt::skip]
@@ -98,14 +107,18 @@ pub use {
         balance_transaction_ext::*,
         charge_ext::*,
         customer_ext::*,
+        dispute_ext::*,
+        file_ext::*,
         payment_intent_ext::*,
         payment_source::*,
         placeholders::*,
         payout_ext::*,
         token_ext::*,
-        setup_intent_ext::*,
+        test_helpers_test_clock_ext::*,
     },
     generated::core::{
+        account_requirements_alternative::*,
+        account_requirements_error::*,
         address::*,
         balance::*,
         balance_transaction::*,
@@ -163,6 +176,7 @@ pub use {
 #[rustfmt::skip]
 #[cfg(feature = "events")]
 pub use {
+    event_version::*,
     webhook_events::*,
     webhook_events::NotificationEventData,
     generated::event::*,
@@ -223,6 +237,7 @@ pub use {
         subscription::SubscriptionItemPriceDataRecurring as SubscriptionPriceDataRecurring,
         subscription::SubscriptionItemPriceData as SubscriptionPriceData,
         subscription::SubscriptionPaymentBehavior as SubscriptionPaymentBehavior,
+        subscription::SubscriptionProrationBehavior as SubscriptionProrationBehavior,
         subscription_schedule::*,
         subscription_billing_thresholds::*,
         subscription_item_billing_thresholds::*,
@@ -237,13 +252,16 @@ pub use {
 #[cfg(feature = "connect")]
 pub use {
     connect::{
+        account_requirements_ext::*,
         login_links_ext::*,
+        oauth::*,
     },
     generated::connect::{
         account_link::*,
         account::*,
         application::*,
         application_fee::*,
+        capability::*,
         connect_collection_transfer::*,
         fee_refund::*,
         login_link::*,
@@ -254,11 +272,37 @@ pub use {
     }
 };
 
+#[rustfmt::skip]
+#[cfg(feature = "financial-connections")]
+pub use {
+    generated::financial_connections::{
+        bank_connections_resource_accountholder::*,
+        financial_connections_account::*,
+        financial_connections_account_owner::*,
+        financial_connections_account_ownership::*,
+        financial_connections_session::*,
+    },
+};
+
 #[rustfmt::skip]
 #[cfg(feature = "fraud")]
 pub use {
     fraud::review_ext::*,
-    generated::fraud::review::*
+    generated::fraud::{
+        radar_early_fraud_warning::*,
+        radar_value_list::*,
+        radar_value_list_item::*,
+        review::*,
+    },
+};
+
+#[rustfmt::skip]
+#[cfg(feature = "identity")]
+pub use {
+    generated::identity::{
+        identity_verification_report::*,
+        identity_verification_session::*,
+    },
 };
 
 #[rustfmt::skip]
@@ -292,6 +336,19 @@ pub use {
     generated::scheduled_query_run::*,
 };
 
+#[rustfmt::skip]
+#[cfg(feature = "tax")]
+pub use {
+    generated::tax::{
+        tax_calculation::*,
+        tax_calculation_line_item::*,
+        tax_product_resource_customer_details::*,
+        tax_product_resource_line_item_tax_breakdown::*,
+        tax_transaction::*,
+        tax_transaction_line_item::*,
+    },
+};
+
 #[rustfmt::skip]
 #[cfg(feature = "terminal")]
 pub use {
@@ -303,6 +360,31 @@ pub use {
     },
 };
 
+#[rustfmt::skip]
+#[cfg(feature = "treasury")]
+pub use {
+    generated::treasury::{
+        treasury_credit_reversal::*,
+        treasury_debit_reversal::*,
+        treasury_financial_account::*,
+        treasury_financial_account_features::*,
+        treasury_financial_accounts_resource_ach_toggle_settings::*,
+        treasury_financial_accounts_resource_toggle_settings::*,
+        treasury_financial_accounts_resource_toggles_setting_status_details::*,
+        treasury_inbound_transfer::*,
+        treasury_outbound_payment::*,
+        treasury_outbound_transfer::*,
+        treasury_received_credit::*,
+        treasury_received_debit::*,
+        treasury_shared_resource_billing_details::*,
+        treasury_shared_resource_initiating_payment_method_details_initiating_payment_method_details::*,
+        treasury_transaction::*,
+        treasury_transaction_entry::*,
+        treasury_transactions_resource_balance_impact::*,
+        treasury_transactions_resource_flow_details::*,
+    },
+};
+
 #[rustfmt::skip]
 #[cfg(feature = "webhook-endpoints")]
 pub use {
@@ -310,6 +392,10 @@ pub use {
     generated::webhook_endpoints::webhook_endpoint::*,
 };
 
+#[rustfmt::skip]
+#[cfg(all(feature = "webhook-endpoints", feature = "events"))]
+pub use webhook_endpoints::webhook_endpoint_reconcile::*;
+
 #[cfg(not(feature = "full"))]
 pub use generated::placeholders::*;
 
@@ -1,6 +1,9 @@
 use serde::{Deserialize, Serialize};
 
-use crate::resources::{BankAccount, Card};
+use crate::client::{Client, Response};
+use crate::ids::{AccountId, ExternalAccountId};
+use crate::params::{Deleted, Expand, List, Metadata, Object, Paginable};
+use crate::resources::{Account, BankAccount, Card};
 
 #[derive(Clone, Debug, Deserialize, Serialize)]
 #[serde(untagged, rename_all = "snake_case")]
@@ -13,3 +16,384 @@ impl std::default::Default for ExternalAccount {
         Self::BankAccount(Default::default())
     }
 }
+
+impl Object for ExternalAccount {
+    type Id = ExternalAccountId;
+    fn id(&self) -> Self::Id {
+        match self {
+            ExternalAccount::BankAccount(x) => ExternalAccountId::BankAccount(x.id()),
+            ExternalAccount::Card(x) => ExternalAccountId::Card(x.id()),
+        }
+    }
+    fn object(&self) -> &'static str {
+        match self {
+            ExternalAccount::BankAccount(x) => x.object(),
+            ExternalAccount::Card(x) => x.object(),
+        }
+    }
+}
+
+impl Account {
+    /// List external accounts for an account.
+    pub fn list_external_accounts(
+        client: &Client,
+        id: &AccountId,
+        params: &ListAccountExternalAccounts<'_>,
+    ) -> Response<List<ExternalAccount>> {
+        client.get_query(&format!("/accounts/{}/external_accounts", id), &params)
+    }
+
+    /// Create an external account for a given account.
+    pub fn create_external_account(
+        client: &Client,
+        id: &AccountId,
+        params: CreateAccountExternalAccount<'_>,
+    ) -> Response<ExternalAccount> {
+        client.post_form(&format!("/accounts/{}/external_accounts", id), &params)
+    }
+
+    /// Retrieve a specified external account for a given account.
+    pub fn retrieve_external_account(
+        client: &Client,
+        id: &AccountId,
+        external_account: &ExternalAccountId,
+        expand: &[&str],
+    ) -> Response<ExternalAccount> {
+        client.get_query(
+            &format!("/accounts/{}/external_accounts/{}", id, external_account),
+            &Expand { expand },
+        )
+    }
+
+    /// Updates the metadata, account holder name, account holder type of a bank account belonging to a [Custom account](https://stripe.com/docs/connect/custom-accounts), and optionally sets it as the default for its currency.
+    ///
+    /// Other bank account details are not editable by design.
+    /// You can re-enable a disabled bank account by performing an update call without providing any arguments or changes.
+    pub fn update_external_account(
+        client: &Client,
+        id: &AccountId,
+        external_account: &ExternalAccountId,
+        params: UpdateAccountExternalAccount<'_>,
+    ) -> Response<ExternalAccount> {
+        client
+            .post_form(&format!("/accounts/{}/external_accounts/{}", id, external_account), &params)
+    }
+
+    /// Delete a specified external account for a given account.
+    pub fn delete_external_account(
+        client: &Client,
+        id: &AccountId,
+        external_account: &ExternalAccountId,
+    ) -> Response<Deleted<ExternalAccountId>> {
+        client.delete(&format!("/accounts/{}/external_accounts/{}", id, external_account))
+    }
+}
+
+/// The parameters for `Account::create_external_account`.
+#[derive(Clone, Debug, Serialize)]
+pub struct CreateAccountExternalAccount<'a> {
+    /// When set to true, or if this is the first external account added in this currency, this account becomes the default external account for its currency.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default_for_currency: Option<bool>,
+
+    /// Specifies which fields in the response should be expanded.
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+
+    /// Please refer to full [documentation](https://stripe.com/docs/api) instead.
+    pub external_account: &'a str,
+
+    /// Set of [key-value pairs](https://stripe.com/docs/api/metadata) that you can attach to an object.
+    ///
+    /// This can be useful for storing additional information about the object in a structured format.
+    /// Individual keys can be unset by posting an empty value to them.
+    /// All keys can be unset by posting an empty value to `metadata`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+}
+
+impl<'a> CreateAccountExternalAccount<'a> {
+    pub fn new(external_account: &'a str) -> Self {
+        CreateAccountExternalAccount {
+            default_for_currency: Default::default(),
+            expand: Default::default(),
+            external_account,
+            metadata: Default::default(),
+        }
+    }
+}
+
+/// The parameters for `Account::list_external_accounts`.
+#[derive(Clone, Debug, Serialize, Default)]
+pub struct ListAccountExternalAccounts<'a> {
+    /// A cursor for use in pagination.
+    ///
+    /// `ending_before` is an object ID that defines your place in the list.
+    /// For instance, if you make a list request and receive 100 objects, starting with `obj_bar`, your subsequent call can include `ending_before=obj_bar` in order to fetch the previous page of the list.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ending_before: Option<ExternalAccountId>,
+
+    /// Specifies which fields in the response should be expanded.
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+
+    /// A limit on the number of objects to be returned.
+    ///
+    /// Limit can range between 1 and 100, and the default is 10.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u64>,
+
+    /// Filter external accounts according to a particular object type.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub object: Option<ListAccountExternalAccountsObject>,
+
+    /// A cursor for use in pagination.
+    ///
+    /// `starting_after` is an object ID that defines your place in the list.
+    /// For instance, if you make a list request and receive 100 objects, ending with `obj_foo`, your subsequent call can include `starting_after=obj_foo` in order to fetch the next page of the list.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub starting_after: Option<ExternalAccountId>,
+}
+
+impl<'a> ListAccountExternalAccounts<'a> {
+    pub fn new() -> Self {
+        ListAccountExternalAccounts {
+            ending_before: Default::default(),
+            expand: Default::default(),
+            limit: Default::default(),
+            object: Default::default(),
+            starting_after: Default::default(),
+        }
+    }
+}
+impl Paginable for ListAccountExternalAccounts<'_> {
+    type O = ExternalAccount;
+    fn set_last(&mut self, item: Self::O) {
+        self.starting_after = Some(item.id());
+    }
+}
+
+/// The parameters for `Account::update_external_account`.
+#[derive(Clone, Debug, Serialize, Default)]
+pub struct UpdateAccountExternalAccount<'a> {
+    /// The name of the person or business that owns the bank account.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub account_holder_name: Option<&'a str>,
+
+    /// The type of entity that holds the account.
+    ///
+    /// This can be either `individual` or `company`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub account_holder_type: Option<UpdateAccountExternalAccountAccountHolderType>,
+
+    /// The bank account type.
+    ///
+    /// This can only be `checking` or `savings` in most countries.
+    /// In Japan, this can only be `futsu` or `toza`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub account_type: Option<UpdateAccountExternalAccountAccountType>,
+
+    /// City/District/Suburb/Town/Village.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub address_city: Option<&'a str>,
+
+    /// Billing address country, if provided when creating card.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub address_country: Option<&'a str>,
+
+    /// Address line 1 (Street address/PO Box/Company name).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub address_line1: Option<&'a str>,
+
+    /// Address line 2 (Apartment/Suite/Unit/Building).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub address_line2: Option<&'a str>,
+
+    /// State/County/Province/Region.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub address_state: Option<&'a str>,
+
+    /// ZIP or postal code.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub address_zip: Option<&'a str>,
+
+    /// When set to true, this becomes the default external account for its currency.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default_for_currency: Option<bool>,
+
+    /// Documents that may be submitted to satisfy various informational requests.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub documents: Option<UpdateAccountExternalAccountDocuments>,
+
+    /// Two digit number representing the card’s expiration month.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub exp_month: Option<&'a str>,
+
+    /// Four digit number representing the card’s expiration year.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub exp_year: Option<&'a str>,
+
+    /// Specifies which fields in the response should be expanded.
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+
+    /// Set of [key-value pairs](https://stripe.com/docs/api/metadata) that you can attach to an object.
+    ///
+    /// This can be useful for storing additional information about the object in a structured format.
+    /// Individual keys can be unset by posting an empty value to them.
+    /// All keys can be unset by posting an empty value to `metadata`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+
+    /// Cardholder name.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<&'a str>,
+}
+
+impl<'a> UpdateAccountExternalAccount<'a> {
+    pub fn new() -> Self {
+        UpdateAccountExternalAccount {
+            account_holder_name: Default::default(),
+            account_holder_type: Default::default(),
+            account_type: Default::default(),
+            address_city: Default::default(),
+            address_country: Default::default(),
+            address_line1: Default::default(),
+            address_line2: Default::default(),
+            address_state: Default::default(),
+            address_zip: Default::default(),
+            default_for_currency: Default::default(),
+            documents: Default::default(),
+            exp_month: Default::default(),
+            exp_year: Default::default(),
+            expand: Default::default(),
+            metadata: Default::default(),
+            name: Default::default(),
+        }
+    }
+}
+
+/// The parameters for `Account::update_person`.
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct UpdateAccountExternalAccountDocuments {
+    /// One or more documents that support the [Bank account ownership verification](https://support.stripe.com/questions/bank-account-ownership-verification) requirement.
+    ///
+    /// Must be a document associated with the bank account that displays the last 4 digits of the account number, either a statement or a voided check.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bank_account_ownership_verification:
+        Option<UpdateAccountExternalAccountDocumentsBankAccountOwnershipVerification>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct UpdateAccountExternalAccountDocumentsBankAccountOwnershipVerification {
+    /// One or more document ids returned by a [file upload](https://stripe.com/docs/api#create_file) with a `purpose` value of `account_requirement`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub files: Option<Vec<String>>,
+}
+
+/// An enum representing the possible values of an `ListAccountExternalAccounts`'s `object` field.
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum ListAccountExternalAccountsObject {
+    BankAccount,
+    Card,
+}
+
+impl ListAccountExternalAccountsObject {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            ListAccountExternalAccountsObject::BankAccount => "bank_account",
+            ListAccountExternalAccountsObject::Card => "card",
+        }
+    }
+}
+
+impl AsRef<str> for ListAccountExternalAccountsObject {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::fmt::Display for ListAccountExternalAccountsObject {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
+impl std::default::Default for ListAccountExternalAccountsObject {
+    fn default() -> Self {
+        Self::BankAccount
+    }
+}
+
+/// An enum representing the possible values of an `UpdateAccountExternalAccount`'s `account_holder_type` field.
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum UpdateAccountExternalAccountAccountHolderType {
+    Company,
+    Individual,
+}
+
+impl UpdateAccountExternalAccountAccountHolderType {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            UpdateAccountExternalAccountAccountHolderType::Company => "company",
+            UpdateAccountExternalAccountAccountHolderType::Individual => "individual",
+        }
+    }
+}
+
+impl AsRef<str> for UpdateAccountExternalAccountAccountHolderType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::fmt::Display for UpdateAccountExternalAccountAccountHolderType {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
+impl std::default::Default for UpdateAccountExternalAccountAccountHolderType {
+    fn default() -> Self {
+        Self::Company
+    }
+}
+
+/// An enum representing the possible values of an `UpdateAccountExternalAccount`'s `account_type` field.
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum UpdateAccountExternalAccountAccountType {
+    Checking,
+    Futsu,
+    Savings,
+    Toza,
+}
+
+impl UpdateAccountExternalAccountAccountType {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            UpdateAccountExternalAccountAccountType::Checking => "checking",
+            UpdateAccountExternalAccountAccountType::Futsu => "futsu",
+            UpdateAccountExternalAccountAccountType::Savings => "savings",
+            UpdateAccountExternalAccountAccountType::Toza => "toza",
+        }
+    }
+}
+
+impl AsRef<str> for UpdateAccountExternalAccountAccountType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::fmt::Display for UpdateAccountExternalAccountAccountType {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
+impl std::default::Default for UpdateAccountExternalAccountAccountType {
+    fn default() -> Self {
+        Self::Checking
+    }
+}
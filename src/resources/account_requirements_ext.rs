@@ -0,0 +1,153 @@
+use crate::ids::PersonId;
+use crate::resources::{
+    AccountCapabilityFutureRequirements, AccountCapabilityRequirements, AccountFutureRequirements,
+    AccountRequirements, AccountRequirementsError,
+};
+
+/// A field listed in a requirements hash, eg. `business_profile.url` or `person_123.dob.day`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Requirement {
+    /// A field of the account itself, eg. `external_account` or `tos_acceptance.date`.
+    Account(String),
+
+    /// A field of one of the account's persons, with the `person_` prefix split off the path.
+    Person { person: PersonId, field: String },
+}
+
+impl Requirement {
+    /// Parses a field as it appears in the `currently_due`, `past_due`, etc. lists.
+    pub fn parse(requirement: &str) -> Self {
+        if let Some((prefix, field)) = requirement.split_once('.') {
+            if let Ok(person) = prefix.parse() {
+                return Requirement::Person { person, field: field.to_string() };
+            }
+        }
+        Requirement::Account(requirement.to_string())
+    }
+
+    /// The path of the field on the object it belongs to.
+    pub fn field(&self) -> &str {
+        match self {
+            Requirement::Account(field) | Requirement::Person { field, .. } => field,
+        }
+    }
+
+    /// The person the field belongs to, if it is not a field of the account.
+    pub fn person(&self) -> Option<&PersonId> {
+        match self {
+            Requirement::Account(_) => None,
+            Requirement::Person { person, .. } => Some(person),
+        }
+    }
+}
+
+impl std::fmt::Display for Requirement {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Requirement::Account(field) => field.fmt(f),
+            Requirement::Person { person, field } => write!(f, "{}.{}", person, field),
+        }
+    }
+}
+
+fn parse_all(fields: &[String]) -> Vec<Requirement> {
+    fields.iter().map(|field| Requirement::parse(field)).collect()
+}
+
+fn or_empty<T>(list: &Option<Vec<T>>) -> &[T] {
+    list.as_deref().unwrap_or_default()
+}
+
+macro_rules! impl_requirements {
+    ($ty:ty, $slice:path) => {
+        impl $ty {
+            /// The fields that need to be collected now, including the ones past due.
+            pub fn currently_due_requirements(&self) -> Vec<Requirement> {
+                parse_all($slice(&self.currently_due))
+            }
+
+            /// The fields that were not collected by `current_deadline`.
+            pub fn past_due_requirements(&self) -> Vec<Requirement> {
+                parse_all($slice(&self.past_due))
+            }
+
+            /// The fields that need to be collected once all volume thresholds are reached.
+            pub fn eventually_due_requirements(&self) -> Vec<Requirement> {
+                parse_all($slice(&self.eventually_due))
+            }
+
+            /// The fields that are waiting on an asynchronous verification.
+            pub fn pending_verification_requirements(&self) -> Vec<Requirement> {
+                parse_all($slice(&self.pending_verification))
+            }
+
+            /// Whether any field needs to be collected now.
+            pub fn is_due(&self) -> bool {
+                !$slice(&self.currently_due).is_empty() || !$slice(&self.past_due).is_empty()
+            }
+
+            /// The errors reported for `requirement`, if it failed validation or verification.
+            pub fn errors_for<'a>(
+                &'a self,
+                requirement: &'a Requirement,
+            ) -> impl Iterator<Item = &'a AccountRequirementsError> + 'a {
+                $slice(&self.errors)
+                    .iter()
+                    .filter(move |error| Requirement::parse(&error.requirement) == *requirement)
+            }
+        }
+    };
+}
+
+impl_requirements!(AccountRequirements, or_empty);
+impl_requirements!(AccountFutureRequirements, or_empty);
+impl_requirements!(AccountCapabilityRequirements, Vec::as_slice);
+impl_requirements!(AccountCapabilityFutureRequirements, Vec::as_slice);
+
+#[cfg(test)]
+mod tests {
+    use super::Requirement;
+    use crate::AccountRequirements;
+
+    #[test]
+    fn parses_person_requirements() {
+        let requirement = Requirement::parse("person_123.verification.document");
+        assert_eq!(requirement.person().map(|p| p.as_str()), Some("person_123"));
+        assert_eq!(requirement.field(), "verification.document");
+        assert_eq!(requirement.to_string(), "person_123.verification.document");
+
+        let requirement = Requirement::parse("individual.verification.document");
+        assert_eq!(requirement, Requirement::Account("individual.verification.document".into()));
+        assert_eq!(Requirement::parse("external_account").field(), "external_account");
+    }
+
+    #[test]
+    fn reports_what_is_due() {
+        let requirements: AccountRequirements = serde_json::from_str(
+            r#"{
+                "alternatives": [],
+                "current_deadline": null,
+                "currently_due": ["external_account", "person_123.dob.day"],
+                "disabled_reason": null,
+                "errors": [{
+                    "code": "invalid_value_other",
+                    "reason": "The date of birth is invalid.",
+                    "requirement": "person_123.dob.day"
+                }],
+                "eventually_due": [],
+                "past_due": [],
+                "pending_verification": []
+            }"#,
+        )
+        .unwrap();
+
+        assert!(requirements.is_due());
+        let due = requirements.currently_due_requirements();
+        assert_eq!(due[0], Requirement::Account("external_account".into()));
+        assert_eq!(due[1].field(), "dob.day");
+        assert_eq!(requirements.errors_for(&due[1]).count(), 1);
+        assert_eq!(requirements.errors_for(&due[0]).count(), 0);
+        assert!(requirements.past_due_requirements().is_empty());
+        assert!(!AccountRequirements::default().is_due());
+    }
+}
@@ -0,0 +1,150 @@
+use crate::client::Client;
+use crate::error::StripeError;
+use crate::ids::FileId;
+use crate::resources::{CreateFile, File, FilePurpose, UpdateDisputeEvidence};
+
+/// The evidence fields of a dispute that reference an uploaded file.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum DisputeEvidenceFile {
+    CancellationPolicy,
+    CustomerCommunication,
+    CustomerSignature,
+    DuplicateChargeDocumentation,
+    Receipt,
+    RefundPolicy,
+    ServiceDocumentation,
+    ShippingDocumentation,
+    UncategorizedFile,
+}
+
+impl DisputeEvidenceFile {
+    fn field(self, evidence: &mut UpdateDisputeEvidence) -> &mut Option<FileId> {
+        match self {
+            DisputeEvidenceFile::CancellationPolicy => &mut evidence.cancellation_policy,
+            DisputeEvidenceFile::CustomerCommunication => &mut evidence.customer_communication,
+            DisputeEvidenceFile::CustomerSignature => &mut evidence.customer_signature,
+            DisputeEvidenceFile::DuplicateChargeDocumentation => {
+                &mut evidence.duplicate_charge_documentation
+            }
+            DisputeEvidenceFile::Receipt => &mut evidence.receipt,
+            DisputeEvidenceFile::RefundPolicy => &mut evidence.refund_policy,
+            DisputeEvidenceFile::ServiceDocumentation => &mut evidence.service_documentation,
+            DisputeEvidenceFile::ShippingDocumentation => &mut evidence.shipping_documentation,
+            DisputeEvidenceFile::UncategorizedFile => &mut evidence.uncategorized_file,
+        }
+    }
+}
+
+/// Collects the evidence for a dispute, uploading its files through the files api.
+///
+/// ```rust,ignore
+/// let mut evidence = UpdateDisputeEvidence::default();
+/// evidence.shipping_tracking_number = Some("1Z999AA10123456784".to_string());
+/// let evidence = DisputeEvidenceBuilder::new(evidence)
+///     .file(DisputeEvidenceFile::Receipt, "receipt.pdf", receipt)
+///     .upload(&client)
+///     .await?;
+///
+/// let mut params = UpdateDispute::new();
+/// params.evidence = Some(evidence);
+/// params.submit = Some(true);
+/// Dispute::update(&client, &dispute_id, params).await?;
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct DisputeEvidenceBuilder {
+    evidence: UpdateDisputeEvidence,
+    files: Vec<(DisputeEvidenceFile, String, Vec<u8>)>,
+}
+
+impl DisputeEvidenceBuilder {
+    /// Starts from `evidence`, typically holding the text fields of the response.
+    pub fn new(evidence: UpdateDisputeEvidence) -> Self {
+        DisputeEvidenceBuilder { evidence, files: Vec::new() }
+    }
+
+    /// Uploads `contents` as `filename` and attaches it to the `kind` field of the evidence.
+    pub fn file(
+        mut self,
+        kind: DisputeEvidenceFile,
+        filename: impl Into<String>,
+        contents: impl Into<Vec<u8>>,
+    ) -> Self {
+        self.files.push((kind, filename.into(), contents.into()));
+        self
+    }
+
+    /// Uploads every file and returns the evidence with the ids of the uploaded files.
+    ///
+    /// # Errors
+    ///
+    /// This function will return the error of the first upload that fails.
+    #[cfg(feature = "async")]
+    pub async fn upload(self, client: &Client) -> Result<UpdateDisputeEvidence, StripeError> {
+        let mut evidence = self.evidence;
+        for (kind, filename, contents) in &self.files {
+            let file = File::create(client, upload(filename, contents)).await?;
+            *kind.field(&mut evidence) = Some(file.id);
+        }
+        Ok(evidence)
+    }
+
+    /// Uploads every file and returns the evidence with the ids of the uploaded files.
+    ///
+    /// # Errors
+    ///
+    /// This function will return the error of the first upload that fails.
+    #[cfg(feature = "blocking")]
+    pub fn upload(self, client: &Client) -> Result<UpdateDisputeEvidence, StripeError> {
+        let mut evidence = self.evidence;
+        for (kind, filename, contents) in &self.files {
+            let file = File::create(client, upload(filename, contents))?;
+            *kind.field(&mut evidence) = Some(file.id);
+        }
+        Ok(evidence)
+    }
+}
+
+fn upload<'a>(filename: &'a str, contents: &'a [u8]) -> CreateFile<'a> {
+    CreateFile::new(contents, filename, FilePurpose::DisputeEvidence)
+}
+
+#[cfg(all(test, feature = "async"))]
+mod tests {
+    use httpmock::Method::POST;
+    use httpmock::MockServer;
+
+    use super::{DisputeEvidenceBuilder, DisputeEvidenceFile};
+    use crate::{Client, UpdateDisputeEvidence};
+
+    #[tokio::test]
+    async fn uploads_files() {
+        let server = MockServer::start_async().await;
+        let client = Client::from_url(&*server.url("/"), "fake_key");
+
+        let mock = server.mock(|when, then| {
+            when.method(POST)
+                .path("/v1/files")
+                .body_contains("name=\"purpose\"\r\n\r\ndispute_evidence")
+                .body_contains("filename=\"receipt.pdf\"");
+            then.status(200).body(
+                r#"{"id": "file_123", "object": "file", "created": 1, "purpose": "dispute_evidence",
+                "size": 8, "links": {"object": "list", "data": [], "has_more": false, "url": "/v1/file_links"}}"#,
+            );
+        });
+
+        let evidence = UpdateDisputeEvidence {
+            customer_name: Some("Jenny Rosen".to_string()),
+            ..Default::default()
+        };
+        let evidence = DisputeEvidenceBuilder::new(evidence)
+            .file(DisputeEvidenceFile::Receipt, "receipt.pdf", b"%PDF-1.4".to_vec())
+            .upload(&client)
+            .await
+            .unwrap();
+
+        mock.assert_hits(1);
+        assert_eq!(evidence.receipt.unwrap().as_str(), "file_123");
+        assert_eq!(evidence.customer_name.as_deref(), Some("Jenny Rosen"));
+        assert!(evidence.shipping_documentation.is_none());
+    }
+}
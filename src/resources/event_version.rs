@@ -0,0 +1,240 @@
+use serde_json::{Map, Value};
+
+use crate::error::{ApiVersionMismatch, WebhookError};
+use crate::resources::generated::core::version::VERSION;
+use crate::resources::Event;
+use crate::ApiVersion;
+
+/// A known change to the shape of an object between two api versions.
+///
+/// When an event was rendered by an api version older than `since`, the migration
+/// rewrites its `data.object` into the shape expected by this crate.
+#[derive(Clone, Copy)]
+pub struct EventMigration {
+    /// The api version that introduced the change.
+    pub since: ApiVersion,
+    /// The `object` type the change applies to, such as `checkout.session`.
+    pub object: &'static str,
+    pub migrate: fn(&mut Map<String, Value>),
+}
+
+impl std::fmt::Debug for EventMigration {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("EventMigration")
+            .field("since", &self.since)
+            .field("object", &self.object)
+            .finish()
+    }
+}
+
+/// The migrations applied by [`EventParser::new`].
+///
+/// This list is deliberately partial: it only covers the changes to the objects most
+/// commonly received in webhooks, and is extended as migrations are reported. Events
+/// that still do not deserialize are surfaced as [`WebhookError::ApiVersionMismatch`],
+/// and further migrations can be added with [`EventParser::with_migration`].
+pub const KNOWN_MIGRATIONS: &[EventMigration] = &[
+    // 2022-08-01 renamed `shipping` to `shipping_details` and replaced
+    // `shipping_rates` with the (required) `shipping_options`
+    EventMigration {
+        since: ApiVersion::V2022_08_01,
+        object: "checkout.session",
+        migrate: |object| {
+            rename(object, "shipping", "shipping_details");
+            object.entry("shipping_options").or_insert_with(|| Value::Array(Vec::new()));
+        },
+    },
+    // 2022-11-15 removed the `charges` list in favour of `latest_charge`
+    EventMigration {
+        since: ApiVersion::V2022_11_15,
+        object: "payment_intent",
+        migrate: |object| {
+            let latest = object
+                .get("charges")
+                .and_then(|charges| charges.get("data"))
+                .and_then(|data| data.get(0))
+                .and_then(|charge| charge.get("id"))
+                .cloned();
+            if let (Some(latest), None | Some(Value::Null)) = (latest, object.get("latest_charge"))
+            {
+                object.insert("latest_charge".into(), latest);
+            }
+        },
+    },
+];
+
+fn rename(object: &mut Map<String, Value>, from: &str, to: &str) {
+    if let Some(value) = object.remove(from) {
+        object.entry(to).or_insert(value);
+    }
+}
+
+/// Deserializes events, taking into account the api version they were rendered with.
+///
+/// Stripe renders `data.object` with the api version of the webhook endpoint, or of
+/// the account for events fetched from the api, which is not necessarily the version
+/// this crate was generated for ([`VERSION`]). For events from an older version the
+/// parser applies the [`EventMigration`]s that cover the gap, and if the event still
+/// does not deserialize it returns a [`WebhookError::ApiVersionMismatch`] carrying the
+/// raw payload, rather than an opaque [`WebhookError::BadParse`].
+#[derive(Clone, Debug)]
+pub struct EventParser {
+    migrations: Vec<EventMigration>,
+    strict: bool,
+}
+
+impl Default for EventParser {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl EventParser {
+    /// A parser that applies the [`KNOWN_MIGRATIONS`].
+    pub fn new() -> Self {
+        EventParser { migrations: KNOWN_MIGRATIONS.to_vec(), strict: false }
+    }
+
+    /// A parser that applies no migrations.
+    pub fn without_migrations() -> Self {
+        EventParser { migrations: Vec::new(), strict: false }
+    }
+
+    /// Apply an additional migration.
+    pub fn with_migration(mut self, migration: EventMigration) -> Self {
+        self.migrations.push(migration);
+        self
+    }
+
+    /// Reject every event rendered by another api version, even if it deserializes.
+    pub fn with_strict(mut self, strict: bool) -> Self {
+        self.strict = strict;
+        self
+    }
+
+    /// Deserialize an event payload.
+    ///
+    /// # Errors
+    ///
+    /// This function will return a WebhookError if:
+    ///  - the payload is not an event
+    ///  - the event was rendered by another api version and could not be deserialized,
+    ///    or the parser is strict
+    pub fn parse(&self, payload: &str) -> Result<Event, WebhookError> {
+        let mut value: Value = serde_json::from_str(payload)?;
+        let event_version = match value.get("api_version").and_then(Value::as_str) {
+            // beta versions look like `2022-11-15; feature_beta=v1`
+            Some(version) => version.split(';').next().unwrap_or_default().trim().to_string(),
+            None => return Ok(serde_json::from_value(value)?),
+        };
+        if event_version == VERSION.as_str() {
+            return Ok(serde_json::from_value(value)?);
+        }
+
+        let mismatch = |source| {
+            WebhookError::ApiVersionMismatch(Box::new(ApiVersionMismatch {
+                event_version: event_version.clone(),
+                expected_version: VERSION,
+                payload: payload.to_string(),
+                source,
+            }))
+        };
+        if self.strict {
+            return Err(mismatch(None));
+        }
+
+        if let Some(object) = value.pointer_mut("/data/object").and_then(Value::as_object_mut) {
+            let object_type =
+                object.get("object").and_then(Value::as_str).unwrap_or_default().to_string();
+            for migration in &self.migrations {
+                // api versions are dates, so they compare correctly as strings
+                if migration.object == object_type
+                    && event_version.as_str() < migration.since.as_str()
+                {
+                    (migration.migrate)(object);
+                }
+            }
+        }
+
+        serde_json::from_value(value).map_err(|e| mismatch(Some(e)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{EventMigration, EventParser};
+    use crate::{ApiVersion, EventObject, WebhookError};
+
+    fn event_json(api_version: &str, object: &str) -> String {
+        format!(
+            r#"{{"id": "evt_123", "object": "event", "api_version": "{}", "created": 1,
+            "livemode": false, "pending_webhooks": 0, "type": "customer.updated",
+            "data": {{"object": {}}}}}"#,
+            api_version, object
+        )
+    }
+
+    #[test]
+    fn migrates_older_events() {
+        let object = r#"{"id": "pi_123", "object": "payment_intent", "amount": 100,
+            "amount_capturable": 0, "amount_received": 100, "metadata": {},
+            "currency": "usd", "created": 1, "livemode": false, "status": "succeeded",
+            "capture_method": "automatic", "confirmation_method": "automatic",
+            "payment_method_types": ["card"],
+            "charges": {"object": "list", "data": [{"id": "ch_2"}, {"id": "ch_1"}],
+            "has_more": false, "url": "/v1/charges"}}"#;
+
+        let event = EventParser::new().parse(&event_json("2020-08-27", object)).unwrap();
+        match event.data.object {
+            EventObject::PaymentIntent(intent) => {
+                assert_eq!(intent.latest_charge.unwrap().id().as_str(), "ch_2")
+            }
+            _ => panic!("expected a payment intent"),
+        }
+
+        let event = EventParser::without_migrations().parse(&event_json("2020-08-27", object));
+        match event.unwrap().data.object {
+            EventObject::PaymentIntent(intent) => assert!(intent.latest_charge.is_none()),
+            _ => panic!("expected a payment intent"),
+        }
+    }
+
+    #[test]
+    fn keeps_payload_on_mismatch() {
+        let object = r#"{"id": "cus_123", "object": "customer", "created": "yesterday"}"#;
+        let payload = event_json("2019-02-19", object);
+
+        match EventParser::new().parse(&payload) {
+            Err(WebhookError::ApiVersionMismatch(mismatch)) => {
+                assert_eq!(mismatch.event_version, "2019-02-19");
+                assert_eq!(mismatch.expected_version, ApiVersion::V2022_11_15);
+                assert_eq!(mismatch.payload, payload);
+                assert!(mismatch.source.is_some());
+            }
+            other => panic!("expected a version mismatch, got {:?}", other),
+        }
+
+        // the same object from the current version is a plain parse error
+        let payload = event_json("2022-11-15", object);
+        assert!(matches!(EventParser::new().parse(&payload), Err(WebhookError::BadParse(_))));
+    }
+
+    #[test]
+    fn custom_and_strict() {
+        let object = r#"{"id": "cus_123", "object": "customer", "created": 1, "desc": "a"}"#;
+        let payload = event_json("2019-02-19", object);
+
+        let parser = EventParser::without_migrations().with_migration(EventMigration {
+            since: ApiVersion::V2020_08_27,
+            object: "customer",
+            migrate: |object| super::rename(object, "desc", "description"),
+        });
+        match parser.parse(&payload).unwrap().data.object {
+            EventObject::Customer(customer) => assert_eq!(customer.description.unwrap(), "a"),
+            _ => panic!("expected a customer"),
+        }
+
+        let strict = parser.with_strict(true).parse(&payload);
+        assert!(matches!(strict, Err(WebhookError::ApiVersionMismatch(m)) if m.source.is_none()));
+    }
+}
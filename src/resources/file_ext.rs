@@ -0,0 +1,114 @@
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::client::{Client, Response};
+use crate::resources::{File, FilePurpose};
+
+/// The parameters for `File::create`.
+#[derive(Clone, Debug)]
+pub struct CreateFile<'a> {
+    /// The contents of the file to upload.
+    pub file: &'a [u8],
+
+    /// The name of the uploaded file, eg. `receipt.pdf`.
+    pub filename: &'a str,
+
+    /// The [purpose](https://stripe.com/docs/file-upload#uploading-a-file) of the uploaded file.
+    pub purpose: FilePurpose,
+}
+
+impl<'a> CreateFile<'a> {
+    pub fn new(file: &'a [u8], filename: &'a str, purpose: FilePurpose) -> Self {
+        CreateFile { file, filename, purpose }
+    }
+}
+
+impl File {
+    /// To upload a file to Stripe, you’ll need to send a request of type `multipart/form-data`.
+    ///
+    /// The request should contain the file you would like to upload, as well as the parameters for creating a file.
+    ///
+    /// For more details see <https://stripe.com/docs/api/files/create>.
+    pub fn create(client: &Client, params: CreateFile<'_>) -> Response<File> {
+        let boundary = boundary(params.file);
+        let content_type = format!("multipart/form-data; boundary={}", boundary);
+        client.post_file_bytes("/files", &content_type, multipart(&params, &boundary))
+    }
+}
+
+/// Picks a multipart boundary that does not appear in the file contents.
+fn boundary(contents: &[u8]) -> String {
+    let mut seed = SystemTime::now().duration_since(UNIX_EPOCH).map_or(0, |d| d.as_nanos());
+    loop {
+        let boundary = format!("----StripeRustBoundary{:x}", seed);
+        if !contents.windows(boundary.len()).any(|w| w == boundary.as_bytes()) {
+            return boundary;
+        }
+        seed += 1;
+    }
+}
+
+/// Percent-encodes the characters that would end the quoted filename or the header line.
+fn escape_filename(filename: &str) -> String {
+    let mut escaped = String::with_capacity(filename.len());
+    for c in filename.chars() {
+        match c {
+            '"' => escaped.push_str("%22"),
+            '\\' => escaped.push_str("%5C"),
+            '\r' => escaped.push_str("%0D"),
+            '\n' => escaped.push_str("%0A"),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+fn multipart(params: &CreateFile<'_>, boundary: &str) -> Vec<u8> {
+    let mut body = Vec::with_capacity(params.file.len() + 512);
+    body.extend_from_slice(
+        format!(
+            "--{}\r\nContent-Disposition: form-data; name=\"purpose\"\r\n\r\n{}\r\n",
+            boundary, params.purpose
+        )
+        .as_bytes(),
+    );
+    body.extend_from_slice(
+        format!(
+            "--{}\r\nContent-Disposition: form-data; name=\"file\"; filename=\"{}\"\r\nContent-Type: application/octet-stream\r\n\r\n",
+            boundary,
+            escape_filename(params.filename)
+        )
+        .as_bytes(),
+    );
+    body.extend_from_slice(params.file);
+    body.extend_from_slice(format!("\r\n--{}--\r\n", boundary).as_bytes());
+    body
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{multipart, CreateFile};
+    use crate::FilePurpose;
+
+    #[test]
+    fn encodes_multipart() {
+        let params = CreateFile::new(b"%PDF-1.4", "receipt.pdf", FilePurpose::DisputeEvidence);
+        assert_eq!(
+            String::from_utf8(multipart(&params, "xyz")).unwrap(),
+            "--xyz\r\nContent-Disposition: form-data; name=\"purpose\"\r\n\r\ndispute_evidence\r\n\
+             --xyz\r\nContent-Disposition: form-data; name=\"file\"; filename=\"receipt.pdf\"\r\n\
+             Content-Type: application/octet-stream\r\n\r\n%PDF-1.4\r\n--xyz--\r\n"
+        );
+    }
+
+    #[test]
+    fn escapes_filename() {
+        let params = CreateFile::new(
+            b"",
+            "a\"b\\c\r\nContent-Type: text/html.pdf",
+            FilePurpose::DisputeEvidence,
+        );
+        let body = String::from_utf8(multipart(&params, "xyz")).unwrap();
+        assert!(body.contains("filename=\"a%22b%5Cc%0D%0AContent-Type: text/html.pdf\"\r\n"));
+        assert!(!body.contains("\nContent-Type: text/html"));
+    }
+}
@@ -11,6 +11,8 @@
 
 #[path = "generated"]
 pub mod core {
+    pub mod account_requirements_alternative;
+    pub mod account_requirements_error;
     pub mod address;
     pub mod api_errors;
     pub mod balance;
@@ -109,6 +111,7 @@ pub mod connect {
     pub mod account_link;
     pub mod application;
     pub mod application_fee;
+    pub mod capability;
     pub mod connect_collection_transfer;
     pub mod fee_refund;
     pub mod login_link;
@@ -118,12 +121,32 @@ pub mod connect {
     pub mod transfer_reversal;
 }
 
+#[path = "generated"]
+#[cfg(feature = "financial-connections")]
+pub mod financial_connections {
+    pub mod bank_connections_resource_accountholder;
+    pub mod financial_connections_account;
+    pub mod financial_connections_account_owner;
+    pub mod financial_connections_account_ownership;
+    pub mod financial_connections_session;
+}
+
 #[path = "generated"]
 #[cfg(feature = "fraud")]
 pub mod fraud {
+    pub mod radar_early_fraud_warning;
+    pub mod radar_value_list;
+    pub mod radar_value_list_item;
     pub mod review;
 }
 
+#[path = "generated"]
+#[cfg(feature = "identity")]
+pub mod identity {
+    pub mod identity_verification_report;
+    pub mod identity_verification_session;
+}
+
 #[path = "generated"]
 #[cfg(feature = "issuing")]
 pub mod issuing {
@@ -137,6 +160,17 @@ pub mod issuing {
 #[cfg(feature = "sigma")]
 pub mod scheduled_query_run;
 
+#[path = "generated"]
+#[cfg(feature = "tax")]
+pub mod tax {
+    pub mod tax_calculation;
+    pub mod tax_calculation_line_item;
+    pub mod tax_product_resource_customer_details;
+    pub mod tax_product_resource_line_item_tax_breakdown;
+    pub mod tax_transaction;
+    pub mod tax_transaction_line_item;
+}
+
 #[path = "generated"]
 #[cfg(feature = "terminal")]
 pub mod terminal {
@@ -146,6 +180,29 @@ pub mod terminal {
     pub mod terminal_reader;
 }
 
+#[path = "generated"]
+#[cfg(feature = "treasury")]
+pub mod treasury {
+    pub mod treasury_credit_reversal;
+    pub mod treasury_debit_reversal;
+    pub mod treasury_financial_account;
+    pub mod treasury_financial_account_features;
+    pub mod treasury_financial_accounts_resource_ach_toggle_settings;
+    pub mod treasury_financial_accounts_resource_toggle_settings;
+    pub mod treasury_financial_accounts_resource_toggles_setting_status_details;
+    pub mod treasury_inbound_transfer;
+    pub mod treasury_outbound_payment;
+    pub mod treasury_outbound_transfer;
+    pub mod treasury_received_credit;
+    pub mod treasury_received_debit;
+    pub mod treasury_shared_resource_billing_details;
+    pub mod treasury_shared_resource_initiating_payment_method_details_initiating_payment_method_details;
+    pub mod treasury_transaction;
+    pub mod treasury_transaction_entry;
+    pub mod treasury_transactions_resource_balance_impact;
+    pub mod treasury_transactions_resource_flow_details;
+}
+
 #[cfg(feature = "events")]
 pub mod event;
 
@@ -5,13 +5,13 @@
 use serde::{Deserialize, Serialize};
 
 use crate::client::{Client, Response};
-use crate::ids::AccountId;
+use crate::ids::{AccountId, CapabilityId, PersonId};
 use crate::params::{
     Deleted, Expand, Expandable, List, Metadata, Object, Paginable, RangeQuery, Timestamp,
 };
 use crate::resources::{
-    Address, Currency, DelayDays, ExternalAccount, File, Person, PersonVerificationParams,
-    VerificationDocumentParams,
+    AccountRequirementsAlternative, AccountRequirementsError, Address, Capability, Currency,
+    DelayDays, ExternalAccount, File, Person, PersonVerificationParams, VerificationDocumentParams,
 };
 
 /// The resource representing a Stripe "Account".
@@ -157,6 +157,98 @@ impl Account {
     pub fn delete(client: &Client, id: &AccountId) -> Response<Deleted<AccountId>> {
         client.delete(&format!("/accounts/{}", id))
     }
+
+    /// Creates a new person.
+    pub fn create_person(
+        client: &Client,
+        id: &AccountId,
+        params: CreateAccountPerson<'_>,
+    ) -> Response<Person> {
+        client.post_form(&format!("/accounts/{}/persons", id), &params)
+    }
+
+    /// Deletes an existing person’s relationship to the account’s legal entity.
+    ///
+    /// Any person with a relationship for an account can be deleted through the API, except if the person is the `account_opener`.
+    /// If your integration is using the `executive` parameter, you cannot delete the only verified `executive` on file.
+    pub fn delete_person(
+        client: &Client,
+        id: &AccountId,
+        person: &PersonId,
+    ) -> Response<Deleted<PersonId>> {
+        client.delete(&format!("/accounts/{}/persons/{}", id, person))
+    }
+
+    /// Returns a list of capabilities associated with the account.
+    ///
+    /// The capabilities are returned sorted by creation date, with the most recent capability appearing first.
+    pub fn list_capabilities(
+        client: &Client,
+        id: &AccountId,
+        expand: &[&str],
+    ) -> Response<List<Capability>> {
+        client.get_query(&format!("/accounts/{}/capabilities", id), &Expand { expand })
+    }
+
+    /// Returns a list of people associated with the account’s legal entity.
+    ///
+    /// The people are returned sorted by creation date, with the most recent people appearing first.
+    pub fn list_persons(
+        client: &Client,
+        id: &AccountId,
+        params: &ListAccountPersons<'_>,
+    ) -> Response<List<Person>> {
+        client.get_query(&format!("/accounts/{}/persons", id), &params)
+    }
+
+    /// With [Connect](https://stripe.com/docs/connect), you may flag accounts as suspicious.
+    ///
+    /// Test-mode Custom and Express accounts can be rejected at any time.
+    /// Accounts created using live-mode keys may only be rejected once all balances are zero.
+    pub fn reject(client: &Client, id: &AccountId, params: RejectAccount<'_>) -> Response<Account> {
+        client.post_form(&format!("/accounts/{}/reject", id), &params)
+    }
+
+    /// Retrieves information about the specified Account Capability.
+    pub fn retrieve_capability(
+        client: &Client,
+        id: &AccountId,
+        capability: &CapabilityId,
+        expand: &[&str],
+    ) -> Response<Capability> {
+        client
+            .get_query(&format!("/accounts/{}/capabilities/{}", id, capability), &Expand { expand })
+    }
+
+    /// Retrieves an existing person.
+    pub fn retrieve_person(
+        client: &Client,
+        id: &AccountId,
+        person: &PersonId,
+        expand: &[&str],
+    ) -> Response<Person> {
+        client.get_query(&format!("/accounts/{}/persons/{}", id, person), &Expand { expand })
+    }
+
+    /// Updates an existing Account Capability.
+    pub fn update_capability(
+        client: &Client,
+        id: &AccountId,
+        capability: &CapabilityId,
+        params: UpdateAccountCapability<'_>,
+    ) -> Response<Capability> {
+        client.post_form(&format!("/accounts/{}/capabilities/{}", id, capability), &params)
+    }
+
+    /// Updates an existing person.
+    pub fn update_person(
+        client: &Client,
+        id: &AccountId,
+        person: &PersonId,
+        params: UpdateAccountPerson<'_>,
+    ) -> Response<Person> {
+        client.post_form(&format!("/accounts/{}/persons/{}", id, person), &params)
+    }
 }
 
 impl Object for Account {
@@ -423,27 +515,6 @@ pub struct AccountRequirements {
     pub pending_verification: Option<Vec<String>>,
 }
 
-#[derive(Clone, Debug, Default, Deserialize, Serialize)]
-pub struct AccountRequirementsAlternative {
-    /// Fields that can be provided to satisfy all fields in `original_fields_due`.
-    pub alternative_fields_due: Vec<String>,
-
-    /// Fields that are due and can be satisfied by providing all fields in `alternative_fields_due`.
-    pub original_fields_due: Vec<String>,
-}
-
-#[derive(Clone, Debug, Default, Deserialize, Serialize)]
-pub struct AccountRequirementsError {
-    /// The code for the type of error.
-    pub code: AccountRequirementsErrorCode,
-
-    /// An informative message that indicates the error type and provides additional details about the error.
-    pub reason: String,
-
-    /// The specific user onboarding requirement field (in the requirements hash) that needs to be resolved.
-    pub requirement: String,
-}
-
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct AccountSettings {
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -929,6 +1000,164 @@ impl<'a> CreateAccount<'a> {
     }
 }
 
+/// The parameters for `Account::create_person`.
+#[derive(Clone, Debug, Serialize, Default)]
+pub struct CreateAccountPerson<'a> {
+    /// The person's address.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub address: Option<Address>,
+
+    /// The Kana variation of the person's address (Japan only).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub address_kana: Option<Address>,
+
+    /// The Kanji variation of the person's address (Japan only).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub address_kanji: Option<Address>,
+
+    /// The person's date of birth.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dob: Option<PersonParamsDob>,
+
+    /// Documents that may be submitted to satisfy various informational requests.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub documents: Option<CreateAccountPersonDocuments>,
+
+    /// The person's email address.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub email: Option<&'a str>,
+
+    /// Specifies which fields in the response should be expanded.
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+
+    /// The person's first name.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub first_name: Option<&'a str>,
+
+    /// The Kana variation of the person's first name (Japan only).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub first_name_kana: Option<&'a str>,
+
+    /// The Kanji variation of the person's first name (Japan only).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub first_name_kanji: Option<&'a str>,
+
+    /// A list of alternate names or aliases that the person is known by.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub full_name_aliases: Option<Vec<String>>,
+
+    /// The person's gender (International regulations require either "male" or "female").
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub gender: Option<&'a str>,
+
+    /// The person's ID number, as appropriate for their country.
+    ///
+    /// For example, a social security number in the U.S., social insurance number in Canada, etc.
+    /// Instead of the number itself, you can also provide a [PII token provided by Stripe.js](https://stripe.com/docs/js/tokens_sources/create_token?type=pii).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id_number: Option<&'a str>,
+
+    /// The person's secondary ID number, as appropriate for their country, will be used for enhanced verification checks.
+    ///
+    /// In Thailand, this would be the laser code found on the back of an ID card.
+    /// Instead of the number itself, you can also provide a [PII token provided by Stripe.js](https://stripe.com/docs/js/tokens_sources/create_token?type=pii).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id_number_secondary: Option<&'a str>,
+
+    /// The person's last name.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_name: Option<&'a str>,
+
+    /// The Kana variation of the person's last name (Japan only).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_name_kana: Option<&'a str>,
+
+    /// The Kanji variation of the person's last name (Japan only).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_name_kanji: Option<&'a str>,
+
+    /// The person's maiden name.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub maiden_name: Option<&'a str>,
+
+    /// Set of [key-value pairs](https://stripe.com/docs/api/metadata) that you can attach to an object.
+    ///
+    /// This can be useful for storing additional information about the object in a structured format.
+    /// Individual keys can be unset by posting an empty value to them.
+    /// All keys can be unset by posting an empty value to `metadata`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+
+    /// The country where the person is a national.
+    ///
+    /// Two-letter country code ([ISO 3166-1 alpha-2](https://en.wikipedia.org/wiki/ISO_3166-1_alpha-2)), or "XX" if unavailable.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub nationality: Option<&'a str>,
+
+    /// A [person token](https://stripe.com/docs/connect/account-tokens), used to securely provide details to the person.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub person_token: Option<&'a str>,
+
+    /// The person's phone number.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub phone: Option<&'a str>,
+
+    /// Indicates if the person or any of their representatives, family members, or other closely related persons, declares that they hold or have held an important public job or function, in any jurisdiction.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub political_exposure: Option<PersonParamsPoliticalExposure>,
+
+    /// The person's registered address.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub registered_address: Option<PersonParamsRegisteredAddress>,
+
+    /// The relationship that this person has with the account's legal entity.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub relationship: Option<CreateAccountPersonRelationship>,
+
+    /// The last four digits of the person's Social Security number (U.S. only).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ssn_last_4: Option<&'a str>,
+
+    /// The person's verification status.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub verification: Option<PersonVerificationParams>,
+}
+
+impl<'a> CreateAccountPerson<'a> {
+    pub fn new() -> Self {
+        CreateAccountPerson {
+            address: Default::default(),
+            address_kana: Default::default(),
+            address_kanji: Default::default(),
+            dob: Default::default(),
+            documents: Default::default(),
+            email: Default::default(),
+            expand: Default::default(),
+            first_name: Default::default(),
+            first_name_kana: Default::default(),
+            first_name_kanji: Default::default(),
+            full_name_aliases: Default::default(),
+            gender: Default::default(),
+            id_number: Default::default(),
+            id_number_secondary: Default::default(),
+            last_name: Default::default(),
+            last_name_kana: Default::default(),
+            last_name_kanji: Default::default(),
+            maiden_name: Default::default(),
+            metadata: Default::default(),
+            nationality: Default::default(),
+            person_token: Default::default(),
+            phone: Default::default(),
+            political_exposure: Default::default(),
+            registered_address: Default::default(),
+            relationship: Default::default(),
+            ssn_last_4: Default::default(),
+            verification: Default::default(),
+        }
+    }
+}
+
 /// The parameters for `Account::list`.
 #[derive(Clone, Debug, Serialize, Default)]
 pub struct ListAccounts<'a> {
@@ -977,6 +1206,75 @@ impl Paginable for ListAccounts<'_> {
         self.starting_after = Some(item.id());
     }
 }
+/// The parameters for `Account::list_persons`.
+#[derive(Clone, Debug, Serialize, Default)]
+pub struct ListAccountPersons<'a> {
+    /// A cursor for use in pagination.
+    ///
+    /// `ending_before` is an object ID that defines your place in the list.
+    /// For instance, if you make a list request and receive 100 objects, starting with `obj_bar`, your subsequent call can include `ending_before=obj_bar` in order to fetch the previous page of the list.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ending_before: Option<PersonId>,
+
+    /// Specifies which fields in the response should be expanded.
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+
+    /// A limit on the number of objects to be returned.
+    ///
+    /// Limit can range between 1 and 100, and the default is 10.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u64>,
+
+    /// Filters on the list of people returned based on the person's relationship to the account's company.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub relationship: Option<ListAccountPersonsRelationship>,
+
+    /// A cursor for use in pagination.
+    ///
+    /// `starting_after` is an object ID that defines your place in the list.
+    /// For instance, if you make a list request and receive 100 objects, ending with `obj_foo`, your subsequent call can include `starting_after=obj_foo` in order to fetch the next page of the list.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub starting_after: Option<PersonId>,
+}
+
+impl<'a> ListAccountPersons<'a> {
+    pub fn new() -> Self {
+        ListAccountPersons {
+            ending_before: Default::default(),
+            expand: Default::default(),
+            limit: Default::default(),
+            relationship: Default::default(),
+            starting_after: Default::default(),
+        }
+    }
+}
+impl Paginable for ListAccountPersons<'_> {
+    type O = Person;
+    fn set_last(&mut self, item: Self::O) {
+        self.starting_after = Some(item.id());
+    }
+}
+
+/// The parameters for `Account::reject`.
+#[derive(Clone, Debug, Serialize)]
+pub struct RejectAccount<'a> {
+    /// Specifies which fields in the response should be expanded.
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+
+    /// The reason for rejecting the account.
+    ///
+    /// Can be `fraud`, `terms_of_service`, or `other`.
+    pub reason: &'a str,
+}
+
+impl<'a> RejectAccount<'a> {
+    pub fn new(reason: &'a str) -> Self {
+        RejectAccount { expand: Default::default(), reason }
+    }
+}
+
 /// The parameters for `Account::update`.
 #[derive(Clone, Debug, Serialize, Default)]
 pub struct UpdateAccount<'a> {
@@ -1079,6 +1377,187 @@ impl<'a> UpdateAccount<'a> {
     }
 }
 
+/// The parameters for `Account::update_capability`.
+#[derive(Clone, Debug, Serialize, Default)]
+pub struct UpdateAccountCapability<'a> {
+    /// Specifies which fields in the response should be expanded.
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+
+    /// To request a new capability for an account, pass true.
+    ///
+    /// There can be a delay before the requested capability becomes active.
+    /// If the capability has any activation requirements, the response includes them in the `requirements` arrays.
+    /// If a capability isn't permanent, you can remove it from the account by passing false.
+    /// Most capabilities are permanent after they've been requested.
+    /// Attempting to remove a permanent capability returns an error.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub requested: Option<bool>,
+}
+
+impl<'a> UpdateAccountCapability<'a> {
+    pub fn new() -> Self {
+        UpdateAccountCapability { expand: Default::default(), requested: Default::default() }
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Default)]
+pub struct UpdateAccountPerson<'a> {
+    /// The person's address.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub address: Option<Address>,
+
+    /// The Kana variation of the person's address (Japan only).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub address_kana: Option<Address>,
+
+    /// The Kanji variation of the person's address (Japan only).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub address_kanji: Option<Address>,
+
+    /// The person's date of birth.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dob: Option<PersonParamsDob>,
+
+    /// Documents that may be submitted to satisfy various informational requests.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub documents: Option<CreateAccountPersonDocuments>,
+
+    /// The person's email address.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub email: Option<&'a str>,
+
+    /// Specifies which fields in the response should be expanded.
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+
+    /// The person's first name.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub first_name: Option<&'a str>,
+
+    /// The Kana variation of the person's first name (Japan only).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub first_name_kana: Option<&'a str>,
+
+    /// The Kanji variation of the person's first name (Japan only).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub first_name_kanji: Option<&'a str>,
+
+    /// A list of alternate names or aliases that the person is known by.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub full_name_aliases: Option<Vec<String>>,
+
+    /// The person's gender (International regulations require either "male" or "female").
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub gender: Option<&'a str>,
+
+    /// The person's ID number, as appropriate for their country.
+    ///
+    /// For example, a social security number in the U.S., social insurance number in Canada, etc.
+    /// Instead of the number itself, you can also provide a [PII token provided by Stripe.js](https://stripe.com/docs/js/tokens_sources/create_token?type=pii).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id_number: Option<&'a str>,
+
+    /// The person's secondary ID number, as appropriate for their country, will be used for enhanced verification checks.
+    ///
+    /// In Thailand, this would be the laser code found on the back of an ID card.
+    /// Instead of the number itself, you can also provide a [PII token provided by Stripe.js](https://stripe.com/docs/js/tokens_sources/create_token?type=pii).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id_number_secondary: Option<&'a str>,
+
+    /// The person's last name.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_name: Option<&'a str>,
+
+    /// The Kana variation of the person's last name (Japan only).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_name_kana: Option<&'a str>,
+
+    /// The Kanji variation of the person's last name (Japan only).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_name_kanji: Option<&'a str>,
+
+    /// The person's maiden name.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub maiden_name: Option<&'a str>,
+
+    /// Set of [key-value pairs](https://stripe.com/docs/api/metadata) that you can attach to an object.
+    ///
+    /// This can be useful for storing additional information about the object in a structured format.
+    /// Individual keys can be unset by posting an empty value to them.
+    /// All keys can be unset by posting an empty value to `metadata`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+
+    /// The country where the person is a national.
+    ///
+    /// Two-letter country code ([ISO 3166-1 alpha-2](https://en.wikipedia.org/wiki/ISO_3166-1_alpha-2)), or "XX" if unavailable.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub nationality: Option<&'a str>,
+
+    /// A [person token](https://stripe.com/docs/connect/account-tokens), used to securely provide details to the person.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub person_token: Option<&'a str>,
+
+    /// The person's phone number.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub phone: Option<&'a str>,
+
+    /// Indicates if the person or any of their representatives, family members, or other closely related persons, declares that they hold or have held an important public job or function, in any jurisdiction.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub political_exposure: Option<PersonParamsPoliticalExposure>,
+
+    /// The person's registered address.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub registered_address: Option<PersonParamsRegisteredAddress>,
+
+    /// The relationship that this person has with the account's legal entity.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub relationship: Option<CreateAccountPersonRelationship>,
+
+    /// The last four digits of the person's Social Security number (U.S. only).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ssn_last_4: Option<&'a str>,
+
+    /// The person's verification status.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub verification: Option<PersonVerificationParams>,
+}
+
+impl<'a> UpdateAccountPerson<'a> {
+    pub fn new() -> Self {
+        UpdateAccountPerson {
+            address: Default::default(),
+            address_kana: Default::default(),
+            address_kanji: Default::default(),
+            dob: Default::default(),
+            documents: Default::default(),
+            email: Default::default(),
+            expand: Default::default(),
+            first_name: Default::default(),
+            first_name_kana: Default::default(),
+            first_name_kanji: Default::default(),
+            full_name_aliases: Default::default(),
+            gender: Default::default(),
+            id_number: Default::default(),
+            id_number_secondary: Default::default(),
+            last_name: Default::default(),
+            last_name_kana: Default::default(),
+            last_name_kanji: Default::default(),
+            maiden_name: Default::default(),
+            metadata: Default::default(),
+            nationality: Default::default(),
+            person_token: Default::default(),
+            phone: Default::default(),
+            political_exposure: Default::default(),
+            registered_address: Default::default(),
+            relationship: Default::default(),
+            ssn_last_4: Default::default(),
+            verification: Default::default(),
+        }
+    }
+}
+
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct AcceptTos {
     /// The Unix timestamp marking when the account representative accepted their service agreement.
@@ -2717,6 +3196,94 @@ pub struct TransferScheduleParams {
     pub weekly_anchor: Option<TransferScheduleParamsWeeklyAnchor>,
 }
 
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct CreateAccountPersonDocuments {
+    /// One or more documents that demonstrate proof that this person is authorized to represent the company.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub company_authorization: Option<CreateAccountPersonDocumentsCompanyAuthorization>,
+
+    /// One or more documents showing the person's passport page with photo and personal data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub passport: Option<CreateAccountPersonDocumentsPassport>,
+
+    /// One or more documents showing the person's visa required for living in the country where they are residing.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub visa: Option<CreateAccountPersonDocumentsVisa>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct CreateAccountPersonRelationship {
+    /// Whether the person is a director of the account's legal entity.
+    ///
+    /// Directors are typically members of the governing board of the company, or responsible for ensuring the company meets its regulatory obligations.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub director: Option<bool>,
+
+    /// Whether the person has significant responsibility to control, manage, or direct the organization.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub executive: Option<bool>,
+
+    /// Whether the person is an owner of the account’s legal entity.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub owner: Option<bool>,
+
+    /// The percent owned by the person of the account's legal entity.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub percent_ownership: Option<f64>,
+
+    /// Whether the person is authorized as the primary representative of the account.
+    ///
+    /// This is the person nominated by the business to provide information about themselves, and general information about the account.
+    /// There can only be one representative at any given time.
+    /// At the time the account is created, this person should be set to the person responsible for opening the account.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub representative: Option<bool>,
+
+    /// The person's title (e.g., CEO, Support Engineer).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct ListAccountPersonsRelationship {
+    /// A filter on the list of people returned based on whether these people are directors of the account's company.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub director: Option<bool>,
+
+    /// A filter on the list of people returned based on whether these people are executives of the account's company.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub executive: Option<bool>,
+
+    /// A filter on the list of people returned based on whether these people are owners of the account's company.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub owner: Option<bool>,
+
+    /// A filter on the list of people returned based on whether these people are the representative of the account's company.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub representative: Option<bool>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct CreateAccountPersonDocumentsCompanyAuthorization {
+    /// One or more document ids returned by a [file upload](https://stripe.com/docs/api#create_file) with a `purpose` value of `account_requirement`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub files: Option<Vec<String>>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct CreateAccountPersonDocumentsPassport {
+    /// One or more document ids returned by a [file upload](https://stripe.com/docs/api#create_file) with a `purpose` value of `account_requirement`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub files: Option<Vec<String>>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct CreateAccountPersonDocumentsVisa {
+    /// One or more document ids returned by a [file upload](https://stripe.com/docs/api#create_file) with a `purpose` value of `account_requirement`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub files: Option<Vec<String>>,
+}
+
 /// An enum representing the possible values of an `Account`'s `business_type` field.
 #[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
 #[serde(rename_all = "snake_case")]
@@ -3727,135 +4294,6 @@ impl std::default::Default for AccountCapabilitiesUsBankAccountAchPayments {
     }
 }
 
-/// An enum representing the possible values of an `AccountRequirementsError`'s `code` field.
-#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
-#[serde(rename_all = "snake_case")]
-pub enum AccountRequirementsErrorCode {
-    InvalidAddressCityStatePostalCode,
-    #[serde(rename = "invalid_dob_age_under_18")]
-    InvalidDobAgeUnder18,
-    InvalidRepresentativeCountry,
-    InvalidStreetAddress,
-    InvalidTosAcceptance,
-    InvalidValueOther,
-    VerificationDocumentAddressMismatch,
-    VerificationDocumentAddressMissing,
-    VerificationDocumentCorrupt,
-    VerificationDocumentCountryNotSupported,
-    VerificationDocumentDobMismatch,
-    VerificationDocumentDuplicateType,
-    VerificationDocumentExpired,
-    VerificationDocumentFailedCopy,
-    VerificationDocumentFailedGreyscale,
-    VerificationDocumentFailedOther,
-    VerificationDocumentFailedTestMode,
-    VerificationDocumentFraudulent,
-    VerificationDocumentIdNumberMismatch,
-    VerificationDocumentIdNumberMissing,
-    VerificationDocumentIncomplete,
-    VerificationDocumentInvalid,
-    VerificationDocumentIssueOrExpiryDateMissing,
-    VerificationDocumentManipulated,
-    VerificationDocumentMissingBack,
-    VerificationDocumentMissingFront,
-    VerificationDocumentNameMismatch,
-    VerificationDocumentNameMissing,
-    VerificationDocumentNationalityMismatch,
-    VerificationDocumentNotReadable,
-    VerificationDocumentNotSigned,
-    VerificationDocumentNotUploaded,
-    VerificationDocumentPhotoMismatch,
-    VerificationDocumentTooLarge,
-    VerificationDocumentTypeNotSupported,
-    VerificationFailedAddressMatch,
-    VerificationFailedBusinessIecNumber,
-    VerificationFailedDocumentMatch,
-    VerificationFailedIdNumberMatch,
-    VerificationFailedKeyedIdentity,
-    VerificationFailedKeyedMatch,
-    VerificationFailedNameMatch,
-    VerificationFailedOther,
-    VerificationFailedResidentialAddress,
-    VerificationFailedTaxIdMatch,
-    VerificationFailedTaxIdNotIssued,
-    VerificationMissingExecutives,
-    VerificationMissingOwners,
-    VerificationRequiresAdditionalMemorandumOfAssociations,
-}
-
-impl AccountRequirementsErrorCode {
-    pub fn as_str(self) -> &'static str {
-        match self {
-            AccountRequirementsErrorCode::InvalidAddressCityStatePostalCode => "invalid_address_city_state_postal_code",
-            AccountRequirementsErrorCode::InvalidDobAgeUnder18 => "invalid_dob_age_under_18",
-            AccountRequirementsErrorCode::InvalidRepresentativeCountry => "invalid_representative_country",
-            AccountRequirementsErrorCode::InvalidStreetAddress => "invalid_street_address",
-            AccountRequirementsErrorCode::InvalidTosAcceptance => "invalid_tos_acceptance",
-            AccountRequirementsErrorCode::InvalidValueOther => "invalid_value_other",
-            AccountRequirementsErrorCode::VerificationDocumentAddressMismatch => "verification_document_address_mismatch",
-            AccountRequirementsErrorCode::VerificationDocumentAddressMissing => "verification_document_address_missing",
-            AccountRequirementsErrorCode::VerificationDocumentCorrupt => "verification_document_corrupt",
-            AccountRequirementsErrorCode::VerificationDocumentCountryNotSupported => "verification_document_country_not_supported",
-            AccountRequirementsErrorCode::VerificationDocumentDobMismatch => "verification_document_dob_mismatch",
-            AccountRequirementsErrorCode::VerificationDocumentDuplicateType => "verification_document_duplicate_type",
-            AccountRequirementsErrorCode::VerificationDocumentExpired => "verification_document_expired",
-            AccountRequirementsErrorCode::VerificationDocumentFailedCopy => "verification_document_failed_copy",
-            AccountRequirementsErrorCode::VerificationDocumentFailedGreyscale => "verification_document_failed_greyscale",
-            AccountRequirementsErrorCode::VerificationDocumentFailedOther => "verification_document_failed_other",
-            AccountRequirementsErrorCode::VerificationDocumentFailedTestMode => "verification_document_failed_test_mode",
-            AccountRequirementsErrorCode::VerificationDocumentFraudulent => "verification_document_fraudulent",
-            AccountRequirementsErrorCode::VerificationDocumentIdNumberMismatch => "verification_document_id_number_mismatch",
-            AccountRequirementsErrorCode::VerificationDocumentIdNumberMissing => "verification_document_id_number_missing",
-            AccountRequirementsErrorCode::VerificationDocumentIncomplete => "verification_document_incomplete",
-            AccountRequirementsErrorCode::VerificationDocumentInvalid => "verification_document_invalid",
-            AccountRequirementsErrorCode::VerificationDocumentIssueOrExpiryDateMissing => "verification_document_issue_or_expiry_date_missing",
-            AccountRequirementsErrorCode::VerificationDocumentManipulated => "verification_document_manipulated",
-            AccountRequirementsErrorCode::VerificationDocumentMissingBack => "verification_document_missing_back",
-            AccountRequirementsErrorCode::VerificationDocumentMissingFront => "verification_document_missing_front",
-            AccountRequirementsErrorCode::VerificationDocumentNameMismatch => "verification_document_name_mismatch",
-            AccountRequirementsErrorCode::VerificationDocumentNameMissing => "verification_document_name_missing",
-            AccountRequirementsErrorCode::VerificationDocumentNationalityMismatch => "verification_document_nationality_mismatch",
-            AccountRequirementsErrorCode::VerificationDocumentNotReadable => "verification_document_not_readable",
-            AccountRequirementsErrorCode::VerificationDocumentNotSigned => "verification_document_not_signed",
-            AccountRequirementsErrorCode::VerificationDocumentNotUploaded => "verification_document_not_uploaded",
-            AccountRequirementsErrorCode::VerificationDocumentPhotoMismatch => "verification_document_photo_mismatch",
-            AccountRequirementsErrorCode::VerificationDocumentTooLarge => "verification_document_too_large",
-            AccountRequirementsErrorCode::VerificationDocumentTypeNotSupported => "verification_document_type_not_supported",
-            AccountRequirementsErrorCode::VerificationFailedAddressMatch => "verification_failed_address_match",
-            AccountRequirementsErrorCode::VerificationFailedBusinessIecNumber => "verification_failed_business_iec_number",
-            AccountRequirementsErrorCode::VerificationFailedDocumentMatch => "verification_failed_document_match",
-            AccountRequirementsErrorCode::VerificationFailedIdNumberMatch => "verification_failed_id_number_match",
-            AccountRequirementsErrorCode::VerificationFailedKeyedIdentity => "verification_failed_keyed_identity",
-            AccountRequirementsErrorCode::VerificationFailedKeyedMatch => "verification_failed_keyed_match",
-            AccountRequirementsErrorCode::VerificationFailedNameMatch => "verification_failed_name_match",
-            AccountRequirementsErrorCode::VerificationFailedOther => "verification_failed_other",
-            AccountRequirementsErrorCode::VerificationFailedResidentialAddress => "verification_failed_residential_address",
-            AccountRequirementsErrorCode::VerificationFailedTaxIdMatch => "verification_failed_tax_id_match",
-            AccountRequirementsErrorCode::VerificationFailedTaxIdNotIssued => "verification_failed_tax_id_not_issued",
-            AccountRequirementsErrorCode::VerificationMissingExecutives => "verification_missing_executives",
-            AccountRequirementsErrorCode::VerificationMissingOwners => "verification_missing_owners",
-            AccountRequirementsErrorCode::VerificationRequiresAdditionalMemorandumOfAssociations => "verification_requires_additional_memorandum_of_associations",
-        }
-    }
-}
-
-impl AsRef<str> for AccountRequirementsErrorCode {
-    fn as_ref(&self) -> &str {
-        self.as_str()
-    }
-}
-
-impl std::fmt::Display for AccountRequirementsErrorCode {
-    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
-        self.as_str().fmt(f)
-    }
-}
-impl std::default::Default for AccountRequirementsErrorCode {
-    fn default() -> Self {
-        Self::InvalidAddressCityStatePostalCode
-    }
-}
-
 /// An enum representing the possible values of an `CreateAccount`'s `type_` field.
 #[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
 #[serde(rename_all = "snake_case")]
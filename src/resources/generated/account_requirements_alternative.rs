@@ -0,0 +1,15 @@
+// ======================================
+// This file was automatically generated.
+// ======================================
+
+use serde::{Deserialize, Serialize};
+
+/// The resource representing a Stripe "AccountRequirementsAlternative".
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct AccountRequirementsAlternative {
+    /// Fields that can be provided to satisfy all fields in `original_fields_due`.
+    pub alternative_fields_due: Vec<String>,
+
+    /// Fields that are due and can be satisfied by providing all fields in `alternative_fields_due`.
+    pub original_fields_due: Vec<String>,
+}
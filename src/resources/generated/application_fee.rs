@@ -5,8 +5,8 @@
 use serde::{Deserialize, Serialize};
 
 use crate::client::{Client, Response};
-use crate::ids::{ApplicationFeeId, ChargeId};
-use crate::params::{Expand, Expandable, List, Object, Paginable, RangeQuery, Timestamp};
+use crate::ids::{ApplicationFeeId, ApplicationFeeRefundId, ChargeId};
+use crate::params::{Expand, Expandable, List, Metadata, Object, Paginable, RangeQuery, Timestamp};
 use crate::resources::{
     Account, Application, ApplicationFeeRefund, BalanceTransaction, Charge, Currency,
 };
@@ -83,6 +83,57 @@ impl ApplicationFee {
     ) -> Response<ApplicationFee> {
         client.get_query(&format!("/application_fees/{}", id), &Expand { expand })
     }
+
+    /// Refunds an application fee that has previously been collected but not yet refunded.
+    ///
+    /// Funds will be refunded to the Stripe account from which the fee was originally collected.
+    /// You can optionally refund only part of an application fee.
+    /// You can do so multiple times, until the entire fee has been refunded.
+    /// Once entirely refunded, an application fee can’t be refunded again.
+    /// This method will raise an error when called on an already-refunded application fee, or when trying to refund more money than is left on an application fee.
+    pub fn create_refund(
+        client: &Client,
+        id: &ApplicationFeeId,
+        params: CreateApplicationFeeRefund<'_>,
+    ) -> Response<ApplicationFeeRefund> {
+        client.post_form(&format!("/application_fees/{}/refunds", id), &params)
+    }
+
+    /// You can see a list of the refunds belonging to a specific application fee.
+    ///
+    /// Note that the 10 most recent refunds are always available by default on the application fee object.
+    /// If you need more than those 10, you can use this API method and the `limit` and `starting_after` parameters to page through additional refunds.
+    pub fn list_refunds(
+        client: &Client,
+        id: &ApplicationFeeId,
+        params: &ListApplicationFeeRefunds<'_>,
+    ) -> Response<List<ApplicationFeeRefund>> {
+        client.get_query(&format!("/application_fees/{}/refunds", id), &params)
+    }
+
+    /// By default, you can see the 10 most recent refunds stored directly on the application fee object, but you can also retrieve details about a specific refund stored on the application fee.
+    pub fn retrieve_refund(
+        client: &Client,
+        id: &ApplicationFeeId,
+        refund: &ApplicationFeeRefundId,
+        expand: &[&str],
+    ) -> Response<ApplicationFeeRefund> {
+        client
+            .get_query(&format!("/application_fees/{}/refunds/{}", id, refund), &Expand { expand })
+    }
+
+    /// Updates the specified application fee refund by setting the values of the parameters passed.
+    ///
+    /// Any parameters not provided will be left unchanged.
+    /// This request only accepts metadata as an argument.
+    pub fn update_refund(
+        client: &Client,
+        id: &ApplicationFeeId,
+        refund: &ApplicationFeeRefundId,
+        params: UpdateApplicationFeeRefund<'_>,
+    ) -> Response<ApplicationFeeRefund> {
+        client.post_form(&format!("/application_fees/{}/refunds/{}", id, refund), &params)
+    }
 }
 
 impl Object for ApplicationFee {
@@ -95,6 +146,83 @@ impl Object for ApplicationFee {
     }
 }
 
+/// The parameters for `ApplicationFee::create_refund`.
+#[derive(Clone, Debug, Serialize, Default)]
+pub struct CreateApplicationFeeRefund<'a> {
+    /// A positive integer, in _cents (or local equivalent)_, representing how much of this fee to refund.
+    ///
+    /// Can refund only up to the remaining unrefunded amount of the fee.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub amount: Option<i64>,
+
+    /// Specifies which fields in the response should be expanded.
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+
+    /// Set of [key-value pairs](https://stripe.com/docs/api/metadata) that you can attach to an object.
+    ///
+    /// This can be useful for storing additional information about the object in a structured format.
+    /// Individual keys can be unset by posting an empty value to them.
+    /// All keys can be unset by posting an empty value to `metadata`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+}
+
+impl<'a> CreateApplicationFeeRefund<'a> {
+    pub fn new() -> Self {
+        CreateApplicationFeeRefund {
+            amount: Default::default(),
+            expand: Default::default(),
+            metadata: Default::default(),
+        }
+    }
+}
+
+/// The parameters for `ApplicationFee::list_refunds`.
+#[derive(Clone, Debug, Serialize, Default)]
+pub struct ListApplicationFeeRefunds<'a> {
+    /// A cursor for use in pagination.
+    ///
+    /// `ending_before` is an object ID that defines your place in the list.
+    /// For instance, if you make a list request and receive 100 objects, starting with `obj_bar`, your subsequent call can include `ending_before=obj_bar` in order to fetch the previous page of the list.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ending_before: Option<ApplicationFeeRefundId>,
+
+    /// Specifies which fields in the response should be expanded.
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+
+    /// A limit on the number of objects to be returned.
+    ///
+    /// Limit can range between 1 and 100, and the default is 10.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u64>,
+
+    /// A cursor for use in pagination.
+    ///
+    /// `starting_after` is an object ID that defines your place in the list.
+    /// For instance, if you make a list request and receive 100 objects, ending with `obj_foo`, your subsequent call can include `starting_after=obj_foo` in order to fetch the next page of the list.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub starting_after: Option<ApplicationFeeRefundId>,
+}
+
+impl<'a> ListApplicationFeeRefunds<'a> {
+    pub fn new() -> Self {
+        ListApplicationFeeRefunds {
+            ending_before: Default::default(),
+            expand: Default::default(),
+            limit: Default::default(),
+            starting_after: Default::default(),
+        }
+    }
+}
+impl Paginable for ListApplicationFeeRefunds<'_> {
+    type O = ApplicationFeeRefund;
+    fn set_last(&mut self, item: Self::O) {
+        self.starting_after = Some(item.id());
+    }
+}
+
 /// The parameters for `ApplicationFee::list`.
 #[derive(Clone, Debug, Serialize, Default)]
 pub struct ListApplicationFees<'a> {
@@ -148,3 +276,25 @@ impl Paginable for ListApplicationFees<'_> {
         self.starting_after = Some(item.id());
     }
 }
+
+/// The parameters for `ApplicationFee::update_refund`.
+#[derive(Clone, Debug, Serialize, Default)]
+pub struct UpdateApplicationFeeRefund<'a> {
+    /// Specifies which fields in the response should be expanded.
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+
+    /// Set of [key-value pairs](https://stripe.com/docs/api/metadata) that you can attach to an object.
+    ///
+    /// This can be useful for storing additional information about the object in a structured format.
+    /// Individual keys can be unset by posting an empty value to them.
+    /// All keys can be unset by posting an empty value to `metadata`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+}
+
+impl<'a> UpdateApplicationFeeRefund<'a> {
+    pub fn new() -> Self {
+        UpdateApplicationFeeRefund { expand: Default::default(), metadata: Default::default() }
+    }
+}
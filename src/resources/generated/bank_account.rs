@@ -6,7 +6,7 @@ use serde::{Deserialize, Serialize};
 
 use crate::ids::BankAccountId;
 use crate::params::{Expandable, Metadata, Object};
-use crate::resources::{Account, BankAccountStatus, Currency, Customer};
+use crate::resources::{Account, AccountRequirementsError, BankAccountStatus, Currency, Customer};
 
 /// The resource representing a Stripe "BankAccount".
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
@@ -140,147 +140,6 @@ pub struct ExternalAccountRequirements {
     pub pending_verification: Option<Vec<String>>,
 }
 
-#[derive(Clone, Debug, Default, Deserialize, Serialize)]
-pub struct AccountRequirementsError {
-    /// The code for the type of error.
-    pub code: AccountRequirementsErrorCode,
-
-    /// An informative message that indicates the error type and provides additional details about the error.
-    pub reason: String,
-
-    /// The specific user onboarding requirement field (in the requirements hash) that needs to be resolved.
-    pub requirement: String,
-}
-
-/// An enum representing the possible values of an `AccountRequirementsError`'s `code` field.
-#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
-#[serde(rename_all = "snake_case")]
-pub enum AccountRequirementsErrorCode {
-    InvalidAddressCityStatePostalCode,
-    #[serde(rename = "invalid_dob_age_under_18")]
-    InvalidDobAgeUnder18,
-    InvalidRepresentativeCountry,
-    InvalidStreetAddress,
-    InvalidTosAcceptance,
-    InvalidValueOther,
-    VerificationDocumentAddressMismatch,
-    VerificationDocumentAddressMissing,
-    VerificationDocumentCorrupt,
-    VerificationDocumentCountryNotSupported,
-    VerificationDocumentDobMismatch,
-    VerificationDocumentDuplicateType,
-    VerificationDocumentExpired,
-    VerificationDocumentFailedCopy,
-    VerificationDocumentFailedGreyscale,
-    VerificationDocumentFailedOther,
-    VerificationDocumentFailedTestMode,
-    VerificationDocumentFraudulent,
-    VerificationDocumentIdNumberMismatch,
-    VerificationDocumentIdNumberMissing,
-    VerificationDocumentIncomplete,
-    VerificationDocumentInvalid,
-    VerificationDocumentIssueOrExpiryDateMissing,
-    VerificationDocumentManipulated,
-    VerificationDocumentMissingBack,
-    VerificationDocumentMissingFront,
-    VerificationDocumentNameMismatch,
-    VerificationDocumentNameMissing,
-    VerificationDocumentNationalityMismatch,
-    VerificationDocumentNotReadable,
-    VerificationDocumentNotSigned,
-    VerificationDocumentNotUploaded,
-    VerificationDocumentPhotoMismatch,
-    VerificationDocumentTooLarge,
-    VerificationDocumentTypeNotSupported,
-    VerificationFailedAddressMatch,
-    VerificationFailedBusinessIecNumber,
-    VerificationFailedDocumentMatch,
-    VerificationFailedIdNumberMatch,
-    VerificationFailedKeyedIdentity,
-    VerificationFailedKeyedMatch,
-    VerificationFailedNameMatch,
-    VerificationFailedOther,
-    VerificationFailedResidentialAddress,
-    VerificationFailedTaxIdMatch,
-    VerificationFailedTaxIdNotIssued,
-    VerificationMissingExecutives,
-    VerificationMissingOwners,
-    VerificationRequiresAdditionalMemorandumOfAssociations,
-}
-
-impl AccountRequirementsErrorCode {
-    pub fn as_str(self) -> &'static str {
-        match self {
-            AccountRequirementsErrorCode::InvalidAddressCityStatePostalCode => "invalid_address_city_state_postal_code",
-            AccountRequirementsErrorCode::InvalidDobAgeUnder18 => "invalid_dob_age_under_18",
-            AccountRequirementsErrorCode::InvalidRepresentativeCountry => "invalid_representative_country",
-            AccountRequirementsErrorCode::InvalidStreetAddress => "invalid_street_address",
-            AccountRequirementsErrorCode::InvalidTosAcceptance => "invalid_tos_acceptance",
-            AccountRequirementsErrorCode::InvalidValueOther => "invalid_value_other",
-            AccountRequirementsErrorCode::VerificationDocumentAddressMismatch => "verification_document_address_mismatch",
-            AccountRequirementsErrorCode::VerificationDocumentAddressMissing => "verification_document_address_missing",
-            AccountRequirementsErrorCode::VerificationDocumentCorrupt => "verification_document_corrupt",
-            AccountRequirementsErrorCode::VerificationDocumentCountryNotSupported => "verification_document_country_not_supported",
-            AccountRequirementsErrorCode::VerificationDocumentDobMismatch => "verification_document_dob_mismatch",
-            AccountRequirementsErrorCode::VerificationDocumentDuplicateType => "verification_document_duplicate_type",
-            AccountRequirementsErrorCode::VerificationDocumentExpired => "verification_document_expired",
-            AccountRequirementsErrorCode::VerificationDocumentFailedCopy => "verification_document_failed_copy",
-            AccountRequirementsErrorCode::VerificationDocumentFailedGreyscale => "verification_document_failed_greyscale",
-            AccountRequirementsErrorCode::VerificationDocumentFailedOther => "verification_document_failed_other",
-            AccountRequirementsErrorCode::VerificationDocumentFailedTestMode => "verification_document_failed_test_mode",
-            AccountRequirementsErrorCode::VerificationDocumentFraudulent => "verification_document_fraudulent",
-            AccountRequirementsErrorCode::VerificationDocumentIdNumberMismatch => "verification_document_id_number_mismatch",
-            AccountRequirementsErrorCode::VerificationDocumentIdNumberMissing => "verification_document_id_number_missing",
-            AccountRequirementsErrorCode::VerificationDocumentIncomplete => "verification_document_incomplete",
-            AccountRequirementsErrorCode::VerificationDocumentInvalid => "verification_document_invalid",
-            AccountRequirementsErrorCode::VerificationDocumentIssueOrExpiryDateMissing => "verification_document_issue_or_expiry_date_missing",
-            AccountRequirementsErrorCode::VerificationDocumentManipulated => "verification_document_manipulated",
-            AccountRequirementsErrorCode::VerificationDocumentMissingBack => "verification_document_missing_back",
-            AccountRequirementsErrorCode::VerificationDocumentMissingFront => "verification_document_missing_front",
-            AccountRequirementsErrorCode::VerificationDocumentNameMismatch => "verification_document_name_mismatch",
-            AccountRequirementsErrorCode::VerificationDocumentNameMissing => "verification_document_name_missing",
-            AccountRequirementsErrorCode::VerificationDocumentNationalityMismatch => "verification_document_nationality_mismatch",
-            AccountRequirementsErrorCode::VerificationDocumentNotReadable => "verification_document_not_readable",
-            AccountRequirementsErrorCode::VerificationDocumentNotSigned => "verification_document_not_signed",
-            AccountRequirementsErrorCode::VerificationDocumentNotUploaded => "verification_document_not_uploaded",
-            AccountRequirementsErrorCode::VerificationDocumentPhotoMismatch => "verification_document_photo_mismatch",
-            AccountRequirementsErrorCode::VerificationDocumentTooLarge => "verification_document_too_large",
-            AccountRequirementsErrorCode::VerificationDocumentTypeNotSupported => "verification_document_type_not_supported",
-            AccountRequirementsErrorCode::VerificationFailedAddressMatch => "verification_failed_address_match",
-            AccountRequirementsErrorCode::VerificationFailedBusinessIecNumber => "verification_failed_business_iec_number",
-            AccountRequirementsErrorCode::VerificationFailedDocumentMatch => "verification_failed_document_match",
-            AccountRequirementsErrorCode::VerificationFailedIdNumberMatch => "verification_failed_id_number_match",
-            AccountRequirementsErrorCode::VerificationFailedKeyedIdentity => "verification_failed_keyed_identity",
-            AccountRequirementsErrorCode::VerificationFailedKeyedMatch => "verification_failed_keyed_match",
-            AccountRequirementsErrorCode::VerificationFailedNameMatch => "verification_failed_name_match",
-            AccountRequirementsErrorCode::VerificationFailedOther => "verification_failed_other",
-            AccountRequirementsErrorCode::VerificationFailedResidentialAddress => "verification_failed_residential_address",
-            AccountRequirementsErrorCode::VerificationFailedTaxIdMatch => "verification_failed_tax_id_match",
-            AccountRequirementsErrorCode::VerificationFailedTaxIdNotIssued => "verification_failed_tax_id_not_issued",
-            AccountRequirementsErrorCode::VerificationMissingExecutives => "verification_missing_executives",
-            AccountRequirementsErrorCode::VerificationMissingOwners => "verification_missing_owners",
-            AccountRequirementsErrorCode::VerificationRequiresAdditionalMemorandumOfAssociations => "verification_requires_additional_memorandum_of_associations",
-        }
-    }
-}
-
-impl AsRef<str> for AccountRequirementsErrorCode {
-    fn as_ref(&self) -> &str {
-        self.as_str()
-    }
-}
-
-impl std::fmt::Display for AccountRequirementsErrorCode {
-    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
-        self.as_str().fmt(f)
-    }
-}
-impl std::default::Default for AccountRequirementsErrorCode {
-    fn default() -> Self {
-        Self::InvalidAddressCityStatePostalCode
-    }
-}
-
 /// An enum representing the possible values of an `BankAccount`'s `available_payout_methods` field.
 #[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
 #[serde(rename_all = "snake_case")]
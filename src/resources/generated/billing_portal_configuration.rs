@@ -4,8 +4,9 @@
 
 use serde::{Deserialize, Serialize};
 
+use crate::client::{Client, Response};
 use crate::ids::BillingPortalConfigurationId;
-use crate::params::{Expandable, Metadata, Object, Timestamp};
+use crate::params::{Expand, Expandable, List, Metadata, Object, Paginable, Timestamp};
 use crate::resources::Application;
 
 /// The resource representing a Stripe "PortalConfiguration".
@@ -55,6 +56,42 @@ pub struct BillingPortalConfiguration {
     pub updated: Timestamp,
 }
 
+impl BillingPortalConfiguration {
+    /// Returns a list of configurations that describe the functionality of the customer portal.
+    pub fn list(
+        client: &Client,
+        params: &ListBillingPortalConfigurations<'_>,
+    ) -> Response<List<BillingPortalConfiguration>> {
+        client.get_query("/billing_portal/configurations", &params)
+    }
+
+    /// Creates a configuration that describes the functionality and behavior of a PortalSession.
+    pub fn create(
+        client: &Client,
+        params: CreateBillingPortalConfiguration<'_>,
+    ) -> Response<BillingPortalConfiguration> {
+        client.post_form("/billing_portal/configurations", &params)
+    }
+
+    /// Retrieves a configuration that describes the functionality of the customer portal.
+    pub fn retrieve(
+        client: &Client,
+        id: &BillingPortalConfigurationId,
+        expand: &[&str],
+    ) -> Response<BillingPortalConfiguration> {
+        client.get_query(&format!("/billing_portal/configurations/{}", id), &Expand { expand })
+    }
+
+    /// Updates a configuration that describes the functionality of the customer portal.
+    pub fn update(
+        client: &Client,
+        id: &BillingPortalConfigurationId,
+        params: UpdateBillingPortalConfiguration<'_>,
+    ) -> Response<BillingPortalConfiguration> {
+        client.post_form(&format!("/billing_portal/configurations/{}", id), &params)
+    }
+}
+
 impl Object for BillingPortalConfiguration {
     type Id = BillingPortalConfigurationId;
     fn id(&self) -> Self::Id {
@@ -187,6 +224,453 @@ pub struct PortalSubscriptionUpdateProduct {
     pub product: String,
 }
 
+/// The parameters for `BillingPortalConfiguration::create`.
+#[derive(Clone, Debug, Serialize)]
+pub struct CreateBillingPortalConfiguration<'a> {
+    /// The business information shown to customers in the portal.
+    pub business_profile: CreateBillingPortalConfigurationBusinessProfile,
+
+    /// The default URL to redirect customers to when they click on the portal's link to return to your website.
+    ///
+    /// This can be [overriden](https://stripe.com/docs/api/customer_portal/sessions/create#create_portal_session-return_url) when creating the session.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default_return_url: Option<String>,
+
+    /// Specifies which fields in the response should be expanded.
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+
+    /// Information about the features available in the portal.
+    pub features: CreateBillingPortalConfigurationFeatures,
+
+    /// The hosted login page for this configuration.
+    ///
+    /// Learn more about the portal login page in our [integration docs](https://stripe.com/docs/billing/subscriptions/integrating-customer-portal#share).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub login_page: Option<CreateBillingPortalConfigurationLoginPage>,
+
+    /// Set of [key-value pairs](https://stripe.com/docs/api/metadata) that you can attach to an object.
+    ///
+    /// This can be useful for storing additional information about the object in a structured format.
+    /// Individual keys can be unset by posting an empty value to them.
+    /// All keys can be unset by posting an empty value to `metadata`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+}
+
+impl<'a> CreateBillingPortalConfiguration<'a> {
+    pub fn new(
+        business_profile: CreateBillingPortalConfigurationBusinessProfile,
+        features: CreateBillingPortalConfigurationFeatures,
+    ) -> Self {
+        CreateBillingPortalConfiguration {
+            business_profile,
+            default_return_url: Default::default(),
+            expand: Default::default(),
+            features,
+            login_page: Default::default(),
+            metadata: Default::default(),
+        }
+    }
+}
+
+/// The parameters for `BillingPortalConfiguration::list`.
+#[derive(Clone, Debug, Serialize, Default)]
+pub struct ListBillingPortalConfigurations<'a> {
+    /// Only return configurations that are active or inactive (e.g., pass `true` to only list active configurations).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub active: Option<bool>,
+
+    /// A cursor for use in pagination.
+    ///
+    /// `ending_before` is an object ID that defines your place in the list.
+    /// For instance, if you make a list request and receive 100 objects, starting with `obj_bar`, your subsequent call can include `ending_before=obj_bar` in order to fetch the previous page of the list.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ending_before: Option<BillingPortalConfigurationId>,
+
+    /// Specifies which fields in the response should be expanded.
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+
+    /// Only return the default or non-default configurations (e.g., pass `true` to only list the default configuration).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_default: Option<bool>,
+
+    /// A limit on the number of objects to be returned.
+    ///
+    /// Limit can range between 1 and 100, and the default is 10.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u64>,
+
+    /// A cursor for use in pagination.
+    ///
+    /// `starting_after` is an object ID that defines your place in the list.
+    /// For instance, if you make a list request and receive 100 objects, ending with `obj_foo`, your subsequent call can include `starting_after=obj_foo` in order to fetch the next page of the list.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub starting_after: Option<BillingPortalConfigurationId>,
+}
+
+impl<'a> ListBillingPortalConfigurations<'a> {
+    pub fn new() -> Self {
+        ListBillingPortalConfigurations {
+            active: Default::default(),
+            ending_before: Default::default(),
+            expand: Default::default(),
+            is_default: Default::default(),
+            limit: Default::default(),
+            starting_after: Default::default(),
+        }
+    }
+}
+impl Paginable for ListBillingPortalConfigurations<'_> {
+    type O = BillingPortalConfiguration;
+    fn set_last(&mut self, item: Self::O) {
+        self.starting_after = Some(item.id());
+    }
+}
+
+/// The parameters for `BillingPortalConfiguration::update`.
+#[derive(Clone, Debug, Serialize, Default)]
+pub struct UpdateBillingPortalConfiguration<'a> {
+    /// Whether the configuration is active and can be used to create portal sessions.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub active: Option<bool>,
+
+    /// The business information shown to customers in the portal.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub business_profile: Option<UpdateBillingPortalConfigurationBusinessProfile>,
+
+    /// The default URL to redirect customers to when they click on the portal's link to return to your website.
+    ///
+    /// This can be [overriden](https://stripe.com/docs/api/customer_portal/sessions/create#create_portal_session-return_url) when creating the session.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default_return_url: Option<String>,
+
+    /// Specifies which fields in the response should be expanded.
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+
+    /// Information about the features available in the portal.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub features: Option<UpdateBillingPortalConfigurationFeatures>,
+
+    /// The hosted login page for this configuration.
+    ///
+    /// Learn more about the portal login page in our [integration docs](https://stripe.com/docs/billing/subscriptions/integrating-customer-portal#share).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub login_page: Option<UpdateBillingPortalConfigurationLoginPage>,
+
+    /// Set of [key-value pairs](https://stripe.com/docs/api/metadata) that you can attach to an object.
+    ///
+    /// This can be useful for storing additional information about the object in a structured format.
+    /// Individual keys can be unset by posting an empty value to them.
+    /// All keys can be unset by posting an empty value to `metadata`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+}
+
+impl<'a> UpdateBillingPortalConfiguration<'a> {
+    pub fn new() -> Self {
+        UpdateBillingPortalConfiguration {
+            active: Default::default(),
+            business_profile: Default::default(),
+            default_return_url: Default::default(),
+            expand: Default::default(),
+            features: Default::default(),
+            login_page: Default::default(),
+            metadata: Default::default(),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct CreateBillingPortalConfigurationBusinessProfile {
+    /// The messaging shown to customers in the portal.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub headline: Option<String>,
+
+    /// A link to the business’s publicly available privacy policy.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub privacy_policy_url: Option<String>,
+
+    /// A link to the business’s publicly available terms of service.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub terms_of_service_url: Option<String>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct CreateBillingPortalConfigurationFeatures {
+    /// Information about updating the customer details in the portal.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub customer_update: Option<CreateBillingPortalConfigurationFeaturesCustomerUpdate>,
+
+    /// Information about showing the billing history in the portal.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub invoice_history: Option<CreateBillingPortalConfigurationFeaturesInvoiceHistory>,
+
+    /// Information about updating payment methods in the portal.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub payment_method_update: Option<CreateBillingPortalConfigurationFeaturesPaymentMethodUpdate>,
+
+    /// Information about canceling subscriptions in the portal.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub subscription_cancel: Option<CreateBillingPortalConfigurationFeaturesSubscriptionCancel>,
+
+    /// Information about pausing subscriptions in the portal.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub subscription_pause: Option<CreateBillingPortalConfigurationFeaturesSubscriptionPause>,
+
+    /// Information about updating subscriptions in the portal.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub subscription_update: Option<CreateBillingPortalConfigurationFeaturesSubscriptionUpdate>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct CreateBillingPortalConfigurationLoginPage {
+    /// Set to `true` to generate a shareable URL [`login_page.url`](https://stripe.com/docs/api/customer_portal/configuration#portal_configuration_object-login_page-url) that will take your customers to a hosted login page for the customer portal.
+    ///
+    /// Set to `false` to deactivate the `login_page.url`.
+    pub enabled: bool,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct UpdateBillingPortalConfigurationBusinessProfile {
+    /// The messaging shown to customers in the portal.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub headline: Option<String>,
+
+    /// A link to the business’s publicly available privacy policy.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub privacy_policy_url: Option<String>,
+
+    /// A link to the business’s publicly available terms of service.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub terms_of_service_url: Option<String>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct UpdateBillingPortalConfigurationLoginPage {
+    /// Set to `true` to generate a shareable URL [`login_page.url`](https://stripe.com/docs/api/customer_portal/configuration#portal_configuration_object-login_page-url) that will take your customers to a hosted login page for the customer portal.
+    ///
+    /// Set to `false` to deactivate the `login_page.url`.
+    pub enabled: bool,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct UpdateBillingPortalConfigurationFeatures {
+    /// Information about updating the customer details in the portal.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub customer_update: Option<UpdateBillingPortalConfigurationFeaturesCustomerUpdate>,
+
+    /// Information about showing the billing history in the portal.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub invoice_history: Option<UpdateBillingPortalConfigurationFeaturesInvoiceHistory>,
+
+    /// Information about updating payment methods in the portal.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub payment_method_update: Option<UpdateBillingPortalConfigurationFeaturesPaymentMethodUpdate>,
+
+    /// Information about canceling subscriptions in the portal.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub subscription_cancel: Option<UpdateBillingPortalConfigurationFeaturesSubscriptionCancel>,
+
+    /// Information about pausing subscriptions in the portal.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub subscription_pause: Option<UpdateBillingPortalConfigurationFeaturesSubscriptionPause>,
+
+    /// Information about updating subscriptions in the portal.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub subscription_update: Option<UpdateBillingPortalConfigurationFeaturesSubscriptionUpdate>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct CreateBillingPortalConfigurationFeaturesCustomerUpdate {
+    /// The types of customer updates that are supported.
+    ///
+    /// When empty, customers are not updateable.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allowed_updates: Option<Vec<PortalCustomerUpdateAllowedUpdates>>,
+
+    /// Whether the feature is enabled.
+    pub enabled: bool,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct CreateBillingPortalConfigurationFeaturesInvoiceHistory {
+    /// Whether the feature is enabled.
+    pub enabled: bool,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct CreateBillingPortalConfigurationFeaturesPaymentMethodUpdate {
+    /// Whether the feature is enabled.
+    pub enabled: bool,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct CreateBillingPortalConfigurationFeaturesSubscriptionCancel {
+    /// Whether the cancellation reasons will be collected in the portal and which options are exposed to the customer.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cancellation_reason:
+        Option<CreateBillingPortalConfigurationFeaturesSubscriptionCancelCancellationReason>,
+
+    /// Whether the feature is enabled.
+    pub enabled: bool,
+
+    /// Whether to cancel subscriptions immediately or at the end of the billing period.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mode: Option<PortalSubscriptionCancelMode>,
+
+    /// Whether to create prorations when canceling subscriptions.
+    ///
+    /// Possible values are `none` and `create_prorations`, which is only compatible with `mode=immediately`.
+    /// No prorations are generated when canceling a subscription at the end of its natural billing period.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub proration_behavior: Option<PortalSubscriptionCancelProrationBehavior>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct CreateBillingPortalConfigurationFeaturesSubscriptionPause {
+    /// Whether the feature is enabled.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enabled: Option<bool>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct CreateBillingPortalConfigurationFeaturesSubscriptionUpdate {
+    /// The types of subscription updates that are supported.
+    ///
+    /// When empty, subscriptions are not updateable.
+    pub default_allowed_updates: Vec<PortalSubscriptionUpdateDefaultAllowedUpdates>,
+
+    /// Whether the feature is enabled.
+    pub enabled: bool,
+
+    /// The list of products that support subscription updates.
+    pub products: Vec<CreateBillingPortalConfigurationFeaturesSubscriptionUpdateProducts>,
+
+    /// Determines how to handle prorations resulting from subscription updates.
+    ///
+    /// Valid values are `none`, `create_prorations`, and `always_invoice`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub proration_behavior: Option<PortalSubscriptionUpdateProrationBehavior>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct CreateBillingPortalConfigurationFeaturesSubscriptionCancelCancellationReason {
+    /// Whether the feature is enabled.
+    pub enabled: bool,
+
+    /// Which cancellation reasons will be given as options to the customer.
+    pub options: Vec<PortalSubscriptionCancellationReasonOptions>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct CreateBillingPortalConfigurationFeaturesSubscriptionUpdateProducts {
+    /// The list of price IDs for the product that a subscription can be updated to.
+    pub prices: Vec<String>,
+
+    /// The product id.
+    pub product: String,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct UpdateBillingPortalConfigurationFeaturesCustomerUpdate {
+    /// The types of customer updates that are supported.
+    ///
+    /// When empty, customers are not updateable.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allowed_updates: Option<Vec<PortalCustomerUpdateAllowedUpdates>>,
+
+    /// Whether the feature is enabled.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enabled: Option<bool>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct UpdateBillingPortalConfigurationFeaturesInvoiceHistory {
+    /// Whether the feature is enabled.
+    pub enabled: bool,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct UpdateBillingPortalConfigurationFeaturesPaymentMethodUpdate {
+    /// Whether the feature is enabled.
+    pub enabled: bool,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct UpdateBillingPortalConfigurationFeaturesSubscriptionCancel {
+    /// Whether the cancellation reasons will be collected in the portal and which options are exposed to the customer.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cancellation_reason:
+        Option<UpdateBillingPortalConfigurationFeaturesSubscriptionCancelCancellationReason>,
+
+    /// Whether the feature is enabled.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enabled: Option<bool>,
+
+    /// Whether to cancel subscriptions immediately or at the end of the billing period.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mode: Option<PortalSubscriptionCancelMode>,
+
+    /// Whether to create prorations when canceling subscriptions.
+    ///
+    /// Possible values are `none` and `create_prorations`, which is only compatible with `mode=immediately`.
+    /// No prorations are generated when canceling a subscription at the end of its natural billing period.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub proration_behavior: Option<PortalSubscriptionCancelProrationBehavior>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct UpdateBillingPortalConfigurationFeaturesSubscriptionPause {
+    /// Whether the feature is enabled.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enabled: Option<bool>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct UpdateBillingPortalConfigurationFeaturesSubscriptionUpdate {
+    /// The types of subscription updates that are supported.
+    ///
+    /// When empty, subscriptions are not updateable.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default_allowed_updates: Option<Vec<PortalSubscriptionUpdateDefaultAllowedUpdates>>,
+
+    /// Whether the feature is enabled.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enabled: Option<bool>,
+
+    /// The list of products that support subscription updates.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub products: Option<Vec<UpdateBillingPortalConfigurationFeaturesSubscriptionUpdateProducts>>,
+
+    /// Determines how to handle prorations resulting from subscription updates.
+    ///
+    /// Valid values are `none`, `create_prorations`, and `always_invoice`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub proration_behavior: Option<PortalSubscriptionUpdateProrationBehavior>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct UpdateBillingPortalConfigurationFeaturesSubscriptionCancelCancellationReason {
+    /// Whether the feature is enabled.
+    pub enabled: bool,
+
+    /// Which cancellation reasons will be given as options to the customer.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub options: Option<Vec<PortalSubscriptionCancellationReasonOptions>>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct UpdateBillingPortalConfigurationFeaturesSubscriptionUpdateProducts {
+    /// The list of price IDs for the product that a subscription can be updated to.
+    pub prices: Vec<String>,
+
+    /// The product id.
+    pub product: String,
+}
+
 /// An enum representing the possible values of an `PortalCustomerUpdate`'s `allowed_updates` field.
 #[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
 #[serde(rename_all = "snake_case")]
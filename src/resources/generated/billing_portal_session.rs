@@ -5,7 +5,7 @@
 use serde::{Deserialize, Serialize};
 
 use crate::client::{Client, Response};
-use crate::ids::{BillingPortalSessionId, CustomerId};
+use crate::ids::{BillingPortalConfigurationId, BillingPortalSessionId, CustomerId};
 use crate::params::{Expand, Expandable, Object, Timestamp};
 use crate::resources::BillingPortalConfiguration;
 
@@ -80,6 +80,12 @@ pub struct PortalFlowsFlow {
     /// Configuration when `flow.type=subscription_cancel`.
     pub subscription_cancel: Option<PortalFlowsFlowSubscriptionCancel>,
 
+    /// Configuration when `flow.type=subscription_update`.
+    pub subscription_update: Option<PortalFlowsFlowSubscriptionUpdate>,
+
+    /// Configuration when `flow.type=subscription_update_confirm`.
+    pub subscription_update_confirm: Option<PortalFlowsFlowSubscriptionUpdateConfirm>,
+
     /// Type of flow that the customer will go through.
     #[serde(rename = "type")]
     pub type_: PortalFlowsFlowType,
@@ -116,6 +122,51 @@ pub struct PortalFlowsFlowSubscriptionCancel {
     pub subscription: String,
 }
 
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct PortalFlowsFlowSubscriptionUpdate {
+    /// The ID of the subscription to be updated.
+    pub subscription: String,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct PortalFlowsFlowSubscriptionUpdateConfirm {
+    /// The coupon or promotion code to apply to this subscription update.
+    ///
+    /// Currently, only up to one may be specified.
+    pub discounts: Option<Vec<PortalFlowsSubscriptionUpdateConfirmDiscount>>,
+
+    /// The [subscription item](https://stripe.com/docs/api/subscription_items) to be updated through this flow.
+    ///
+    /// Currently, only up to one may be specified and subscriptions with multiple items are not updatable.
+    pub items: Vec<PortalFlowsSubscriptionUpdateConfirmItem>,
+
+    /// The ID of the subscription to be updated.
+    pub subscription: String,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct PortalFlowsSubscriptionUpdateConfirmDiscount {
+    /// The ID of the coupon to apply to this subscription update.
+    pub coupon: Option<String>,
+
+    /// The ID of a promotion code to apply to this subscription update.
+    pub promotion_code: Option<String>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct PortalFlowsSubscriptionUpdateConfirmItem {
+    /// The ID of the [subscription item](https://stripe.com/docs/api/subscriptions/object#subscription_object-items-data-id) to be updated.
+    pub id: Option<String>,
+
+    /// The price the customer should subscribe to through this flow.
+    ///
+    /// The price must also be included in the configuration's [`features.subscription_update.products`](docs/api/customer_portal/configuration#portal_configuration_object-features-subscription_update-products).
+    pub price: Option<String>,
+
+    /// [Quantity](https://stripe.com/docs/subscriptions/quantities) for this item that the customer should subscribe to through this flow.
+    pub quantity: Option<u64>,
+}
+
 /// The parameters for `BillingPortalSession::create`.
 #[derive(Clone, Debug, Serialize)]
 pub struct CreateBillingPortalSession<'a> {
@@ -123,7 +174,7 @@ pub struct CreateBillingPortalSession<'a> {
     ///
     /// If not specified, the session uses the default configuration.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub configuration: Option<&'a str>,
+    pub configuration: Option<BillingPortalConfigurationId>,
 
     /// The ID of an existing customer.
     pub customer: CustomerId,
@@ -181,6 +232,15 @@ pub struct CreateBillingPortalSessionFlowData {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub subscription_cancel: Option<CreateBillingPortalSessionFlowDataSubscriptionCancel>,
 
+    /// Configuration when `flow_data.type=subscription_update`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub subscription_update: Option<CreateBillingPortalSessionFlowDataSubscriptionUpdate>,
+
+    /// Configuration when `flow_data.type=subscription_update_confirm`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub subscription_update_confirm:
+        Option<CreateBillingPortalSessionFlowDataSubscriptionUpdateConfirm>,
+
     /// Type of flow that the customer will go through.
     #[serde(rename = "type")]
     pub type_: CreateBillingPortalSessionFlowDataType,
@@ -221,6 +281,57 @@ pub struct CreateBillingPortalSessionFlowDataAfterCompletionRedirect {
     pub return_url: String,
 }
 
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct CreateBillingPortalSessionFlowDataSubscriptionUpdate {
+    /// The ID of the subscription to be updated.
+    pub subscription: String,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct CreateBillingPortalSessionFlowDataSubscriptionUpdateConfirm {
+    /// The coupon or promotion code to apply to this subscription update.
+    ///
+    /// Currently, only up to one may be specified.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub discounts:
+        Option<Vec<CreateBillingPortalSessionFlowDataSubscriptionUpdateConfirmDiscounts>>,
+
+    /// The [subscription item](https://stripe.com/docs/api/subscription_items) to be updated through this flow.
+    ///
+    /// Currently, only up to one may be specified and subscriptions with multiple items are not updatable.
+    pub items: Vec<CreateBillingPortalSessionFlowDataSubscriptionUpdateConfirmItems>,
+
+    /// The ID of the subscription to be updated.
+    pub subscription: String,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct CreateBillingPortalSessionFlowDataSubscriptionUpdateConfirmDiscounts {
+    /// The ID of the coupon to apply to this subscription update.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub coupon: Option<String>,
+
+    /// The ID of a promotion code to apply to this subscription update.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub promotion_code: Option<String>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct CreateBillingPortalSessionFlowDataSubscriptionUpdateConfirmItems {
+    /// The ID of the [subscription item](https://stripe.com/docs/api/subscriptions/object#subscription_object-items-data-id) to be updated.
+    pub id: String,
+
+    /// The price the customer should subscribe to through this flow.
+    ///
+    /// The price must also be included in the configuration's [`features.subscription_update.products`](docs/api/customer_portal/configuration#portal_configuration_object-features-subscription_update-products).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub price: Option<String>,
+
+    /// [Quantity](https://stripe.com/docs/subscriptions/quantities) for this item that the customer should subscribe to through this flow.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub quantity: Option<u64>,
+}
+
 /// An enum representing the possible values of an `BillingPortalSession`'s `locale` field.
 #[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
 #[serde(rename_all = "snake_case")]
@@ -403,6 +514,8 @@ impl std::default::Default for CreateBillingPortalSessionFlowDataAfterCompletion
 pub enum CreateBillingPortalSessionFlowDataType {
     PaymentMethodUpdate,
     SubscriptionCancel,
+    SubscriptionUpdate,
+    SubscriptionUpdateConfirm,
 }
 
 impl CreateBillingPortalSessionFlowDataType {
@@ -410,6 +523,10 @@ impl CreateBillingPortalSessionFlowDataType {
         match self {
             CreateBillingPortalSessionFlowDataType::PaymentMethodUpdate => "payment_method_update",
             CreateBillingPortalSessionFlowDataType::SubscriptionCancel => "subscription_cancel",
+            CreateBillingPortalSessionFlowDataType::SubscriptionUpdate => "subscription_update",
+            CreateBillingPortalSessionFlowDataType::SubscriptionUpdateConfirm => {
+                "subscription_update_confirm"
+            }
         }
     }
 }
@@ -473,6 +590,8 @@ impl std::default::Default for PortalFlowsFlowAfterCompletionType {
 pub enum PortalFlowsFlowType {
     PaymentMethodUpdate,
     SubscriptionCancel,
+    SubscriptionUpdate,
+    SubscriptionUpdateConfirm,
 }
 
 impl PortalFlowsFlowType {
@@ -480,6 +599,8 @@ impl PortalFlowsFlowType {
         match self {
             PortalFlowsFlowType::PaymentMethodUpdate => "payment_method_update",
             PortalFlowsFlowType::SubscriptionCancel => "subscription_cancel",
+            PortalFlowsFlowType::SubscriptionUpdate => "subscription_update",
+            PortalFlowsFlowType::SubscriptionUpdateConfirm => "subscription_update_confirm",
         }
     }
 }
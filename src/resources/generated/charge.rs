@@ -729,6 +729,10 @@ pub struct PaymentMethodDetailsCard {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub moto: Option<bool>,
 
+    /// Information about the [multicapture](https://stripe.com/docs/payments/multicapture) availability of this charge.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub multicapture: Option<PaymentFlowsPrivatePaymentMethodsCardDetailsApiResourceMulticapture>,
+
     /// Identifies which network this charge was processed on.
     ///
     /// Can be `amex`, `cartes_bancaires`, `diners`, `discover`, `eftpos_au`, `interac`, `jcb`, `mastercard`, `unionpay`, `visa`, or `unknown`.
@@ -747,6 +751,12 @@ pub struct PaymentMethodDetailsCardInstallments {
     pub plan: Option<PaymentMethodDetailsCardInstallmentsPlan>,
 }
 
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct PaymentFlowsPrivatePaymentMethodsCardDetailsApiResourceMulticapture {
+    /// Indicates whether or not multiple captures are supported.
+    pub status: PaymentFlowsPrivatePaymentMethodsCardDetailsApiResourceMulticaptureStatus,
+}
+
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct PaymentMethodDetailsCardPresent {
     /// The authorized amount.
@@ -1754,6 +1764,44 @@ impl std::default::Default for FraudDetailsParamsUserReport {
     }
 }
 
+/// An enum representing the possible values of an `PaymentFlowsPrivatePaymentMethodsCardDetailsApiResourceMulticapture`'s `status` field.
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum PaymentFlowsPrivatePaymentMethodsCardDetailsApiResourceMulticaptureStatus {
+    Available,
+    Unavailable,
+}
+
+impl PaymentFlowsPrivatePaymentMethodsCardDetailsApiResourceMulticaptureStatus {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            PaymentFlowsPrivatePaymentMethodsCardDetailsApiResourceMulticaptureStatus::Available => "available",
+            PaymentFlowsPrivatePaymentMethodsCardDetailsApiResourceMulticaptureStatus::Unavailable => "unavailable",
+        }
+    }
+}
+
+impl AsRef<str> for PaymentFlowsPrivatePaymentMethodsCardDetailsApiResourceMulticaptureStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::fmt::Display
+    for PaymentFlowsPrivatePaymentMethodsCardDetailsApiResourceMulticaptureStatus
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
+impl std::default::Default
+    for PaymentFlowsPrivatePaymentMethodsCardDetailsApiResourceMulticaptureStatus
+{
+    fn default() -> Self {
+        Self::Available
+    }
+}
+
 /// An enum representing the possible values of an `PaymentMethodDetailsAchDebit`'s `account_holder_type` field.
 #[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
 #[serde(rename_all = "snake_case")]
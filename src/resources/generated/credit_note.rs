@@ -143,6 +143,32 @@ impl CreditNote {
     ) -> Response<CreditNote> {
         client.post_form(&format!("/credit_notes/{}", id), &params)
     }
+
+    /// When retrieving a credit note, you’ll get a **lines** property containing the the first handful of those items.
+    ///
+    /// There is also a URL where you can retrieve the full (paginated) list of line items.
+    pub fn list_line_items(
+        client: &Client,
+        id: &CreditNoteId,
+        params: &ListCreditNoteLineItems<'_>,
+    ) -> Response<List<CreditNoteLineItem>> {
+        client.get_query(&format!("/credit_notes/{}/lines", id), &params)
+    }
+
+    /// When retrieving a credit note preview, you’ll get a **lines** property containing the first handful of those items.
+    ///
+    /// This URL you can retrieve the full (paginated) list of line items.
+    pub fn list_preview_line_items(
+        client: &Client,
+        params: &ListCreditNotePreviewLineItems<'_>,
+    ) -> Response<List<CreditNoteLineItem>> {
+        client.get_query("/credit_notes/preview/lines", &params)
+    }
+
+    /// Get a preview of a credit note without creating it.
+    pub fn preview(client: &Client, params: &PreviewCreditNote<'_>) -> Response<CreditNote> {
+        client.get_query("/credit_notes/preview", &params)
+    }
 }
 
 impl Object for CreditNote {
@@ -252,6 +278,144 @@ impl<'a> CreateCreditNote<'a> {
     }
 }
 
+/// The parameters for `CreditNote::list_line_items`.
+#[derive(Clone, Debug, Serialize, Default)]
+pub struct ListCreditNoteLineItems<'a> {
+    /// A cursor for use in pagination.
+    ///
+    /// `ending_before` is an object ID that defines your place in the list.
+    /// For instance, if you make a list request and receive 100 objects, starting with `obj_bar`, your subsequent call can include `ending_before=obj_bar` in order to fetch the previous page of the list.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ending_before: Option<&'a str>,
+
+    /// Specifies which fields in the response should be expanded.
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+
+    /// A limit on the number of objects to be returned.
+    ///
+    /// Limit can range between 1 and 100, and the default is 10.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u64>,
+
+    /// A cursor for use in pagination.
+    ///
+    /// `starting_after` is an object ID that defines your place in the list.
+    /// For instance, if you make a list request and receive 100 objects, ending with `obj_foo`, your subsequent call can include `starting_after=obj_foo` in order to fetch the next page of the list.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub starting_after: Option<&'a str>,
+}
+
+impl<'a> ListCreditNoteLineItems<'a> {
+    pub fn new() -> Self {
+        ListCreditNoteLineItems {
+            ending_before: Default::default(),
+            expand: Default::default(),
+            limit: Default::default(),
+            starting_after: Default::default(),
+        }
+    }
+}
+
+/// The parameters for `CreditNote::list_preview_line_items`.
+#[derive(Clone, Debug, Serialize)]
+pub struct ListCreditNotePreviewLineItems<'a> {
+    /// The integer amount in cents (or local equivalent) representing the total amount of the credit note.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub amount: Option<i64>,
+
+    /// The integer amount in cents (or local equivalent) representing the amount to credit the customer's balance, which will be automatically applied to their next invoice.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub credit_amount: Option<i64>,
+
+    /// A cursor for use in pagination.
+    ///
+    /// `ending_before` is an object ID that defines your place in the list.
+    /// For instance, if you make a list request and receive 100 objects, starting with `obj_bar`, your subsequent call can include `ending_before=obj_bar` in order to fetch the previous page of the list.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ending_before: Option<&'a str>,
+
+    /// Specifies which fields in the response should be expanded.
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+
+    /// ID of the invoice.
+    pub invoice: InvoiceId,
+
+    /// A limit on the number of objects to be returned.
+    ///
+    /// Limit can range between 1 and 100, and the default is 10.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u64>,
+
+    /// Line items that make up the credit note.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub lines: Option<Vec<ListCreditNotePreviewLineItemsLines>>,
+
+    /// The credit note's memo appears on the credit note PDF.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub memo: Option<&'a str>,
+
+    /// Set of [key-value pairs](https://stripe.com/docs/api/metadata) that you can attach to an object.
+    ///
+    /// This can be useful for storing additional information about the object in a structured format.
+    /// Individual keys can be unset by posting an empty value to them.
+    /// All keys can be unset by posting an empty value to `metadata`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+
+    /// The integer amount in cents (or local equivalent) representing the amount that is credited outside of Stripe.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub out_of_band_amount: Option<i64>,
+
+    /// Reason for issuing this credit note, one of `duplicate`, `fraudulent`, `order_change`, or `product_unsatisfactory`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reason: Option<CreditNoteReason>,
+
+    /// ID of an existing refund to link this credit note to.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub refund: Option<RefundId>,
+
+    /// The integer amount in cents (or local equivalent) representing the amount to refund.
+    ///
+    /// If set, a refund will be created for the charge associated with the invoice.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub refund_amount: Option<i64>,
+
+    /// When shipping_cost contains the shipping_rate from the invoice, the shipping_cost is included in the credit note.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub shipping_cost: Option<ListCreditNotePreviewLineItemsShippingCost>,
+
+    /// A cursor for use in pagination.
+    ///
+    /// `starting_after` is an object ID that defines your place in the list.
+    /// For instance, if you make a list request and receive 100 objects, ending with `obj_foo`, your subsequent call can include `starting_after=obj_foo` in order to fetch the next page of the list.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub starting_after: Option<&'a str>,
+}
+
+impl<'a> ListCreditNotePreviewLineItems<'a> {
+    pub fn new(invoice: InvoiceId) -> Self {
+        ListCreditNotePreviewLineItems {
+            amount: Default::default(),
+            credit_amount: Default::default(),
+            ending_before: Default::default(),
+            expand: Default::default(),
+            invoice,
+            limit: Default::default(),
+            lines: Default::default(),
+            memo: Default::default(),
+            metadata: Default::default(),
+            out_of_band_amount: Default::default(),
+            reason: Default::default(),
+            refund: Default::default(),
+            refund_amount: Default::default(),
+            shipping_cost: Default::default(),
+            starting_after: Default::default(),
+        }
+    }
+}
+
 /// The parameters for `CreditNote::list`.
 #[derive(Clone, Debug, Serialize, Default)]
 pub struct ListCreditNotes<'a> {
@@ -306,6 +470,82 @@ impl Paginable for ListCreditNotes<'_> {
         self.starting_after = Some(item.id());
     }
 }
+/// The parameters for `CreditNote::preview`.
+#[derive(Clone, Debug, Serialize)]
+pub struct PreviewCreditNote<'a> {
+    /// The integer amount in cents (or local equivalent) representing the total amount of the credit note.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub amount: Option<i64>,
+
+    /// The integer amount in cents (or local equivalent) representing the amount to credit the customer's balance, which will be automatically applied to their next invoice.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub credit_amount: Option<i64>,
+
+    /// Specifies which fields in the response should be expanded.
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+
+    /// ID of the invoice.
+    pub invoice: InvoiceId,
+
+    /// Line items that make up the credit note.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub lines: Option<Vec<PreviewCreditNoteLines>>,
+
+    /// The credit note's memo appears on the credit note PDF.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub memo: Option<&'a str>,
+
+    /// Set of [key-value pairs](https://stripe.com/docs/api/metadata) that you can attach to an object.
+    ///
+    /// This can be useful for storing additional information about the object in a structured format.
+    /// Individual keys can be unset by posting an empty value to them.
+    /// All keys can be unset by posting an empty value to `metadata`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+
+    /// The integer amount in cents (or local equivalent) representing the amount that is credited outside of Stripe.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub out_of_band_amount: Option<i64>,
+
+    /// Reason for issuing this credit note, one of `duplicate`, `fraudulent`, `order_change`, or `product_unsatisfactory`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reason: Option<CreditNoteReason>,
+
+    /// ID of an existing refund to link this credit note to.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub refund: Option<RefundId>,
+
+    /// The integer amount in cents (or local equivalent) representing the amount to refund.
+    ///
+    /// If set, a refund will be created for the charge associated with the invoice.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub refund_amount: Option<i64>,
+
+    /// When shipping_cost contains the shipping_rate from the invoice, the shipping_cost is included in the credit note.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub shipping_cost: Option<PreviewCreditNoteShippingCost>,
+}
+
+impl<'a> PreviewCreditNote<'a> {
+    pub fn new(invoice: InvoiceId) -> Self {
+        PreviewCreditNote {
+            amount: Default::default(),
+            credit_amount: Default::default(),
+            expand: Default::default(),
+            invoice,
+            lines: Default::default(),
+            memo: Default::default(),
+            metadata: Default::default(),
+            out_of_band_amount: Default::default(),
+            reason: Default::default(),
+            refund: Default::default(),
+            refund_amount: Default::default(),
+            shipping_cost: Default::default(),
+        }
+    }
+}
+
 /// The parameters for `CreditNote::update`.
 #[derive(Clone, Debug, Serialize, Default)]
 pub struct UpdateCreditNote<'a> {
@@ -391,6 +631,116 @@ pub struct CreateCreditNoteShippingCost {
     pub shipping_rate: Option<String>,
 }
 
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct ListCreditNotePreviewLineItemsLines {
+    /// The line item amount to credit.
+    ///
+    /// Only valid when `type` is `invoice_line_item`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub amount: Option<i64>,
+
+    /// The description of the credit note line item.
+    ///
+    /// Only valid when the `type` is `custom_line_item`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+
+    /// The invoice line item to credit.
+    ///
+    /// Only valid when the `type` is `invoice_line_item`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub invoice_line_item: Option<String>,
+
+    /// The line item quantity to credit.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub quantity: Option<u64>,
+
+    /// The tax rates which apply to the credit note line item.
+    ///
+    /// Only valid when the `type` is `custom_line_item`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tax_rates: Option<Vec<String>>,
+
+    /// Type of the credit note line item, one of `invoice_line_item` or `custom_line_item`.
+    #[serde(rename = "type")]
+    pub type_: ListCreditNotePreviewLineItemsLinesType,
+
+    /// The integer unit amount in cents (or local equivalent) of the credit note line item.
+    ///
+    /// This `unit_amount` will be multiplied by the quantity to get the full amount to credit for this line item.
+    /// Only valid when `type` is `custom_line_item`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub unit_amount: Option<i64>,
+
+    /// Same as `unit_amount`, but accepts a decimal value in cents (or local equivalent) with at most 12 decimal places.
+    ///
+    /// Only one of `unit_amount` and `unit_amount_decimal` can be set.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub unit_amount_decimal: Option<String>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct ListCreditNotePreviewLineItemsShippingCost {
+    /// The ID of the shipping rate to use for this order.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub shipping_rate: Option<String>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct PreviewCreditNoteLines {
+    /// The line item amount to credit.
+    ///
+    /// Only valid when `type` is `invoice_line_item`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub amount: Option<i64>,
+
+    /// The description of the credit note line item.
+    ///
+    /// Only valid when the `type` is `custom_line_item`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+
+    /// The invoice line item to credit.
+    ///
+    /// Only valid when the `type` is `invoice_line_item`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub invoice_line_item: Option<String>,
+
+    /// The line item quantity to credit.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub quantity: Option<u64>,
+
+    /// The tax rates which apply to the credit note line item.
+    ///
+    /// Only valid when the `type` is `custom_line_item`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tax_rates: Option<Vec<String>>,
+
+    /// Type of the credit note line item, one of `invoice_line_item` or `custom_line_item`.
+    #[serde(rename = "type")]
+    pub type_: PreviewCreditNoteLinesType,
+
+    /// The integer unit amount in cents (or local equivalent) of the credit note line item.
+    ///
+    /// This `unit_amount` will be multiplied by the quantity to get the full amount to credit for this line item.
+    /// Only valid when `type` is `custom_line_item`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub unit_amount: Option<i64>,
+
+    /// Same as `unit_amount`, but accepts a decimal value in cents (or local equivalent) with at most 12 decimal places.
+    ///
+    /// Only one of `unit_amount` and `unit_amount_decimal` can be set.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub unit_amount_decimal: Option<String>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct PreviewCreditNoteShippingCost {
+    /// The ID of the shipping rate to use for this order.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub shipping_rate: Option<String>,
+}
+
 /// An enum representing the possible values of an `CreateCreditNoteLines`'s `type` field.
 #[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
 #[serde(rename_all = "snake_case")]
@@ -530,3 +880,71 @@ impl std::default::Default for CreditNoteType {
         Self::PostPayment
     }
 }
+
+/// An enum representing the possible values of an `ListCreditNotePreviewLineItemsLines`'s `type` field.
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum ListCreditNotePreviewLineItemsLinesType {
+    CustomLineItem,
+    InvoiceLineItem,
+}
+
+impl ListCreditNotePreviewLineItemsLinesType {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            ListCreditNotePreviewLineItemsLinesType::CustomLineItem => "custom_line_item",
+            ListCreditNotePreviewLineItemsLinesType::InvoiceLineItem => "invoice_line_item",
+        }
+    }
+}
+
+impl AsRef<str> for ListCreditNotePreviewLineItemsLinesType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::fmt::Display for ListCreditNotePreviewLineItemsLinesType {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
+impl std::default::Default for ListCreditNotePreviewLineItemsLinesType {
+    fn default() -> Self {
+        Self::CustomLineItem
+    }
+}
+
+/// An enum representing the possible values of an `PreviewCreditNoteLines`'s `type` field.
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum PreviewCreditNoteLinesType {
+    CustomLineItem,
+    InvoiceLineItem,
+}
+
+impl PreviewCreditNoteLinesType {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            PreviewCreditNoteLinesType::CustomLineItem => "custom_line_item",
+            PreviewCreditNoteLinesType::InvoiceLineItem => "invoice_line_item",
+        }
+    }
+}
+
+impl AsRef<str> for PreviewCreditNoteLinesType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::fmt::Display for PreviewCreditNoteLinesType {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
+impl std::default::Default for PreviewCreditNoteLinesType {
+    fn default() -> Self {
+        Self::CustomLineItem
+    }
+}
@@ -5,7 +5,9 @@
 use serde::{Deserialize, Serialize};
 
 use crate::client::{Client, Response};
-use crate::ids::{CouponId, CustomerId, PaymentMethodId, PaymentSourceId, PromotionCodeId};
+use crate::ids::{
+    CouponId, CustomerId, PaymentMethodId, PaymentSourceId, PromotionCodeId, TaxIdId,
+};
 use crate::params::{
     Deleted, Expand, Expandable, List, Metadata, Object, Paginable, RangeQuery, Timestamp,
 };
@@ -198,6 +200,43 @@ impl Customer {
     pub fn delete(client: &Client, id: &CustomerId) -> Response<Deleted<CustomerId>> {
         client.delete(&format!("/customers/{}", id))
     }
+
+    /// Creates a new `TaxID` object for a customer.
+    pub fn create_tax_id(
+        client: &Client,
+        id: &CustomerId,
+        params: CreateCustomerTaxId<'_>,
+    ) -> Response<TaxId> {
+        client.post_form(&format!("/customers/{}/tax_ids", id), &params)
+    }
+
+    /// Deletes an existing `TaxID` object.
+    pub fn delete_tax_id(
+        client: &Client,
+        id: &CustomerId,
+        tax_id: &TaxIdId,
+    ) -> Response<Deleted<TaxIdId>> {
+        client.delete(&format!("/customers/{}/tax_ids/{}", id, tax_id))
+    }
+
+    /// Returns a list of tax IDs for a customer.
+    pub fn list_tax_ids(
+        client: &Client,
+        id: &CustomerId,
+        params: &ListCustomerTaxIds<'_>,
+    ) -> Response<List<TaxId>> {
+        client.get_query(&format!("/customers/{}/tax_ids", id), &params)
+    }
+
+    /// Retrieves the `TaxID` object with the given identifier.
+    pub fn retrieve_tax_id(
+        client: &Client,
+        id: &CustomerId,
+        tax_id: &TaxIdId,
+        expand: &[&str],
+    ) -> Response<TaxId> {
+        client.get_query(&format!("/customers/{}/tax_ids/{}", id, tax_id), &Expand { expand })
+    }
 }
 
 impl Object for Customer {
@@ -402,6 +441,72 @@ impl<'a> CreateCustomer<'a> {
     }
 }
 
+/// The parameters for `Customer::create_tax_id`.
+#[derive(Clone, Debug, Serialize)]
+pub struct CreateCustomerTaxId<'a> {
+    /// Specifies which fields in the response should be expanded.
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+
+    /// Type of the tax ID, one of `ae_trn`, `au_abn`, `au_arn`, `bg_uic`, `br_cnpj`, `br_cpf`, `ca_bn`, `ca_gst_hst`, `ca_pst_bc`, `ca_pst_mb`, `ca_pst_sk`, `ca_qst`, `ch_vat`, `cl_tin`, `eg_tin`, `es_cif`, `eu_oss_vat`, `eu_vat`, `gb_vat`, `ge_vat`, `hk_br`, `hu_tin`, `id_npwp`, `il_vat`, `in_gst`, `is_vat`, `jp_cn`, `jp_rn`, `jp_trn`, `ke_pin`, `kr_brn`, `li_uid`, `mx_rfc`, `my_frp`, `my_itn`, `my_sst`, `no_vat`, `nz_gst`, `ph_tin`, `ru_inn`, `ru_kpp`, `sa_vat`, `sg_gst`, `sg_uen`, `si_tin`, `th_vat`, `tr_tin`, `tw_vat`, `ua_vat`, `us_ein`, or `za_vat`.
+    #[serde(rename = "type")]
+    pub type_: TaxIdType,
+
+    /// Value of the tax ID.
+    pub value: &'a str,
+}
+
+impl<'a> CreateCustomerTaxId<'a> {
+    pub fn new(type_: TaxIdType, value: &'a str) -> Self {
+        CreateCustomerTaxId { expand: Default::default(), type_, value }
+    }
+}
+
+/// The parameters for `Customer::list_tax_ids`.
+#[derive(Clone, Debug, Serialize, Default)]
+pub struct ListCustomerTaxIds<'a> {
+    /// A cursor for use in pagination.
+    ///
+    /// `ending_before` is an object ID that defines your place in the list.
+    /// For instance, if you make a list request and receive 100 objects, starting with `obj_bar`, your subsequent call can include `ending_before=obj_bar` in order to fetch the previous page of the list.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ending_before: Option<TaxIdId>,
+
+    /// Specifies which fields in the response should be expanded.
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+
+    /// A limit on the number of objects to be returned.
+    ///
+    /// Limit can range between 1 and 100, and the default is 10.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u64>,
+
+    /// A cursor for use in pagination.
+    ///
+    /// `starting_after` is an object ID that defines your place in the list.
+    /// For instance, if you make a list request and receive 100 objects, ending with `obj_foo`, your subsequent call can include `starting_after=obj_foo` in order to fetch the next page of the list.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub starting_after: Option<TaxIdId>,
+}
+
+impl<'a> ListCustomerTaxIds<'a> {
+    pub fn new() -> Self {
+        ListCustomerTaxIds {
+            ending_before: Default::default(),
+            expand: Default::default(),
+            limit: Default::default(),
+            starting_after: Default::default(),
+        }
+    }
+}
+impl Paginable for ListCustomerTaxIds<'_> {
+    type O = TaxId;
+    fn set_last(&mut self, item: Self::O) {
+        self.starting_after = Some(item.id());
+    }
+}
+
 /// The parameters for `Customer::list`.
 #[derive(Clone, Debug, Serialize, Default)]
 pub struct ListCustomers<'a> {
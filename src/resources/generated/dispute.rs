@@ -5,7 +5,7 @@
 use serde::{Deserialize, Serialize};
 
 use crate::client::{Client, Response};
-use crate::ids::{ChargeId, DisputeId, PaymentIntentId};
+use crate::ids::{ChargeId, DisputeId, FileId, PaymentIntentId};
 use crate::params::{Expand, Expandable, List, Metadata, Object, Paginable, RangeQuery, Timestamp};
 use crate::resources::{BalanceTransaction, Charge, Currency, File, PaymentIntent};
 
@@ -84,6 +84,23 @@ impl Dispute {
     pub fn retrieve(client: &Client, id: &DisputeId, expand: &[&str]) -> Response<Dispute> {
         client.get_query(&format!("/disputes/{}", id), &Expand { expand })
     }
+
+    /// When you get a dispute, contacting your customer is always the best first step.
+    ///
+    /// If that doesn’t work, you can submit evidence to help us resolve the dispute in your favor.
+    /// You can do this in your [dashboard](https://dashboard.stripe.com/disputes), but if you prefer, you can use the API to submit evidence programmatically.
+    /// Depending on your dispute type, different evidence fields will give you a better chance of winning your dispute.
+    /// To figure out which evidence fields to provide, see our [guide to dispute types](https://stripe.com/docs/disputes/categories).
+    pub fn update(client: &Client, id: &DisputeId, params: UpdateDispute<'_>) -> Response<Dispute> {
+        client.post_form(&format!("/disputes/{}", id), &params)
+    }
+
+    /// Closing the dispute for a charge indicates that you do not have any evidence to submit and are essentially dismissing the dispute, acknowledging it as lost.
+    ///
+    /// The status of the dispute will change from `needs_response` to `lost`. _Closing a dispute is irreversible_.
+    pub fn close(client: &Client, id: &DisputeId, params: CloseDispute<'_>) -> Response<Dispute> {
+        client.post_form(&format!("/disputes/{}/close", id), &params)
+    }
 }
 
 impl Object for Dispute {
@@ -277,6 +294,189 @@ impl Paginable for ListDisputes<'_> {
         self.starting_after = Some(item.id());
     }
 }
+/// The parameters for `Dispute::update`.
+#[derive(Clone, Debug, Serialize, Default)]
+pub struct UpdateDispute<'a> {
+    /// Evidence to upload, to respond to a dispute.
+    ///
+    /// Updating any field in the hash will submit all fields in the hash for review.
+    /// The combined character count of all fields is limited to 150,000.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub evidence: Option<UpdateDisputeEvidence>,
+
+    /// Specifies which fields in the response should be expanded.
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+
+    /// Set of [key-value pairs](https://stripe.com/docs/api/metadata) that you can attach to an object.
+    ///
+    /// This can be useful for storing additional information about the object in a structured format.
+    /// Individual keys can be unset by posting an empty value to them.
+    /// All keys can be unset by posting an empty value to `metadata`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+
+    /// Whether to immediately submit evidence to the bank.
+    ///
+    /// If `false`, evidence is staged on the dispute.
+    /// Staged evidence is visible in the API and Dashboard, and can be submitted to the bank by making another request with this attribute set to `true` (the default).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub submit: Option<bool>,
+}
+
+impl<'a> UpdateDispute<'a> {
+    pub fn new() -> Self {
+        UpdateDispute {
+            evidence: Default::default(),
+            expand: Default::default(),
+            metadata: Default::default(),
+            submit: Default::default(),
+        }
+    }
+}
+
+/// The parameters for `Dispute::close`.
+#[derive(Clone, Debug, Serialize, Default)]
+pub struct CloseDispute<'a> {
+    /// Specifies which fields in the response should be expanded.
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+}
+
+impl<'a> CloseDispute<'a> {
+    pub fn new() -> Self {
+        CloseDispute { expand: Default::default() }
+    }
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct UpdateDisputeEvidence {
+    /// Any server or activity logs showing proof that the customer accessed or downloaded the purchased digital product.
+    ///
+    /// This information should include IP addresses, corresponding timestamps, and any detailed recorded activity.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub access_activity_log: Option<String>,
+
+    /// The billing address provided by the customer.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub billing_address: Option<String>,
+
+    /// (ID of a [file upload](https://stripe.com/docs/guides/file-upload)) Your subscription cancellation policy, as shown to the customer.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cancellation_policy: Option<FileId>,
+
+    /// An explanation of how and when the customer was shown your refund policy prior to purchase.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cancellation_policy_disclosure: Option<String>,
+
+    /// A justification for why the customer's subscription was not canceled.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cancellation_rebuttal: Option<String>,
+
+    /// (ID of a [file upload](https://stripe.com/docs/guides/file-upload)) Any communication with the customer that you feel is relevant to your case.
+    ///
+    /// Examples include emails proving that the customer received the product or service, or demonstrating their use of or satisfaction with the product or service.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub customer_communication: Option<FileId>,
+
+    /// The email address of the customer.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub customer_email_address: Option<String>,
+
+    /// The name of the customer.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub customer_name: Option<String>,
+
+    /// The IP address that the customer used when making the purchase.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub customer_purchase_ip: Option<String>,
+
+    /// (ID of a [file upload](https://stripe.com/docs/guides/file-upload)) A relevant document or contract showing the customer's signature.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub customer_signature: Option<FileId>,
+
+    /// (ID of a [file upload](https://stripe.com/docs/guides/file-upload)) Documentation for the prior charge that can uniquely identify the charge, such as a receipt, shipping label, work order, etc.
+    ///
+    /// This document should be paired with a similar document from the disputed payment that proves the two payments are separate.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub duplicate_charge_documentation: Option<FileId>,
+
+    /// An explanation of the difference between the disputed charge versus the prior charge that appears to be a duplicate.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub duplicate_charge_explanation: Option<String>,
+
+    /// The Stripe ID for the prior charge which appears to be a duplicate of the disputed charge.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub duplicate_charge_id: Option<String>,
+
+    /// A description of the product or service that was sold.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub product_description: Option<String>,
+
+    /// (ID of a [file upload](https://stripe.com/docs/guides/file-upload)) Any receipt or message sent to the customer notifying them of the charge.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub receipt: Option<FileId>,
+
+    /// (ID of a [file upload](https://stripe.com/docs/guides/file-upload)) Your refund policy, as shown to the customer.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub refund_policy: Option<FileId>,
+
+    /// Documentation demonstrating that the customer was shown your refund policy prior to purchase.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub refund_policy_disclosure: Option<String>,
+
+    /// A justification for why the customer is not entitled to a refund.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub refund_refusal_explanation: Option<String>,
+
+    /// The date on which the customer received or began receiving the purchased service, in a clear human-readable format.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub service_date: Option<String>,
+
+    /// (ID of a [file upload](https://stripe.com/docs/guides/file-upload)) Documentation showing proof that a service was provided to the customer.
+    ///
+    /// This could include a copy of a signed contract, work order, or other form of written agreement.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub service_documentation: Option<FileId>,
+
+    /// The address to which a physical product was shipped.
+    ///
+    /// You should try to include as complete address information as possible.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub shipping_address: Option<String>,
+
+    /// The delivery service that shipped a physical product, such as Fedex, UPS, USPS, etc.
+    ///
+    /// If multiple carriers were used for this purchase, please separate them with commas.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub shipping_carrier: Option<String>,
+
+    /// The date on which a physical product began its route to the shipping address, in a clear human-readable format.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub shipping_date: Option<String>,
+
+    /// (ID of a [file upload](https://stripe.com/docs/guides/file-upload)) Documentation showing proof that a product was shipped to the customer at the same address the customer provided to you.
+    ///
+    /// This could include a copy of the shipment receipt, shipping label, etc.
+    /// It should show the customer's full shipping address, if possible.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub shipping_documentation: Option<FileId>,
+
+    /// The tracking number for a physical product, obtained from the delivery service.
+    ///
+    /// If multiple tracking numbers were generated for this purchase, please separate them with commas.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub shipping_tracking_number: Option<String>,
+
+    /// (ID of a [file upload](https://stripe.com/docs/guides/file-upload)) Any additional evidence or statements.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub uncategorized_file: Option<FileId>,
+
+    /// Any additional evidence or statements.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub uncategorized_text: Option<String>,
+}
+
 /// An enum representing the possible values of an `Dispute`'s `status` field.
 #[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
 #[serde(rename_all = "snake_case")]
@@ -2,11 +2,19 @@
 // This file was automatically generated.
 // ======================================
 
-use crate::ids::{FinancialConnectionsAccountId};
-use crate::params::{Expandable, Object, Timestamp};
-use crate::resources::{BankConnectionsResourceAccountholder, FinancialConnectionsAccountOwnership};
 use serde::{Deserialize, Serialize};
 
+use crate::client::{Client, Response};
+use crate::ids::{
+    AccountId, CustomerId, FinancialConnectionsAccountId, FinancialConnectionsAccountOwnerId,
+    FinancialConnectionsSessionId,
+};
+use crate::params::{Expand, Expandable, List, Object, Paginable, Timestamp};
+use crate::resources::{
+    BankConnectionsResourceAccountholder, FinancialConnectionsAccountOwner,
+    FinancialConnectionsAccountOwnership,
+};
+
 /// The resource representing a Stripe "BankConnectionsResourceLinkedAccount".
 ///
 /// For more details see <https://stripe.com/docs/api/financial_connections/accounts/object>
@@ -80,6 +88,54 @@ pub struct FinancialConnectionsAccount {
     pub supported_payment_method_types: Vec<FinancialConnectionsAccountSupportedPaymentMethodTypes>,
 }
 
+impl FinancialConnectionsAccount {
+    /// Returns a list of Financial Connections `Account` objects.
+    pub fn list(
+        client: &Client,
+        params: &ListFinancialConnectionsAccounts<'_>,
+    ) -> Response<List<FinancialConnectionsAccount>> {
+        client.get_query("/financial_connections/accounts", &params)
+    }
+
+    /// Retrieves the details of an Financial Connections `Account`.
+    pub fn retrieve(
+        client: &Client,
+        id: &FinancialConnectionsAccountId,
+        expand: &[&str],
+    ) -> Response<FinancialConnectionsAccount> {
+        client.get_query(&format!("/financial_connections/accounts/{}", id), &Expand { expand })
+    }
+
+    /// Disables your access to a Financial Connections `Account`.
+    ///
+    /// You will no longer be able to access data associated with the account (e.g. balances, transactions).
+    pub fn disconnect(
+        client: &Client,
+        id: &FinancialConnectionsAccountId,
+        params: DisconnectFinancialConnectionsAccount<'_>,
+    ) -> Response<FinancialConnectionsAccount> {
+        client.post_form(&format!("/financial_connections/accounts/{}/disconnect", id), &params)
+    }
+
+    /// Lists all owners for a given `Account`.
+    pub fn list_owners(
+        client: &Client,
+        id: &FinancialConnectionsAccountId,
+        params: &ListFinancialConnectionsAccountOwners<'_>,
+    ) -> Response<List<FinancialConnectionsAccountOwner>> {
+        client.get_query(&format!("/financial_connections/accounts/{}/owners", id), &params)
+    }
+
+    /// Refreshes the data associated with a Financial Connections `Account`.
+    pub fn refresh(
+        client: &Client,
+        id: &FinancialConnectionsAccountId,
+        params: RefreshFinancialConnectionsAccount<'_>,
+    ) -> Response<FinancialConnectionsAccount> {
+        client.post_form(&format!("/financial_connections/accounts/{}/refresh", id), &params)
+    }
+}
+
 impl Object for FinancialConnectionsAccount {
     type Id = FinancialConnectionsAccountId;
     fn id(&self) -> Self::Id {
@@ -92,7 +148,6 @@ impl Object for FinancialConnectionsAccount {
 
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct BankConnectionsResourceBalance {
-
     /// The time that the external institution calculated this balance.
     ///
     /// Measured in seconds since the Unix epoch.
@@ -123,7 +178,6 @@ pub struct BankConnectionsResourceBalance {
 
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct BankConnectionsResourceBalanceApiResourceCashBalance {
-
     /// The funds available to the account holder.
     ///
     /// Typically this is the current balance less any holds.  Each key is a three-letter [ISO currency code](https://www.iso.org/iso-4217-currency-codes.html), in lowercase.  Each value is a integer amount.
@@ -134,7 +188,6 @@ pub struct BankConnectionsResourceBalanceApiResourceCashBalance {
 
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct BankConnectionsResourceBalanceApiResourceCreditBalance {
-
     /// The credit that has been used by the account holder.
     ///
     /// Each key is a three-letter [ISO currency code](https://www.iso.org/iso-4217-currency-codes.html), in lowercase.
@@ -148,7 +201,6 @@ pub struct BankConnectionsResourceBalanceApiResourceCreditBalance {
 
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct BankConnectionsResourceBalanceRefresh {
-
     /// The time at which the last refresh attempt was initiated.
     ///
     /// Measured in seconds since the Unix epoch.
@@ -160,7 +212,6 @@ pub struct BankConnectionsResourceBalanceRefresh {
 
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct BankConnectionsResourceOwnershipRefresh {
-
     /// The time at which the last refresh attempt was initiated.
     ///
     /// Measured in seconds since the Unix epoch.
@@ -170,6 +221,152 @@ pub struct BankConnectionsResourceOwnershipRefresh {
     pub status: BankConnectionsResourceOwnershipRefreshStatus,
 }
 
+/// The parameters for `FinancialConnectionsAccount::list`.
+#[derive(Clone, Debug, Serialize, Default)]
+pub struct ListFinancialConnectionsAccounts<'a> {
+    /// If present, only return accounts that belong to the specified account holder.
+    ///
+    /// `account_holder[customer]` and `account_holder[account]` are mutually exclusive.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub account_holder: Option<ListFinancialConnectionsAccountsAccountHolder>,
+
+    /// A cursor for use in pagination.
+    ///
+    /// `ending_before` is an object ID that defines your place in the list.
+    /// For instance, if you make a list request and receive 100 objects, starting with `obj_bar`, your subsequent call can include `ending_before=obj_bar` in order to fetch the previous page of the list.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ending_before: Option<FinancialConnectionsAccountId>,
+
+    /// Specifies which fields in the response should be expanded.
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+
+    /// A limit on the number of objects to be returned.
+    ///
+    /// Limit can range between 1 and 100, and the default is 10.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u64>,
+
+    /// If present, only return accounts that were collected as part of the given session.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub session: Option<FinancialConnectionsSessionId>,
+
+    /// A cursor for use in pagination.
+    ///
+    /// `starting_after` is an object ID that defines your place in the list.
+    /// For instance, if you make a list request and receive 100 objects, ending with `obj_foo`, your subsequent call can include `starting_after=obj_foo` in order to fetch the next page of the list.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub starting_after: Option<FinancialConnectionsAccountId>,
+}
+
+impl<'a> ListFinancialConnectionsAccounts<'a> {
+    pub fn new() -> Self {
+        ListFinancialConnectionsAccounts {
+            account_holder: Default::default(),
+            ending_before: Default::default(),
+            expand: Default::default(),
+            limit: Default::default(),
+            session: Default::default(),
+            starting_after: Default::default(),
+        }
+    }
+}
+impl Paginable for ListFinancialConnectionsAccounts<'_> {
+    type O = FinancialConnectionsAccount;
+    fn set_last(&mut self, item: Self::O) {
+        self.starting_after = Some(item.id());
+    }
+}
+/// The parameters for `FinancialConnectionsAccount::disconnect`.
+#[derive(Clone, Debug, Serialize, Default)]
+pub struct DisconnectFinancialConnectionsAccount<'a> {
+    /// Specifies which fields in the response should be expanded.
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+}
+
+impl<'a> DisconnectFinancialConnectionsAccount<'a> {
+    pub fn new() -> Self {
+        DisconnectFinancialConnectionsAccount { expand: Default::default() }
+    }
+}
+
+/// The parameters for `FinancialConnectionsAccount::refresh`.
+#[derive(Clone, Debug, Serialize)]
+pub struct RefreshFinancialConnectionsAccount<'a> {
+    /// Specifies which fields in the response should be expanded.
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+
+    /// The list of account features that you would like to refresh.
+    pub features: Vec<RefreshFinancialConnectionsAccountFeatures>,
+}
+
+impl<'a> RefreshFinancialConnectionsAccount<'a> {
+    pub fn new(features: Vec<RefreshFinancialConnectionsAccountFeatures>) -> Self {
+        RefreshFinancialConnectionsAccount { expand: Default::default(), features }
+    }
+}
+
+/// The parameters for `FinancialConnectionsAccount::list_owners`.
+#[derive(Clone, Debug, Serialize)]
+pub struct ListFinancialConnectionsAccountOwners<'a> {
+    /// A cursor for use in pagination.
+    ///
+    /// `ending_before` is an object ID that defines your place in the list.
+    /// For instance, if you make a list request and receive 100 objects, starting with `obj_bar`, your subsequent call can include `ending_before=obj_bar` in order to fetch the previous page of the list.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ending_before: Option<FinancialConnectionsAccountOwnerId>,
+
+    /// Specifies which fields in the response should be expanded.
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+
+    /// A limit on the number of objects to be returned.
+    ///
+    /// Limit can range between 1 and 100, and the default is 10.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u64>,
+
+    /// The ID of the ownership object to fetch owners from.
+    pub ownership: &'a str,
+
+    /// A cursor for use in pagination.
+    ///
+    /// `starting_after` is an object ID that defines your place in the list.
+    /// For instance, if you make a list request and receive 100 objects, ending with `obj_foo`, your subsequent call can include `starting_after=obj_foo` in order to fetch the next page of the list.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub starting_after: Option<FinancialConnectionsAccountOwnerId>,
+}
+
+impl<'a> ListFinancialConnectionsAccountOwners<'a> {
+    pub fn new(ownership: &'a str) -> Self {
+        ListFinancialConnectionsAccountOwners {
+            ending_before: Default::default(),
+            expand: Default::default(),
+            limit: Default::default(),
+            ownership,
+            starting_after: Default::default(),
+        }
+    }
+}
+impl Paginable for ListFinancialConnectionsAccountOwners<'_> {
+    type O = FinancialConnectionsAccountOwner;
+    fn set_last(&mut self, item: Self::O) {
+        self.starting_after = Some(item.id());
+    }
+}
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct ListFinancialConnectionsAccountsAccountHolder {
+    /// The ID of the Stripe account whose accounts will be retrieved.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub account: Option<AccountId>,
+
+    /// The ID of the Stripe customer whose accounts will be retrieved.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub customer: Option<CustomerId>,
+}
+
 /// An enum representing the possible values of an `BankConnectionsResourceBalanceRefresh`'s `status` field.
 #[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
 #[serde(rename_all = "snake_case")]
@@ -442,7 +639,9 @@ impl FinancialConnectionsAccountSupportedPaymentMethodTypes {
     pub fn as_str(self) -> &'static str {
         match self {
             FinancialConnectionsAccountSupportedPaymentMethodTypes::Link => "link",
-            FinancialConnectionsAccountSupportedPaymentMethodTypes::UsBankAccount => "us_bank_account",
+            FinancialConnectionsAccountSupportedPaymentMethodTypes::UsBankAccount => {
+                "us_bank_account"
+            }
         }
     }
 }
@@ -463,3 +662,37 @@ impl std::default::Default for FinancialConnectionsAccountSupportedPaymentMethod
         Self::Link
     }
 }
+
+/// An enum representing the possible values of an `RefreshFinancialConnectionsAccount`'s `features` field.
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum RefreshFinancialConnectionsAccountFeatures {
+    Balance,
+    Ownership,
+}
+
+impl RefreshFinancialConnectionsAccountFeatures {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            RefreshFinancialConnectionsAccountFeatures::Balance => "balance",
+            RefreshFinancialConnectionsAccountFeatures::Ownership => "ownership",
+        }
+    }
+}
+
+impl AsRef<str> for RefreshFinancialConnectionsAccountFeatures {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::fmt::Display for RefreshFinancialConnectionsAccountFeatures {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
+impl std::default::Default for RefreshFinancialConnectionsAccountFeatures {
+    fn default() -> Self {
+        Self::Balance
+    }
+}
@@ -2,10 +2,11 @@
 // This file was automatically generated.
 // ======================================
 
-use crate::ids::{FinancialConnectionsAccountOwnerId};
-use crate::params::{Object, Timestamp};
 use serde::{Deserialize, Serialize};
 
+use crate::ids::FinancialConnectionsAccountOwnerId;
+use crate::params::{Object, Timestamp};
+
 /// The resource representing a Stripe "BankConnectionsResourceOwner".
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct FinancialConnectionsAccountOwner {
@@ -2,11 +2,12 @@
 // This file was automatically generated.
 // ======================================
 
-use crate::ids::{FinancialConnectionsAccountOwnershipId};
-use crate::params::{List, Object, Timestamp};
-use crate::resources::{FinancialConnectionsAccountOwner};
 use serde::{Deserialize, Serialize};
 
+use crate::ids::FinancialConnectionsAccountOwnershipId;
+use crate::params::{List, Object, Timestamp};
+use crate::resources::FinancialConnectionsAccountOwner;
+
 /// The resource representing a Stripe "BankConnectionsResourceOwnership".
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct FinancialConnectionsAccountOwnership {
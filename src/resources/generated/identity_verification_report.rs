@@ -2,11 +2,13 @@
 // This file was automatically generated.
 // ======================================
 
-use crate::ids::{IdentityVerificationReportId};
-use crate::params::{Object, Timestamp};
-use crate::resources::{Address};
 use serde::{Deserialize, Serialize};
 
+use crate::client::{Client, Response};
+use crate::ids::IdentityVerificationReportId;
+use crate::params::{Expand, List, Object, Paginable, RangeQuery, Timestamp};
+use crate::resources::Address;
+
 /// The resource representing a Stripe "GelatoVerificationReport".
 ///
 /// For more details see <https://stripe.com/docs/api/identity/verification_reports/object>
@@ -44,6 +46,25 @@ pub struct IdentityVerificationReport {
     pub verification_session: Option<String>,
 }
 
+impl IdentityVerificationReport {
+    /// List all verification reports.
+    pub fn list(
+        client: &Client,
+        params: &ListIdentityVerificationReports<'_>,
+    ) -> Response<List<IdentityVerificationReport>> {
+        client.get_query("/identity/verification_reports", &params)
+    }
+
+    /// Retrieves an existing VerificationReport.
+    pub fn retrieve(
+        client: &Client,
+        id: &IdentityVerificationReportId,
+        expand: &[&str],
+    ) -> Response<IdentityVerificationReport> {
+        client.get_query(&format!("/identity/verification_reports/{}", id), &Expand { expand })
+    }
+}
+
 impl Object for IdentityVerificationReport {
     type Id = IdentityVerificationReportId;
     fn id(&self) -> Self::Id {
@@ -56,7 +77,6 @@ impl Object for IdentityVerificationReport {
 
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct GelatoDocumentReport {
-
     /// Address as it appears in the document.
     pub address: Option<Address>,
 
@@ -99,7 +119,6 @@ pub struct GelatoDocumentReport {
 
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct GelatoDataDocumentReportDateOfBirth {
-
     /// Numerical day between 1 and 31.
     pub day: Option<i64>,
 
@@ -112,7 +131,6 @@ pub struct GelatoDataDocumentReportDateOfBirth {
 
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct GelatoDataDocumentReportExpirationDate {
-
     /// Numerical day between 1 and 31.
     pub day: Option<i64>,
 
@@ -125,7 +143,6 @@ pub struct GelatoDataDocumentReportExpirationDate {
 
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct GelatoDataDocumentReportIssuedDate {
-
     /// Numerical day between 1 and 31.
     pub day: Option<i64>,
 
@@ -138,7 +155,6 @@ pub struct GelatoDataDocumentReportIssuedDate {
 
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct GelatoDocumentReportError {
-
     /// A short machine-readable string giving the reason for the verification failure.
     pub code: Option<GelatoDocumentReportErrorCode>,
 
@@ -150,7 +166,6 @@ pub struct GelatoDocumentReportError {
 
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct GelatoIdNumberReport {
-
     /// Date of birth.
     pub dob: Option<GelatoDataIdNumberReportDate>,
 
@@ -177,7 +192,6 @@ pub struct GelatoIdNumberReport {
 
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct GelatoDataIdNumberReportDate {
-
     /// Numerical day between 1 and 31.
     pub day: Option<i64>,
 
@@ -190,7 +204,6 @@ pub struct GelatoDataIdNumberReportDate {
 
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct GelatoIdNumberReportError {
-
     /// A short machine-readable string giving the reason for the verification failure.
     pub code: Option<GelatoIdNumberReportErrorCode>,
 
@@ -202,7 +215,6 @@ pub struct GelatoIdNumberReportError {
 
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct GelatoSelfieReport {
-
     /// ID of the [File](https://stripe.com/docs/api/files) holding the image of the identity document used in this check.
     pub document: Option<String>,
 
@@ -220,7 +232,6 @@ pub struct GelatoSelfieReport {
 
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct GelatoSelfieReportError {
-
     /// A short machine-readable string giving the reason for the verification failure.
     pub code: Option<GelatoSelfieReportErrorCode>,
 
@@ -232,7 +243,6 @@ pub struct GelatoSelfieReportError {
 
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct GelatoVerificationReportOptions {
-
     #[serde(skip_serializing_if = "Option::is_none")]
     pub document: Option<GelatoReportDocumentOptions>,
 
@@ -242,7 +252,6 @@ pub struct GelatoVerificationReportOptions {
 
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct GelatoReportDocumentOptions {
-
     /// Array of strings of allowed identity document types.
     ///
     /// If the provided identity document isn’t one of the allowed types, the verification check will fail with a document_type_not_allowed error code.
@@ -265,7 +274,68 @@ pub struct GelatoReportDocumentOptions {
 }
 
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
-pub struct GelatoReportIdNumberOptions {
+pub struct GelatoReportIdNumberOptions {}
+
+/// The parameters for `IdentityVerificationReport::list`.
+#[derive(Clone, Debug, Serialize, Default)]
+pub struct ListIdentityVerificationReports<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created: Option<RangeQuery<Timestamp>>,
+
+    /// A cursor for use in pagination.
+    ///
+    /// `ending_before` is an object ID that defines your place in the list.
+    /// For instance, if you make a list request and receive 100 objects, starting with `obj_bar`, your subsequent call can include `ending_before=obj_bar` in order to fetch the previous page of the list.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ending_before: Option<IdentityVerificationReportId>,
+
+    /// Specifies which fields in the response should be expanded.
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+
+    /// A limit on the number of objects to be returned.
+    ///
+    /// Limit can range between 1 and 100, and the default is 10.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u64>,
+
+    /// A cursor for use in pagination.
+    ///
+    /// `starting_after` is an object ID that defines your place in the list.
+    /// For instance, if you make a list request and receive 100 objects, ending with `obj_foo`, your subsequent call can include `starting_after=obj_foo` in order to fetch the next page of the list.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub starting_after: Option<IdentityVerificationReportId>,
+
+    /// Only return VerificationReports of this type.
+    #[serde(rename = "type")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub type_: Option<IdentityVerificationReportType>,
+
+    /// Only return VerificationReports created by this VerificationSession ID.
+    ///
+    /// It is allowed to provide a VerificationIntent ID.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub verification_session: Option<&'a str>,
+}
+
+impl<'a> ListIdentityVerificationReports<'a> {
+    pub fn new() -> Self {
+        ListIdentityVerificationReports {
+            created: Default::default(),
+            ending_before: Default::default(),
+            expand: Default::default(),
+            limit: Default::default(),
+            starting_after: Default::default(),
+            type_: Default::default(),
+            verification_session: Default::default(),
+        }
+    }
+}
+impl Paginable for ListIdentityVerificationReports<'_> {
+    type O = IdentityVerificationReport;
+    fn set_last(&mut self, item: Self::O) {
+        self.starting_after = Some(item.id());
+    }
 }
 
 /// An enum representing the possible values of an `GelatoDocumentReportError`'s `code` field.
@@ -281,7 +351,9 @@ impl GelatoDocumentReportErrorCode {
     pub fn as_str(self) -> &'static str {
         match self {
             GelatoDocumentReportErrorCode::DocumentExpired => "document_expired",
-            GelatoDocumentReportErrorCode::DocumentTypeNotSupported => "document_type_not_supported",
+            GelatoDocumentReportErrorCode::DocumentTypeNotSupported => {
+                "document_type_not_supported"
+            }
             GelatoDocumentReportErrorCode::DocumentUnverifiedOther => "document_unverified_other",
         }
     }
@@ -386,7 +458,9 @@ pub enum GelatoIdNumberReportErrorCode {
 impl GelatoIdNumberReportErrorCode {
     pub fn as_str(self) -> &'static str {
         match self {
-            GelatoIdNumberReportErrorCode::IdNumberInsufficientDocumentData => "id_number_insufficient_document_data",
+            GelatoIdNumberReportErrorCode::IdNumberInsufficientDocumentData => {
+                "id_number_insufficient_document_data"
+            }
             GelatoIdNumberReportErrorCode::IdNumberMismatch => "id_number_mismatch",
             GelatoIdNumberReportErrorCode::IdNumberUnverifiedOther => "id_number_unverified_other",
         }
@@ -529,7 +603,9 @@ pub enum GelatoSelfieReportErrorCode {
 impl GelatoSelfieReportErrorCode {
     pub fn as_str(self) -> &'static str {
         match self {
-            GelatoSelfieReportErrorCode::SelfieDocumentMissingPhoto => "selfie_document_missing_photo",
+            GelatoSelfieReportErrorCode::SelfieDocumentMissingPhoto => {
+                "selfie_document_missing_photo"
+            }
             GelatoSelfieReportErrorCode::SelfieFaceMismatch => "selfie_face_mismatch",
             GelatoSelfieReportErrorCode::SelfieManipulated => "selfie_manipulated",
             GelatoSelfieReportErrorCode::SelfieUnverifiedOther => "selfie_unverified_other",
@@ -2,11 +2,13 @@
 // This file was automatically generated.
 // ======================================
 
-use crate::ids::{IdentityVerificationSessionId};
-use crate::params::{Expandable, Metadata, Object, Timestamp};
-use crate::resources::{Address, IdentityVerificationReport};
 use serde::{Deserialize, Serialize};
 
+use crate::client::{Client, Response};
+use crate::ids::IdentityVerificationSessionId;
+use crate::params::{Expand, Expandable, List, Metadata, Object, Paginable, RangeQuery, Timestamp};
+use crate::resources::{Address, IdentityVerificationReport};
+
 /// The resource representing a Stripe "GelatoVerificationSession".
 ///
 /// For more details see <https://stripe.com/docs/api/identity/verification_sessions/object>
@@ -72,6 +74,74 @@ pub struct IdentityVerificationSession {
     pub verified_outputs: Option<GelatoVerifiedOutputs>,
 }
 
+impl IdentityVerificationSession {
+    /// Returns a list of VerificationSessions.
+    pub fn list(
+        client: &Client,
+        params: &ListIdentityVerificationSessions<'_>,
+    ) -> Response<List<IdentityVerificationSession>> {
+        client.get_query("/identity/verification_sessions", &params)
+    }
+
+    /// Creates a VerificationSession object.
+    ///
+    /// After the VerificationSession is created, display a verification modal using the session `client_secret` or send your users to the session’s `url`.
+    /// If your API key is in test mode, verification checks won’t actually process, though everything else will occur as if in live mode.
+    pub fn create(
+        client: &Client,
+        params: CreateIdentityVerificationSession<'_>,
+    ) -> Response<IdentityVerificationSession> {
+        client.post_form("/identity/verification_sessions", &params)
+    }
+
+    /// Retrieves the details of a VerificationSession that was previously created.
+    ///
+    /// When the session status is `requires_input`, you can use this method to retrieve a valid `client_secret` or `url` to allow re-submission.
+    pub fn retrieve(
+        client: &Client,
+        id: &IdentityVerificationSessionId,
+        expand: &[&str],
+    ) -> Response<IdentityVerificationSession> {
+        client.get_query(&format!("/identity/verification_sessions/{}", id), &Expand { expand })
+    }
+
+    /// Updates a VerificationSession object.
+    ///
+    /// When the session status is `requires_input`, you can use this method to update the verification check and options.
+    pub fn update(
+        client: &Client,
+        id: &IdentityVerificationSessionId,
+        params: UpdateIdentityVerificationSession<'_>,
+    ) -> Response<IdentityVerificationSession> {
+        client.post_form(&format!("/identity/verification_sessions/{}", id), &params)
+    }
+
+    /// A VerificationSession object can be canceled when it is in `requires_input` [status](https://stripe.com/docs/identity/how-sessions-work).
+    ///
+    /// Once canceled, future submission attempts are disabled.
+    /// This cannot be undone.
+    pub fn cancel(
+        client: &Client,
+        id: &IdentityVerificationSessionId,
+        params: CancelIdentityVerificationSession<'_>,
+    ) -> Response<IdentityVerificationSession> {
+        client.post_form(&format!("/identity/verification_sessions/{}/cancel", id), &params)
+    }
+
+    /// Redact a VerificationSession to remove all collected information from Stripe.
+    ///
+    /// This will redact the VerificationSession and all objects related to it, including VerificationReports, Events, request logs, etc.
+    /// A VerificationSession object can be redacted when it is in `requires_input` or `verified` [status](https://stripe.com/docs/identity/how-sessions-work).
+    /// Redacting a VerificationSession in `requires_action` state will automatically cancel it.
+    pub fn redact(
+        client: &Client,
+        id: &IdentityVerificationSessionId,
+        params: RedactIdentityVerificationSession<'_>,
+    ) -> Response<IdentityVerificationSession> {
+        client.post_form(&format!("/identity/verification_sessions/{}/redact", id), &params)
+    }
+}
+
 impl Object for IdentityVerificationSession {
     type Id = IdentityVerificationSessionId;
     fn id(&self) -> Self::Id {
@@ -84,7 +154,6 @@ impl Object for IdentityVerificationSession {
 
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct GelatoSessionLastError {
-
     /// A short machine-readable string giving the reason for the verification or user-session failure.
     pub code: Option<GelatoSessionLastErrorCode>,
 
@@ -94,7 +163,6 @@ pub struct GelatoSessionLastError {
 
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct GelatoVerificationSessionOptions {
-
     #[serde(skip_serializing_if = "Option::is_none")]
     pub document: Option<GelatoSessionDocumentOptions>,
 
@@ -104,7 +172,6 @@ pub struct GelatoVerificationSessionOptions {
 
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct GelatoSessionDocumentOptions {
-
     /// Array of strings of allowed identity document types.
     ///
     /// If the provided identity document isn’t one of the allowed types, the verification check will fail with a document_type_not_allowed error code.
@@ -127,12 +194,10 @@ pub struct GelatoSessionDocumentOptions {
 }
 
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
-pub struct GelatoSessionIdNumberOptions {
-}
+pub struct GelatoSessionIdNumberOptions {}
 
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct GelatoVerifiedOutputs {
-
     /// The user's verified address.
     pub address: Option<Address>,
 
@@ -154,7 +219,6 @@ pub struct GelatoVerifiedOutputs {
 
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct GelatoDataVerifiedOutputsDate {
-
     /// Numerical day between 1 and 31.
     pub day: Option<i64>,
 
@@ -167,11 +231,169 @@ pub struct GelatoDataVerifiedOutputsDate {
 
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct VerificationSessionRedaction {
-
     /// Indicates whether this object and its related objects have been redacted or not.
     pub status: VerificationSessionRedactionStatus,
 }
 
+/// The parameters for `IdentityVerificationSession::create`.
+#[derive(Clone, Debug, Serialize)]
+pub struct CreateIdentityVerificationSession<'a> {
+    /// Specifies which fields in the response should be expanded.
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+
+    /// Set of [key-value pairs](https://stripe.com/docs/api/metadata) that you can attach to an object.
+    ///
+    /// This can be useful for storing additional information about the object in a structured format.
+    /// Individual keys can be unset by posting an empty value to them.
+    /// All keys can be unset by posting an empty value to `metadata`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+
+    /// A set of options for the session’s verification checks.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub options: Option<GelatoVerificationSessionOptions>,
+
+    /// The URL that the user will be redirected to upon completing the verification flow.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub return_url: Option<&'a str>,
+
+    /// The type of [verification check](https://stripe.com/docs/identity/verification-checks) to be performed.
+    #[serde(rename = "type")]
+    pub type_: IdentityVerificationSessionType,
+}
+
+impl<'a> CreateIdentityVerificationSession<'a> {
+    pub fn new(type_: IdentityVerificationSessionType) -> Self {
+        CreateIdentityVerificationSession {
+            expand: Default::default(),
+            metadata: Default::default(),
+            options: Default::default(),
+            return_url: Default::default(),
+            type_,
+        }
+    }
+}
+
+/// The parameters for `IdentityVerificationSession::list`.
+#[derive(Clone, Debug, Serialize, Default)]
+pub struct ListIdentityVerificationSessions<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created: Option<RangeQuery<Timestamp>>,
+
+    /// A cursor for use in pagination.
+    ///
+    /// `ending_before` is an object ID that defines your place in the list.
+    /// For instance, if you make a list request and receive 100 objects, starting with `obj_bar`, your subsequent call can include `ending_before=obj_bar` in order to fetch the previous page of the list.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ending_before: Option<IdentityVerificationSessionId>,
+
+    /// Specifies which fields in the response should be expanded.
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+
+    /// A limit on the number of objects to be returned.
+    ///
+    /// Limit can range between 1 and 100, and the default is 10.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u64>,
+
+    /// A cursor for use in pagination.
+    ///
+    /// `starting_after` is an object ID that defines your place in the list.
+    /// For instance, if you make a list request and receive 100 objects, ending with `obj_foo`, your subsequent call can include `starting_after=obj_foo` in order to fetch the next page of the list.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub starting_after: Option<IdentityVerificationSessionId>,
+
+    /// Only return VerificationSessions with this status.
+    ///
+    /// [Learn more about the lifecycle of sessions](https://stripe.com/docs/identity/how-sessions-work).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<IdentityVerificationSessionStatus>,
+}
+
+impl<'a> ListIdentityVerificationSessions<'a> {
+    pub fn new() -> Self {
+        ListIdentityVerificationSessions {
+            created: Default::default(),
+            ending_before: Default::default(),
+            expand: Default::default(),
+            limit: Default::default(),
+            starting_after: Default::default(),
+            status: Default::default(),
+        }
+    }
+}
+impl Paginable for ListIdentityVerificationSessions<'_> {
+    type O = IdentityVerificationSession;
+    fn set_last(&mut self, item: Self::O) {
+        self.starting_after = Some(item.id());
+    }
+}
+/// The parameters for `IdentityVerificationSession::update`.
+#[derive(Clone, Debug, Serialize, Default)]
+pub struct UpdateIdentityVerificationSession<'a> {
+    /// Specifies which fields in the response should be expanded.
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+
+    /// Set of [key-value pairs](https://stripe.com/docs/api/metadata) that you can attach to an object.
+    ///
+    /// This can be useful for storing additional information about the object in a structured format.
+    /// Individual keys can be unset by posting an empty value to them.
+    /// All keys can be unset by posting an empty value to `metadata`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+
+    /// A set of options for the session’s verification checks.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub options: Option<GelatoVerificationSessionOptions>,
+
+    /// The type of [verification check](https://stripe.com/docs/identity/verification-checks) to be performed.
+    #[serde(rename = "type")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub type_: Option<IdentityVerificationSessionType>,
+}
+
+impl<'a> UpdateIdentityVerificationSession<'a> {
+    pub fn new() -> Self {
+        UpdateIdentityVerificationSession {
+            expand: Default::default(),
+            metadata: Default::default(),
+            options: Default::default(),
+            type_: Default::default(),
+        }
+    }
+}
+
+/// The parameters for `IdentityVerificationSession::cancel`.
+#[derive(Clone, Debug, Serialize, Default)]
+pub struct CancelIdentityVerificationSession<'a> {
+    /// Specifies which fields in the response should be expanded.
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+}
+
+impl<'a> CancelIdentityVerificationSession<'a> {
+    pub fn new() -> Self {
+        CancelIdentityVerificationSession { expand: Default::default() }
+    }
+}
+
+/// The parameters for `IdentityVerificationSession::redact`.
+#[derive(Clone, Debug, Serialize, Default)]
+pub struct RedactIdentityVerificationSession<'a> {
+    /// Specifies which fields in the response should be expanded.
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+}
+
+impl<'a> RedactIdentityVerificationSession<'a> {
+    pub fn new() -> Self {
+        RedactIdentityVerificationSession { expand: Default::default() }
+    }
+}
+
 /// An enum representing the possible values of an `GelatoSessionDocumentOptions`'s `allowed_types` field.
 #[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
 #[serde(rename_all = "snake_case")]
@@ -239,10 +461,14 @@ impl GelatoSessionLastErrorCode {
             GelatoSessionLastErrorCode::DocumentExpired => "document_expired",
             GelatoSessionLastErrorCode::DocumentTypeNotSupported => "document_type_not_supported",
             GelatoSessionLastErrorCode::DocumentUnverifiedOther => "document_unverified_other",
-            GelatoSessionLastErrorCode::IdNumberInsufficientDocumentData => "id_number_insufficient_document_data",
+            GelatoSessionLastErrorCode::IdNumberInsufficientDocumentData => {
+                "id_number_insufficient_document_data"
+            }
             GelatoSessionLastErrorCode::IdNumberMismatch => "id_number_mismatch",
             GelatoSessionLastErrorCode::IdNumberUnverifiedOther => "id_number_unverified_other",
-            GelatoSessionLastErrorCode::SelfieDocumentMissingPhoto => "selfie_document_missing_photo",
+            GelatoSessionLastErrorCode::SelfieDocumentMissingPhoto => {
+                "selfie_document_missing_photo"
+            }
             GelatoSessionLastErrorCode::SelfieFaceMismatch => "selfie_face_mismatch",
             GelatoSessionLastErrorCode::SelfieManipulated => "selfie_manipulated",
             GelatoSessionLastErrorCode::SelfieUnverifiedOther => "selfie_unverified_other",
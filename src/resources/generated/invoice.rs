@@ -5,7 +5,7 @@
 use serde::{Deserialize, Serialize};
 
 use crate::client::{Client, Response};
-use crate::ids::{CustomerId, InvoiceId, SubscriptionId};
+use crate::ids::{CustomerId, InvoiceId, InvoiceLineItemId, SubscriptionId};
 use crate::params::{
     Deleted, Expand, Expandable, List, Metadata, Object, Paginable, RangeQuery, Timestamp,
 };
@@ -476,6 +476,77 @@ impl Invoice {
     pub fn delete(client: &Client, id: &InvoiceId) -> Response<Deleted<InvoiceId>> {
         client.delete(&format!("/invoices/{}", id))
     }
+
+    /// Draft invoices are fully editable.
+    ///
+    /// Once an invoice is [finalized](https://stripe.com/docs/billing/invoices/workflow#finalized), monetary values, as well as `collection_method`, become uneditable.
+    /// If you would like to stop the Stripe Billing engine from automatically finalizing, reattempting payments on, sending reminders for, or [automatically reconciling](https://stripe.com/docs/billing/invoices/reconciliation) invoices, pass `auto_advance=false`.
+    pub fn update(client: &Client, id: &InvoiceId, params: UpdateInvoice<'_>) -> Response<Invoice> {
+        client.post_form(&format!("/invoices/{}", id), &params)
+    }
+
+    /// Stripe automatically finalizes drafts before sending and attempting payment on invoices.
+    ///
+    /// However, if you’d like to finalize a draft invoice manually, you can do so using this method.
+    pub fn finalize_invoice(
+        client: &Client,
+        id: &InvoiceId,
+        params: FinalizeInvoice<'_>,
+    ) -> Response<Invoice> {
+        client.post_form(&format!("/invoices/{}/finalize", id), &params)
+    }
+
+    /// Stripe will automatically send invoices to customers according to your [subscriptions settings](https://dashboard.stripe.com/account/billing/automatic).
+    ///
+    /// However, if you’d like to manually send an invoice to your customer out of the normal schedule, you can do so.
+    /// When sending invoices that have already been paid, there will be no reference to the payment in the email.
+    /// Requests made in test-mode result in no emails being sent, despite sending an `invoice.sent` event.
+    pub fn send_invoice(
+        client: &Client,
+        id: &InvoiceId,
+        params: SendInvoice<'_>,
+    ) -> Response<Invoice> {
+        client.post_form(&format!("/invoices/{}/send", id), &params)
+    }
+
+    /// Mark a finalized invoice as void.
+    ///
+    /// This cannot be undone.
+    /// Voiding an invoice is similar to [deletion](https://stripe.com/docs/api#delete_invoice), however it only applies to finalized invoices and maintains a papertrail where the invoice can still be found.
+    pub fn void_invoice(
+        client: &Client,
+        id: &InvoiceId,
+        params: VoidInvoice<'_>,
+    ) -> Response<Invoice> {
+        client.post_form(&format!("/invoices/{}/void", id), &params)
+    }
+
+    /// Marking an invoice as uncollectible is useful for keeping track of bad debts that can be written off for accounting purposes.
+    pub fn mark_uncollectible(
+        client: &Client,
+        id: &InvoiceId,
+        params: MarkInvoiceUncollectible<'_>,
+    ) -> Response<Invoice> {
+        client.post_form(&format!("/invoices/{}/mark_uncollectible", id), &params)
+    }
+
+    /// Stripe automatically creates and then attempts to collect payment on invoices for customers on subscriptions according to your [subscriptions settings](https://dashboard.stripe.com/account/billing/automatic).
+    ///
+    /// However, if you’d like to attempt payment on an invoice out of the normal collection schedule or for some other reason, you can do so.
+    pub fn pay(client: &Client, id: &InvoiceId, params: PayInvoice<'_>) -> Response<Invoice> {
+        client.post_form(&format!("/invoices/{}/pay", id), &params)
+    }
+
+    /// When retrieving an invoice, you’ll get a **lines** property containing the total count of line items and the first handful of those items.
+    ///
+    /// There is also a URL where you can retrieve the full (paginated) list of line items.
+    pub fn list_line_items(
+        client: &Client,
+        id: &InvoiceId,
+        params: &ListInvoiceLineItems<'_>,
+    ) -> Response<List<InvoiceLineItem>> {
+        client.get_query(&format!("/invoices/{}/lines", id), &params)
+    }
 }
 
 impl Object for Invoice {
@@ -927,6 +998,340 @@ impl Paginable for ListInvoices<'_> {
         self.starting_after = Some(item.id());
     }
 }
+/// The parameters for `Invoice::update`.
+#[derive(Clone, Debug, Serialize, Default)]
+pub struct UpdateInvoice<'a> {
+    /// The account tax IDs associated with the invoice.
+    ///
+    /// Only editable when the invoice is a draft.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub account_tax_ids: Option<Vec<String>>,
+
+    /// A fee in cents (or local equivalent) that will be applied to the invoice and transferred to the application owner's Stripe account.
+    ///
+    /// The request must be made with an OAuth key or the Stripe-Account header in order to take an application fee.
+    /// For more information, see the application fees [documentation](https://stripe.com/docs/billing/invoices/connect#collecting-fees).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub application_fee_amount: Option<i64>,
+
+    /// Controls whether Stripe will perform [automatic collection](https://stripe.com/docs/billing/invoices/workflow/#auto_advance) of the invoice.
+    ///
+    /// When `false`, the invoice's state will not automatically advance without an explicit action.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub auto_advance: Option<bool>,
+
+    /// Settings for automatic tax lookup for this invoice.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub automatic_tax: Option<CreateInvoiceAutomaticTax>,
+
+    /// Either `charge_automatically`, or `send_invoice`.
+    ///
+    /// When charging automatically, Stripe will attempt to pay this invoice using the default source attached to the customer.
+    /// When sending an invoice, Stripe will email this invoice to the customer with payment instructions.
+    /// Defaults to `charge_automatically`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub collection_method: Option<CollectionMethod>,
+
+    /// A list of up to 4 custom fields to be displayed on the invoice.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_fields: Option<Vec<CreateInvoiceCustomFields>>,
+
+    /// The number of days from when the invoice is created until it is due.
+    ///
+    /// Valid only for invoices where `collection_method=send_invoice`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub days_until_due: Option<u32>,
+
+    /// ID of the default payment method for the invoice.
+    ///
+    /// It must belong to the customer associated with the invoice.
+    /// If not set, defaults to the subscription's default payment method, if any, or to the default payment method in the customer's invoice settings.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default_payment_method: Option<&'a str>,
+
+    /// ID of the default payment source for the invoice.
+    ///
+    /// It must belong to the customer associated with the invoice and be in a chargeable state.
+    /// If not set, defaults to the subscription's default source, if any, or to the customer's default source.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default_source: Option<&'a str>,
+
+    /// The tax rates that will apply to any line item that does not have `tax_rates` set.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default_tax_rates: Option<Vec<String>>,
+
+    /// An arbitrary string attached to the object.
+    ///
+    /// Often useful for displaying to users.
+    /// Referenced as 'memo' in the Dashboard.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<&'a str>,
+
+    /// The coupons to redeem into discounts for the invoice.
+    ///
+    /// If not specified, inherits the discount from the invoice's customer.
+    /// Pass an empty string to avoid inheriting any discounts.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub discounts: Option<Vec<CreateInvoiceDiscounts>>,
+
+    /// The date on which payment for this invoice is due.
+    ///
+    /// Valid only for invoices where `collection_method=send_invoice`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub due_date: Option<Timestamp>,
+
+    /// Specifies which fields in the response should be expanded.
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+
+    /// Footer to be displayed on the invoice.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub footer: Option<&'a str>,
+
+    /// Set of [key-value pairs](https://stripe.com/docs/api/metadata) that you can attach to an object.
+    ///
+    /// This can be useful for storing additional information about the object in a structured format.
+    /// Individual keys can be unset by posting an empty value to them.
+    /// All keys can be unset by posting an empty value to `metadata`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+
+    /// The account (if any) for which the funds of the invoice payment are intended.
+    ///
+    /// If set, the invoice will be presented with the branding and support information of the specified account.
+    /// See the [Invoices with Connect](https://stripe.com/docs/billing/invoices/connect) documentation for details.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub on_behalf_of: Option<&'a str>,
+
+    /// Configuration settings for the PaymentIntent that is generated when the invoice is finalized.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub payment_settings: Option<CreateInvoicePaymentSettings>,
+
+    /// Options for invoice PDF rendering.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rendering_options: Option<CreateInvoiceRenderingOptions>,
+
+    /// Settings for the cost of shipping for this invoice.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub shipping_cost: Option<CreateInvoiceShippingCost>,
+
+    /// Shipping details for the invoice.
+    ///
+    /// The Invoice PDF will use the `shipping_details` value if it is set, otherwise the PDF will render the shipping address from the customer.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub shipping_details: Option<CreateInvoiceShippingDetails>,
+
+    /// Extra information about a charge for the customer's credit card statement.
+    ///
+    /// It must contain at least one letter.
+    /// If not specified and this invoice is part of a subscription, the default `statement_descriptor` will be set to the first subscription item's product's `statement_descriptor`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub statement_descriptor: Option<&'a str>,
+
+    /// If specified, the funds from the invoice will be transferred to the destination and the ID of the resulting transfer will be found on the invoice's charge.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub transfer_data: Option<CreateInvoiceTransferData>,
+}
+
+impl<'a> UpdateInvoice<'a> {
+    pub fn new() -> Self {
+        UpdateInvoice {
+            account_tax_ids: Default::default(),
+            application_fee_amount: Default::default(),
+            auto_advance: Default::default(),
+            automatic_tax: Default::default(),
+            collection_method: Default::default(),
+            custom_fields: Default::default(),
+            days_until_due: Default::default(),
+            default_payment_method: Default::default(),
+            default_source: Default::default(),
+            default_tax_rates: Default::default(),
+            description: Default::default(),
+            discounts: Default::default(),
+            due_date: Default::default(),
+            expand: Default::default(),
+            footer: Default::default(),
+            metadata: Default::default(),
+            on_behalf_of: Default::default(),
+            payment_settings: Default::default(),
+            rendering_options: Default::default(),
+            shipping_cost: Default::default(),
+            shipping_details: Default::default(),
+            statement_descriptor: Default::default(),
+            transfer_data: Default::default(),
+        }
+    }
+}
+
+/// The parameters for `Invoice::finalize_invoice`.
+#[derive(Clone, Debug, Serialize, Default)]
+pub struct FinalizeInvoice<'a> {
+    /// Controls whether Stripe will perform [automatic collection](https://stripe.com/docs/invoicing/integration/automatic-advancement-collection) of the invoice.
+    ///
+    /// When `false`, the invoice's state will not automatically advance without an explicit action.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub auto_advance: Option<bool>,
+
+    /// Specifies which fields in the response should be expanded.
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+}
+
+impl<'a> FinalizeInvoice<'a> {
+    pub fn new() -> Self {
+        FinalizeInvoice { auto_advance: Default::default(), expand: Default::default() }
+    }
+}
+
+/// The parameters for `Invoice::send_invoice`.
+#[derive(Clone, Debug, Serialize, Default)]
+pub struct SendInvoice<'a> {
+    /// Specifies which fields in the response should be expanded.
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+}
+
+impl<'a> SendInvoice<'a> {
+    pub fn new() -> Self {
+        SendInvoice { expand: Default::default() }
+    }
+}
+
+/// The parameters for `Invoice::void_invoice`.
+#[derive(Clone, Debug, Serialize, Default)]
+pub struct VoidInvoice<'a> {
+    /// Specifies which fields in the response should be expanded.
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+}
+
+impl<'a> VoidInvoice<'a> {
+    pub fn new() -> Self {
+        VoidInvoice { expand: Default::default() }
+    }
+}
+
+/// The parameters for `Invoice::mark_uncollectible`.
+#[derive(Clone, Debug, Serialize, Default)]
+pub struct MarkInvoiceUncollectible<'a> {
+    /// Specifies which fields in the response should be expanded.
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+}
+
+impl<'a> MarkInvoiceUncollectible<'a> {
+    pub fn new() -> Self {
+        MarkInvoiceUncollectible { expand: Default::default() }
+    }
+}
+
+/// The parameters for `Invoice::pay`.
+#[derive(Clone, Debug, Serialize, Default)]
+pub struct PayInvoice<'a> {
+    /// Specifies which fields in the response should be expanded.
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+
+    /// In cases where the source used to pay the invoice has insufficient funds, passing `forgive=true` controls whether a charge should be attempted for the full amount available on the source, up to the amount to fully pay the invoice.
+    ///
+    /// This effectively forgives the difference between the amount available on the source and the amount due.
+    /// Passing `forgive=false` will fail the charge if the source hasn't been pre-funded with the right amount.
+    /// An example for this case is with ACH Credit Transfers and wires: if the amount wired is less than the amount due by a small amount, you might want to forgive the difference.
+    /// Defaults to `false`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub forgive: Option<bool>,
+
+    /// ID of the mandate to be used for this invoice.
+    ///
+    /// It must correspond to the payment method used to pay the invoice, including the payment_method param or the invoice's default_payment_method or default_source, if set.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mandate: Option<&'a str>,
+
+    /// Indicates if a customer is on or off-session while an invoice payment is attempted.
+    ///
+    /// Defaults to `true` (off-session).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub off_session: Option<bool>,
+
+    /// Boolean representing whether an invoice is paid outside of Stripe.
+    ///
+    /// This will result in no charge being made.
+    /// Defaults to `false`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub paid_out_of_band: Option<bool>,
+
+    /// A PaymentMethod to be charged.
+    ///
+    /// The PaymentMethod must be the ID of a PaymentMethod belonging to the customer associated with the invoice being paid.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub payment_method: Option<&'a str>,
+
+    /// A payment source to be charged.
+    ///
+    /// The source must be the ID of a source belonging to the customer associated with the invoice being paid.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub source: Option<&'a str>,
+}
+
+impl<'a> PayInvoice<'a> {
+    pub fn new() -> Self {
+        PayInvoice {
+            expand: Default::default(),
+            forgive: Default::default(),
+            mandate: Default::default(),
+            off_session: Default::default(),
+            paid_out_of_band: Default::default(),
+            payment_method: Default::default(),
+            source: Default::default(),
+        }
+    }
+}
+
+/// The parameters for `Invoice::list_line_items`.
+#[derive(Clone, Debug, Serialize, Default)]
+pub struct ListInvoiceLineItems<'a> {
+    /// A cursor for use in pagination.
+    ///
+    /// `ending_before` is an object ID that defines your place in the list.
+    /// For instance, if you make a list request and receive 100 objects, starting with `obj_bar`, your subsequent call can include `ending_before=obj_bar` in order to fetch the previous page of the list.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ending_before: Option<InvoiceLineItemId>,
+
+    /// Specifies which fields in the response should be expanded.
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+
+    /// A limit on the number of objects to be returned.
+    ///
+    /// Limit can range between 1 and 100, and the default is 10.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u64>,
+
+    /// A cursor for use in pagination.
+    ///
+    /// `starting_after` is an object ID that defines your place in the list.
+    /// For instance, if you make a list request and receive 100 objects, ending with `obj_foo`, your subsequent call can include `starting_after=obj_foo` in order to fetch the next page of the list.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub starting_after: Option<InvoiceLineItemId>,
+}
+
+impl<'a> ListInvoiceLineItems<'a> {
+    pub fn new() -> Self {
+        ListInvoiceLineItems {
+            ending_before: Default::default(),
+            expand: Default::default(),
+            limit: Default::default(),
+            starting_after: Default::default(),
+        }
+    }
+}
+impl Paginable for ListInvoiceLineItems<'_> {
+    type O = InvoiceLineItem;
+    fn set_last(&mut self, item: Self::O) {
+        self.starting_after = Some(item.id());
+    }
+}
+
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct CreateInvoiceAutomaticTax {
     /// Whether Stripe automatically computes tax on this invoice.
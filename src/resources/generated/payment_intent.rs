@@ -230,6 +230,76 @@ impl PaymentIntent {
     ) -> Response<PaymentIntent> {
         client.post_form(&format!("/payment_intents/{}", id), &params)
     }
+
+    /// Manually reconcile the remaining amount for a customer_balance PaymentIntent.
+    pub fn apply_customer_balance(
+        client: &Client,
+        id: &PaymentIntentId,
+        params: ApplyCustomerBalancePaymentIntent<'_>,
+    ) -> Response<PaymentIntent> {
+        client.post_form(&format!("/payment_intents/{}/apply_customer_balance", id), &params)
+    }
+
+    /// A PaymentIntent object can be canceled when it is in one of these statuses: `requires_payment_method`, `requires_capture`, `requires_confirmation`, `requires_action` or, [in rare cases](https://stripe.com/docs/payments/intents), `processing`.
+    ///
+    /// Once canceled, no additional charges will be made by the PaymentIntent and any operations on the PaymentIntent will fail with an error.
+    /// For PaymentIntents with a `status` of `requires_capture`, the remaining `amount_capturable` will automatically be refunded.
+    pub fn cancel(
+        client: &Client,
+        id: &PaymentIntentId,
+        params: CancelPaymentIntent<'_>,
+    ) -> Response<PaymentIntent> {
+        client.post_form(&format!("/payment_intents/{}/cancel", id), &params)
+    }
+
+    /// Capture the funds of an existing uncaptured PaymentIntent when its status is `requires_capture`.
+    ///
+    /// Uncaptured PaymentIntents will be canceled a set number of days after they are created (7 by default).
+    /// Learn more about [separate authorization and capture](https://stripe.com/docs/payments/capture-later).
+    pub fn capture(
+        client: &Client,
+        id: &PaymentIntentId,
+        params: CapturePaymentIntent<'_>,
+    ) -> Response<PaymentIntent> {
+        client.post_form(&format!("/payment_intents/{}/capture", id), &params)
+    }
+
+    /// Confirm that your customer intends to pay with current or provided payment method.
+    ///
+    /// Upon confirmation, the PaymentIntent will attempt to initiate a payment.
+    /// If the selected payment method requires additional authentication steps, the PaymentIntent will transition to the `requires_action` status and suggest additional actions via `next_action`.
+    /// If payment fails, the PaymentIntent will transition to the `requires_payment_method` status.
+    /// If payment succeeds, the PaymentIntent will transition to the `succeeded` status (or `requires_capture`, if `capture_method` is set to `manual`).
+    pub fn confirm(
+        client: &Client,
+        id: &PaymentIntentId,
+        params: ConfirmPaymentIntent<'_>,
+    ) -> Response<PaymentIntent> {
+        client.post_form(&format!("/payment_intents/{}/confirm", id), &params)
+    }
+
+    /// Perform an incremental authorization on an eligible [PaymentIntent](https://stripe.com/docs/api/payment_intents/object).
+    ///
+    /// To be eligible, the PaymentIntent’s status must be `requires_capture` and [incremental_authorization_supported](https://stripe.com/docs/api/charges/object#charge_object-payment_method_details-card_present-incremental_authorization_supported) must be `true`.
+    /// Incremental authorizations attempt to increase the authorized amount on your customer’s card to the new, higher `amount` provided.
+    /// As with the initial authorization, incremental authorizations may be declined.
+    /// A single PaymentIntent can call this endpoint multiple times to further increase the authorized amount.
+    pub fn increment_authorization(
+        client: &Client,
+        id: &PaymentIntentId,
+        params: IncrementAuthorizationPaymentIntent<'_>,
+    ) -> Response<PaymentIntent> {
+        client.post_form(&format!("/payment_intents/{}/increment_authorization", id), &params)
+    }
+
+    /// Verifies microdeposits on a PaymentIntent object.
+    pub fn verify_microdeposits(
+        client: &Client,
+        id: &PaymentIntentId,
+        params: VerifyMicrodepositsPaymentIntent<'_>,
+    ) -> Response<PaymentIntent> {
+        client.post_form(&format!("/payment_intents/{}/verify_microdeposits", id), &params)
+    }
 }
 
 impl Object for PaymentIntent {
@@ -831,6 +901,15 @@ pub struct PaymentIntentPaymentMethodOptionsCard {
     /// Can be only set confirm-time.
     pub network: Option<PaymentIntentPaymentMethodOptionsCardNetwork>,
 
+    /// Request ability to [increment](https://stripe.com/docs/payments/incremental-authorization) this PaymentIntent if the combination of MCC and card brand is eligible.
+    ///
+    /// Check [incremental_authorization_supported](https://stripe.com/docs/api/charges/object#charge_object-payment_method_details-card-incremental_authorization) in the [Confirm](https://stripe.com/docs/api/payment_intents/confirm) response to verify support.
+    pub request_incremental_authorization:
+        Option<PaymentIntentPaymentMethodOptionsCardRequestIncrementalAuthorization>,
+
+    /// Request ability to make [multiple captures](https://stripe.com/docs/payments/multicapture) for this PaymentIntent.
+    pub request_multicapture: Option<PaymentIntentPaymentMethodOptionsCardRequestMulticapture>,
+
     /// We strongly recommend that you rely on our SCA Engine to automatically prompt your customers for authentication based on risk level and [other requirements](https://stripe.com/docs/strong-customer-authentication).
     ///
     /// However, if you wish to request 3D Secure based on logic from your own fraud engine, provide this option.
@@ -1395,6 +1474,235 @@ pub struct TransferData {
     pub destination: Expandable<Account>,
 }
 
+/// The parameters for `PaymentIntent::apply_customer_balance`.
+#[derive(Clone, Debug, Serialize, Default)]
+pub struct ApplyCustomerBalancePaymentIntent<'a> {
+    /// Amount intended to be applied to this PaymentIntent from the customer’s cash balance.
+    ///
+    /// A positive integer representing how much to charge in the [smallest currency unit](https://stripe.com/docs/currencies#zero-decimal) (e.g., 100 cents to charge $1.00 or 100 to charge ¥100, a zero-decimal currency).
+    /// The maximum amount is the amount of the PaymentIntent.
+    /// When omitted, the amount defaults to the remaining amount requested on the PaymentIntent.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub amount: Option<i64>,
+
+    /// Three-letter [ISO currency code](https://www.iso.org/iso-4217-currency-codes.html), in lowercase.
+    ///
+    /// Must be a [supported currency](https://stripe.com/docs/currencies).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub currency: Option<Currency>,
+
+    /// Specifies which fields in the response should be expanded.
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+}
+
+impl<'a> ApplyCustomerBalancePaymentIntent<'a> {
+    pub fn new() -> Self {
+        ApplyCustomerBalancePaymentIntent {
+            amount: Default::default(),
+            currency: Default::default(),
+            expand: Default::default(),
+        }
+    }
+}
+
+/// The parameters for `PaymentIntent::cancel`.
+#[derive(Clone, Debug, Serialize, Default)]
+pub struct CancelPaymentIntent<'a> {
+    /// Reason for canceling this PaymentIntent.
+    ///
+    /// Possible values are `duplicate`, `fraudulent`, `requested_by_customer`, or `abandoned`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cancellation_reason: Option<CancelPaymentIntentCancellationReason>,
+
+    /// Specifies which fields in the response should be expanded.
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+}
+
+impl<'a> CancelPaymentIntent<'a> {
+    pub fn new() -> Self {
+        CancelPaymentIntent { cancellation_reason: Default::default(), expand: Default::default() }
+    }
+}
+
+/// The parameters for `PaymentIntent::capture`.
+#[derive(Clone, Debug, Serialize, Default)]
+pub struct CapturePaymentIntent<'a> {
+    /// The amount to capture from the PaymentIntent, which must be less than or equal to the original amount.
+    ///
+    /// Any additional amount will be automatically refunded.
+    /// Defaults to the full `amount_capturable` if not provided.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub amount_to_capture: Option<i64>,
+
+    /// The amount of the application fee (if any) that will be requested to be applied to the payment and transferred to the application owner's Stripe account.
+    ///
+    /// The amount of the application fee collected will be capped at the total payment amount.
+    /// For more information, see the PaymentIntents [use case for connected accounts](https://stripe.com/docs/payments/connected-accounts).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub application_fee_amount: Option<i64>,
+
+    /// Specifies which fields in the response should be expanded.
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+
+    /// Defaults to `true`.
+    ///
+    /// When capturing a PaymentIntent, setting `final_capture` to `false` notifies Stripe to not release the remaining uncaptured funds to make sure that they're captured in future requests.
+    /// You can only use this setting when [multicapture](https://stripe.com/docs/payments/multicapture) is available for PaymentIntents.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub final_capture: Option<bool>,
+
+    /// Set of [key-value pairs](https://stripe.com/docs/api/metadata) that you can attach to an object.
+    ///
+    /// This can be useful for storing additional information about the object in a structured format.
+    /// Individual keys can be unset by posting an empty value to them.
+    /// All keys can be unset by posting an empty value to `metadata`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+
+    /// For non-card charges, you can use this value as the complete description that appears on your customers’ statements.
+    ///
+    /// Must contain at least one letter, maximum 22 characters.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub statement_descriptor: Option<&'a str>,
+
+    /// Provides information about a card payment that customers see on their statements.
+    ///
+    /// Concatenated with the prefix (shortened descriptor) or statement descriptor that’s set on the account to form the complete statement descriptor.
+    /// Maximum 22 characters for the concatenated descriptor.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub statement_descriptor_suffix: Option<&'a str>,
+
+    /// The parameters used to automatically create a Transfer when the payment is captured.
+    ///
+    /// For more information, see the PaymentIntents [use case for connected accounts](https://stripe.com/docs/payments/connected-accounts).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub transfer_data: Option<CapturePaymentIntentTransferData>,
+}
+
+impl<'a> CapturePaymentIntent<'a> {
+    pub fn new() -> Self {
+        CapturePaymentIntent {
+            amount_to_capture: Default::default(),
+            application_fee_amount: Default::default(),
+            expand: Default::default(),
+            final_capture: Default::default(),
+            metadata: Default::default(),
+            statement_descriptor: Default::default(),
+            statement_descriptor_suffix: Default::default(),
+            transfer_data: Default::default(),
+        }
+    }
+}
+
+/// The parameters for `PaymentIntent::confirm`.
+#[derive(Clone, Debug, Serialize, Default)]
+pub struct ConfirmPaymentIntent<'a> {
+    /// Controls when the funds will be captured from the customer's account.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub capture_method: Option<PaymentIntentCaptureMethod>,
+
+    /// The client secret of the PaymentIntent.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub client_secret: Option<&'a str>,
+
+    /// Set to `true` to fail the payment attempt if the PaymentIntent transitions into `requires_action`.
+    ///
+    /// This parameter is intended for simpler integrations that do not handle customer actions, like [saving cards without authentication](https://stripe.com/docs/payments/save-card-without-authentication).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error_on_requires_action: Option<bool>,
+
+    /// Specifies which fields in the response should be expanded.
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+
+    /// ID of the mandate to be used for this payment.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mandate: Option<MandateId>,
+
+    /// This hash contains details about the Mandate to create.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mandate_data: Option<CreatePaymentIntentMandateData>,
+
+    /// Set to `true` to indicate that the customer is not in your checkout flow during this payment attempt, and therefore is unable to authenticate.
+    ///
+    /// This parameter is intended for scenarios where you collect card details and [charge them later](https://stripe.com/docs/payments/cards/charging-saved-cards).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub off_session: Option<PaymentIntentOffSession>,
+
+    /// ID of the payment method (a PaymentMethod, Card, or [compatible Source](https://stripe.com/docs/payments/payment-methods/transitioning#compatibility) object) to attach to this PaymentIntent.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub payment_method: Option<PaymentMethodId>,
+
+    /// If provided, this hash will be used to create a PaymentMethod.
+    ///
+    /// The new PaymentMethod will appear in the [payment_method](https://stripe.com/docs/api/payment_intents/object#payment_intent_object-payment_method) property on the PaymentIntent.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub payment_method_data: Option<CreatePaymentIntentPaymentMethodData>,
+
+    /// Payment-method-specific configuration for this PaymentIntent.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub payment_method_options: Option<CreatePaymentIntentPaymentMethodOptions>,
+
+    /// Options to configure Radar.
+    ///
+    /// See [Radar Session](https://stripe.com/docs/radar/radar-session) for more information.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub radar_options: Option<CreatePaymentIntentRadarOptions>,
+
+    /// Email address that the receipt for the resulting payment will be sent to.
+    ///
+    /// If `receipt_email` is specified for a payment in live mode, a receipt will be sent regardless of your [email settings](https://dashboard.stripe.com/account/emails).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub receipt_email: Option<&'a str>,
+
+    /// The URL to redirect your customer back to after they authenticate or cancel their payment on the payment method's app or site.
+    ///
+    /// If you'd prefer to redirect to a mobile application, you can alternatively supply an application URI scheme.
+    /// This parameter is only used for cards and other redirect-based payment methods.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub return_url: Option<&'a str>,
+
+    /// Indicates that you intend to make future payments with this PaymentIntent's payment method.
+    ///
+    /// Providing this parameter will [attach the payment method](https://stripe.com/docs/payments/save-during-payment) to the PaymentIntent's Customer, if present, after the PaymentIntent is confirmed and any required actions from the user are complete.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub setup_future_usage: Option<PaymentIntentSetupFutureUsage>,
+
+    /// Shipping information for this PaymentIntent.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub shipping: Option<CreatePaymentIntentShipping>,
+
+    /// Set to `true` only when using manual confirmation and the iOS or Android SDKs to handle additional authentication steps.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub use_stripe_sdk: Option<bool>,
+}
+
+impl<'a> ConfirmPaymentIntent<'a> {
+    pub fn new() -> Self {
+        ConfirmPaymentIntent {
+            capture_method: Default::default(),
+            client_secret: Default::default(),
+            error_on_requires_action: Default::default(),
+            expand: Default::default(),
+            mandate: Default::default(),
+            mandate_data: Default::default(),
+            off_session: Default::default(),
+            payment_method: Default::default(),
+            payment_method_data: Default::default(),
+            payment_method_options: Default::default(),
+            radar_options: Default::default(),
+            receipt_email: Default::default(),
+            return_url: Default::default(),
+            setup_future_usage: Default::default(),
+            shipping: Default::default(),
+            use_stripe_sdk: Default::default(),
+        }
+    }
+}
+
 /// The parameters for `PaymentIntent::create`.
 #[derive(Clone, Debug, Serialize)]
 pub struct CreatePaymentIntent<'a> {
@@ -1616,6 +1924,66 @@ impl<'a> CreatePaymentIntent<'a> {
     }
 }
 
+/// The parameters for `PaymentIntent::increment_authorization`.
+#[derive(Clone, Debug, Serialize)]
+pub struct IncrementAuthorizationPaymentIntent<'a> {
+    /// The updated total amount you intend to collect from the cardholder.
+    ///
+    /// This amount must be greater than the currently authorized amount.
+    pub amount: i64,
+
+    /// The amount of the application fee (if any) that will be requested to be applied to the payment and transferred to the application owner's Stripe account.
+    ///
+    /// The amount of the application fee collected will be capped at the total payment amount.
+    /// For more information, see the PaymentIntents [use case for connected accounts](https://stripe.com/docs/payments/connected-accounts).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub application_fee_amount: Option<i64>,
+
+    /// An arbitrary string attached to the object.
+    ///
+    /// Often useful for displaying to users.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<&'a str>,
+
+    /// Specifies which fields in the response should be expanded.
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+
+    /// Set of [key-value pairs](https://stripe.com/docs/api/metadata) that you can attach to an object.
+    ///
+    /// This can be useful for storing additional information about the object in a structured format.
+    /// Individual keys can be unset by posting an empty value to them.
+    /// All keys can be unset by posting an empty value to `metadata`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+
+    /// For non-card charges, you can use this value as the complete description that appears on your customers’ statements.
+    ///
+    /// Must contain at least one letter, maximum 22 characters.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub statement_descriptor: Option<&'a str>,
+
+    /// The parameters used to automatically create a Transfer when the payment is captured.
+    ///
+    /// For more information, see the PaymentIntents [use case for connected accounts](https://stripe.com/docs/payments/connected-accounts).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub transfer_data: Option<IncrementAuthorizationPaymentIntentTransferData>,
+}
+
+impl<'a> IncrementAuthorizationPaymentIntent<'a> {
+    pub fn new(amount: i64) -> Self {
+        IncrementAuthorizationPaymentIntent {
+            amount,
+            application_fee_amount: Default::default(),
+            description: Default::default(),
+            expand: Default::default(),
+            metadata: Default::default(),
+            statement_descriptor: Default::default(),
+            transfer_data: Default::default(),
+        }
+    }
+}
+
 /// The parameters for `PaymentIntent::list`.
 #[derive(Clone, Debug, Serialize, Default)]
 pub struct ListPaymentIntents<'a> {
@@ -1818,6 +2186,44 @@ impl<'a> UpdatePaymentIntent<'a> {
     }
 }
 
+/// The parameters for `PaymentIntent::verify_microdeposits`.
+#[derive(Clone, Debug, Serialize, Default)]
+pub struct VerifyMicrodepositsPaymentIntent<'a> {
+    /// Two positive integers, in *cents*, equal to the values of the microdeposits sent to the bank account.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub amounts: Option<Vec<i64>>,
+
+    /// The client secret of the PaymentIntent.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub client_secret: Option<&'a str>,
+
+    /// A six-character code starting with SM present in the microdeposit sent to the bank account.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub descriptor_code: Option<&'a str>,
+
+    /// Specifies which fields in the response should be expanded.
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+}
+
+impl<'a> VerifyMicrodepositsPaymentIntent<'a> {
+    pub fn new() -> Self {
+        VerifyMicrodepositsPaymentIntent {
+            amounts: Default::default(),
+            client_secret: Default::default(),
+            descriptor_code: Default::default(),
+            expand: Default::default(),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct CapturePaymentIntentTransferData {
+    /// The amount that will be transferred automatically when a charge succeeds.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub amount: Option<i64>,
+}
+
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct CreatePaymentIntentAutomaticPaymentMethods {
     /// Whether this feature is enabled.
@@ -2151,6 +2557,13 @@ pub struct CreatePaymentIntentTransferData {
     pub destination: String,
 }
 
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct IncrementAuthorizationPaymentIntentTransferData {
+    /// The amount that will be transferred automatically when a charge succeeds.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub amount: Option<i64>,
+}
+
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct UpdatePaymentIntentPaymentMethodData {
     /// If this is an `acss_debit` PaymentMethod, this hash contains details about the ACSS Debit payment method.
@@ -2857,6 +3270,18 @@ pub struct CreatePaymentIntentPaymentMethodOptionsCard {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub network: Option<CreatePaymentIntentPaymentMethodOptionsCardNetwork>,
 
+    /// Request ability to [increment](https://stripe.com/docs/payments/incremental-authorization) this PaymentIntent if the combination of MCC and card brand is eligible.
+    ///
+    /// Check [incremental_authorization_supported](https://stripe.com/docs/api/charges/object#charge_object-payment_method_details-card-incremental_authorization) in the [Confirm](https://stripe.com/docs/api/payment_intents/confirm) response to verify support.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub request_incremental_authorization:
+        Option<CreatePaymentIntentPaymentMethodOptionsCardRequestIncrementalAuthorization>,
+
+    /// Request ability to make [multiple captures](https://stripe.com/docs/payments/multicapture) for this PaymentIntent.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub request_multicapture:
+        Option<CreatePaymentIntentPaymentMethodOptionsCardRequestMulticapture>,
+
     /// We strongly recommend that you rely on our SCA Engine to automatically prompt your customers for authentication based on risk level and [other requirements](https://stripe.com/docs/strong-customer-authentication).
     ///
     /// However, if you wish to request 3D Secure based on logic from your own fraud engine, provide this option.
@@ -3652,6 +4077,18 @@ pub struct UpdatePaymentIntentPaymentMethodOptionsCard {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub network: Option<UpdatePaymentIntentPaymentMethodOptionsCardNetwork>,
 
+    /// Request ability to [increment](https://stripe.com/docs/payments/incremental-authorization) this PaymentIntent if the combination of MCC and card brand is eligible.
+    ///
+    /// Check [incremental_authorization_supported](https://stripe.com/docs/api/charges/object#charge_object-payment_method_details-card-incremental_authorization) in the [Confirm](https://stripe.com/docs/api/payment_intents/confirm) response to verify support.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub request_incremental_authorization:
+        Option<UpdatePaymentIntentPaymentMethodOptionsCardRequestIncrementalAuthorization>,
+
+    /// Request ability to make [multiple captures](https://stripe.com/docs/payments/multicapture) for this PaymentIntent.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub request_multicapture:
+        Option<UpdatePaymentIntentPaymentMethodOptionsCardRequestMulticapture>,
+
     /// We strongly recommend that you rely on our SCA Engine to automatically prompt your customers for authentication based on risk level and [other requirements](https://stripe.com/docs/strong-customer-authentication).
     ///
     /// However, if you wish to request 3D Secure based on logic from your own fraud engine, provide this option.
@@ -4479,6 +4916,44 @@ pub struct UpdatePaymentIntentPaymentMethodOptionsCustomerBalanceBankTransferEuB
     pub country: String,
 }
 
+/// An enum representing the possible values of an `CancelPaymentIntent`'s `cancellation_reason` field.
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum CancelPaymentIntentCancellationReason {
+    Abandoned,
+    Duplicate,
+    Fraudulent,
+    RequestedByCustomer,
+}
+
+impl CancelPaymentIntentCancellationReason {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            CancelPaymentIntentCancellationReason::Abandoned => "abandoned",
+            CancelPaymentIntentCancellationReason::Duplicate => "duplicate",
+            CancelPaymentIntentCancellationReason::Fraudulent => "fraudulent",
+            CancelPaymentIntentCancellationReason::RequestedByCustomer => "requested_by_customer",
+        }
+    }
+}
+
+impl AsRef<str> for CancelPaymentIntentCancellationReason {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::fmt::Display for CancelPaymentIntentCancellationReason {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
+impl std::default::Default for CancelPaymentIntentCancellationReason {
+    fn default() -> Self {
+        Self::Abandoned
+    }
+}
+
 /// An enum representing the possible values of an `CreatePaymentIntentMandateDataCustomerAcceptance`'s `type` field.
 #[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
 #[serde(rename_all = "snake_case")]
@@ -5873,6 +6348,80 @@ impl std::default::Default for CreatePaymentIntentPaymentMethodOptionsCardNetwor
     }
 }
 
+/// An enum representing the possible values of an `CreatePaymentIntentPaymentMethodOptionsCard`'s `request_incremental_authorization` field.
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum CreatePaymentIntentPaymentMethodOptionsCardRequestIncrementalAuthorization {
+    IfAvailable,
+    Never,
+}
+
+impl CreatePaymentIntentPaymentMethodOptionsCardRequestIncrementalAuthorization {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            CreatePaymentIntentPaymentMethodOptionsCardRequestIncrementalAuthorization::IfAvailable => "if_available",
+            CreatePaymentIntentPaymentMethodOptionsCardRequestIncrementalAuthorization::Never => "never",
+        }
+    }
+}
+
+impl AsRef<str> for CreatePaymentIntentPaymentMethodOptionsCardRequestIncrementalAuthorization {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::fmt::Display
+    for CreatePaymentIntentPaymentMethodOptionsCardRequestIncrementalAuthorization
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
+impl std::default::Default
+    for CreatePaymentIntentPaymentMethodOptionsCardRequestIncrementalAuthorization
+{
+    fn default() -> Self {
+        Self::IfAvailable
+    }
+}
+
+/// An enum representing the possible values of an `CreatePaymentIntentPaymentMethodOptionsCard`'s `request_multicapture` field.
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum CreatePaymentIntentPaymentMethodOptionsCardRequestMulticapture {
+    IfAvailable,
+    Never,
+}
+
+impl CreatePaymentIntentPaymentMethodOptionsCardRequestMulticapture {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            CreatePaymentIntentPaymentMethodOptionsCardRequestMulticapture::IfAvailable => {
+                "if_available"
+            }
+            CreatePaymentIntentPaymentMethodOptionsCardRequestMulticapture::Never => "never",
+        }
+    }
+}
+
+impl AsRef<str> for CreatePaymentIntentPaymentMethodOptionsCardRequestMulticapture {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::fmt::Display for CreatePaymentIntentPaymentMethodOptionsCardRequestMulticapture {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
+impl std::default::Default for CreatePaymentIntentPaymentMethodOptionsCardRequestMulticapture {
+    fn default() -> Self {
+        Self::IfAvailable
+    }
+}
+
 /// An enum representing the possible values of an `CreatePaymentIntentPaymentMethodOptionsCard`'s `request_three_d_secure` field.
 #[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
 #[serde(rename_all = "snake_case")]
@@ -7639,6 +8188,78 @@ impl std::default::Default for PaymentIntentPaymentMethodOptionsCardNetwork {
     }
 }
 
+/// An enum representing the possible values of an `PaymentIntentPaymentMethodOptionsCard`'s `request_incremental_authorization` field.
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum PaymentIntentPaymentMethodOptionsCardRequestIncrementalAuthorization {
+    IfAvailable,
+    Never,
+}
+
+impl PaymentIntentPaymentMethodOptionsCardRequestIncrementalAuthorization {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            PaymentIntentPaymentMethodOptionsCardRequestIncrementalAuthorization::IfAvailable => {
+                "if_available"
+            }
+            PaymentIntentPaymentMethodOptionsCardRequestIncrementalAuthorization::Never => "never",
+        }
+    }
+}
+
+impl AsRef<str> for PaymentIntentPaymentMethodOptionsCardRequestIncrementalAuthorization {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::fmt::Display for PaymentIntentPaymentMethodOptionsCardRequestIncrementalAuthorization {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
+impl std::default::Default
+    for PaymentIntentPaymentMethodOptionsCardRequestIncrementalAuthorization
+{
+    fn default() -> Self {
+        Self::IfAvailable
+    }
+}
+
+/// An enum representing the possible values of an `PaymentIntentPaymentMethodOptionsCard`'s `request_multicapture` field.
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum PaymentIntentPaymentMethodOptionsCardRequestMulticapture {
+    IfAvailable,
+    Never,
+}
+
+impl PaymentIntentPaymentMethodOptionsCardRequestMulticapture {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            PaymentIntentPaymentMethodOptionsCardRequestMulticapture::IfAvailable => "if_available",
+            PaymentIntentPaymentMethodOptionsCardRequestMulticapture::Never => "never",
+        }
+    }
+}
+
+impl AsRef<str> for PaymentIntentPaymentMethodOptionsCardRequestMulticapture {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::fmt::Display for PaymentIntentPaymentMethodOptionsCardRequestMulticapture {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
+impl std::default::Default for PaymentIntentPaymentMethodOptionsCardRequestMulticapture {
+    fn default() -> Self {
+        Self::IfAvailable
+    }
+}
+
 /// An enum representing the possible values of an `PaymentIntentPaymentMethodOptionsCard`'s `request_three_d_secure` field.
 #[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
 #[serde(rename_all = "snake_case")]
@@ -10683,6 +11304,80 @@ impl std::default::Default for UpdatePaymentIntentPaymentMethodOptionsCardNetwor
     }
 }
 
+/// An enum representing the possible values of an `UpdatePaymentIntentPaymentMethodOptionsCard`'s `request_incremental_authorization` field.
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum UpdatePaymentIntentPaymentMethodOptionsCardRequestIncrementalAuthorization {
+    IfAvailable,
+    Never,
+}
+
+impl UpdatePaymentIntentPaymentMethodOptionsCardRequestIncrementalAuthorization {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            UpdatePaymentIntentPaymentMethodOptionsCardRequestIncrementalAuthorization::IfAvailable => "if_available",
+            UpdatePaymentIntentPaymentMethodOptionsCardRequestIncrementalAuthorization::Never => "never",
+        }
+    }
+}
+
+impl AsRef<str> for UpdatePaymentIntentPaymentMethodOptionsCardRequestIncrementalAuthorization {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::fmt::Display
+    for UpdatePaymentIntentPaymentMethodOptionsCardRequestIncrementalAuthorization
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
+impl std::default::Default
+    for UpdatePaymentIntentPaymentMethodOptionsCardRequestIncrementalAuthorization
+{
+    fn default() -> Self {
+        Self::IfAvailable
+    }
+}
+
+/// An enum representing the possible values of an `UpdatePaymentIntentPaymentMethodOptionsCard`'s `request_multicapture` field.
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum UpdatePaymentIntentPaymentMethodOptionsCardRequestMulticapture {
+    IfAvailable,
+    Never,
+}
+
+impl UpdatePaymentIntentPaymentMethodOptionsCardRequestMulticapture {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            UpdatePaymentIntentPaymentMethodOptionsCardRequestMulticapture::IfAvailable => {
+                "if_available"
+            }
+            UpdatePaymentIntentPaymentMethodOptionsCardRequestMulticapture::Never => "never",
+        }
+    }
+}
+
+impl AsRef<str> for UpdatePaymentIntentPaymentMethodOptionsCardRequestMulticapture {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::fmt::Display for UpdatePaymentIntentPaymentMethodOptionsCardRequestMulticapture {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
+impl std::default::Default for UpdatePaymentIntentPaymentMethodOptionsCardRequestMulticapture {
+    fn default() -> Self {
+        Self::IfAvailable
+    }
+}
+
 /// An enum representing the possible values of an `UpdatePaymentIntentPaymentMethodOptionsCard`'s `request_three_d_secure` field.
 #[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
 #[serde(rename_all = "snake_case")]
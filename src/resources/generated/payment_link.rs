@@ -138,6 +138,17 @@ impl PaymentLink {
     ) -> Response<PaymentLink> {
         client.post_form(&format!("/payment_links/{}", id), &params)
     }
+
+    /// When retrieving a payment link, there is an includable **line_items** property containing the first handful of those items.
+    ///
+    /// There is also a URL where you can retrieve the full (paginated) list of line items.
+    pub fn list_line_items(
+        client: &Client,
+        id: &PaymentLinkId,
+        params: &ListPaymentLinkLineItems<'_>,
+    ) -> Response<List<CheckoutSessionItem>> {
+        client.get_query(&format!("/payment_links/{}/line_items", id), &params)
+    }
 }
 
 impl Object for PaymentLink {
@@ -534,6 +545,45 @@ impl<'a> CreatePaymentLink<'a> {
     }
 }
 
+/// The parameters for `PaymentLink::list_line_items`.
+#[derive(Clone, Debug, Serialize, Default)]
+pub struct ListPaymentLinkLineItems<'a> {
+    /// A cursor for use in pagination.
+    ///
+    /// `ending_before` is an object ID that defines your place in the list.
+    /// For instance, if you make a list request and receive 100 objects, starting with `obj_bar`, your subsequent call can include `ending_before=obj_bar` in order to fetch the previous page of the list.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ending_before: Option<&'a str>,
+
+    /// Specifies which fields in the response should be expanded.
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+
+    /// A limit on the number of objects to be returned.
+    ///
+    /// Limit can range between 1 and 100, and the default is 10.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u64>,
+
+    /// A cursor for use in pagination.
+    ///
+    /// `starting_after` is an object ID that defines your place in the list.
+    /// For instance, if you make a list request and receive 100 objects, ending with `obj_foo`, your subsequent call can include `starting_after=obj_foo` in order to fetch the next page of the list.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub starting_after: Option<&'a str>,
+}
+
+impl<'a> ListPaymentLinkLineItems<'a> {
+    pub fn new() -> Self {
+        ListPaymentLinkLineItems {
+            ending_before: Default::default(),
+            expand: Default::default(),
+            limit: Default::default(),
+            starting_after: Default::default(),
+        }
+    }
+}
+
 /// The parameters for `PaymentLink::list`.
 #[derive(Clone, Debug, Serialize, Default)]
 pub struct ListPaymentLinks<'a> {
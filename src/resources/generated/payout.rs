@@ -133,6 +133,15 @@ impl Payout {
     pub fn update(client: &Client, id: &PayoutId, params: UpdatePayout<'_>) -> Response<Payout> {
         client.post_form(&format!("/payouts/{}", id), &params)
     }
+
+    /// Reverses a payout by debiting the destination bank account.
+    ///
+    /// Only payouts for connected accounts to US bank accounts may be reversed at this time.
+    /// If the payout is in the `pending` status, `/v1/payouts/:id/cancel` should be used instead.
+    /// By requesting a reversal via `/v1/payouts/:id/reverse`, you confirm that the authorized signatory of the selected bank account has authorized the debit on the bank account and that no other authorization is required.
+    pub fn reverse(client: &Client, id: &PayoutId, params: ReversePayout<'_>) -> Response<Payout> {
+        client.post_form(&format!("/payouts/{}/reverse", id), &params)
+    }
 }
 
 impl Object for Payout {
@@ -283,6 +292,28 @@ impl Paginable for ListPayouts<'_> {
         self.starting_after = Some(item.id());
     }
 }
+/// The parameters for `Payout::reverse`.
+#[derive(Clone, Debug, Serialize, Default)]
+pub struct ReversePayout<'a> {
+    /// Specifies which fields in the response should be expanded.
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+
+    /// Set of [key-value pairs](https://stripe.com/docs/api/metadata) that you can attach to an object.
+    ///
+    /// This can be useful for storing additional information about the object in a structured format.
+    /// Individual keys can be unset by posting an empty value to them.
+    /// All keys can be unset by posting an empty value to `metadata`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+}
+
+impl<'a> ReversePayout<'a> {
+    pub fn new() -> Self {
+        ReversePayout { expand: Default::default(), metadata: Default::default() }
+    }
+}
+
 /// The parameters for `Payout::update`.
 #[derive(Clone, Debug, Serialize, Default)]
 pub struct UpdatePayout<'a> {
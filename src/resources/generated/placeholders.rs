@@ -139,6 +139,40 @@ impl Object for ApplicationFeeRefund {
     }
 }
 
+#[cfg(not(feature = "financial-connections"))]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct FinancialConnectionsAccount {
+    pub id: FinancialConnectionsAccountId,
+}
+
+#[cfg(not(feature = "financial-connections"))]
+impl Object for FinancialConnectionsAccount {
+    type Id = FinancialConnectionsAccountId;
+    fn id(&self) -> Self::Id {
+        self.id.clone()
+    }
+    fn object(&self) -> &'static str {
+        "financial_connections.account"
+    }
+}
+
+#[cfg(not(feature = "identity"))]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct IdentityVerificationSession {
+    pub id: IdentityVerificationSessionId,
+}
+
+#[cfg(not(feature = "identity"))]
+impl Object for IdentityVerificationSession {
+    type Id = IdentityVerificationSessionId;
+    fn id(&self) -> Self::Id {
+        self.id.clone()
+    }
+    fn object(&self) -> &'static str {
+        "identity.verification_session"
+    }
+}
+
 #[cfg(not(feature = "billing"))]
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct Invoice {
@@ -360,6 +394,23 @@ impl Object for Plan {
     }
 }
 
+#[cfg(not(feature = "fraud"))]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct RadarEarlyFraudWarning {
+    pub id: RadarEarlyFraudWarningId,
+}
+
+#[cfg(not(feature = "fraud"))]
+impl Object for RadarEarlyFraudWarning {
+    type Id = RadarEarlyFraudWarningId;
+    fn id(&self) -> Self::Id {
+        self.id.clone()
+    }
+    fn object(&self) -> &'static str {
+        "radar.early_fraud_warning"
+    }
+}
+
 #[cfg(not(feature = "fraud"))]
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct Review {
@@ -513,6 +564,23 @@ impl Object for TaxRate {
     }
 }
 
+#[cfg(not(feature = "terminal"))]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct TerminalReader {
+    pub id: TerminalReaderId,
+}
+
+#[cfg(not(feature = "terminal"))]
+impl Object for TerminalReader {
+    type Id = TerminalReaderId;
+    fn id(&self) -> Self::Id {
+        self.id.clone()
+    }
+    fn object(&self) -> &'static str {
+        "terminal.reader"
+    }
+}
+
 #[cfg(not(feature = "connect"))]
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct Topup {
@@ -564,6 +632,142 @@ impl Object for TransferReversal {
     }
 }
 
+#[cfg(not(feature = "treasury"))]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct TreasuryCreditReversal {
+    pub id: TreasuryCreditReversalId,
+}
+
+#[cfg(not(feature = "treasury"))]
+impl Object for TreasuryCreditReversal {
+    type Id = TreasuryCreditReversalId;
+    fn id(&self) -> Self::Id {
+        self.id.clone()
+    }
+    fn object(&self) -> &'static str {
+        "treasury.credit_reversal"
+    }
+}
+
+#[cfg(not(feature = "treasury"))]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct TreasuryDebitReversal {
+    pub id: TreasuryDebitReversalId,
+}
+
+#[cfg(not(feature = "treasury"))]
+impl Object for TreasuryDebitReversal {
+    type Id = TreasuryDebitReversalId;
+    fn id(&self) -> Self::Id {
+        self.id.clone()
+    }
+    fn object(&self) -> &'static str {
+        "treasury.debit_reversal"
+    }
+}
+
+#[cfg(not(feature = "treasury"))]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct TreasuryFinancialAccount {
+    pub id: TreasuryFinancialAccountId,
+}
+
+#[cfg(not(feature = "treasury"))]
+impl Object for TreasuryFinancialAccount {
+    type Id = TreasuryFinancialAccountId;
+    fn id(&self) -> Self::Id {
+        self.id.clone()
+    }
+    fn object(&self) -> &'static str {
+        "treasury.financial_account"
+    }
+}
+
+#[cfg(not(feature = "treasury"))]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct TreasuryInboundTransfer {
+    pub id: TreasuryInboundTransferId,
+}
+
+#[cfg(not(feature = "treasury"))]
+impl Object for TreasuryInboundTransfer {
+    type Id = TreasuryInboundTransferId;
+    fn id(&self) -> Self::Id {
+        self.id.clone()
+    }
+    fn object(&self) -> &'static str {
+        "treasury.inbound_transfer"
+    }
+}
+
+#[cfg(not(feature = "treasury"))]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct TreasuryOutboundPayment {
+    pub id: TreasuryOutboundPaymentId,
+}
+
+#[cfg(not(feature = "treasury"))]
+impl Object for TreasuryOutboundPayment {
+    type Id = TreasuryOutboundPaymentId;
+    fn id(&self) -> Self::Id {
+        self.id.clone()
+    }
+    fn object(&self) -> &'static str {
+        "treasury.outbound_payment"
+    }
+}
+
+#[cfg(not(feature = "treasury"))]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct TreasuryOutboundTransfer {
+    pub id: TreasuryOutboundTransferId,
+}
+
+#[cfg(not(feature = "treasury"))]
+impl Object for TreasuryOutboundTransfer {
+    type Id = TreasuryOutboundTransferId;
+    fn id(&self) -> Self::Id {
+        self.id.clone()
+    }
+    fn object(&self) -> &'static str {
+        "treasury.outbound_transfer"
+    }
+}
+
+#[cfg(not(feature = "treasury"))]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct TreasuryReceivedCredit {
+    pub id: TreasuryReceivedCreditId,
+}
+
+#[cfg(not(feature = "treasury"))]
+impl Object for TreasuryReceivedCredit {
+    type Id = TreasuryReceivedCreditId;
+    fn id(&self) -> Self::Id {
+        self.id.clone()
+    }
+    fn object(&self) -> &'static str {
+        "treasury.received_credit"
+    }
+}
+
+#[cfg(not(feature = "treasury"))]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct TreasuryReceivedDebit {
+    pub id: TreasuryReceivedDebitId,
+}
+
+#[cfg(not(feature = "treasury"))]
+impl Object for TreasuryReceivedDebit {
+    type Id = TreasuryReceivedDebitId;
+    fn id(&self) -> Self::Id {
+        self.id.clone()
+    }
+    fn object(&self) -> &'static str {
+        "treasury.received_debit"
+    }
+}
+
 #[cfg(not(feature = "webhook-endpoints"))]
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct WebhookEndpoint {
@@ -5,7 +5,7 @@
 use serde::{Deserialize, Serialize};
 
 use crate::client::{Client, Response};
-use crate::ids::{CustomerId, QuoteId};
+use crate::ids::{CheckoutSessionItemId, CustomerId, QuoteId, TestHelpersTestClockId};
 use crate::params::{Expand, Expandable, List, Metadata, Object, Paginable, Timestamp};
 use crate::resources::{
     Account, Application, CheckoutSessionItem, Currency, Customer, Discount, Invoice,
@@ -153,6 +153,57 @@ impl Quote {
     pub fn retrieve(client: &Client, id: &QuoteId, expand: &[&str]) -> Response<Quote> {
         client.get_query(&format!("/quotes/{}", id), &Expand { expand })
     }
+
+    /// A quote models prices and services for a customer.
+    pub fn create(client: &Client, params: CreateQuote<'_>) -> Response<Quote> {
+        client.post_form("/quotes", &params)
+    }
+
+    /// A quote models prices and services for a customer.
+    pub fn update(client: &Client, id: &QuoteId, params: UpdateQuote<'_>) -> Response<Quote> {
+        client.post_form(&format!("/quotes/{}", id), &params)
+    }
+
+    /// Finalizes the quote.
+    pub fn finalize_quote(
+        client: &Client,
+        id: &QuoteId,
+        params: FinalizeQuote<'_>,
+    ) -> Response<Quote> {
+        client.post_form(&format!("/quotes/{}/finalize", id), &params)
+    }
+
+    /// Accepts the specified quote.
+    pub fn accept(client: &Client, id: &QuoteId, params: AcceptQuote<'_>) -> Response<Quote> {
+        client.post_form(&format!("/quotes/{}/accept", id), &params)
+    }
+
+    /// Cancels the quote.
+    pub fn cancel(client: &Client, id: &QuoteId, params: CancelQuote<'_>) -> Response<Quote> {
+        client.post_form(&format!("/quotes/{}/cancel", id), &params)
+    }
+
+    /// When retrieving a quote, there is an includable **line_items** property containing the first handful of those items.
+    ///
+    /// There is also a URL where you can retrieve the full (paginated) list of line items.
+    pub fn list_line_items(
+        client: &Client,
+        id: &QuoteId,
+        params: &ListQuoteLineItems<'_>,
+    ) -> Response<List<CheckoutSessionItem>> {
+        client.get_query(&format!("/quotes/{}/line_items", id), &params)
+    }
+
+    /// When retrieving a quote, there is an includable [**computed.upfront.line_items**](https://stripe.com/docs/api/quotes/object#quote_object-computed-upfront-line_items) property containing the first handful of those items.
+    ///
+    /// There is also a URL where you can retrieve the full (paginated) list of upfront line items.
+    pub fn list_computed_upfront_line_items(
+        client: &Client,
+        id: &QuoteId,
+        params: &ListQuoteComputedUpfrontLineItems<'_>,
+    ) -> Response<List<CheckoutSessionItem>> {
+        client.get_query(&format!("/quotes/{}/computed_upfront_line_items", id), &params)
+    }
 }
 
 impl Object for Quote {
@@ -292,6 +343,143 @@ pub struct QuotesResourceUpfront {
     pub total_details: QuotesResourceTotalDetails,
 }
 
+/// The parameters for `Quote::create`.
+#[derive(Clone, Debug, Serialize, Default)]
+pub struct CreateQuote<'a> {
+    /// The amount of the application fee (if any) that will be requested to be applied to the payment and transferred to the application owner's Stripe account.
+    ///
+    /// There cannot be any line items with recurring prices when using this field.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub application_fee_amount: Option<i64>,
+
+    /// A non-negative decimal between 0 and 100, with at most two decimal places.
+    ///
+    /// This represents the percentage of the subscription invoice subtotal that will be transferred to the application owner's Stripe account.
+    /// There must be at least 1 line item with a recurring price to use this field.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub application_fee_percent: Option<f64>,
+
+    /// Settings for automatic tax lookup for this quote and resulting invoices and subscriptions.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub automatic_tax: Option<CreateQuoteAutomaticTax>,
+
+    /// Either `charge_automatically`, or `send_invoice`.
+    ///
+    /// When charging automatically, Stripe will attempt to pay invoices at the end of the subscription cycle or at invoice finalization using the default payment method attached to the subscription or customer.
+    /// When sending an invoice, Stripe will email your customer an invoice with payment instructions and mark the subscription as `active`.
+    /// Defaults to `charge_automatically`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub collection_method: Option<QuoteCollectionMethod>,
+
+    /// The customer for which this quote belongs to.
+    ///
+    /// A customer is required before finalizing the quote.
+    /// Once specified, it cannot be changed.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub customer: Option<CustomerId>,
+
+    /// The tax rates that will apply to any line item that does not have `tax_rates` set.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default_tax_rates: Option<Vec<String>>,
+
+    /// A description that will be displayed on the quote PDF.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<&'a str>,
+
+    /// The discounts applied to the quote.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub discounts: Option<Vec<CreateQuoteDiscounts>>,
+
+    /// Specifies which fields in the response should be expanded.
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+
+    /// A future timestamp on which the quote will be canceled if in `open` or `draft` status.
+    ///
+    /// Measured in seconds since the Unix epoch.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expires_at: Option<Timestamp>,
+
+    /// A footer that will be displayed on the quote PDF.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub footer: Option<&'a str>,
+
+    /// Clone an existing quote.
+    ///
+    /// The new quote will be created in `status=draft`.
+    /// When using this parameter, you cannot specify any other parameters except for `expires_at`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub from_quote: Option<CreateQuoteFromQuote>,
+
+    /// A header that will be displayed on the quote PDF.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub header: Option<&'a str>,
+
+    /// All invoices will be billed using the specified settings.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub invoice_settings: Option<CreateQuoteInvoiceSettings>,
+
+    /// A list of line items the customer is being quoted for.
+    ///
+    /// Each line item includes information about the product, the quantity, and the resulting cost.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub line_items: Option<Vec<CreateQuoteLineItems>>,
+
+    /// Set of [key-value pairs](https://stripe.com/docs/api/metadata) that you can attach to an object.
+    ///
+    /// This can be useful for storing additional information about the object in a structured format.
+    /// Individual keys can be unset by posting an empty value to them.
+    /// All keys can be unset by posting an empty value to `metadata`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+
+    /// The account on behalf of which to charge.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub on_behalf_of: Option<&'a str>,
+
+    /// When creating a subscription or subscription schedule, the specified configuration data will be used.
+    ///
+    /// There must be at least one line item with a recurring price for a subscription or subscription schedule to be created.
+    /// A subscription schedule is created if `subscription_data[effective_date]` is present and in the future, otherwise a subscription is created.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub subscription_data: Option<CreateQuoteSubscriptionData>,
+
+    /// ID of the test clock to attach to the quote.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub test_clock: Option<TestHelpersTestClockId>,
+
+    /// The data with which to automatically create a Transfer for each of the invoices.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub transfer_data: Option<CreateQuoteTransferData>,
+}
+
+impl<'a> CreateQuote<'a> {
+    pub fn new() -> Self {
+        CreateQuote {
+            application_fee_amount: Default::default(),
+            application_fee_percent: Default::default(),
+            automatic_tax: Default::default(),
+            collection_method: Default::default(),
+            customer: Default::default(),
+            default_tax_rates: Default::default(),
+            description: Default::default(),
+            discounts: Default::default(),
+            expand: Default::default(),
+            expires_at: Default::default(),
+            footer: Default::default(),
+            from_quote: Default::default(),
+            header: Default::default(),
+            invoice_settings: Default::default(),
+            line_items: Default::default(),
+            metadata: Default::default(),
+            on_behalf_of: Default::default(),
+            subscription_data: Default::default(),
+            test_clock: Default::default(),
+            transfer_data: Default::default(),
+        }
+    }
+}
+
 /// The parameters for `Quote::list`.
 #[derive(Clone, Debug, Serialize, Default)]
 pub struct ListQuotes<'a> {
@@ -353,104 +541,660 @@ impl Paginable for ListQuotes<'_> {
         self.starting_after = Some(item.id());
     }
 }
-/// An enum representing the possible values of an `Quote`'s `collection_method` field.
-#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
-#[serde(rename_all = "snake_case")]
-pub enum QuoteCollectionMethod {
-    ChargeAutomatically,
-    SendInvoice,
-}
+/// The parameters for `Quote::update`.
+#[derive(Clone, Debug, Serialize, Default)]
+pub struct UpdateQuote<'a> {
+    /// The amount of the application fee (if any) that will be requested to be applied to the payment and transferred to the application owner's Stripe account.
+    ///
+    /// There cannot be any line items with recurring prices when using this field.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub application_fee_amount: Option<i64>,
 
-impl QuoteCollectionMethod {
-    pub fn as_str(self) -> &'static str {
-        match self {
-            QuoteCollectionMethod::ChargeAutomatically => "charge_automatically",
-            QuoteCollectionMethod::SendInvoice => "send_invoice",
-        }
-    }
-}
+    /// A non-negative decimal between 0 and 100, with at most two decimal places.
+    ///
+    /// This represents the percentage of the subscription invoice subtotal that will be transferred to the application owner's Stripe account.
+    /// There must be at least 1 line item with a recurring price to use this field.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub application_fee_percent: Option<f64>,
 
-impl AsRef<str> for QuoteCollectionMethod {
-    fn as_ref(&self) -> &str {
-        self.as_str()
-    }
-}
+    /// Settings for automatic tax lookup for this quote and resulting invoices and subscriptions.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub automatic_tax: Option<UpdateQuoteAutomaticTax>,
 
-impl std::fmt::Display for QuoteCollectionMethod {
-    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
-        self.as_str().fmt(f)
-    }
-}
-impl std::default::Default for QuoteCollectionMethod {
-    fn default() -> Self {
-        Self::ChargeAutomatically
-    }
-}
+    /// Either `charge_automatically`, or `send_invoice`.
+    ///
+    /// When charging automatically, Stripe will attempt to pay invoices at the end of the subscription cycle or at invoice finalization using the default payment method attached to the subscription or customer.
+    /// When sending an invoice, Stripe will email your customer an invoice with payment instructions and mark the subscription as `active`.
+    /// Defaults to `charge_automatically`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub collection_method: Option<QuoteCollectionMethod>,
 
-/// An enum representing the possible values of an `Quote`'s `status` field.
-#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
-#[serde(rename_all = "snake_case")]
-pub enum QuoteStatus {
-    Accepted,
-    Canceled,
-    Draft,
-    Open,
+    /// The customer for which this quote belongs to.
+    ///
+    /// A customer is required before finalizing the quote.
+    /// Once specified, it cannot be changed.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub customer: Option<CustomerId>,
+
+    /// The tax rates that will apply to any line item that does not have `tax_rates` set.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default_tax_rates: Option<Vec<String>>,
+
+    /// A description that will be displayed on the quote PDF.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<&'a str>,
+
+    /// The discounts applied to the quote.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub discounts: Option<Vec<UpdateQuoteDiscounts>>,
+
+    /// Specifies which fields in the response should be expanded.
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+
+    /// A future timestamp on which the quote will be canceled if in `open` or `draft` status.
+    ///
+    /// Measured in seconds since the Unix epoch.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expires_at: Option<Timestamp>,
+
+    /// A footer that will be displayed on the quote PDF.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub footer: Option<&'a str>,
+
+    /// A header that will be displayed on the quote PDF.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub header: Option<&'a str>,
+
+    /// All invoices will be billed using the specified settings.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub invoice_settings: Option<UpdateQuoteInvoiceSettings>,
+
+    /// A list of line items the customer is being quoted for.
+    ///
+    /// Each line item includes information about the product, the quantity, and the resulting cost.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub line_items: Option<Vec<UpdateQuoteLineItems>>,
+
+    /// Set of [key-value pairs](https://stripe.com/docs/api/metadata) that you can attach to an object.
+    ///
+    /// This can be useful for storing additional information about the object in a structured format.
+    /// Individual keys can be unset by posting an empty value to them.
+    /// All keys can be unset by posting an empty value to `metadata`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+
+    /// The account on behalf of which to charge.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub on_behalf_of: Option<&'a str>,
+
+    /// When creating a subscription or subscription schedule, the specified configuration data will be used.
+    ///
+    /// There must be at least one line item with a recurring price for a subscription or subscription schedule to be created.
+    /// A subscription schedule is created if `subscription_data[effective_date]` is present and in the future, otherwise a subscription is created.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub subscription_data: Option<UpdateQuoteSubscriptionData>,
+
+    /// The data with which to automatically create a Transfer for each of the invoices.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub transfer_data: Option<UpdateQuoteTransferData>,
 }
 
-impl QuoteStatus {
-    pub fn as_str(self) -> &'static str {
-        match self {
-            QuoteStatus::Accepted => "accepted",
-            QuoteStatus::Canceled => "canceled",
-            QuoteStatus::Draft => "draft",
-            QuoteStatus::Open => "open",
+impl<'a> UpdateQuote<'a> {
+    pub fn new() -> Self {
+        UpdateQuote {
+            application_fee_amount: Default::default(),
+            application_fee_percent: Default::default(),
+            automatic_tax: Default::default(),
+            collection_method: Default::default(),
+            customer: Default::default(),
+            default_tax_rates: Default::default(),
+            description: Default::default(),
+            discounts: Default::default(),
+            expand: Default::default(),
+            expires_at: Default::default(),
+            footer: Default::default(),
+            header: Default::default(),
+            invoice_settings: Default::default(),
+            line_items: Default::default(),
+            metadata: Default::default(),
+            on_behalf_of: Default::default(),
+            subscription_data: Default::default(),
+            transfer_data: Default::default(),
         }
     }
 }
 
-impl AsRef<str> for QuoteStatus {
-    fn as_ref(&self) -> &str {
-        self.as_str()
-    }
-}
+/// The parameters for `Quote::finalize_quote`.
+#[derive(Clone, Debug, Serialize, Default)]
+pub struct FinalizeQuote<'a> {
+    /// Specifies which fields in the response should be expanded.
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
 
-impl std::fmt::Display for QuoteStatus {
-    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
-        self.as_str().fmt(f)
-    }
+    /// A future timestamp on which the quote will be canceled if in `open` or `draft` status.
+    ///
+    /// Measured in seconds since the Unix epoch.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expires_at: Option<Timestamp>,
 }
-impl std::default::Default for QuoteStatus {
-    fn default() -> Self {
-        Self::Accepted
+
+impl<'a> FinalizeQuote<'a> {
+    pub fn new() -> Self {
+        FinalizeQuote { expand: Default::default(), expires_at: Default::default() }
     }
 }
 
-/// An enum representing the possible values of an `QuotesResourceAutomaticTax`'s `status` field.
-#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
-#[serde(rename_all = "snake_case")]
-pub enum QuotesResourceAutomaticTaxStatus {
-    Complete,
-    Failed,
-    RequiresLocationInputs,
+/// The parameters for `Quote::accept`.
+#[derive(Clone, Debug, Serialize, Default)]
+pub struct AcceptQuote<'a> {
+    /// Specifies which fields in the response should be expanded.
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
 }
 
-impl QuotesResourceAutomaticTaxStatus {
-    pub fn as_str(self) -> &'static str {
-        match self {
-            QuotesResourceAutomaticTaxStatus::Complete => "complete",
-            QuotesResourceAutomaticTaxStatus::Failed => "failed",
-            QuotesResourceAutomaticTaxStatus::RequiresLocationInputs => "requires_location_inputs",
-        }
+impl<'a> AcceptQuote<'a> {
+    pub fn new() -> Self {
+        AcceptQuote { expand: Default::default() }
     }
 }
 
-impl AsRef<str> for QuotesResourceAutomaticTaxStatus {
-    fn as_ref(&self) -> &str {
-        self.as_str()
+/// The parameters for `Quote::cancel`.
+#[derive(Clone, Debug, Serialize, Default)]
+pub struct CancelQuote<'a> {
+    /// Specifies which fields in the response should be expanded.
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+}
+
+impl<'a> CancelQuote<'a> {
+    pub fn new() -> Self {
+        CancelQuote { expand: Default::default() }
     }
 }
 
-impl std::fmt::Display for QuotesResourceAutomaticTaxStatus {
+/// The parameters for `Quote::list_line_items`.
+#[derive(Clone, Debug, Serialize, Default)]
+pub struct ListQuoteLineItems<'a> {
+    /// A cursor for use in pagination.
+    ///
+    /// `ending_before` is an object ID that defines your place in the list.
+    /// For instance, if you make a list request and receive 100 objects, starting with `obj_bar`, your subsequent call can include `ending_before=obj_bar` in order to fetch the previous page of the list.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ending_before: Option<CheckoutSessionItemId>,
+
+    /// Specifies which fields in the response should be expanded.
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+
+    /// A limit on the number of objects to be returned.
+    ///
+    /// Limit can range between 1 and 100, and the default is 10.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u64>,
+
+    /// A cursor for use in pagination.
+    ///
+    /// `starting_after` is an object ID that defines your place in the list.
+    /// For instance, if you make a list request and receive 100 objects, ending with `obj_foo`, your subsequent call can include `starting_after=obj_foo` in order to fetch the next page of the list.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub starting_after: Option<CheckoutSessionItemId>,
+}
+
+impl<'a> ListQuoteLineItems<'a> {
+    pub fn new() -> Self {
+        ListQuoteLineItems {
+            ending_before: Default::default(),
+            expand: Default::default(),
+            limit: Default::default(),
+            starting_after: Default::default(),
+        }
+    }
+}
+impl Paginable for ListQuoteLineItems<'_> {
+    type O = CheckoutSessionItem;
+    fn set_last(&mut self, item: Self::O) {
+        self.starting_after = Some(item.id());
+    }
+}
+/// The parameters for `Quote::list_computed_upfront_line_items`.
+#[derive(Clone, Debug, Serialize, Default)]
+pub struct ListQuoteComputedUpfrontLineItems<'a> {
+    /// A cursor for use in pagination.
+    ///
+    /// `ending_before` is an object ID that defines your place in the list.
+    /// For instance, if you make a list request and receive 100 objects, starting with `obj_bar`, your subsequent call can include `ending_before=obj_bar` in order to fetch the previous page of the list.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ending_before: Option<CheckoutSessionItemId>,
+
+    /// Specifies which fields in the response should be expanded.
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+
+    /// A limit on the number of objects to be returned.
+    ///
+    /// Limit can range between 1 and 100, and the default is 10.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u64>,
+
+    /// A cursor for use in pagination.
+    ///
+    /// `starting_after` is an object ID that defines your place in the list.
+    /// For instance, if you make a list request and receive 100 objects, ending with `obj_foo`, your subsequent call can include `starting_after=obj_foo` in order to fetch the next page of the list.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub starting_after: Option<CheckoutSessionItemId>,
+}
+
+impl<'a> ListQuoteComputedUpfrontLineItems<'a> {
+    pub fn new() -> Self {
+        ListQuoteComputedUpfrontLineItems {
+            ending_before: Default::default(),
+            expand: Default::default(),
+            limit: Default::default(),
+            starting_after: Default::default(),
+        }
+    }
+}
+impl Paginable for ListQuoteComputedUpfrontLineItems<'_> {
+    type O = CheckoutSessionItem;
+    fn set_last(&mut self, item: Self::O) {
+        self.starting_after = Some(item.id());
+    }
+}
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct CreateQuoteAutomaticTax {
+    /// Controls whether Stripe will automatically compute tax on the resulting invoices or subscriptions as well as the quote itself.
+    pub enabled: bool,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct CreateQuoteDiscounts {
+    /// ID of the coupon to create a new discount for.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub coupon: Option<String>,
+
+    /// ID of an existing discount on the object (or one of its ancestors) to reuse.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub discount: Option<String>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct CreateQuoteFromQuote {
+    /// Whether this quote is a revision of the previous quote.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_revision: Option<bool>,
+
+    /// The `id` of the quote that will be cloned.
+    pub quote: String,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct CreateQuoteInvoiceSettings {
+    /// Number of days within which a customer must pay the invoice generated by this quote.
+    ///
+    /// This value will be `null` for quotes where `collection_method=charge_automatically`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub days_until_due: Option<u32>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct CreateQuoteLineItems {
+    /// The ID of the price object.
+    ///
+    /// One of `price` or `price_data` is required.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub price: Option<String>,
+
+    /// Data used to generate a new [Price](https://stripe.com/docs/api/prices) object inline.
+    ///
+    /// One of `price` or `price_data` is required.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub price_data: Option<CreateQuoteLineItemsPriceData>,
+
+    /// The quantity of the line item.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub quantity: Option<u64>,
+
+    /// The tax rates which apply to the line item.
+    ///
+    /// When set, the `default_tax_rates` on the quote do not apply to this line item.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tax_rates: Option<Vec<String>>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct CreateQuoteSubscriptionData {
+    /// The subscription's description, meant to be displayable to the customer.
+    ///
+    /// Use this field to optionally store an explanation of the subscription.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+
+    /// When creating a new subscription, the date of which the subscription schedule will start after the quote is accepted.
+    ///
+    /// When updating a subscription, the date of which the subscription will be updated using a subscription schedule.
+    /// The special value `current_period_end` can be provided to update a subscription at the end of its current period.
+    /// The `effective_date` is ignored if it is in the past when the quote is accepted.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub effective_date: Option<Timestamp>,
+
+    /// Integer representing the number of trial period days before the customer is charged for the first time.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub trial_period_days: Option<u32>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct CreateQuoteTransferData {
+    /// The amount that will be transferred automatically when the invoice is paid.
+    ///
+    /// If no amount is set, the full amount is transferred.
+    /// There cannot be any line items with recurring prices when using this field.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub amount: Option<i64>,
+
+    /// A non-negative decimal between 0 and 100, with at most two decimal places.
+    ///
+    /// This represents the percentage of the subscription invoice subtotal that will be transferred to the destination account.
+    /// By default, the entire amount is transferred to the destination.
+    /// There must be at least 1 line item with a recurring price to use this field.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub amount_percent: Option<f64>,
+
+    /// ID of an existing, connected Stripe account.
+    pub destination: String,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct CreateQuoteLineItemsPriceData {
+    /// Three-letter [ISO currency code](https://www.iso.org/iso-4217-currency-codes.html), in lowercase.
+    ///
+    /// Must be a [supported currency](https://stripe.com/docs/currencies).
+    pub currency: Currency,
+
+    /// The ID of the product that this price will belong to.
+    pub product: String,
+
+    /// The recurring components of a price such as `interval` and `interval_count`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recurring: Option<CreateQuoteLineItemsPriceDataRecurring>,
+
+    /// Only required if a [default tax behavior](https://stripe.com/docs/tax/products-prices-tax-categories-tax-behavior#setting-a-default-tax-behavior-(recommended)) was not provided in the Stripe Tax settings.
+    ///
+    /// Specifies whether the price is considered inclusive of taxes or exclusive of taxes.
+    /// One of `inclusive`, `exclusive`, or `unspecified`.
+    /// Once specified as either `inclusive` or `exclusive`, it cannot be changed.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tax_behavior: Option<CreateQuoteLineItemsPriceDataTaxBehavior>,
+
+    /// A positive integer in cents (or local equivalent) (or 0 for a free price) representing how much to charge.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub unit_amount: Option<i64>,
+
+    /// Same as `unit_amount`, but accepts a decimal value in cents (or local equivalent) with at most 12 decimal places.
+    ///
+    /// Only one of `unit_amount` and `unit_amount_decimal` can be set.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub unit_amount_decimal: Option<String>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct CreateQuoteLineItemsPriceDataRecurring {
+    /// Specifies billing frequency.
+    ///
+    /// Either `day`, `week`, `month` or `year`.
+    pub interval: CreateQuoteLineItemsPriceDataRecurringInterval,
+
+    /// The number of intervals between subscription billings.
+    ///
+    /// For example, `interval=month` and `interval_count=3` bills every 3 months.
+    /// Maximum of one year interval allowed (1 year, 12 months, or 52 weeks).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub interval_count: Option<u64>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct UpdateQuoteAutomaticTax {
+    /// Controls whether Stripe will automatically compute tax on the resulting invoices or subscriptions as well as the quote itself.
+    pub enabled: bool,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct UpdateQuoteDiscounts {
+    /// ID of the coupon to create a new discount for.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub coupon: Option<String>,
+
+    /// ID of an existing discount on the object (or one of its ancestors) to reuse.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub discount: Option<String>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct UpdateQuoteInvoiceSettings {
+    /// Number of days within which a customer must pay the invoice generated by this quote.
+    ///
+    /// This value will be `null` for quotes where `collection_method=charge_automatically`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub days_until_due: Option<u32>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct UpdateQuoteLineItems {
+    /// The ID of an existing line item on the quote.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+
+    /// The ID of the price object.
+    ///
+    /// One of `price` or `price_data` is required.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub price: Option<String>,
+
+    /// Data used to generate a new [Price](https://stripe.com/docs/api/prices) object inline.
+    ///
+    /// One of `price` or `price_data` is required.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub price_data: Option<UpdateQuoteLineItemsPriceData>,
+
+    /// The quantity of the line item.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub quantity: Option<u64>,
+
+    /// The tax rates which apply to the line item.
+    ///
+    /// When set, the `default_tax_rates` on the quote do not apply to this line item.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tax_rates: Option<Vec<String>>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct UpdateQuoteSubscriptionData {
+    /// The subscription's description, meant to be displayable to the customer.
+    ///
+    /// Use this field to optionally store an explanation of the subscription.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+
+    /// When creating a new subscription, the date of which the subscription schedule will start after the quote is accepted.
+    ///
+    /// When updating a subscription, the date of which the subscription will be updated using a subscription schedule.
+    /// The special value `current_period_end` can be provided to update a subscription at the end of its current period.
+    /// The `effective_date` is ignored if it is in the past when the quote is accepted.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub effective_date: Option<Timestamp>,
+
+    /// Integer representing the number of trial period days before the customer is charged for the first time.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub trial_period_days: Option<u32>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct UpdateQuoteTransferData {
+    /// The amount that will be transferred automatically when the invoice is paid.
+    ///
+    /// If no amount is set, the full amount is transferred.
+    /// There cannot be any line items with recurring prices when using this field.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub amount: Option<i64>,
+
+    /// A non-negative decimal between 0 and 100, with at most two decimal places.
+    ///
+    /// This represents the percentage of the subscription invoice subtotal that will be transferred to the destination account.
+    /// By default, the entire amount is transferred to the destination.
+    /// There must be at least 1 line item with a recurring price to use this field.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub amount_percent: Option<f64>,
+
+    /// ID of an existing, connected Stripe account.
+    pub destination: String,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct UpdateQuoteLineItemsPriceData {
+    /// Three-letter [ISO currency code](https://www.iso.org/iso-4217-currency-codes.html), in lowercase.
+    ///
+    /// Must be a [supported currency](https://stripe.com/docs/currencies).
+    pub currency: Currency,
+
+    /// The ID of the product that this price will belong to.
+    pub product: String,
+
+    /// The recurring components of a price such as `interval` and `interval_count`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recurring: Option<UpdateQuoteLineItemsPriceDataRecurring>,
+
+    /// Only required if a [default tax behavior](https://stripe.com/docs/tax/products-prices-tax-categories-tax-behavior#setting-a-default-tax-behavior-(recommended)) was not provided in the Stripe Tax settings.
+    ///
+    /// Specifies whether the price is considered inclusive of taxes or exclusive of taxes.
+    /// One of `inclusive`, `exclusive`, or `unspecified`.
+    /// Once specified as either `inclusive` or `exclusive`, it cannot be changed.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tax_behavior: Option<UpdateQuoteLineItemsPriceDataTaxBehavior>,
+
+    /// A positive integer in cents (or local equivalent) (or 0 for a free price) representing how much to charge.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub unit_amount: Option<i64>,
+
+    /// Same as `unit_amount`, but accepts a decimal value in cents (or local equivalent) with at most 12 decimal places.
+    ///
+    /// Only one of `unit_amount` and `unit_amount_decimal` can be set.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub unit_amount_decimal: Option<String>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct UpdateQuoteLineItemsPriceDataRecurring {
+    /// Specifies billing frequency.
+    ///
+    /// Either `day`, `week`, `month` or `year`.
+    pub interval: UpdateQuoteLineItemsPriceDataRecurringInterval,
+
+    /// The number of intervals between subscription billings.
+    ///
+    /// For example, `interval=month` and `interval_count=3` bills every 3 months.
+    /// Maximum of one year interval allowed (1 year, 12 months, or 52 weeks).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub interval_count: Option<u64>,
+}
+
+/// An enum representing the possible values of an `Quote`'s `collection_method` field.
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum QuoteCollectionMethod {
+    ChargeAutomatically,
+    SendInvoice,
+}
+
+impl QuoteCollectionMethod {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            QuoteCollectionMethod::ChargeAutomatically => "charge_automatically",
+            QuoteCollectionMethod::SendInvoice => "send_invoice",
+        }
+    }
+}
+
+impl AsRef<str> for QuoteCollectionMethod {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::fmt::Display for QuoteCollectionMethod {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
+impl std::default::Default for QuoteCollectionMethod {
+    fn default() -> Self {
+        Self::ChargeAutomatically
+    }
+}
+
+/// An enum representing the possible values of an `Quote`'s `status` field.
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum QuoteStatus {
+    Accepted,
+    Canceled,
+    Draft,
+    Open,
+}
+
+impl QuoteStatus {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            QuoteStatus::Accepted => "accepted",
+            QuoteStatus::Canceled => "canceled",
+            QuoteStatus::Draft => "draft",
+            QuoteStatus::Open => "open",
+        }
+    }
+}
+
+impl AsRef<str> for QuoteStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::fmt::Display for QuoteStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
+impl std::default::Default for QuoteStatus {
+    fn default() -> Self {
+        Self::Accepted
+    }
+}
+
+/// An enum representing the possible values of an `QuotesResourceAutomaticTax`'s `status` field.
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum QuotesResourceAutomaticTaxStatus {
+    Complete,
+    Failed,
+    RequiresLocationInputs,
+}
+
+impl QuotesResourceAutomaticTaxStatus {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            QuotesResourceAutomaticTaxStatus::Complete => "complete",
+            QuotesResourceAutomaticTaxStatus::Failed => "failed",
+            QuotesResourceAutomaticTaxStatus::RequiresLocationInputs => "requires_location_inputs",
+        }
+    }
+}
+
+impl AsRef<str> for QuotesResourceAutomaticTaxStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::fmt::Display for QuotesResourceAutomaticTaxStatus {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
         self.as_str().fmt(f)
     }
@@ -498,3 +1242,151 @@ impl std::default::Default for QuotesResourceRecurringInterval {
         Self::Day
     }
 }
+
+/// An enum representing the possible values of an `CreateQuoteLineItemsPriceDataRecurring`'s `interval` field.
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum CreateQuoteLineItemsPriceDataRecurringInterval {
+    Day,
+    Month,
+    Week,
+    Year,
+}
+
+impl CreateQuoteLineItemsPriceDataRecurringInterval {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            CreateQuoteLineItemsPriceDataRecurringInterval::Day => "day",
+            CreateQuoteLineItemsPriceDataRecurringInterval::Month => "month",
+            CreateQuoteLineItemsPriceDataRecurringInterval::Week => "week",
+            CreateQuoteLineItemsPriceDataRecurringInterval::Year => "year",
+        }
+    }
+}
+
+impl AsRef<str> for CreateQuoteLineItemsPriceDataRecurringInterval {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::fmt::Display for CreateQuoteLineItemsPriceDataRecurringInterval {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
+impl std::default::Default for CreateQuoteLineItemsPriceDataRecurringInterval {
+    fn default() -> Self {
+        Self::Day
+    }
+}
+
+/// An enum representing the possible values of an `CreateQuoteLineItemsPriceData`'s `tax_behavior` field.
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum CreateQuoteLineItemsPriceDataTaxBehavior {
+    Exclusive,
+    Inclusive,
+    Unspecified,
+}
+
+impl CreateQuoteLineItemsPriceDataTaxBehavior {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            CreateQuoteLineItemsPriceDataTaxBehavior::Exclusive => "exclusive",
+            CreateQuoteLineItemsPriceDataTaxBehavior::Inclusive => "inclusive",
+            CreateQuoteLineItemsPriceDataTaxBehavior::Unspecified => "unspecified",
+        }
+    }
+}
+
+impl AsRef<str> for CreateQuoteLineItemsPriceDataTaxBehavior {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::fmt::Display for CreateQuoteLineItemsPriceDataTaxBehavior {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
+impl std::default::Default for CreateQuoteLineItemsPriceDataTaxBehavior {
+    fn default() -> Self {
+        Self::Exclusive
+    }
+}
+
+/// An enum representing the possible values of an `UpdateQuoteLineItemsPriceDataRecurring`'s `interval` field.
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum UpdateQuoteLineItemsPriceDataRecurringInterval {
+    Day,
+    Month,
+    Week,
+    Year,
+}
+
+impl UpdateQuoteLineItemsPriceDataRecurringInterval {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            UpdateQuoteLineItemsPriceDataRecurringInterval::Day => "day",
+            UpdateQuoteLineItemsPriceDataRecurringInterval::Month => "month",
+            UpdateQuoteLineItemsPriceDataRecurringInterval::Week => "week",
+            UpdateQuoteLineItemsPriceDataRecurringInterval::Year => "year",
+        }
+    }
+}
+
+impl AsRef<str> for UpdateQuoteLineItemsPriceDataRecurringInterval {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::fmt::Display for UpdateQuoteLineItemsPriceDataRecurringInterval {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
+impl std::default::Default for UpdateQuoteLineItemsPriceDataRecurringInterval {
+    fn default() -> Self {
+        Self::Day
+    }
+}
+
+/// An enum representing the possible values of an `UpdateQuoteLineItemsPriceData`'s `tax_behavior` field.
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum UpdateQuoteLineItemsPriceDataTaxBehavior {
+    Exclusive,
+    Inclusive,
+    Unspecified,
+}
+
+impl UpdateQuoteLineItemsPriceDataTaxBehavior {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            UpdateQuoteLineItemsPriceDataTaxBehavior::Exclusive => "exclusive",
+            UpdateQuoteLineItemsPriceDataTaxBehavior::Inclusive => "inclusive",
+            UpdateQuoteLineItemsPriceDataTaxBehavior::Unspecified => "unspecified",
+        }
+    }
+}
+
+impl AsRef<str> for UpdateQuoteLineItemsPriceDataTaxBehavior {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::fmt::Display for UpdateQuoteLineItemsPriceDataTaxBehavior {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
+impl std::default::Default for UpdateQuoteLineItemsPriceDataTaxBehavior {
+    fn default() -> Self {
+        Self::Exclusive
+    }
+}
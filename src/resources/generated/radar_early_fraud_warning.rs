@@ -2,11 +2,13 @@
 // This file was automatically generated.
 // ======================================
 
-use crate::ids::{RadarEarlyFraudWarningId};
-use crate::params::{Expandable, Object, Timestamp};
-use crate::resources::{Charge, PaymentIntent};
 use serde::{Deserialize, Serialize};
 
+use crate::client::{Client, Response};
+use crate::ids::RadarEarlyFraudWarningId;
+use crate::params::{Expand, Expandable, List, Object, Paginable, Timestamp};
+use crate::resources::{Charge, PaymentIntent};
+
 /// The resource representing a Stripe "RadarEarlyFraudWarning".
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct RadarEarlyFraudWarning {
@@ -39,6 +41,27 @@ pub struct RadarEarlyFraudWarning {
     pub payment_intent: Option<Expandable<PaymentIntent>>,
 }
 
+impl RadarEarlyFraudWarning {
+    /// Returns a list of early fraud warnings.
+    pub fn list(
+        client: &Client,
+        params: &ListRadarEarlyFraudWarnings<'_>,
+    ) -> Response<List<RadarEarlyFraudWarning>> {
+        client.get_query("/radar/early_fraud_warnings", &params)
+    }
+
+    /// Retrieves the details of an early fraud warning that has previously been created.
+    ///
+    /// Please refer to the [early fraud warning](https://stripe.com/docs/api#early_fraud_warning_object) object reference for more details.
+    pub fn retrieve(
+        client: &Client,
+        id: &RadarEarlyFraudWarningId,
+        expand: &[&str],
+    ) -> Response<RadarEarlyFraudWarning> {
+        client.get_query(&format!("/radar/early_fraud_warnings/{}", id), &Expand { expand })
+    }
+}
+
 impl Object for RadarEarlyFraudWarning {
     type Id = RadarEarlyFraudWarningId;
     fn id(&self) -> Self::Id {
@@ -48,3 +71,58 @@ impl Object for RadarEarlyFraudWarning {
         "radar.early_fraud_warning"
     }
 }
+
+/// The parameters for `RadarEarlyFraudWarning::list`.
+#[derive(Clone, Debug, Serialize, Default)]
+pub struct ListRadarEarlyFraudWarnings<'a> {
+    /// Only return early fraud warnings for the charge specified by this charge ID.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub charge: Option<&'a str>,
+
+    /// A cursor for use in pagination.
+    ///
+    /// `ending_before` is an object ID that defines your place in the list.
+    /// For instance, if you make a list request and receive 100 objects, starting with `obj_bar`, your subsequent call can include `ending_before=obj_bar` in order to fetch the previous page of the list.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ending_before: Option<RadarEarlyFraudWarningId>,
+
+    /// Specifies which fields in the response should be expanded.
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+
+    /// A limit on the number of objects to be returned.
+    ///
+    /// Limit can range between 1 and 100, and the default is 10.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u64>,
+
+    /// Only return early fraud warnings for charges that were created by the PaymentIntent specified by this PaymentIntent ID.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub payment_intent: Option<&'a str>,
+
+    /// A cursor for use in pagination.
+    ///
+    /// `starting_after` is an object ID that defines your place in the list.
+    /// For instance, if you make a list request and receive 100 objects, ending with `obj_foo`, your subsequent call can include `starting_after=obj_foo` in order to fetch the next page of the list.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub starting_after: Option<RadarEarlyFraudWarningId>,
+}
+
+impl<'a> ListRadarEarlyFraudWarnings<'a> {
+    pub fn new() -> Self {
+        ListRadarEarlyFraudWarnings {
+            charge: Default::default(),
+            ending_before: Default::default(),
+            expand: Default::default(),
+            limit: Default::default(),
+            payment_intent: Default::default(),
+            starting_after: Default::default(),
+        }
+    }
+}
+impl Paginable for ListRadarEarlyFraudWarnings<'_> {
+    type O = RadarEarlyFraudWarning;
+    fn set_last(&mut self, item: Self::O) {
+        self.starting_after = Some(item.id());
+    }
+}
@@ -2,11 +2,13 @@
 // This file was automatically generated.
 // ======================================
 
-use crate::ids::{RadarValueListId};
-use crate::params::{List, Metadata, Object, Timestamp};
-use crate::resources::{RadarValueListItem};
 use serde::{Deserialize, Serialize};
 
+use crate::client::{Client, Response};
+use crate::ids::RadarValueListId;
+use crate::params::{Deleted, Expand, List, Metadata, Object, Paginable, RangeQuery, Timestamp};
+use crate::resources::RadarValueListItem;
+
 /// The resource representing a Stripe "RadarListList".
 ///
 /// For more details see <https://stripe.com/docs/api/radar/value_lists/object>
@@ -58,6 +60,49 @@ pub struct RadarValueList {
     pub name: Option<String>,
 }
 
+impl RadarValueList {
+    /// Returns a list of `ValueList` objects.
+    pub fn list(
+        client: &Client,
+        params: &ListRadarValueLists<'_>,
+    ) -> Response<List<RadarValueList>> {
+        client.get_query("/radar/value_lists", &params)
+    }
+
+    /// Creates a new `ValueList` object, which can then be referenced in rules.
+    pub fn create(client: &Client, params: CreateRadarValueList<'_>) -> Response<RadarValueList> {
+        client.post_form("/radar/value_lists", &params)
+    }
+
+    /// Retrieves a `ValueList` object.
+    pub fn retrieve(
+        client: &Client,
+        id: &RadarValueListId,
+        expand: &[&str],
+    ) -> Response<RadarValueList> {
+        client.get_query(&format!("/radar/value_lists/{}", id), &Expand { expand })
+    }
+
+    /// Updates a `ValueList` object by setting the values of the parameters passed.
+    ///
+    /// Any parameters not provided will be left unchanged.
+    /// Note that `item_type` is immutable.
+    pub fn update(
+        client: &Client,
+        id: &RadarValueListId,
+        params: UpdateRadarValueList<'_>,
+    ) -> Response<RadarValueList> {
+        client.post_form(&format!("/radar/value_lists/{}", id), &params)
+    }
+
+    /// Deletes a `ValueList` object, also deleting any items contained within the value list.
+    ///
+    /// To be deleted, a value list must not be referenced in any rules.
+    pub fn delete(client: &Client, id: &RadarValueListId) -> Response<Deleted<RadarValueListId>> {
+        client.delete(&format!("/radar/value_lists/{}", id))
+    }
+}
+
 impl Object for RadarValueList {
     type Id = RadarValueListId;
     fn id(&self) -> Self::Id {
@@ -68,6 +113,140 @@ impl Object for RadarValueList {
     }
 }
 
+/// The parameters for `RadarValueList::create`.
+#[derive(Clone, Debug, Serialize)]
+pub struct CreateRadarValueList<'a> {
+    /// The name of the value list for use in rules.
+    pub alias: &'a str,
+
+    /// Specifies which fields in the response should be expanded.
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+
+    /// Type of the items in the value list.
+    ///
+    /// One of `card_fingerprint`, `card_bin`, `email`, `ip_address`, `country`, `string`, `case_sensitive_string`, or `customer_id`.
+    /// Use `string` if the item type is unknown or mixed.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub item_type: Option<RadarValueListItemType>,
+
+    /// Set of [key-value pairs](https://stripe.com/docs/api/metadata) that you can attach to an object.
+    ///
+    /// This can be useful for storing additional information about the object in a structured format.
+    /// Individual keys can be unset by posting an empty value to them.
+    /// All keys can be unset by posting an empty value to `metadata`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+
+    /// The human-readable name of the value list.
+    pub name: &'a str,
+}
+
+impl<'a> CreateRadarValueList<'a> {
+    pub fn new(alias: &'a str, name: &'a str) -> Self {
+        CreateRadarValueList {
+            alias,
+            expand: Default::default(),
+            item_type: Default::default(),
+            metadata: Default::default(),
+            name,
+        }
+    }
+}
+
+/// The parameters for `RadarValueList::list`.
+#[derive(Clone, Debug, Serialize, Default)]
+pub struct ListRadarValueLists<'a> {
+    /// The alias used to reference the value list when writing rules.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub alias: Option<&'a str>,
+
+    /// A value contained within a value list - returns all value lists containing this value.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub contains: Option<&'a str>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created: Option<RangeQuery<Timestamp>>,
+
+    /// A cursor for use in pagination.
+    ///
+    /// `ending_before` is an object ID that defines your place in the list.
+    /// For instance, if you make a list request and receive 100 objects, starting with `obj_bar`, your subsequent call can include `ending_before=obj_bar` in order to fetch the previous page of the list.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ending_before: Option<RadarValueListId>,
+
+    /// Specifies which fields in the response should be expanded.
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+
+    /// A limit on the number of objects to be returned.
+    ///
+    /// Limit can range between 1 and 100, and the default is 10.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u64>,
+
+    /// A cursor for use in pagination.
+    ///
+    /// `starting_after` is an object ID that defines your place in the list.
+    /// For instance, if you make a list request and receive 100 objects, ending with `obj_foo`, your subsequent call can include `starting_after=obj_foo` in order to fetch the next page of the list.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub starting_after: Option<RadarValueListId>,
+}
+
+impl<'a> ListRadarValueLists<'a> {
+    pub fn new() -> Self {
+        ListRadarValueLists {
+            alias: Default::default(),
+            contains: Default::default(),
+            created: Default::default(),
+            ending_before: Default::default(),
+            expand: Default::default(),
+            limit: Default::default(),
+            starting_after: Default::default(),
+        }
+    }
+}
+impl Paginable for ListRadarValueLists<'_> {
+    type O = RadarValueList;
+    fn set_last(&mut self, item: Self::O) {
+        self.starting_after = Some(item.id());
+    }
+}
+/// The parameters for `RadarValueList::update`.
+#[derive(Clone, Debug, Serialize, Default)]
+pub struct UpdateRadarValueList<'a> {
+    /// The name of the value list for use in rules.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub alias: Option<&'a str>,
+
+    /// Specifies which fields in the response should be expanded.
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+
+    /// Set of [key-value pairs](https://stripe.com/docs/api/metadata) that you can attach to an object.
+    ///
+    /// This can be useful for storing additional information about the object in a structured format.
+    /// Individual keys can be unset by posting an empty value to them.
+    /// All keys can be unset by posting an empty value to `metadata`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+
+    /// The human-readable name of the value list.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<&'a str>,
+}
+
+impl<'a> UpdateRadarValueList<'a> {
+    pub fn new() -> Self {
+        UpdateRadarValueList {
+            alias: Default::default(),
+            expand: Default::default(),
+            metadata: Default::default(),
+            name: Default::default(),
+        }
+    }
+}
+
 /// An enum representing the possible values of an `RadarValueList`'s `item_type` field.
 #[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
 #[serde(rename_all = "snake_case")]
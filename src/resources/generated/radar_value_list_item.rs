@@ -2,10 +2,12 @@
 // This file was automatically generated.
 // ======================================
 
-use crate::ids::{RadarValueListItemId};
-use crate::params::{Object, Timestamp};
 use serde::{Deserialize, Serialize};
 
+use crate::client::{Client, Response};
+use crate::ids::{RadarValueListId, RadarValueListItemId};
+use crate::params::{Deleted, Expand, List, Object, Paginable, RangeQuery, Timestamp};
+
 /// The resource representing a Stripe "RadarListListItem".
 ///
 /// For more details see <https://stripe.com/docs/api/radar/value_list_items/object>
@@ -41,6 +43,41 @@ pub struct RadarValueListItem {
     pub value_list: Option<String>,
 }
 
+impl RadarValueListItem {
+    /// Returns a list of `ValueListItem` objects.
+    pub fn list(
+        client: &Client,
+        params: &ListRadarValueListItems<'_>,
+    ) -> Response<List<RadarValueListItem>> {
+        client.get_query("/radar/value_list_items", &params)
+    }
+
+    /// Creates a new `ValueListItem` object, which is added to the specified parent value list.
+    pub fn create(
+        client: &Client,
+        params: CreateRadarValueListItem<'_>,
+    ) -> Response<RadarValueListItem> {
+        client.post_form("/radar/value_list_items", &params)
+    }
+
+    /// Retrieves a `ValueListItem` object.
+    pub fn retrieve(
+        client: &Client,
+        id: &RadarValueListItemId,
+        expand: &[&str],
+    ) -> Response<RadarValueListItem> {
+        client.get_query(&format!("/radar/value_list_items/{}", id), &Expand { expand })
+    }
+
+    /// Deletes a `ValueListItem` object, removing it from its parent value list.
+    pub fn delete(
+        client: &Client,
+        id: &RadarValueListItemId,
+    ) -> Response<Deleted<RadarValueListItemId>> {
+        client.delete(&format!("/radar/value_list_items/{}", id))
+    }
+}
+
 impl Object for RadarValueListItem {
     type Id = RadarValueListItemId;
     fn id(&self) -> Self::Id {
@@ -50,3 +87,81 @@ impl Object for RadarValueListItem {
         "radar.value_list_item"
     }
 }
+
+/// The parameters for `RadarValueListItem::create`.
+#[derive(Clone, Debug, Serialize)]
+pub struct CreateRadarValueListItem<'a> {
+    /// Specifies which fields in the response should be expanded.
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+
+    /// The value of the item (whose type must match the type of the parent value list).
+    pub value: &'a str,
+
+    /// The identifier of the value list which the created item will be added to.
+    pub value_list: RadarValueListId,
+}
+
+impl<'a> CreateRadarValueListItem<'a> {
+    pub fn new(value: &'a str, value_list: RadarValueListId) -> Self {
+        CreateRadarValueListItem { expand: Default::default(), value, value_list }
+    }
+}
+
+/// The parameters for `RadarValueListItem::list`.
+#[derive(Clone, Debug, Serialize)]
+pub struct ListRadarValueListItems<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created: Option<RangeQuery<Timestamp>>,
+
+    /// A cursor for use in pagination.
+    ///
+    /// `ending_before` is an object ID that defines your place in the list.
+    /// For instance, if you make a list request and receive 100 objects, starting with `obj_bar`, your subsequent call can include `ending_before=obj_bar` in order to fetch the previous page of the list.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ending_before: Option<RadarValueListItemId>,
+
+    /// Specifies which fields in the response should be expanded.
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+
+    /// A limit on the number of objects to be returned.
+    ///
+    /// Limit can range between 1 and 100, and the default is 10.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u64>,
+
+    /// A cursor for use in pagination.
+    ///
+    /// `starting_after` is an object ID that defines your place in the list.
+    /// For instance, if you make a list request and receive 100 objects, ending with `obj_foo`, your subsequent call can include `starting_after=obj_foo` in order to fetch the next page of the list.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub starting_after: Option<RadarValueListItemId>,
+
+    /// Return items belonging to the parent list whose value matches the specified value (using an "is like" match).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub value: Option<&'a str>,
+
+    /// Identifier for the parent value list this item belongs to.
+    pub value_list: RadarValueListId,
+}
+
+impl<'a> ListRadarValueListItems<'a> {
+    pub fn new(value_list: RadarValueListId) -> Self {
+        ListRadarValueListItems {
+            created: Default::default(),
+            ending_before: Default::default(),
+            expand: Default::default(),
+            limit: Default::default(),
+            starting_after: Default::default(),
+            value: Default::default(),
+            value_list,
+        }
+    }
+}
+impl Paginable for ListRadarValueListItems<'_> {
+    type O = RadarValueListItem;
+    fn set_last(&mut self, item: Self::O) {
+        self.starting_after = Some(item.id());
+    }
+}
@@ -118,6 +118,26 @@ impl Refund {
     pub fn update(client: &Client, id: &RefundId, params: UpdateRefund<'_>) -> Response<Refund> {
         client.post_form(&format!("/refunds/{}", id), &params)
     }
+
+    /// Cancels a refund with a status of `requires_action`.
+    ///
+    /// Refunds in other states cannot be canceled, and only refunds for payment methods that require customer action will enter the `requires_action` state.
+    pub fn cancel(client: &Client, id: &RefundId, params: CancelRefund<'_>) -> Response<Refund> {
+        client.post_form(&format!("/refunds/{}/cancel", id), &params)
+    }
+}
+
+/// Test mode requests that simulate the lifecycle of a [`Refund`].
+///
+/// Stripe rejects these requests in live mode.
+#[derive(Clone, Copy, Debug)]
+pub struct TestHelpersRefund;
+
+impl TestHelpersRefund {
+    /// Expire a refund with a status of `requires_action`.
+    pub fn expire(client: &Client, id: &RefundId, params: ExpireRefund<'_>) -> Response<Refund> {
+        client.post_form(&format!("/test_helpers/refunds/{}/expire", id), &params)
+    }
 }
 
 impl Object for Refund {
@@ -157,6 +177,20 @@ pub struct EmailSent {
     pub email_sent_to: String,
 }
 
+/// The parameters for `Refund::cancel`.
+#[derive(Clone, Debug, Serialize, Default)]
+pub struct CancelRefund<'a> {
+    /// Specifies which fields in the response should be expanded.
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+}
+
+impl<'a> CancelRefund<'a> {
+    pub fn new() -> Self {
+        CancelRefund { expand: Default::default() }
+    }
+}
+
 /// The parameters for `Refund::create`.
 #[derive(Clone, Debug, Serialize, Default)]
 pub struct CreateRefund<'a> {
@@ -229,6 +263,20 @@ impl<'a> CreateRefund<'a> {
     }
 }
 
+/// The parameters for `TestHelpersRefund::expire`.
+#[derive(Clone, Debug, Serialize, Default)]
+pub struct ExpireRefund<'a> {
+    /// Specifies which fields in the response should be expanded.
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+}
+
+impl<'a> ExpireRefund<'a> {
+    pub fn new() -> Self {
+        ExpireRefund { expand: Default::default() }
+    }
+}
+
 /// The parameters for `Refund::list`.
 #[derive(Clone, Debug, Serialize, Default)]
 pub struct ListRefunds<'a> {
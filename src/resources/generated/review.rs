@@ -77,6 +77,11 @@ impl Review {
     pub fn retrieve(client: &Client, id: &ReviewId, expand: &[&str]) -> Response<Review> {
         client.get_query(&format!("/reviews/{}", id), &Expand { expand })
     }
+
+    /// Approves a `Review` object, closing it and removing it from the list of reviews.
+    pub fn approve(client: &Client, id: &ReviewId, params: ApproveReview<'_>) -> Response<Review> {
+        client.post_form(&format!("/reviews/{}/approve", id), &params)
+    }
 }
 
 impl Object for Review {
@@ -122,6 +127,20 @@ pub struct RadarReviewResourceSession {
     pub version: Option<String>,
 }
 
+/// The parameters for `Review::approve`.
+#[derive(Clone, Debug, Serialize, Default)]
+pub struct ApproveReview<'a> {
+    /// Specifies which fields in the response should be expanded.
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+}
+
+impl<'a> ApproveReview<'a> {
+    pub fn new() -> Self {
+        ApproveReview { expand: Default::default() }
+    }
+}
+
 /// The parameters for `Review::list`.
 #[derive(Clone, Debug, Serialize, Default)]
 pub struct ListReviews<'a> {
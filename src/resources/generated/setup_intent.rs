@@ -150,6 +150,40 @@ impl SetupIntent {
     ) -> Response<SetupIntent> {
         client.post_form(&format!("/setup_intents/{}", id), &params)
     }
+
+    /// A SetupIntent object can be canceled when it is in one of these statuses: `requires_payment_method`, `requires_confirmation`, or `requires_action`.
+    ///
+    /// Once canceled, setup is abandoned and any operations on the SetupIntent will fail with an error.
+    pub fn cancel(
+        client: &Client,
+        id: &SetupIntentId,
+        params: CancelSetupIntent<'_>,
+    ) -> Response<SetupIntent> {
+        client.post_form(&format!("/setup_intents/{}/cancel", id), &params)
+    }
+
+    /// Confirm that your customer intends to set up the current or provided payment method.
+    ///
+    /// For example, you would confirm a SetupIntent when a customer hits the “Save” button on a payment method management page on your website.
+    /// If the selected payment method does not require any additional steps from the customer, the SetupIntent will transition to the `succeeded` status.
+    /// Otherwise, it will transition to the `requires_action` status and suggest additional actions via `next_action`.
+    /// If setup fails, the SetupIntent will transition to the `requires_payment_method` status.
+    pub fn confirm(
+        client: &Client,
+        id: &SetupIntentId,
+        params: ConfirmSetupIntent<'_>,
+    ) -> Response<SetupIntent> {
+        client.post_form(&format!("/setup_intents/{}/confirm", id), &params)
+    }
+
+    /// Verifies microdeposits on a SetupIntent object.
+    pub fn verify_microdeposits(
+        client: &Client,
+        id: &SetupIntentId,
+        params: VerifyMicrodepositsSetupIntent<'_>,
+    ) -> Response<SetupIntent> {
+        client.post_form(&format!("/setup_intents/{}/verify_microdeposits", id), &params)
+    }
 }
 
 impl Object for SetupIntent {
@@ -388,6 +422,75 @@ pub struct SetupIntentPaymentMethodOptionsUsBankAccount {
     pub verification_method: Option<SetupIntentPaymentMethodOptionsUsBankAccountVerificationMethod>,
 }
 
+/// The parameters for `SetupIntent::cancel`.
+#[derive(Clone, Debug, Serialize, Default)]
+pub struct CancelSetupIntent<'a> {
+    /// Reason for canceling this SetupIntent.
+    ///
+    /// Possible values are `abandoned`, `requested_by_customer`, or `duplicate`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cancellation_reason: Option<CancelSetupIntentCancellationReason>,
+
+    /// Specifies which fields in the response should be expanded.
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+}
+
+impl<'a> CancelSetupIntent<'a> {
+    pub fn new() -> Self {
+        CancelSetupIntent { cancellation_reason: Default::default(), expand: Default::default() }
+    }
+}
+
+/// The parameters for `SetupIntent::confirm`.
+#[derive(Clone, Debug, Serialize, Default)]
+pub struct ConfirmSetupIntent<'a> {
+    /// The client secret of the SetupIntent.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub client_secret: Option<&'a str>,
+
+    /// Specifies which fields in the response should be expanded.
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+
+    /// This hash contains details about the Mandate to create.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mandate_data: Option<CreateSetupIntentMandateData>,
+
+    /// ID of the payment method (a PaymentMethod, Card, or saved Source object) to attach to this SetupIntent.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub payment_method: Option<PaymentMethodId>,
+
+    /// When included, this hash creates a PaymentMethod that is set as the [`payment_method`](https://stripe.com/docs/api/setup_intents/object#setup_intent_object-payment_method) value in the SetupIntent.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub payment_method_data: Option<CreateSetupIntentPaymentMethodData>,
+
+    /// Payment-method-specific configuration for this SetupIntent.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub payment_method_options: Option<CreateSetupIntentPaymentMethodOptions>,
+
+    /// The URL to redirect your customer back to after they authenticate on the payment method's app or site.
+    ///
+    /// If you'd prefer to redirect to a mobile application, you can alternatively supply an application URI scheme.
+    /// This parameter is only used for cards and other redirect-based payment methods.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub return_url: Option<&'a str>,
+}
+
+impl<'a> ConfirmSetupIntent<'a> {
+    pub fn new() -> Self {
+        ConfirmSetupIntent {
+            client_secret: Default::default(),
+            expand: Default::default(),
+            mandate_data: Default::default(),
+            payment_method: Default::default(),
+            payment_method_data: Default::default(),
+            payment_method_options: Default::default(),
+            return_url: Default::default(),
+        }
+    }
+}
+
 /// The parameters for `SetupIntent::create`.
 #[derive(Clone, Debug, Serialize, Default)]
 pub struct CreateSetupIntent<'a> {
@@ -664,6 +767,37 @@ impl<'a> UpdateSetupIntent<'a> {
     }
 }
 
+/// The parameters for `SetupIntent::verify_microdeposits`.
+#[derive(Clone, Debug, Serialize, Default)]
+pub struct VerifyMicrodepositsSetupIntent<'a> {
+    /// Two positive integers, in *cents*, equal to the values of the microdeposits sent to the bank account.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub amounts: Option<Vec<i64>>,
+
+    /// The client secret of the SetupIntent.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub client_secret: Option<&'a str>,
+
+    /// A six-character code starting with SM present in the microdeposit sent to the bank account.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub descriptor_code: Option<&'a str>,
+
+    /// Specifies which fields in the response should be expanded.
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+}
+
+impl<'a> VerifyMicrodepositsSetupIntent<'a> {
+    pub fn new() -> Self {
+        VerifyMicrodepositsSetupIntent {
+            amounts: Default::default(),
+            client_secret: Default::default(),
+            descriptor_code: Default::default(),
+            expand: Default::default(),
+        }
+    }
+}
+
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct CreateSetupIntentAutomaticPaymentMethods {
     /// Whether this feature is enabled.
@@ -1938,6 +2072,42 @@ pub struct UpdateSetupIntentPaymentMethodOptionsUsBankAccountNetworks {
     pub requested: Option<Vec<UpdateSetupIntentPaymentMethodOptionsUsBankAccountNetworksRequested>>,
 }
 
+/// An enum representing the possible values of an `CancelSetupIntent`'s `cancellation_reason` field.
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum CancelSetupIntentCancellationReason {
+    Abandoned,
+    Duplicate,
+    RequestedByCustomer,
+}
+
+impl CancelSetupIntentCancellationReason {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            CancelSetupIntentCancellationReason::Abandoned => "abandoned",
+            CancelSetupIntentCancellationReason::Duplicate => "duplicate",
+            CancelSetupIntentCancellationReason::RequestedByCustomer => "requested_by_customer",
+        }
+    }
+}
+
+impl AsRef<str> for CancelSetupIntentCancellationReason {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::fmt::Display for CancelSetupIntentCancellationReason {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
+impl std::default::Default for CancelSetupIntentCancellationReason {
+    fn default() -> Self {
+        Self::Abandoned
+    }
+}
+
 /// An enum representing the possible values of an `CreateSetupIntent`'s `flow_directions` field.
 #[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
 #[serde(rename_all = "snake_case")]
@@ -5,7 +5,9 @@
 use serde::{Deserialize, Serialize};
 
 use crate::client::{Client, Response};
-use crate::ids::{CouponId, CustomerId, PlanId, PriceId, PromotionCodeId, SubscriptionId};
+use crate::ids::{
+    CouponId, CustomerId, DiscountId, PlanId, PriceId, PromotionCodeId, SubscriptionId,
+};
 use crate::params::{
     Deleted, Expand, Expandable, List, Metadata, Object, Paginable, RangeQuery, Timestamp,
 };
@@ -251,6 +253,24 @@ impl Subscription {
     pub fn delete(client: &Client, id: &SubscriptionId) -> Response<Deleted<SubscriptionId>> {
         client.delete(&format!("/subscriptions/{}", id))
     }
+
+    /// Initiates resumption of a paused subscription, optionally resetting the billing cycle anchor and creating prorations.
+    ///
+    /// If a resumption invoice is generated, it must be paid or marked uncollectible before the subscription will be unpaused.
+    /// If payment succeeds the subscription will become `active`, and if payment fails the subscription will be `past_due`.
+    /// The resumption invoice will void automatically if not paid by the expiration date.
+    pub fn resume(
+        client: &Client,
+        id: &SubscriptionId,
+        params: ResumeSubscription<'_>,
+    ) -> Response<Subscription> {
+        client.post_form(&format!("/subscriptions/{}/resume", id), &params)
+    }
+
+    /// Removes the currently applied discount on a subscription.
+    pub fn delete_discount(client: &Client, id: &SubscriptionId) -> Response<Deleted<DiscountId>> {
+        client.delete(&format!("/subscriptions/{}/discount", id))
+    }
 }
 
 impl Object for Subscription {
@@ -754,6 +774,45 @@ impl Paginable for ListSubscriptions<'_> {
         self.starting_after = Some(item.id());
     }
 }
+/// The parameters for `Subscription::resume`.
+#[derive(Clone, Debug, Serialize, Default)]
+pub struct ResumeSubscription<'a> {
+    /// Either `now` or `unchanged`.
+    ///
+    /// Setting the value to `now` resets the subscription's billing cycle anchor to the current time (in UTC).
+    /// Setting the value to `unchanged` advances the subscription's billing cycle anchor to the period that surrounds the current time.
+    /// For more information, see the billing cycle [documentation](https://stripe.com/docs/billing/subscriptions/billing-cycle).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub billing_cycle_anchor: Option<SubscriptionBillingCycleAnchor>,
+
+    /// Specifies which fields in the response should be expanded.
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+
+    /// Determines how to handle [prorations](https://stripe.com/docs/subscriptions/billing-cycle#prorations) when the billing cycle changes (e.g., when switching plans, resetting `billing_cycle_anchor=now`, or starting a trial), or if an item's `quantity` changes.
+    ///
+    /// The default value is `create_prorations`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub proration_behavior: Option<SubscriptionProrationBehavior>,
+
+    /// If set, the proration will be calculated as though the subscription was resumed at the given time.
+    ///
+    /// This can be used to apply exactly the same proration that was previewed with [upcoming invoice](https://stripe.com/docs/api#retrieve_customer_invoice) endpoint.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub proration_date: Option<Timestamp>,
+}
+
+impl<'a> ResumeSubscription<'a> {
+    pub fn new() -> Self {
+        ResumeSubscription {
+            billing_cycle_anchor: Default::default(),
+            expand: Default::default(),
+            proration_behavior: Default::default(),
+            proration_date: Default::default(),
+        }
+    }
+}
+
 /// The parameters for `Subscription::update`.
 #[derive(Clone, Debug, Serialize, Default)]
 pub struct UpdateSubscription<'a> {
@@ -123,6 +123,29 @@ impl SubscriptionSchedule {
     ) -> Response<SubscriptionSchedule> {
         client.post_form(&format!("/subscription_schedules/{}", id), &params)
     }
+
+    /// Cancels a subscription schedule and its associated subscription immediately (if the subscription schedule has an active subscription).
+    ///
+    /// A subscription schedule can only be canceled if its status is `not_started` or `active`.
+    pub fn cancel(
+        client: &Client,
+        id: &SubscriptionScheduleId,
+        params: CancelSubscriptionSchedule<'_>,
+    ) -> Response<SubscriptionSchedule> {
+        client.post_form(&format!("/subscription_schedules/{}/cancel", id), &params)
+    }
+
+    /// Releases the subscription schedule immediately, which will stop scheduling of its phases, but leave any existing subscription in place.
+    ///
+    /// A schedule can only be released if its status is `not_started` or `active`.
+    /// If the subscription schedule is currently associated with a subscription, releasing it will remove its `subscription` property and set the subscription’s ID to the `released_subscription` property.
+    pub fn release(
+        client: &Client,
+        id: &SubscriptionScheduleId,
+        params: ReleaseSubscriptionSchedule<'_>,
+    ) -> Response<SubscriptionSchedule> {
+        client.post_form(&format!("/subscription_schedules/{}/release", id), &params)
+    }
 }
 
 impl Object for SubscriptionSchedule {
@@ -341,6 +364,36 @@ pub struct SubscriptionSchedulesResourceDefaultSettingsAutomaticTax {
     pub enabled: bool,
 }
 
+/// The parameters for `SubscriptionSchedule::cancel`.
+#[derive(Clone, Debug, Serialize, Default)]
+pub struct CancelSubscriptionSchedule<'a> {
+    /// Specifies which fields in the response should be expanded.
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+
+    /// If the subscription schedule is `active`, indicates if a final invoice will be generated that contains any un-invoiced metered usage and new/pending proration invoice items.
+    ///
+    /// Defaults to `true`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub invoice_now: Option<bool>,
+
+    /// If the subscription schedule is `active`, indicates if the cancellation should be prorated.
+    ///
+    /// Defaults to `true`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub prorate: Option<bool>,
+}
+
+impl<'a> CancelSubscriptionSchedule<'a> {
+    pub fn new() -> Self {
+        CancelSubscriptionSchedule {
+            expand: Default::default(),
+            invoice_now: Default::default(),
+            prorate: Default::default(),
+        }
+    }
+}
+
 /// The parameters for `SubscriptionSchedule::create`.
 #[derive(Clone, Debug, Serialize, Default)]
 pub struct CreateSubscriptionSchedule<'a> {
@@ -483,6 +536,27 @@ impl Paginable for ListSubscriptionSchedules<'_> {
         self.starting_after = Some(item.id());
     }
 }
+/// The parameters for `SubscriptionSchedule::release`.
+#[derive(Clone, Debug, Serialize, Default)]
+pub struct ReleaseSubscriptionSchedule<'a> {
+    /// Specifies which fields in the response should be expanded.
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+
+    /// Keep any cancellation on the subscription that the schedule has set.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub preserve_cancel_date: Option<bool>,
+}
+
+impl<'a> ReleaseSubscriptionSchedule<'a> {
+    pub fn new() -> Self {
+        ReleaseSubscriptionSchedule {
+            expand: Default::default(),
+            preserve_cancel_date: Default::default(),
+        }
+    }
+}
+
 /// The parameters for `SubscriptionSchedule::update`.
 #[derive(Clone, Debug, Serialize, Default)]
 pub struct UpdateSubscriptionSchedule<'a> {
@@ -2,11 +2,18 @@
 // This file was automatically generated.
 // ======================================
 
-use crate::ids::{TaxCalculationId};
-use crate::params::{List, Object, Timestamp};
-use crate::resources::{Currency, TaxCalculationLineItem, TaxProductResourceCustomerDetails};
 use serde::{Deserialize, Serialize};
 
+use crate::client::{Client, Response};
+use crate::ids::{CustomerId, TaxCalculationId};
+use crate::params::{Expand, List, Object, Timestamp};
+use crate::resources::{
+    Currency, TaxCalculationLineItem, TaxCalculationLineItemTaxBehavior,
+    TaxProductResourceCustomerDetails, TaxProductResourceCustomerDetailsAddressSource,
+    TaxProductResourceCustomerDetailsResourceTaxIdType,
+    TaxProductResourceCustomerDetailsTaxabilityOverride, TaxProductResourceLineItemTaxBreakdown,
+};
+
 /// The resource representing a Stripe "TaxProductResourceTaxCalculation".
 ///
 /// For more details see <https://stripe.com/docs/api/tax/calculations/object>
@@ -53,6 +60,22 @@ pub struct TaxCalculation {
     pub tax_date: Timestamp,
 }
 
+impl TaxCalculation {
+    /// Calculates tax based on input and returns a Tax `Calculation` object.
+    pub fn create(client: &Client, params: CreateTaxCalculation<'_>) -> Response<TaxCalculation> {
+        client.post_form("/tax/calculations", &params)
+    }
+
+    /// Retrieves the line items of a persisted tax calculation as a collection.
+    pub fn list_line_items(
+        client: &Client,
+        id: &TaxCalculationId,
+        params: &ListTaxCalculationLineItems<'_>,
+    ) -> Response<List<TaxCalculationLineItem>> {
+        client.get_query(&format!("/tax/calculations/{}/line_items", id), &params)
+    }
+}
+
 impl Object for TaxCalculation {
     type Id = TaxCalculationId;
     fn id(&self) -> Self::Id {
@@ -65,7 +88,6 @@ impl Object for TaxCalculation {
 
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct TaxProductResourceTaxBreakdown {
-
     /// The amount of tax, in integer cents.
     pub amount: i64,
 
@@ -80,7 +102,6 @@ pub struct TaxProductResourceTaxBreakdown {
 
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct TaxProductResourceTaxCalculationShippingCost {
-
     /// The shipping amount in integer cents.
     ///
     /// If `tax_behavior=inclusive`, then this amount includes taxes.
@@ -108,265 +129,245 @@ pub struct TaxProductResourceTaxCalculationShippingCost {
 }
 
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
-pub struct TaxProductResourceLineItemTaxBreakdown {
+pub struct TaxProductResourceTaxRateDetails {
+    /// Two-letter country code ([ISO 3166-1 alpha-2](https://en.wikipedia.org/wiki/ISO_3166-1_alpha-2)).
+    pub country: Option<String>,
 
-    /// The amount of tax, in integer cents.
-    pub amount: i64,
+    /// The tax rate percentage as a string.
+    ///
+    /// For example, 8.5% is represented as `"8.5"`.
+    pub percentage_decimal: String,
 
-    pub jurisdiction: TaxProductResourceJurisdiction,
+    /// State, county, province, or region.
+    pub state: Option<String>,
 
-    /// Indicates whether the jurisdiction was determined by the origin (merchant's address) or destination (customer's address).
-    pub sourcing: TaxProductResourceLineItemTaxBreakdownSourcing,
+    /// The tax type, such as `vat` or `sales_tax`.
+    pub tax_type: Option<TaxProductResourceTaxRateDetailsTaxType>,
+}
 
-    /// Details regarding the rate for this tax.
+/// The parameters for `TaxCalculation::create`.
+#[derive(Clone, Debug, Serialize)]
+pub struct CreateTaxCalculation<'a> {
+    /// Three-letter [ISO currency code](https://www.iso.org/iso-4217-currency-codes.html), in lowercase.
     ///
-    /// This field will be `null` when the tax is not imposed, for example if the product is exempt from tax.
-    pub tax_rate_details: Option<TaxProductResourceLineItemTaxRateDetails>,
+    /// Must be a [supported currency](https://stripe.com/docs/currencies).
+    pub currency: Currency,
 
-    /// The reasoning behind this tax, for example, if the product is tax exempt.
+    /// The ID of an existing customer to use for this calculation.
     ///
-    /// The possible values for this field may be extended as new tax rules are supported.
-    pub taxability_reason: TaxProductResourceLineItemTaxBreakdownTaxabilityReason,
-
-    /// The amount on which tax is calculated, in integer cents.
-    pub taxable_amount: i64,
-}
+    /// If provided, the customer's address and tax IDs are copied to `customer_details`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub customer: Option<CustomerId>,
 
-#[derive(Clone, Debug, Default, Deserialize, Serialize)]
-pub struct TaxProductResourceJurisdiction {
+    /// Details about the customer, including address and tax IDs.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub customer_details: Option<CreateTaxCalculationCustomerDetails>,
 
-    /// Two-letter country code ([ISO 3166-1 alpha-2](https://en.wikipedia.org/wiki/ISO_3166-1_alpha-2)).
-    pub country: String,
+    /// Specifies which fields in the response should be expanded.
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
 
-    /// A human-readable name for the jurisdiction imposing the tax.
-    pub display_name: String,
+    /// A list of items the customer is purchasing.
+    pub line_items: Vec<CreateTaxCalculationLineItems>,
 
-    /// Indicates the level of the jurisdiction imposing the tax.
-    pub level: TaxProductResourceJurisdictionLevel,
+    /// Shipping cost details to be used for the calculation.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub shipping_cost: Option<CreateTaxCalculationShippingCost>,
 
-    /// [ISO 3166-2 subdivision code](https://en.wikipedia.org/wiki/ISO_3166-2:US), without country prefix.
+    /// Timestamp of date at which the tax rules and rates in effect applies for the calculation.
     ///
-    /// For example, "NY" for New York, United States.
-    pub state: Option<String>,
+    /// Measured in seconds since the Unix epoch.
+    /// Can be up to 48 hours in the past, and up to 48 hours in the future.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tax_date: Option<Timestamp>,
+}
+
+impl<'a> CreateTaxCalculation<'a> {
+    pub fn new(currency: Currency, line_items: Vec<CreateTaxCalculationLineItems>) -> Self {
+        CreateTaxCalculation {
+            currency,
+            customer: Default::default(),
+            customer_details: Default::default(),
+            expand: Default::default(),
+            line_items,
+            shipping_cost: Default::default(),
+            tax_date: Default::default(),
+        }
+    }
 }
 
-#[derive(Clone, Debug, Default, Deserialize, Serialize)]
-pub struct TaxProductResourceLineItemTaxRateDetails {
+/// The parameters for `TaxCalculation::list_line_items`.
+#[derive(Clone, Debug, Serialize, Default)]
+pub struct ListTaxCalculationLineItems<'a> {
+    /// A cursor for use in pagination.
+    ///
+    /// `ending_before` is an object ID that defines your place in the list.
+    /// For instance, if you make a list request and receive 100 objects, starting with `obj_bar`, your subsequent call can include `ending_before=obj_bar` in order to fetch the previous page of the list.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ending_before: Option<&'a str>,
 
-    /// A localized display name for tax type, intended to be human-readable.
+    /// Specifies which fields in the response should be expanded.
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+
+    /// A limit on the number of objects to be returned.
     ///
-    /// For example, "Local Sales and Use Tax", "Value-added tax (VAT)", or "Umsatzsteuer (USt.)".
-    pub display_name: String,
+    /// Limit can range between 1 and 100, and the default is 10.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u64>,
 
-    /// The tax rate percentage as a string.
+    /// A cursor for use in pagination.
     ///
-    /// For example, 8.5% is represented as "8.5".
-    pub percentage_decimal: String,
+    /// `starting_after` is an object ID that defines your place in the list.
+    /// For instance, if you make a list request and receive 100 objects, ending with `obj_foo`, your subsequent call can include `starting_after=obj_foo` in order to fetch the next page of the list.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub starting_after: Option<&'a str>,
+}
 
-    /// The tax type, such as `vat` or `sales_tax`.
-    pub tax_type: TaxProductResourceLineItemTaxRateDetailsTaxType,
+impl<'a> ListTaxCalculationLineItems<'a> {
+    pub fn new() -> Self {
+        ListTaxCalculationLineItems {
+            ending_before: Default::default(),
+            expand: Default::default(),
+            limit: Default::default(),
+            starting_after: Default::default(),
+        }
+    }
 }
 
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
-pub struct TaxProductResourceTaxRateDetails {
+pub struct CreateTaxCalculationCustomerDetails {
+    /// The customer's postal address (for example, home or business location).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub address: Option<CreateTaxCalculationCustomerDetailsAddress>,
 
-    /// Two-letter country code ([ISO 3166-1 alpha-2](https://en.wikipedia.org/wiki/ISO_3166-1_alpha-2)).
-    pub country: Option<String>,
+    /// The type of customer address provided.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub address_source: Option<TaxProductResourceCustomerDetailsAddressSource>,
 
-    /// The tax rate percentage as a string.
-    ///
-    /// For example, 8.5% is represented as `"8.5"`.
-    pub percentage_decimal: String,
+    /// The customer's IP address (IPv4 or IPv6).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ip_address: Option<String>,
 
-    /// State, county, province, or region.
-    pub state: Option<String>,
+    /// The customer's tax IDs.
+    ///
+    /// Stripe Tax might consider a transaction with applicable tax IDs to be B2B, which might affect the tax calculation result.
+    /// Stripe Tax doesn't validate tax IDs for correctness.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tax_ids: Option<Vec<CreateTaxCalculationCustomerDetailsTaxIds>>,
 
-    /// The tax type, such as `vat` or `sales_tax`.
-    pub tax_type: Option<TaxProductResourceTaxRateDetailsTaxType>,
+    /// Overrides the tax calculation result to allow you to not collect tax from your customer.
+    ///
+    /// Use this if you've manually checked your customer's tax exemptions.
+    /// Prefer providing the customer's `tax_ids` where possible, which automatically determines whether `reverse_charge` applies.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub taxability_override: Option<TaxProductResourceCustomerDetailsTaxabilityOverride>,
 }
 
-/// An enum representing the possible values of an `TaxProductResourceJurisdiction`'s `level` field.
-#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
-#[serde(rename_all = "snake_case")]
-pub enum TaxProductResourceJurisdictionLevel {
-    City,
-    Country,
-    County,
-    District,
-    State,
-}
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct CreateTaxCalculationCustomerDetailsAddress {
+    /// City, district, suburb, town, or village.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub city: Option<String>,
 
-impl TaxProductResourceJurisdictionLevel {
-    pub fn as_str(self) -> &'static str {
-        match self {
-            TaxProductResourceJurisdictionLevel::City => "city",
-            TaxProductResourceJurisdictionLevel::Country => "country",
-            TaxProductResourceJurisdictionLevel::County => "county",
-            TaxProductResourceJurisdictionLevel::District => "district",
-            TaxProductResourceJurisdictionLevel::State => "state",
-        }
-    }
-}
+    /// Two-letter country code ([ISO 3166-1 alpha-2](https://en.wikipedia.org/wiki/ISO_3166-1_alpha-2)).
+    pub country: String,
 
-impl AsRef<str> for TaxProductResourceJurisdictionLevel {
-    fn as_ref(&self) -> &str {
-        self.as_str()
-    }
-}
+    /// Address line 1 (e.g., street, PO Box, or company name).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub line1: Option<String>,
 
-impl std::fmt::Display for TaxProductResourceJurisdictionLevel {
-    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
-        self.as_str().fmt(f)
-    }
-}
-impl std::default::Default for TaxProductResourceJurisdictionLevel {
-    fn default() -> Self {
-        Self::City
-    }
-}
+    /// Address line 2 (e.g., apartment, suite, unit, or building).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub line2: Option<String>,
 
-/// An enum representing the possible values of an `TaxProductResourceLineItemTaxBreakdown`'s `sourcing` field.
-#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
-#[serde(rename_all = "snake_case")]
-pub enum TaxProductResourceLineItemTaxBreakdownSourcing {
-    Destination,
-    Origin,
-}
+    /// ZIP or postal code.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub postal_code: Option<String>,
 
-impl TaxProductResourceLineItemTaxBreakdownSourcing {
-    pub fn as_str(self) -> &'static str {
-        match self {
-            TaxProductResourceLineItemTaxBreakdownSourcing::Destination => "destination",
-            TaxProductResourceLineItemTaxBreakdownSourcing::Origin => "origin",
-        }
-    }
+    /// State/province as an [ISO 3166-2](https://en.wikipedia.org/wiki/ISO_3166-2) subdivision code, without country prefix.
+    ///
+    /// Example: "NY" or "TX".
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub state: Option<String>,
 }
 
-impl AsRef<str> for TaxProductResourceLineItemTaxBreakdownSourcing {
-    fn as_ref(&self) -> &str {
-        self.as_str()
-    }
-}
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct CreateTaxCalculationCustomerDetailsTaxIds {
+    /// Type of the tax ID, one of `ae_trn`, `au_abn`, `au_arn`, `bg_uic`, `br_cnpj`, `br_cpf`, `ca_bn`, `ca_gst_hst`, `ca_pst_bc`, `ca_pst_mb`, `ca_pst_sk`, `ca_qst`, `ch_vat`, `cl_tin`, `eg_tin`, `es_cif`, `eu_oss_vat`, `eu_vat`, `gb_vat`, `ge_vat`, `hk_br`, `hu_tin`, `id_npwp`, `il_vat`, `in_gst`, `is_vat`, `jp_cn`, `jp_rn`, `jp_trn`, `ke_pin`, `kr_brn`, `li_uid`, `mx_rfc`, `my_frp`, `my_itn`, `my_sst`, `no_vat`, `nz_gst`, `ph_tin`, `ru_inn`, `ru_kpp`, `sa_vat`, `sg_gst`, `sg_uen`, `si_tin`, `th_vat`, `tr_tin`, `tw_vat`, `ua_vat`, `us_ein`, or `za_vat`.
+    #[serde(rename = "type")]
+    pub type_: TaxProductResourceCustomerDetailsResourceTaxIdType,
 
-impl std::fmt::Display for TaxProductResourceLineItemTaxBreakdownSourcing {
-    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
-        self.as_str().fmt(f)
-    }
-}
-impl std::default::Default for TaxProductResourceLineItemTaxBreakdownSourcing {
-    fn default() -> Self {
-        Self::Destination
-    }
+    /// Value of the tax ID.
+    pub value: String,
 }
 
-/// An enum representing the possible values of an `TaxProductResourceLineItemTaxBreakdown`'s `taxability_reason` field.
-#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
-#[serde(rename_all = "snake_case")]
-pub enum TaxProductResourceLineItemTaxBreakdownTaxabilityReason {
-    CustomerExempt,
-    NotCollecting,
-    NotSubjectToTax,
-    NotSupported,
-    PortionProductExempt,
-    PortionReducedRated,
-    PortionStandardRated,
-    ProductExempt,
-    ProductExemptHoliday,
-    ProportionallyRated,
-    ReducedRated,
-    ReverseCharge,
-    StandardRated,
-    TaxableBasisReduced,
-    ZeroRated,
-}
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct CreateTaxCalculationLineItems {
+    /// A positive integer in cents representing the line item's total price.
+    ///
+    /// If `tax_behavior=inclusive`, then this amount includes taxes.
+    /// Otherwise, taxes are calculated on top of this amount.
+    pub amount: i64,
 
-impl TaxProductResourceLineItemTaxBreakdownTaxabilityReason {
-    pub fn as_str(self) -> &'static str {
-        match self {
-            TaxProductResourceLineItemTaxBreakdownTaxabilityReason::CustomerExempt => "customer_exempt",
-            TaxProductResourceLineItemTaxBreakdownTaxabilityReason::NotCollecting => "not_collecting",
-            TaxProductResourceLineItemTaxBreakdownTaxabilityReason::NotSubjectToTax => "not_subject_to_tax",
-            TaxProductResourceLineItemTaxBreakdownTaxabilityReason::NotSupported => "not_supported",
-            TaxProductResourceLineItemTaxBreakdownTaxabilityReason::PortionProductExempt => "portion_product_exempt",
-            TaxProductResourceLineItemTaxBreakdownTaxabilityReason::PortionReducedRated => "portion_reduced_rated",
-            TaxProductResourceLineItemTaxBreakdownTaxabilityReason::PortionStandardRated => "portion_standard_rated",
-            TaxProductResourceLineItemTaxBreakdownTaxabilityReason::ProductExempt => "product_exempt",
-            TaxProductResourceLineItemTaxBreakdownTaxabilityReason::ProductExemptHoliday => "product_exempt_holiday",
-            TaxProductResourceLineItemTaxBreakdownTaxabilityReason::ProportionallyRated => "proportionally_rated",
-            TaxProductResourceLineItemTaxBreakdownTaxabilityReason::ReducedRated => "reduced_rated",
-            TaxProductResourceLineItemTaxBreakdownTaxabilityReason::ReverseCharge => "reverse_charge",
-            TaxProductResourceLineItemTaxBreakdownTaxabilityReason::StandardRated => "standard_rated",
-            TaxProductResourceLineItemTaxBreakdownTaxabilityReason::TaxableBasisReduced => "taxable_basis_reduced",
-            TaxProductResourceLineItemTaxBreakdownTaxabilityReason::ZeroRated => "zero_rated",
-        }
-    }
-}
+    /// If provided, the product's `tax_code` will be used as the line item's `tax_code`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub product: Option<String>,
 
-impl AsRef<str> for TaxProductResourceLineItemTaxBreakdownTaxabilityReason {
-    fn as_ref(&self) -> &str {
-        self.as_str()
-    }
-}
+    /// The number of units of the item being purchased.
+    ///
+    /// Used to calculate the per-unit price from the total `amount` for the line.
+    /// For example, if `amount=100` and `quantity=4`, the calculated unit price is 25.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub quantity: Option<u64>,
 
-impl std::fmt::Display for TaxProductResourceLineItemTaxBreakdownTaxabilityReason {
-    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
-        self.as_str().fmt(f)
-    }
-}
-impl std::default::Default for TaxProductResourceLineItemTaxBreakdownTaxabilityReason {
-    fn default() -> Self {
-        Self::CustomerExempt
-    }
-}
+    /// A custom identifier for this line item, which must be unique across the line items in the calculation.
+    ///
+    /// The reference helps identify each line item in exported [tax reports](https://stripe.com/docs/tax/reports).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reference: Option<String>,
 
-/// An enum representing the possible values of an `TaxProductResourceLineItemTaxRateDetails`'s `tax_type` field.
-#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
-#[serde(rename_all = "snake_case")]
-pub enum TaxProductResourceLineItemTaxRateDetailsTaxType {
-    Gst,
-    Hst,
-    Igst,
-    Jct,
-    LeaseTax,
-    Pst,
-    Qst,
-    Rst,
-    SalesTax,
-    Vat,
-}
+    /// Specifies whether the `amount` includes taxes.
+    ///
+    /// Defaults to `exclusive`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tax_behavior: Option<TaxCalculationLineItemTaxBehavior>,
 
-impl TaxProductResourceLineItemTaxRateDetailsTaxType {
-    pub fn as_str(self) -> &'static str {
-        match self {
-            TaxProductResourceLineItemTaxRateDetailsTaxType::Gst => "gst",
-            TaxProductResourceLineItemTaxRateDetailsTaxType::Hst => "hst",
-            TaxProductResourceLineItemTaxRateDetailsTaxType::Igst => "igst",
-            TaxProductResourceLineItemTaxRateDetailsTaxType::Jct => "jct",
-            TaxProductResourceLineItemTaxRateDetailsTaxType::LeaseTax => "lease_tax",
-            TaxProductResourceLineItemTaxRateDetailsTaxType::Pst => "pst",
-            TaxProductResourceLineItemTaxRateDetailsTaxType::Qst => "qst",
-            TaxProductResourceLineItemTaxRateDetailsTaxType::Rst => "rst",
-            TaxProductResourceLineItemTaxRateDetailsTaxType::SalesTax => "sales_tax",
-            TaxProductResourceLineItemTaxRateDetailsTaxType::Vat => "vat",
-        }
-    }
+    /// A [tax code](https://stripe.com/docs/tax/tax-categories) ID to use for this line item.
+    ///
+    /// If not provided, we will use the tax code from the provided `product` param.
+    /// If neither `tax_code` nor `product` is provided, we will use the default tax code from your Tax Settings.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tax_code: Option<String>,
 }
 
-impl AsRef<str> for TaxProductResourceLineItemTaxRateDetailsTaxType {
-    fn as_ref(&self) -> &str {
-        self.as_str()
-    }
-}
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct CreateTaxCalculationShippingCost {
+    /// A positive integer in cents representing the shipping charge.
+    ///
+    /// If `tax_behavior=inclusive`, then this amount includes taxes.
+    /// Otherwise, taxes are calculated on top of this amount.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub amount: Option<i64>,
 
-impl std::fmt::Display for TaxProductResourceLineItemTaxRateDetailsTaxType {
-    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
-        self.as_str().fmt(f)
-    }
-}
-impl std::default::Default for TaxProductResourceLineItemTaxRateDetailsTaxType {
-    fn default() -> Self {
-        Self::Gst
-    }
+    /// If provided, the [shipping rate](https://stripe.com/docs/api/shipping_rates/object)'s `amount`, `tax_code` and `tax_behavior` are used.
+    ///
+    /// If you provide a shipping rate, then you cannot pass the `amount`, `tax_code`, or `tax_behavior` parameters.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub shipping_rate: Option<String>,
+
+    /// Specifies whether the `amount` includes taxes.
+    ///
+    /// If `tax_behavior=inclusive`, then the amount includes taxes.
+    /// Defaults to `exclusive`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tax_behavior: Option<TaxProductResourceTaxCalculationShippingCostTaxBehavior>,
+
+    /// The [tax code](https://stripe.com/docs/tax/tax-categories) used to calculate tax on shipping.
+    ///
+    /// If not provided, the default shipping tax code from your [Tax Settings](/settings/tax) is used.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tax_code: Option<String>,
 }
 
 /// An enum representing the possible values of an `TaxProductResourceTaxCalculationShippingCost`'s `tax_behavior` field.
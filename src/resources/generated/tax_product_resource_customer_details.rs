@@ -7,7 +7,6 @@ use serde::{Deserialize, Serialize};
 /// The resource representing a Stripe "TaxProductResourceCustomerDetails".
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct TaxProductResourceCustomerDetails {
-
     /// The customer's postal address (for example, home or business location).
     pub address: Option<TaxProductResourcePostalAddress>,
 
@@ -26,7 +25,6 @@ pub struct TaxProductResourceCustomerDetails {
 
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct TaxProductResourceCustomerDetailsResourceTaxId {
-
     /// The type of the tax ID, one of `eu_vat`, `br_cnpj`, `br_cpf`, `eu_oss_vat`, `gb_vat`, `nz_gst`, `au_abn`, `au_arn`, `in_gst`, `no_vat`, `za_vat`, `ch_vat`, `mx_rfc`, `sg_uen`, `ru_inn`, `ru_kpp`, `ca_bn`, `hk_br`, `es_cif`, `tw_vat`, `th_vat`, `jp_cn`, `jp_rn`, `jp_trn`, `li_uid`, `my_itn`, `us_ein`, `kr_brn`, `ca_qst`, `ca_gst_hst`, `ca_pst_bc`, `ca_pst_mb`, `ca_pst_sk`, `my_sst`, `sg_gst`, `ae_trn`, `cl_tin`, `sa_vat`, `id_npwp`, `my_frp`, `il_vat`, `ge_vat`, `ua_vat`, `is_vat`, `bg_uic`, `hu_tin`, `si_tin`, `ke_pin`, `tr_tin`, `eg_tin`, `ph_tin`, or `unknown`.
     #[serde(rename = "type")]
     pub type_: TaxProductResourceCustomerDetailsResourceTaxIdType,
@@ -37,7 +35,6 @@ pub struct TaxProductResourceCustomerDetailsResourceTaxId {
 
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct TaxProductResourcePostalAddress {
-
     /// City, district, suburb, town, or village.
     pub city: Option<String>,
 
@@ -239,7 +236,9 @@ pub enum TaxProductResourceCustomerDetailsTaxabilityOverride {
 impl TaxProductResourceCustomerDetailsTaxabilityOverride {
     pub fn as_str(self) -> &'static str {
         match self {
-            TaxProductResourceCustomerDetailsTaxabilityOverride::CustomerExempt => "customer_exempt",
+            TaxProductResourceCustomerDetailsTaxabilityOverride::CustomerExempt => {
+                "customer_exempt"
+            }
             TaxProductResourceCustomerDetailsTaxabilityOverride::None => "none",
             TaxProductResourceCustomerDetailsTaxabilityOverride::ReverseCharge => "reverse_charge",
         }
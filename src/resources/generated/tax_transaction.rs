@@ -2,11 +2,16 @@
 // This file was automatically generated.
 // ======================================
 
-use crate::ids::{TaxTransactionId};
-use crate::params::{List, Metadata, Object, Timestamp};
-use crate::resources::{Currency, TaxProductResourceCustomerDetails, TaxTransactionLineItem};
 use serde::{Deserialize, Serialize};
 
+use crate::client::{Client, Response};
+use crate::ids::TaxTransactionId;
+use crate::params::{Expand, List, Metadata, Object, Timestamp};
+use crate::resources::{
+    Currency, TaxProductResourceCustomerDetails, TaxProductResourceLineItemTaxBreakdown,
+    TaxTransactionLineItem,
+};
+
 /// The resource representing a Stripe "TaxProductResourceTaxTransaction".
 ///
 /// For more details see <https://stripe.com/docs/api/tax/transactions/object>
@@ -58,6 +63,42 @@ pub struct TaxTransaction {
     pub type_: TaxTransactionType,
 }
 
+impl TaxTransaction {
+    /// Retrieves a Tax `Transaction` object.
+    pub fn retrieve(
+        client: &Client,
+        id: &TaxTransactionId,
+        expand: &[&str],
+    ) -> Response<TaxTransaction> {
+        client.get_query(&format!("/tax/transactions/{}", id), &Expand { expand })
+    }
+
+    /// Creates a Tax `Transaction` from a calculation.
+    pub fn create_from_calculation(
+        client: &Client,
+        params: CreateTaxTransactionFromCalculation<'_>,
+    ) -> Response<TaxTransaction> {
+        client.post_form("/tax/transactions/create_from_calculation", &params)
+    }
+
+    /// Partially or fully reverses a previously created `Transaction`.
+    pub fn create_reversal(
+        client: &Client,
+        params: CreateTaxTransactionReversal<'_>,
+    ) -> Response<TaxTransaction> {
+        client.post_form("/tax/transactions/create_reversal", &params)
+    }
+
+    /// Retrieves the line items of a committed standalone transaction as a collection.
+    pub fn list_line_items(
+        client: &Client,
+        id: &TaxTransactionId,
+        params: &ListTaxTransactionLineItems<'_>,
+    ) -> Response<List<TaxTransactionLineItem>> {
+        client.get_query(&format!("/tax/transactions/{}/line_items", id), &params)
+    }
+}
+
 impl Object for TaxTransaction {
     type Id = TaxTransactionId;
     fn id(&self) -> Self::Id {
@@ -70,14 +111,12 @@ impl Object for TaxTransaction {
 
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct TaxProductResourceTaxTransactionResourceReversal {
-
     /// The `id` of the reversed `Transaction` object.
     pub original_transaction: Option<String>,
 }
 
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct TaxProductResourceTaxTransactionShippingCost {
-
     /// The shipping amount in integer cents.
     ///
     /// If `tax_behavior=inclusive`, then this amount includes taxes.
@@ -108,248 +147,176 @@ pub struct TaxProductResourceTaxTransactionShippingCost {
     pub tax_code: String,
 }
 
-#[derive(Clone, Debug, Default, Deserialize, Serialize)]
-pub struct TaxProductResourceLineItemTaxBreakdown {
-
-    /// The amount of tax, in integer cents.
-    pub amount: i64,
+/// The parameters for `TaxTransaction::create_from_calculation`.
+#[derive(Clone, Debug, Serialize)]
+pub struct CreateTaxTransactionFromCalculation<'a> {
+    /// Tax Calculation ID to be used as input when creating the transaction.
+    pub calculation: &'a str,
 
-    pub jurisdiction: TaxProductResourceJurisdiction,
+    /// Specifies which fields in the response should be expanded.
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
 
-    /// Indicates whether the jurisdiction was determined by the origin (merchant's address) or destination (customer's address).
-    pub sourcing: TaxProductResourceLineItemTaxBreakdownSourcing,
-
-    /// Details regarding the rate for this tax.
+    /// Set of [key-value pairs](https://stripe.com/docs/api/metadata) that you can attach to an object.
     ///
-    /// This field will be `null` when the tax is not imposed, for example if the product is exempt from tax.
-    pub tax_rate_details: Option<TaxProductResourceLineItemTaxRateDetails>,
+    /// This can be useful for storing additional information about the object in a structured format.
+    /// Individual keys can be unset by posting an empty value to them.
+    /// All keys can be unset by posting an empty value to `metadata`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
 
-    /// The reasoning behind this tax, for example, if the product is tax exempt.
+    /// A custom order or sale identifier, such as 'myOrder_123'.
     ///
-    /// The possible values for this field may be extended as new tax rules are supported.
-    pub taxability_reason: TaxProductResourceLineItemTaxBreakdownTaxabilityReason,
-
-    /// The amount on which tax is calculated, in integer cents.
-    pub taxable_amount: i64,
+    /// Must be unique across all transactions, including reversals.
+    pub reference: &'a str,
 }
 
-#[derive(Clone, Debug, Default, Deserialize, Serialize)]
-pub struct TaxProductResourceJurisdiction {
-
-    /// Two-letter country code ([ISO 3166-1 alpha-2](https://en.wikipedia.org/wiki/ISO_3166-1_alpha-2)).
-    pub country: String,
-
-    /// A human-readable name for the jurisdiction imposing the tax.
-    pub display_name: String,
+impl<'a> CreateTaxTransactionFromCalculation<'a> {
+    pub fn new(calculation: &'a str, reference: &'a str) -> Self {
+        CreateTaxTransactionFromCalculation {
+            calculation,
+            expand: Default::default(),
+            metadata: Default::default(),
+            reference,
+        }
+    }
+}
 
-    /// Indicates the level of the jurisdiction imposing the tax.
-    pub level: TaxProductResourceJurisdictionLevel,
+/// The parameters for `TaxTransaction::create_reversal`.
+#[derive(Clone, Debug, Serialize)]
+pub struct CreateTaxTransactionReversal<'a> {
+    /// Specifies which fields in the response should be expanded.
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
 
-    /// [ISO 3166-2 subdivision code](https://en.wikipedia.org/wiki/ISO_3166-2:US), without country prefix.
+    /// A flat amount to reverse across the entire transaction, in negative integer cents.
     ///
-    /// For example, "NY" for New York, United States.
-    pub state: Option<String>,
-}
+    /// This value represents the total amount to refund from the transaction, including taxes.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub flat_amount: Option<i64>,
 
-#[derive(Clone, Debug, Default, Deserialize, Serialize)]
-pub struct TaxProductResourceLineItemTaxRateDetails {
+    /// The line item amounts to reverse.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub line_items: Option<Vec<CreateTaxTransactionReversalLineItems>>,
 
-    /// A localized display name for tax type, intended to be human-readable.
+    /// Set of [key-value pairs](https://stripe.com/docs/api/metadata) that you can attach to an object.
     ///
-    /// For example, "Local Sales and Use Tax", "Value-added tax (VAT)", or "Umsatzsteuer (USt.)".
-    pub display_name: String,
+    /// This can be useful for storing additional information about the object in a structured format.
+    /// Individual keys can be unset by posting an empty value to them.
+    /// All keys can be unset by posting an empty value to `metadata`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
 
-    /// The tax rate percentage as a string.
+    /// If `partial`, the provided line item or shipping cost amounts are reversed.
     ///
-    /// For example, 8.5% is represented as "8.5".
-    pub percentage_decimal: String,
+    /// If `full`, the original transaction is fully reversed.
+    pub mode: CreateTaxTransactionReversalMode,
 
-    /// The tax type, such as `vat` or `sales_tax`.
-    pub tax_type: TaxProductResourceLineItemTaxRateDetailsTaxType,
-}
+    /// The ID of the Transaction to partially or fully reverse.
+    pub original_transaction: &'a str,
 
-/// An enum representing the possible values of an `TaxProductResourceJurisdiction`'s `level` field.
-#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
-#[serde(rename_all = "snake_case")]
-pub enum TaxProductResourceJurisdictionLevel {
-    City,
-    Country,
-    County,
-    District,
-    State,
-}
+    /// A custom identifier for this reversal, such as `myOrder_123-refund_1`, which must be unique across all transactions.
+    ///
+    /// The reference helps identify this reversal transaction in exported [tax reports](https://stripe.com/docs/tax/reports).
+    pub reference: &'a str,
 
-impl TaxProductResourceJurisdictionLevel {
-    pub fn as_str(self) -> &'static str {
-        match self {
-            TaxProductResourceJurisdictionLevel::City => "city",
-            TaxProductResourceJurisdictionLevel::Country => "country",
-            TaxProductResourceJurisdictionLevel::County => "county",
-            TaxProductResourceJurisdictionLevel::District => "district",
-            TaxProductResourceJurisdictionLevel::State => "state",
+    /// The shipping cost to reverse.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub shipping_cost: Option<CreateTaxTransactionReversalShippingCost>,
+}
+
+impl<'a> CreateTaxTransactionReversal<'a> {
+    pub fn new(
+        mode: CreateTaxTransactionReversalMode,
+        original_transaction: &'a str,
+        reference: &'a str,
+    ) -> Self {
+        CreateTaxTransactionReversal {
+            expand: Default::default(),
+            flat_amount: Default::default(),
+            line_items: Default::default(),
+            metadata: Default::default(),
+            mode,
+            original_transaction,
+            reference,
+            shipping_cost: Default::default(),
         }
     }
 }
 
-impl AsRef<str> for TaxProductResourceJurisdictionLevel {
-    fn as_ref(&self) -> &str {
-        self.as_str()
-    }
-}
+/// The parameters for `TaxTransaction::list_line_items`.
+#[derive(Clone, Debug, Serialize, Default)]
+pub struct ListTaxTransactionLineItems<'a> {
+    /// A cursor for use in pagination.
+    ///
+    /// `ending_before` is an object ID that defines your place in the list.
+    /// For instance, if you make a list request and receive 100 objects, starting with `obj_bar`, your subsequent call can include `ending_before=obj_bar` in order to fetch the previous page of the list.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ending_before: Option<&'a str>,
 
-impl std::fmt::Display for TaxProductResourceJurisdictionLevel {
-    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
-        self.as_str().fmt(f)
-    }
-}
-impl std::default::Default for TaxProductResourceJurisdictionLevel {
-    fn default() -> Self {
-        Self::City
-    }
-}
+    /// Specifies which fields in the response should be expanded.
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
 
-/// An enum representing the possible values of an `TaxProductResourceLineItemTaxBreakdown`'s `sourcing` field.
-#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
-#[serde(rename_all = "snake_case")]
-pub enum TaxProductResourceLineItemTaxBreakdownSourcing {
-    Destination,
-    Origin,
-}
-
-impl TaxProductResourceLineItemTaxBreakdownSourcing {
-    pub fn as_str(self) -> &'static str {
-        match self {
-            TaxProductResourceLineItemTaxBreakdownSourcing::Destination => "destination",
-            TaxProductResourceLineItemTaxBreakdownSourcing::Origin => "origin",
-        }
-    }
-}
+    /// A limit on the number of objects to be returned.
+    ///
+    /// Limit can range between 1 and 100, and the default is 10.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u64>,
 
-impl AsRef<str> for TaxProductResourceLineItemTaxBreakdownSourcing {
-    fn as_ref(&self) -> &str {
-        self.as_str()
-    }
+    /// A cursor for use in pagination.
+    ///
+    /// `starting_after` is an object ID that defines your place in the list.
+    /// For instance, if you make a list request and receive 100 objects, ending with `obj_foo`, your subsequent call can include `starting_after=obj_foo` in order to fetch the next page of the list.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub starting_after: Option<&'a str>,
 }
 
-impl std::fmt::Display for TaxProductResourceLineItemTaxBreakdownSourcing {
-    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
-        self.as_str().fmt(f)
-    }
-}
-impl std::default::Default for TaxProductResourceLineItemTaxBreakdownSourcing {
-    fn default() -> Self {
-        Self::Destination
+impl<'a> ListTaxTransactionLineItems<'a> {
+    pub fn new() -> Self {
+        ListTaxTransactionLineItems {
+            ending_before: Default::default(),
+            expand: Default::default(),
+            limit: Default::default(),
+            starting_after: Default::default(),
+        }
     }
 }
 
-/// An enum representing the possible values of an `TaxProductResourceLineItemTaxBreakdown`'s `taxability_reason` field.
-#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
-#[serde(rename_all = "snake_case")]
-pub enum TaxProductResourceLineItemTaxBreakdownTaxabilityReason {
-    CustomerExempt,
-    NotCollecting,
-    NotSubjectToTax,
-    NotSupported,
-    PortionProductExempt,
-    PortionReducedRated,
-    PortionStandardRated,
-    ProductExempt,
-    ProductExemptHoliday,
-    ProportionallyRated,
-    ReducedRated,
-    ReverseCharge,
-    StandardRated,
-    TaxableBasisReduced,
-    ZeroRated,
-}
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct CreateTaxTransactionReversalLineItems {
+    /// The amount to reverse, in negative integer cents.
+    pub amount: i64,
 
-impl TaxProductResourceLineItemTaxBreakdownTaxabilityReason {
-    pub fn as_str(self) -> &'static str {
-        match self {
-            TaxProductResourceLineItemTaxBreakdownTaxabilityReason::CustomerExempt => "customer_exempt",
-            TaxProductResourceLineItemTaxBreakdownTaxabilityReason::NotCollecting => "not_collecting",
-            TaxProductResourceLineItemTaxBreakdownTaxabilityReason::NotSubjectToTax => "not_subject_to_tax",
-            TaxProductResourceLineItemTaxBreakdownTaxabilityReason::NotSupported => "not_supported",
-            TaxProductResourceLineItemTaxBreakdownTaxabilityReason::PortionProductExempt => "portion_product_exempt",
-            TaxProductResourceLineItemTaxBreakdownTaxabilityReason::PortionReducedRated => "portion_reduced_rated",
-            TaxProductResourceLineItemTaxBreakdownTaxabilityReason::PortionStandardRated => "portion_standard_rated",
-            TaxProductResourceLineItemTaxBreakdownTaxabilityReason::ProductExempt => "product_exempt",
-            TaxProductResourceLineItemTaxBreakdownTaxabilityReason::ProductExemptHoliday => "product_exempt_holiday",
-            TaxProductResourceLineItemTaxBreakdownTaxabilityReason::ProportionallyRated => "proportionally_rated",
-            TaxProductResourceLineItemTaxBreakdownTaxabilityReason::ReducedRated => "reduced_rated",
-            TaxProductResourceLineItemTaxBreakdownTaxabilityReason::ReverseCharge => "reverse_charge",
-            TaxProductResourceLineItemTaxBreakdownTaxabilityReason::StandardRated => "standard_rated",
-            TaxProductResourceLineItemTaxBreakdownTaxabilityReason::TaxableBasisReduced => "taxable_basis_reduced",
-            TaxProductResourceLineItemTaxBreakdownTaxabilityReason::ZeroRated => "zero_rated",
-        }
-    }
-}
+    /// The amount of tax to reverse, in negative integer cents.
+    pub amount_tax: i64,
 
-impl AsRef<str> for TaxProductResourceLineItemTaxBreakdownTaxabilityReason {
-    fn as_ref(&self) -> &str {
-        self.as_str()
-    }
-}
+    /// Set of [key-value pairs](https://stripe.com/docs/api/metadata) that you can attach to an object.
+    ///
+    /// This can be useful for storing additional information about the object in a structured format.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
 
-impl std::fmt::Display for TaxProductResourceLineItemTaxBreakdownTaxabilityReason {
-    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
-        self.as_str().fmt(f)
-    }
-}
-impl std::default::Default for TaxProductResourceLineItemTaxBreakdownTaxabilityReason {
-    fn default() -> Self {
-        Self::CustomerExempt
-    }
-}
+    /// The `id` of the line item to reverse in the original transaction.
+    pub original_line_item: String,
 
-/// An enum representing the possible values of an `TaxProductResourceLineItemTaxRateDetails`'s `tax_type` field.
-#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
-#[serde(rename_all = "snake_case")]
-pub enum TaxProductResourceLineItemTaxRateDetailsTaxType {
-    Gst,
-    Hst,
-    Igst,
-    Jct,
-    LeaseTax,
-    Pst,
-    Qst,
-    Rst,
-    SalesTax,
-    Vat,
-}
+    /// The quantity reversed.
+    ///
+    /// Appears in [tax exports](https://stripe.com/docs/tax/reports), but does not affect the amount of tax reversed.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub quantity: Option<u64>,
 
-impl TaxProductResourceLineItemTaxRateDetailsTaxType {
-    pub fn as_str(self) -> &'static str {
-        match self {
-            TaxProductResourceLineItemTaxRateDetailsTaxType::Gst => "gst",
-            TaxProductResourceLineItemTaxRateDetailsTaxType::Hst => "hst",
-            TaxProductResourceLineItemTaxRateDetailsTaxType::Igst => "igst",
-            TaxProductResourceLineItemTaxRateDetailsTaxType::Jct => "jct",
-            TaxProductResourceLineItemTaxRateDetailsTaxType::LeaseTax => "lease_tax",
-            TaxProductResourceLineItemTaxRateDetailsTaxType::Pst => "pst",
-            TaxProductResourceLineItemTaxRateDetailsTaxType::Qst => "qst",
-            TaxProductResourceLineItemTaxRateDetailsTaxType::Rst => "rst",
-            TaxProductResourceLineItemTaxRateDetailsTaxType::SalesTax => "sales_tax",
-            TaxProductResourceLineItemTaxRateDetailsTaxType::Vat => "vat",
-        }
-    }
+    /// A custom identifier for this line item in the reversal transaction, such as 'L1-refund'.
+    pub reference: String,
 }
 
-impl AsRef<str> for TaxProductResourceLineItemTaxRateDetailsTaxType {
-    fn as_ref(&self) -> &str {
-        self.as_str()
-    }
-}
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct CreateTaxTransactionReversalShippingCost {
+    /// The amount to reverse, in negative integer cents.
+    pub amount: i64,
 
-impl std::fmt::Display for TaxProductResourceLineItemTaxRateDetailsTaxType {
-    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
-        self.as_str().fmt(f)
-    }
-}
-impl std::default::Default for TaxProductResourceLineItemTaxRateDetailsTaxType {
-    fn default() -> Self {
-        Self::Gst
-    }
+    /// The amount of tax to reverse, in negative integer cents.
+    pub amount_tax: i64,
 }
 
 /// An enum representing the possible values of an `TaxProductResourceTaxTransactionShippingCost`'s `tax_behavior` field.
@@ -419,3 +386,37 @@ impl std::default::Default for TaxTransactionType {
         Self::Reversal
     }
 }
+
+/// An enum representing the possible values of an `CreateTaxTransactionReversal`'s `mode` field.
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum CreateTaxTransactionReversalMode {
+    Full,
+    Partial,
+}
+
+impl CreateTaxTransactionReversalMode {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            CreateTaxTransactionReversalMode::Full => "full",
+            CreateTaxTransactionReversalMode::Partial => "partial",
+        }
+    }
+}
+
+impl AsRef<str> for CreateTaxTransactionReversalMode {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::fmt::Display for CreateTaxTransactionReversalMode {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
+impl std::default::Default for CreateTaxTransactionReversalMode {
+    fn default() -> Self {
+        Self::Full
+    }
+}
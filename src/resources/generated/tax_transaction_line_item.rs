@@ -2,10 +2,11 @@
 // This file was automatically generated.
 // ======================================
 
-use crate::ids::{TaxTransactionLineItemId};
-use crate::params::{Metadata, Object};
 use serde::{Deserialize, Serialize};
 
+use crate::ids::TaxTransactionLineItemId;
+use crate::params::{Metadata, Object};
+
 /// The resource representing a Stripe "TaxProductResourceTaxTransactionLineItem".
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct TaxTransactionLineItem {
@@ -65,7 +66,6 @@ impl Object for TaxTransactionLineItem {
 
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct TaxProductResourceTaxTransactionLineItemResourceReversal {
-
     /// The `id` of the line item to reverse in the original transaction.
     pub original_line_item: String,
 }
@@ -4,8 +4,9 @@
 
 use serde::{Deserialize, Serialize};
 
+use crate::client::{Client, Response};
 use crate::ids::TerminalConfigurationId;
-use crate::params::{Expandable, Object};
+use crate::params::{Deleted, Expand, Expandable, List, Object, Paginable};
 use crate::resources::File;
 
 /// The resource representing a Stripe "TerminalConfigurationConfiguration".
@@ -38,6 +39,50 @@ pub struct TerminalConfiguration {
     pub verifone_p400: Option<TerminalConfigurationConfigurationResourceDeviceTypeSpecificConfig>,
 }
 
+impl TerminalConfiguration {
+    /// Returns a list of `Configuration` objects.
+    pub fn list(
+        client: &Client,
+        params: &ListTerminalConfigurations<'_>,
+    ) -> Response<List<TerminalConfiguration>> {
+        client.get_query("/terminal/configurations", &params)
+    }
+
+    /// Creates a new `Configuration` object.
+    pub fn create(
+        client: &Client,
+        params: CreateTerminalConfiguration<'_>,
+    ) -> Response<TerminalConfiguration> {
+        client.post_form("/terminal/configurations", &params)
+    }
+
+    /// Retrieves a `Configuration` object.
+    pub fn retrieve(
+        client: &Client,
+        id: &TerminalConfigurationId,
+        expand: &[&str],
+    ) -> Response<TerminalConfiguration> {
+        client.get_query(&format!("/terminal/configurations/{}", id), &Expand { expand })
+    }
+
+    /// Updates a new `Configuration` object.
+    pub fn update(
+        client: &Client,
+        id: &TerminalConfigurationId,
+        params: UpdateTerminalConfiguration<'_>,
+    ) -> Response<TerminalConfiguration> {
+        client.post_form(&format!("/terminal/configurations/{}", id), &params)
+    }
+
+    /// Deletes a `Configuration` object.
+    pub fn delete(
+        client: &Client,
+        id: &TerminalConfigurationId,
+    ) -> Response<Deleted<TerminalConfigurationId>> {
+        client.delete(&format!("/terminal/configurations/{}", id))
+    }
+}
+
 impl Object for TerminalConfiguration {
     type Id = TerminalConfigurationId;
     fn id(&self) -> Self::Id {
@@ -48,6 +93,145 @@ impl Object for TerminalConfiguration {
     }
 }
 
+/// The parameters for `TerminalConfiguration::create`.
+#[derive(Clone, Debug, Serialize, Default)]
+pub struct CreateTerminalConfiguration<'a> {
+    /// An object containing device type specific settings for BBPOS WisePOS E readers.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bbpos_wisepos_e: Option<CreateTerminalConfigurationBbposWiseposE>,
+
+    /// Specifies which fields in the response should be expanded.
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+
+    /// Tipping configurations for readers supporting on-reader tips.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tipping: Option<TerminalConfigurationConfigurationResourceTipping>,
+
+    /// An object containing device type specific settings for Verifone P400 readers.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub verifone_p400: Option<CreateTerminalConfigurationVerifoneP400>,
+}
+
+impl<'a> CreateTerminalConfiguration<'a> {
+    pub fn new() -> Self {
+        CreateTerminalConfiguration {
+            bbpos_wisepos_e: Default::default(),
+            expand: Default::default(),
+            tipping: Default::default(),
+            verifone_p400: Default::default(),
+        }
+    }
+}
+
+/// The parameters for `TerminalConfiguration::list`.
+#[derive(Clone, Debug, Serialize, Default)]
+pub struct ListTerminalConfigurations<'a> {
+    /// A cursor for use in pagination.
+    ///
+    /// `ending_before` is an object ID that defines your place in the list.
+    /// For instance, if you make a list request and receive 100 objects, starting with `obj_bar`, your subsequent call can include `ending_before=obj_bar` in order to fetch the previous page of the list.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ending_before: Option<TerminalConfigurationId>,
+
+    /// Specifies which fields in the response should be expanded.
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+
+    /// if present, only return the account default or non-default configurations.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_account_default: Option<bool>,
+
+    /// A limit on the number of objects to be returned.
+    ///
+    /// Limit can range between 1 and 100, and the default is 10.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u64>,
+
+    /// A cursor for use in pagination.
+    ///
+    /// `starting_after` is an object ID that defines your place in the list.
+    /// For instance, if you make a list request and receive 100 objects, ending with `obj_foo`, your subsequent call can include `starting_after=obj_foo` in order to fetch the next page of the list.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub starting_after: Option<TerminalConfigurationId>,
+}
+
+impl<'a> ListTerminalConfigurations<'a> {
+    pub fn new() -> Self {
+        ListTerminalConfigurations {
+            ending_before: Default::default(),
+            expand: Default::default(),
+            is_account_default: Default::default(),
+            limit: Default::default(),
+            starting_after: Default::default(),
+        }
+    }
+}
+impl Paginable for ListTerminalConfigurations<'_> {
+    type O = TerminalConfiguration;
+    fn set_last(&mut self, item: Self::O) {
+        self.starting_after = Some(item.id());
+    }
+}
+/// The parameters for `TerminalConfiguration::update`.
+#[derive(Clone, Debug, Serialize, Default)]
+pub struct UpdateTerminalConfiguration<'a> {
+    /// An object containing device type specific settings for BBPOS WisePOS E readers.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bbpos_wisepos_e: Option<UpdateTerminalConfigurationBbposWiseposE>,
+
+    /// Specifies which fields in the response should be expanded.
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+
+    /// Tipping configurations for readers supporting on-reader tips.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tipping: Option<TerminalConfigurationConfigurationResourceTipping>,
+
+    /// An object containing device type specific settings for Verifone P400 readers.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub verifone_p400: Option<UpdateTerminalConfigurationVerifoneP400>,
+}
+
+impl<'a> UpdateTerminalConfiguration<'a> {
+    pub fn new() -> Self {
+        UpdateTerminalConfiguration {
+            bbpos_wisepos_e: Default::default(),
+            expand: Default::default(),
+            tipping: Default::default(),
+            verifone_p400: Default::default(),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct CreateTerminalConfigurationBbposWiseposE {
+    /// A File ID representing an image you would like displayed on the reader.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub splashscreen: Option<String>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct CreateTerminalConfigurationVerifoneP400 {
+    /// A File ID representing an image you would like displayed on the reader.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub splashscreen: Option<String>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct UpdateTerminalConfigurationBbposWiseposE {
+    /// A File ID representing an image you would like displayed on the reader.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub splashscreen: Option<String>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct UpdateTerminalConfigurationVerifoneP400 {
+    /// A File ID representing an image you would like displayed on the reader.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub splashscreen: Option<String>,
+}
+
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct TerminalConfigurationConfigurationResourceDeviceTypeSpecificConfig {
     /// A File ID representing an image you would like displayed on the reader.
@@ -4,8 +4,9 @@
 
 use serde::{Deserialize, Serialize};
 
-use crate::ids::TerminalReaderId;
-use crate::params::{Expandable, Metadata, Object};
+use crate::client::{Client, Response};
+use crate::ids::{ChargeId, PaymentIntentId, SetupIntentId, TerminalReaderId};
+use crate::params::{Deleted, Expand, Expandable, List, Metadata, Object, Paginable};
 use crate::resources::{Charge, Currency, PaymentIntent, Refund, SetupIntent, TerminalLocation};
 
 /// The resource representing a Stripe "TerminalReaderReader".
@@ -63,6 +64,113 @@ pub struct TerminalReader {
     pub status: Option<String>,
 }
 
+impl TerminalReader {
+    /// Returns a list of `Reader` objects.
+    pub fn list(
+        client: &Client,
+        params: &ListTerminalReaders<'_>,
+    ) -> Response<List<TerminalReader>> {
+        client.get_query("/terminal/readers", &params)
+    }
+
+    /// Creates a new `Reader` object.
+    pub fn create(client: &Client, params: CreateTerminalReader<'_>) -> Response<TerminalReader> {
+        client.post_form("/terminal/readers", &params)
+    }
+
+    /// Retrieves a `Reader` object.
+    pub fn retrieve(
+        client: &Client,
+        id: &TerminalReaderId,
+        expand: &[&str],
+    ) -> Response<TerminalReader> {
+        client.get_query(&format!("/terminal/readers/{}", id), &Expand { expand })
+    }
+
+    /// Updates a `Reader` object by setting the values of the parameters passed.
+    ///
+    /// Any parameters not provided will be left unchanged.
+    pub fn update(
+        client: &Client,
+        id: &TerminalReaderId,
+        params: UpdateTerminalReader<'_>,
+    ) -> Response<TerminalReader> {
+        client.post_form(&format!("/terminal/readers/{}", id), &params)
+    }
+
+    /// Deletes a `Reader` object.
+    pub fn delete(client: &Client, id: &TerminalReaderId) -> Response<Deleted<TerminalReaderId>> {
+        client.delete(&format!("/terminal/readers/{}", id))
+    }
+
+    /// Cancels the current reader action.
+    pub fn cancel_action(
+        client: &Client,
+        id: &TerminalReaderId,
+        params: CancelTerminalReaderAction<'_>,
+    ) -> Response<TerminalReader> {
+        client.post_form(&format!("/terminal/readers/{}/cancel_action", id), &params)
+    }
+
+    /// Initiates a payment flow on a Reader.
+    pub fn process_payment_intent(
+        client: &Client,
+        id: &TerminalReaderId,
+        params: ProcessTerminalReaderPaymentIntent<'_>,
+    ) -> Response<TerminalReader> {
+        client.post_form(&format!("/terminal/readers/{}/process_payment_intent", id), &params)
+    }
+
+    /// Initiates a setup intent flow on a Reader.
+    pub fn process_setup_intent(
+        client: &Client,
+        id: &TerminalReaderId,
+        params: ProcessTerminalReaderSetupIntent<'_>,
+    ) -> Response<TerminalReader> {
+        client.post_form(&format!("/terminal/readers/{}/process_setup_intent", id), &params)
+    }
+
+    /// Initiates a refund on a Reader.
+    pub fn refund_payment(
+        client: &Client,
+        id: &TerminalReaderId,
+        params: RefundTerminalReaderPayment<'_>,
+    ) -> Response<TerminalReader> {
+        client.post_form(&format!("/terminal/readers/{}/refund_payment", id), &params)
+    }
+
+    /// Sets reader display to show cart details.
+    pub fn set_reader_display(
+        client: &Client,
+        id: &TerminalReaderId,
+        params: SetTerminalReaderReaderDisplay<'_>,
+    ) -> Response<TerminalReader> {
+        client.post_form(&format!("/terminal/readers/{}/set_reader_display", id), &params)
+    }
+}
+
+/// Test mode requests that simulate the lifecycle of a [`TerminalReader`].
+///
+/// Stripe rejects these requests in live mode.
+#[derive(Clone, Copy, Debug)]
+pub struct TestHelpersTerminalReader;
+
+impl TestHelpersTerminalReader {
+    /// Presents a payment method on a simulated reader.
+    ///
+    /// Can be used to simulate accepting a payment, saving a card or refunding a transaction.
+    pub fn present_payment_method(
+        client: &Client,
+        id: &TerminalReaderId,
+        params: PresentTerminalReaderPaymentMethod<'_>,
+    ) -> Response<TerminalReader> {
+        client.post_form(
+            &format!("/test_helpers/terminal/readers/{}/present_payment_method", id),
+            &params,
+        )
+    }
+}
+
 impl Object for TerminalReader {
     type Id = TerminalReaderId;
     fn id(&self) -> Self::Id {
@@ -230,6 +338,383 @@ pub struct TerminalReaderReaderResourceTippingConfig {
     pub amount_eligible: Option<i64>,
 }
 
+/// The parameters for `TerminalReader::create`.
+#[derive(Clone, Debug, Serialize)]
+pub struct CreateTerminalReader<'a> {
+    /// Specifies which fields in the response should be expanded.
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+
+    /// Custom label given to the reader for easier identification.
+    ///
+    /// If no label is specified, the registration code will be used.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub label: Option<&'a str>,
+
+    /// The location to assign the reader to.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub location: Option<&'a str>,
+
+    /// Set of [key-value pairs](https://stripe.com/docs/api/metadata) that you can attach to an object.
+    ///
+    /// This can be useful for storing additional information about the object in a structured format.
+    /// Individual keys can be unset by posting an empty value to them.
+    /// All keys can be unset by posting an empty value to `metadata`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+
+    /// A code generated by the reader used for registering to an account.
+    pub registration_code: &'a str,
+}
+
+impl<'a> CreateTerminalReader<'a> {
+    pub fn new(registration_code: &'a str) -> Self {
+        CreateTerminalReader {
+            expand: Default::default(),
+            label: Default::default(),
+            location: Default::default(),
+            metadata: Default::default(),
+            registration_code,
+        }
+    }
+}
+
+/// The parameters for `TerminalReader::list`.
+#[derive(Clone, Debug, Serialize, Default)]
+pub struct ListTerminalReaders<'a> {
+    /// Filters readers by device type.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub device_type: Option<TerminalReaderDeviceType>,
+
+    /// A cursor for use in pagination.
+    ///
+    /// `ending_before` is an object ID that defines your place in the list.
+    /// For instance, if you make a list request and receive 100 objects, starting with `obj_bar`, your subsequent call can include `ending_before=obj_bar` in order to fetch the previous page of the list.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ending_before: Option<TerminalReaderId>,
+
+    /// Specifies which fields in the response should be expanded.
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+
+    /// A limit on the number of objects to be returned.
+    ///
+    /// Limit can range between 1 and 100, and the default is 10.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u64>,
+
+    /// A location ID to filter the response list to only readers at the specific location.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub location: Option<&'a str>,
+
+    /// Filters readers by serial number.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub serial_number: Option<&'a str>,
+
+    /// A cursor for use in pagination.
+    ///
+    /// `starting_after` is an object ID that defines your place in the list.
+    /// For instance, if you make a list request and receive 100 objects, ending with `obj_foo`, your subsequent call can include `starting_after=obj_foo` in order to fetch the next page of the list.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub starting_after: Option<TerminalReaderId>,
+
+    /// A status filter to filter readers to only offline or online readers.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<TerminalReaderStatus>,
+}
+
+impl<'a> ListTerminalReaders<'a> {
+    pub fn new() -> Self {
+        ListTerminalReaders {
+            device_type: Default::default(),
+            ending_before: Default::default(),
+            expand: Default::default(),
+            limit: Default::default(),
+            location: Default::default(),
+            serial_number: Default::default(),
+            starting_after: Default::default(),
+            status: Default::default(),
+        }
+    }
+}
+impl Paginable for ListTerminalReaders<'_> {
+    type O = TerminalReader;
+    fn set_last(&mut self, item: Self::O) {
+        self.starting_after = Some(item.id());
+    }
+}
+/// The parameters for `TerminalReader::update`.
+#[derive(Clone, Debug, Serialize, Default)]
+pub struct UpdateTerminalReader<'a> {
+    /// Specifies which fields in the response should be expanded.
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+
+    /// The new label of the reader.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub label: Option<&'a str>,
+
+    /// Set of [key-value pairs](https://stripe.com/docs/api/metadata) that you can attach to an object.
+    ///
+    /// This can be useful for storing additional information about the object in a structured format.
+    /// Individual keys can be unset by posting an empty value to them.
+    /// All keys can be unset by posting an empty value to `metadata`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+}
+
+impl<'a> UpdateTerminalReader<'a> {
+    pub fn new() -> Self {
+        UpdateTerminalReader {
+            expand: Default::default(),
+            label: Default::default(),
+            metadata: Default::default(),
+        }
+    }
+}
+
+/// The parameters for `TerminalReader::cancel_action`.
+#[derive(Clone, Debug, Serialize, Default)]
+pub struct CancelTerminalReaderAction<'a> {
+    /// Specifies which fields in the response should be expanded.
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+}
+
+impl<'a> CancelTerminalReaderAction<'a> {
+    pub fn new() -> Self {
+        CancelTerminalReaderAction { expand: Default::default() }
+    }
+}
+
+/// The parameters for `TerminalReader::process_payment_intent`.
+#[derive(Clone, Debug, Serialize)]
+pub struct ProcessTerminalReaderPaymentIntent<'a> {
+    /// Specifies which fields in the response should be expanded.
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+
+    /// PaymentIntent ID.
+    pub payment_intent: PaymentIntentId,
+
+    /// Configuration overrides.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub process_config: Option<ProcessTerminalReaderPaymentIntentProcessConfig>,
+}
+
+impl<'a> ProcessTerminalReaderPaymentIntent<'a> {
+    pub fn new(payment_intent: PaymentIntentId) -> Self {
+        ProcessTerminalReaderPaymentIntent {
+            expand: Default::default(),
+            payment_intent,
+            process_config: Default::default(),
+        }
+    }
+}
+
+/// The parameters for `TerminalReader::process_setup_intent`.
+#[derive(Clone, Debug, Serialize)]
+pub struct ProcessTerminalReaderSetupIntent<'a> {
+    /// Customer Consent Collected.
+    pub customer_consent_collected: bool,
+
+    /// Specifies which fields in the response should be expanded.
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+
+    /// SetupIntent ID.
+    pub setup_intent: SetupIntentId,
+}
+
+impl<'a> ProcessTerminalReaderSetupIntent<'a> {
+    pub fn new(customer_consent_collected: bool, setup_intent: SetupIntentId) -> Self {
+        ProcessTerminalReaderSetupIntent {
+            customer_consent_collected,
+            expand: Default::default(),
+            setup_intent,
+        }
+    }
+}
+
+/// The parameters for `TerminalReader::refund_payment`.
+#[derive(Clone, Debug, Serialize, Default)]
+pub struct RefundTerminalReaderPayment<'a> {
+    /// A positive integer in __cents__ representing how much of this charge to refund.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub amount: Option<i64>,
+
+    /// ID of the Charge to refund.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub charge: Option<ChargeId>,
+
+    /// Specifies which fields in the response should be expanded.
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+
+    /// Set of [key-value pairs](https://stripe.com/docs/api/metadata) that you can attach to an object.
+    ///
+    /// This can be useful for storing additional information about the object in a structured format.
+    /// Individual keys can be unset by posting an empty value to them.
+    /// All keys can be unset by posting an empty value to `metadata`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+
+    /// ID of the PaymentIntent to refund.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub payment_intent: Option<PaymentIntentId>,
+
+    /// Boolean indicating whether the application fee should be refunded when refunding this charge.
+    ///
+    /// If a full charge refund is given, the full application fee will be refunded.
+    /// Otherwise, the application fee will be refunded in an amount proportional to the amount of the charge refunded.
+    /// An application fee can be refunded only by the application that created the charge.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub refund_application_fee: Option<bool>,
+
+    /// Boolean indicating whether the transfer should be reversed when refunding this charge.
+    ///
+    /// The transfer will be reversed proportionally to the amount being refunded (either the entire or partial amount).
+    /// A transfer can be reversed only by the application that created the charge.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reverse_transfer: Option<bool>,
+}
+
+impl<'a> RefundTerminalReaderPayment<'a> {
+    pub fn new() -> Self {
+        RefundTerminalReaderPayment {
+            amount: Default::default(),
+            charge: Default::default(),
+            expand: Default::default(),
+            metadata: Default::default(),
+            payment_intent: Default::default(),
+            refund_application_fee: Default::default(),
+            reverse_transfer: Default::default(),
+        }
+    }
+}
+
+/// The parameters for `TerminalReader::set_reader_display`.
+#[derive(Clone, Debug, Serialize)]
+pub struct SetTerminalReaderReaderDisplay<'a> {
+    /// Cart.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cart: Option<SetTerminalReaderReaderDisplayCart>,
+
+    /// Specifies which fields in the response should be expanded.
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+
+    /// Type.
+    #[serde(rename = "type")]
+    pub type_: SetTerminalReaderReaderDisplayType,
+}
+
+impl<'a> SetTerminalReaderReaderDisplay<'a> {
+    pub fn new(type_: SetTerminalReaderReaderDisplayType) -> Self {
+        SetTerminalReaderReaderDisplay {
+            cart: Default::default(),
+            expand: Default::default(),
+            type_,
+        }
+    }
+}
+
+/// The parameters for `TestHelpersTerminalReader::present_payment_method`.
+#[derive(Clone, Debug, Serialize, Default)]
+pub struct PresentTerminalReaderPaymentMethod<'a> {
+    /// Simulated data for the card_present payment method.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub card_present: Option<PresentTerminalReaderPaymentMethodCardPresent>,
+
+    /// Specifies which fields in the response should be expanded.
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+
+    /// Simulated data for the interac_present payment method.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub interac_present: Option<PresentTerminalReaderPaymentMethodInteracPresent>,
+
+    /// Simulated payment type.
+    #[serde(rename = "type")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub type_: Option<PresentTerminalReaderPaymentMethodType>,
+}
+
+impl<'a> PresentTerminalReaderPaymentMethod<'a> {
+    pub fn new() -> Self {
+        PresentTerminalReaderPaymentMethod {
+            card_present: Default::default(),
+            expand: Default::default(),
+            interac_present: Default::default(),
+            type_: Default::default(),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct PresentTerminalReaderPaymentMethodCardPresent {
+    /// The card number, as a string without any separators.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub number: Option<String>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct PresentTerminalReaderPaymentMethodInteracPresent {
+    /// Card Number.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub number: Option<String>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct ProcessTerminalReaderPaymentIntentProcessConfig {
+    /// Override showing a tipping selection screen on this transaction.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub skip_tipping: Option<bool>,
+
+    /// Tipping configuration for this transaction.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tipping: Option<ProcessTerminalReaderPaymentIntentProcessConfigTipping>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct ProcessTerminalReaderPaymentIntentProcessConfigTipping {
+    /// Amount used to calculate tip suggestions on tipping selection screen for this transaction.
+    ///
+    /// Must be a positive integer in the smallest currency unit (e.g., 100 cents to represent $1.00 or 100 to represent ¥100, a zero-decimal currency).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub amount_eligible: Option<i64>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct SetTerminalReaderReaderDisplayCart {
+    /// Three-letter [ISO currency code](https://www.iso.org/iso-4217-currency-codes.html), in lowercase.
+    ///
+    /// Must be a [supported currency](https://stripe.com/docs/currencies).
+    pub currency: Currency,
+
+    /// Array of line items that were purchased.
+    pub line_items: Vec<SetTerminalReaderReaderDisplayCartLineItems>,
+
+    /// The amount of tax in cents.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tax: Option<i64>,
+
+    /// Total balance of cart due in cents.
+    pub total: i64,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct SetTerminalReaderReaderDisplayCartLineItems {
+    /// The price of the item in cents.
+    pub amount: i64,
+
+    /// The description or name of the item.
+    pub description: String,
+
+    /// The quantity of the line item being purchased.
+    pub quantity: u64,
+}
+
 /// An enum representing the possible values of an `TerminalReader`'s `device_type` field.
 #[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
 #[serde(rename_all = "snake_case")]
@@ -420,3 +905,103 @@ impl std::default::Default for TerminalReaderReaderResourceSetReaderDisplayActio
         Self::Cart
     }
 }
+
+/// An enum representing the possible values of an `PresentTerminalReaderPaymentMethod`'s `type` field.
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum PresentTerminalReaderPaymentMethodType {
+    CardPresent,
+    InteracPresent,
+}
+
+impl PresentTerminalReaderPaymentMethodType {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            PresentTerminalReaderPaymentMethodType::CardPresent => "card_present",
+            PresentTerminalReaderPaymentMethodType::InteracPresent => "interac_present",
+        }
+    }
+}
+
+impl AsRef<str> for PresentTerminalReaderPaymentMethodType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::fmt::Display for PresentTerminalReaderPaymentMethodType {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
+impl std::default::Default for PresentTerminalReaderPaymentMethodType {
+    fn default() -> Self {
+        Self::CardPresent
+    }
+}
+
+/// An enum representing the possible values of an `SetTerminalReaderReaderDisplay`'s `type` field.
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum SetTerminalReaderReaderDisplayType {
+    Cart,
+}
+
+impl SetTerminalReaderReaderDisplayType {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            SetTerminalReaderReaderDisplayType::Cart => "cart",
+        }
+    }
+}
+
+impl AsRef<str> for SetTerminalReaderReaderDisplayType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::fmt::Display for SetTerminalReaderReaderDisplayType {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
+impl std::default::Default for SetTerminalReaderReaderDisplayType {
+    fn default() -> Self {
+        Self::Cart
+    }
+}
+
+/// An enum representing the possible values of an `TerminalReader`'s `status` field.
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum TerminalReaderStatus {
+    Offline,
+    Online,
+}
+
+impl TerminalReaderStatus {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            TerminalReaderStatus::Offline => "offline",
+            TerminalReaderStatus::Online => "online",
+        }
+    }
+}
+
+impl AsRef<str> for TerminalReaderStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::fmt::Display for TerminalReaderStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
+impl std::default::Default for TerminalReaderStatus {
+    fn default() -> Self {
+        Self::Offline
+    }
+}
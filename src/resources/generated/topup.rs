@@ -97,6 +97,13 @@ impl Topup {
     pub fn update(client: &Client, id: &TopupId, params: UpdateTopup<'_>) -> Response<Topup> {
         client.post_form(&format!("/topups/{}", id), &params)
     }
+
+    /// Cancels a top-up.
+    ///
+    /// Only pending top-ups can be canceled.
+    pub fn cancel(client: &Client, id: &TopupId, params: CancelTopup<'_>) -> Response<Topup> {
+        client.post_form(&format!("/topups/{}/cancel", id), &params)
+    }
 }
 
 impl Object for Topup {
@@ -109,6 +116,20 @@ impl Object for Topup {
     }
 }
 
+/// The parameters for `Topup::cancel`.
+#[derive(Clone, Debug, Serialize, Default)]
+pub struct CancelTopup<'a> {
+    /// Specifies which fields in the response should be expanded.
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+}
+
+impl<'a> CancelTopup<'a> {
+    pub fn new() -> Self {
+        CancelTopup { expand: Default::default() }
+    }
+}
+
 /// The parameters for `Topup::list`.
 #[derive(Clone, Debug, Serialize, Default)]
 pub struct ListTopups<'a> {
@@ -5,7 +5,7 @@
 use serde::{Deserialize, Serialize};
 
 use crate::client::{Client, Response};
-use crate::ids::{ChargeId, TransferId};
+use crate::ids::{ChargeId, TransferId, TransferReversalId};
 use crate::params::{Expand, Expandable, List, Metadata, Object, Paginable, RangeQuery, Timestamp};
 use crate::resources::{Account, BalanceTransaction, Charge, Currency, TransferReversal};
 
@@ -111,6 +111,55 @@ impl Transfer {
     ) -> Response<Transfer> {
         client.post_form(&format!("/transfers/{}", id), &params)
     }
+
+    /// When you create a new reversal, you must specify a transfer to create it on.
+    ///
+    /// When reversing transfers, you can optionally reverse part of the transfer.
+    /// You can do so as many times as you wish until the entire transfer has been reversed.
+    /// Once entirely reversed, a transfer can’t be reversed again.
+    /// This method will return an error when called on an already-reversed transfer, or when trying to reverse more money than is left on a transfer.
+    pub fn create_reversal(
+        client: &Client,
+        id: &TransferId,
+        params: CreateTransferReversal<'_>,
+    ) -> Response<TransferReversal> {
+        client.post_form(&format!("/transfers/{}/reversals", id), &params)
+    }
+
+    /// You can see a list of the reversals belonging to a specific transfer.
+    ///
+    /// Note that the 10 most recent reversals are always available by default on the transfer object.
+    /// If you need more than those 10, you can use this API method and the `limit` and `starting_after` parameters to page through additional reversals.
+    pub fn list_reversals(
+        client: &Client,
+        id: &TransferId,
+        params: &ListTransferReversals<'_>,
+    ) -> Response<List<TransferReversal>> {
+        client.get_query(&format!("/transfers/{}/reversals", id), &params)
+    }
+
+    /// By default, you can see the 10 most recent reversals stored directly on the transfer object, but you can also retrieve details about a specific reversal stored on the transfer.
+    pub fn retrieve_reversal(
+        client: &Client,
+        id: &TransferId,
+        reversal: &TransferReversalId,
+        expand: &[&str],
+    ) -> Response<TransferReversal> {
+        client.get_query(&format!("/transfers/{}/reversals/{}", id, reversal), &Expand { expand })
+    }
+
+    /// Updates the specified reversal by setting the values of the parameters passed.
+    ///
+    /// Any parameters not provided will be left unchanged.
+    /// This request only accepts metadata and description as arguments.
+    pub fn update_reversal(
+        client: &Client,
+        id: &TransferId,
+        reversal: &TransferReversalId,
+        params: UpdateTransferReversal<'_>,
+    ) -> Response<TransferReversal> {
+        client.post_form(&format!("/transfers/{}/reversals/{}", id, reversal), &params)
+    }
 }
 
 impl Object for Transfer {
@@ -193,6 +242,101 @@ impl<'a> CreateTransfer<'a> {
     }
 }
 
+/// The parameters for `Transfer::create_reversal`.
+#[derive(Clone, Debug, Serialize, Default)]
+pub struct CreateTransferReversal<'a> {
+    /// A positive integer in cents (or local equivalent) representing how much of this transfer to reverse.
+    ///
+    /// Can only reverse up to the unreversed amount remaining of the transfer.
+    /// Partial transfer reversals are only allowed for transfers to Stripe Accounts.
+    /// Defaults to the entire transfer amount.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub amount: Option<i64>,
+
+    /// An arbitrary string which you can attach to a reversal object.
+    ///
+    /// It is displayed alongside the reversal in the Dashboard.
+    /// This will be unset if you POST an empty value.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<&'a str>,
+
+    /// Specifies which fields in the response should be expanded.
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+
+    /// Set of [key-value pairs](https://stripe.com/docs/api/metadata) that you can attach to an object.
+    ///
+    /// This can be useful for storing additional information about the object in a structured format.
+    /// Individual keys can be unset by posting an empty value to them.
+    /// All keys can be unset by posting an empty value to `metadata`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+
+    /// Boolean indicating whether the application fee should be refunded when reversing this transfer.
+    ///
+    /// If a full transfer reversal is given, the full application fee will be refunded.
+    /// Otherwise, the application fee will be refunded with an amount proportional to the amount of the transfer reversed.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub refund_application_fee: Option<bool>,
+}
+
+impl<'a> CreateTransferReversal<'a> {
+    pub fn new() -> Self {
+        CreateTransferReversal {
+            amount: Default::default(),
+            description: Default::default(),
+            expand: Default::default(),
+            metadata: Default::default(),
+            refund_application_fee: Default::default(),
+        }
+    }
+}
+
+/// The parameters for `Transfer::list_reversals`.
+#[derive(Clone, Debug, Serialize, Default)]
+pub struct ListTransferReversals<'a> {
+    /// A cursor for use in pagination.
+    ///
+    /// `ending_before` is an object ID that defines your place in the list.
+    /// For instance, if you make a list request and receive 100 objects, starting with `obj_bar`, your subsequent call can include `ending_before=obj_bar` in order to fetch the previous page of the list.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ending_before: Option<TransferReversalId>,
+
+    /// Specifies which fields in the response should be expanded.
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+
+    /// A limit on the number of objects to be returned.
+    ///
+    /// Limit can range between 1 and 100, and the default is 10.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u64>,
+
+    /// A cursor for use in pagination.
+    ///
+    /// `starting_after` is an object ID that defines your place in the list.
+    /// For instance, if you make a list request and receive 100 objects, ending with `obj_foo`, your subsequent call can include `starting_after=obj_foo` in order to fetch the next page of the list.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub starting_after: Option<TransferReversalId>,
+}
+
+impl<'a> ListTransferReversals<'a> {
+    pub fn new() -> Self {
+        ListTransferReversals {
+            ending_before: Default::default(),
+            expand: Default::default(),
+            limit: Default::default(),
+            starting_after: Default::default(),
+        }
+    }
+}
+impl Paginable for ListTransferReversals<'_> {
+    type O = TransferReversal;
+    fn set_last(&mut self, item: Self::O) {
+        self.starting_after = Some(item.id());
+    }
+}
+
 /// The parameters for `Transfer::list`.
 #[derive(Clone, Debug, Serialize, Default)]
 pub struct ListTransfers<'a> {
@@ -283,6 +427,28 @@ impl<'a> UpdateTransfer<'a> {
     }
 }
 
+/// The parameters for `Transfer::update_reversal`.
+#[derive(Clone, Debug, Serialize, Default)]
+pub struct UpdateTransferReversal<'a> {
+    /// Specifies which fields in the response should be expanded.
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+
+    /// Set of [key-value pairs](https://stripe.com/docs/api/metadata) that you can attach to an object.
+    ///
+    /// This can be useful for storing additional information about the object in a structured format.
+    /// Individual keys can be unset by posting an empty value to them.
+    /// All keys can be unset by posting an empty value to `metadata`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+}
+
+impl<'a> UpdateTransferReversal<'a> {
+    pub fn new() -> Self {
+        UpdateTransferReversal { expand: Default::default(), metadata: Default::default() }
+    }
+}
+
 /// An enum representing the possible values of an `CreateTransfer`'s `source_type` field.
 #[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
 #[serde(rename_all = "snake_case")]
@@ -2,11 +2,13 @@
 // This file was automatically generated.
 // ======================================
 
-use crate::ids::{TreasuryCreditReversalId};
-use crate::params::{Expandable, Metadata, Object, Timestamp};
-use crate::resources::{Currency, TreasuryTransaction};
 use serde::{Deserialize, Serialize};
 
+use crate::client::{Client, Response};
+use crate::ids::TreasuryCreditReversalId;
+use crate::params::{Expand, Expandable, List, Metadata, Object, Paginable, Timestamp};
+use crate::resources::{Currency, TreasuryTransaction};
+
 /// The resource representing a Stripe "TreasuryReceivedCreditsResourceCreditReversal".
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct TreasuryCreditReversal {
@@ -55,6 +57,33 @@ pub struct TreasuryCreditReversal {
     pub transaction: Option<Expandable<TreasuryTransaction>>,
 }
 
+impl TreasuryCreditReversal {
+    /// Returns a list of CreditReversals.
+    pub fn list(
+        client: &Client,
+        params: &ListTreasuryCreditReversals<'_>,
+    ) -> Response<List<TreasuryCreditReversal>> {
+        client.get_query("/treasury/credit_reversals", &params)
+    }
+
+    /// Reverses a ReceivedCredit and creates a CreditReversal object.
+    pub fn create(
+        client: &Client,
+        params: CreateTreasuryCreditReversal<'_>,
+    ) -> Response<TreasuryCreditReversal> {
+        client.post_form("/treasury/credit_reversals", &params)
+    }
+
+    /// Retrieves the details of an existing CreditReversal by passing the unique CreditReversal ID from either the CreditReversal creation request or CreditReversal list.
+    pub fn retrieve(
+        client: &Client,
+        id: &TreasuryCreditReversalId,
+        expand: &[&str],
+    ) -> Response<TreasuryCreditReversal> {
+        client.get_query(&format!("/treasury/credit_reversals/{}", id), &Expand { expand })
+    }
+}
+
 impl Object for TreasuryCreditReversal {
     type Id = TreasuryCreditReversalId;
     fn id(&self) -> Self::Id {
@@ -67,11 +96,98 @@ impl Object for TreasuryCreditReversal {
 
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct TreasuryReceivedCreditsResourceStatusTransitions {
-
     /// Timestamp describing when the CreditReversal changed status to `posted`.
     pub posted_at: Option<Timestamp>,
 }
 
+/// The parameters for `TreasuryCreditReversal::create`.
+#[derive(Clone, Debug, Serialize)]
+pub struct CreateTreasuryCreditReversal<'a> {
+    /// Specifies which fields in the response should be expanded.
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+
+    /// Set of [key-value pairs](https://stripe.com/docs/api/metadata) that you can attach to an object.
+    ///
+    /// This can be useful for storing additional information about the object in a structured format.
+    /// Individual keys can be unset by posting an empty value to them.
+    /// All keys can be unset by posting an empty value to `metadata`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+
+    /// The ReceivedCredit to reverse.
+    pub received_credit: &'a str,
+}
+
+impl<'a> CreateTreasuryCreditReversal<'a> {
+    pub fn new(received_credit: &'a str) -> Self {
+        CreateTreasuryCreditReversal {
+            expand: Default::default(),
+            metadata: Default::default(),
+            received_credit,
+        }
+    }
+}
+
+/// The parameters for `TreasuryCreditReversal::list`.
+#[derive(Clone, Debug, Serialize)]
+pub struct ListTreasuryCreditReversals<'a> {
+    /// A cursor for use in pagination.
+    ///
+    /// `ending_before` is an object ID that defines your place in the list.
+    /// For instance, if you make a list request and receive 100 objects, starting with `obj_bar`, your subsequent call can include `ending_before=obj_bar` in order to fetch the previous page of the list.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ending_before: Option<TreasuryCreditReversalId>,
+
+    /// Specifies which fields in the response should be expanded.
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+
+    /// Returns objects associated with this FinancialAccount.
+    pub financial_account: &'a str,
+
+    /// A limit on the number of objects to be returned.
+    ///
+    /// Limit can range between 1 and 100, and the default is 10.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u64>,
+
+    /// Only return CreditReversals for the ReceivedCredit ID.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub received_credit: Option<&'a str>,
+
+    /// A cursor for use in pagination.
+    ///
+    /// `starting_after` is an object ID that defines your place in the list.
+    /// For instance, if you make a list request and receive 100 objects, ending with `obj_foo`, your subsequent call can include `starting_after=obj_foo` in order to fetch the next page of the list.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub starting_after: Option<TreasuryCreditReversalId>,
+
+    /// Only return CreditReversals for a given status.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<TreasuryCreditReversalStatus>,
+}
+
+impl<'a> ListTreasuryCreditReversals<'a> {
+    pub fn new(financial_account: &'a str) -> Self {
+        ListTreasuryCreditReversals {
+            ending_before: Default::default(),
+            expand: Default::default(),
+            financial_account,
+            limit: Default::default(),
+            received_credit: Default::default(),
+            starting_after: Default::default(),
+            status: Default::default(),
+        }
+    }
+}
+impl Paginable for ListTreasuryCreditReversals<'_> {
+    type O = TreasuryCreditReversal;
+    fn set_last(&mut self, item: Self::O) {
+        self.starting_after = Some(item.id());
+    }
+}
+
 /// An enum representing the possible values of an `TreasuryCreditReversal`'s `network` field.
 #[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
 #[serde(rename_all = "snake_case")]
@@ -2,11 +2,13 @@
 // This file was automatically generated.
 // ======================================
 
-use crate::ids::{TreasuryDebitReversalId};
-use crate::params::{Expandable, Metadata, Object, Timestamp};
-use crate::resources::{Currency, TreasuryTransaction};
 use serde::{Deserialize, Serialize};
 
+use crate::client::{Client, Response};
+use crate::ids::TreasuryDebitReversalId;
+use crate::params::{Expand, Expandable, List, Metadata, Object, Paginable, Timestamp};
+use crate::resources::{Currency, TreasuryTransaction};
+
 /// The resource representing a Stripe "TreasuryReceivedDebitsResourceDebitReversal".
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct TreasuryDebitReversal {
@@ -58,6 +60,33 @@ pub struct TreasuryDebitReversal {
     pub transaction: Option<Expandable<TreasuryTransaction>>,
 }
 
+impl TreasuryDebitReversal {
+    /// Returns a list of DebitReversals.
+    pub fn list(
+        client: &Client,
+        params: &ListTreasuryDebitReversals<'_>,
+    ) -> Response<List<TreasuryDebitReversal>> {
+        client.get_query("/treasury/debit_reversals", &params)
+    }
+
+    /// Reverses a ReceivedDebit and creates a DebitReversal object.
+    pub fn create(
+        client: &Client,
+        params: CreateTreasuryDebitReversal<'_>,
+    ) -> Response<TreasuryDebitReversal> {
+        client.post_form("/treasury/debit_reversals", &params)
+    }
+
+    /// Retrieves the details of an existing DebitReversal by passing the unique DebitReversal ID from either the DebitReversal creation request or DebitReversal list.
+    pub fn retrieve(
+        client: &Client,
+        id: &TreasuryDebitReversalId,
+        expand: &[&str],
+    ) -> Response<TreasuryDebitReversal> {
+        client.get_query(&format!("/treasury/debit_reversals/{}", id), &Expand { expand })
+    }
+}
+
 impl Object for TreasuryDebitReversal {
     type Id = TreasuryDebitReversalId;
     fn id(&self) -> Self::Id {
@@ -70,18 +99,109 @@ impl Object for TreasuryDebitReversal {
 
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct TreasuryReceivedDebitsResourceDebitReversalLinkedFlows {
-
     /// Set if there is an Issuing dispute associated with the DebitReversal.
     pub issuing_dispute: Option<String>,
 }
 
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct TreasuryReceivedDebitsResourceStatusTransitions {
-
     /// Timestamp describing when the DebitReversal changed status to `completed`.
     pub completed_at: Option<Timestamp>,
 }
 
+/// The parameters for `TreasuryDebitReversal::create`.
+#[derive(Clone, Debug, Serialize)]
+pub struct CreateTreasuryDebitReversal<'a> {
+    /// Specifies which fields in the response should be expanded.
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+
+    /// Set of [key-value pairs](https://stripe.com/docs/api/metadata) that you can attach to an object.
+    ///
+    /// This can be useful for storing additional information about the object in a structured format.
+    /// Individual keys can be unset by posting an empty value to them.
+    /// All keys can be unset by posting an empty value to `metadata`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+
+    /// The ReceivedDebit to reverse.
+    pub received_debit: &'a str,
+}
+
+impl<'a> CreateTreasuryDebitReversal<'a> {
+    pub fn new(received_debit: &'a str) -> Self {
+        CreateTreasuryDebitReversal {
+            expand: Default::default(),
+            metadata: Default::default(),
+            received_debit,
+        }
+    }
+}
+
+/// The parameters for `TreasuryDebitReversal::list`.
+#[derive(Clone, Debug, Serialize)]
+pub struct ListTreasuryDebitReversals<'a> {
+    /// A cursor for use in pagination.
+    ///
+    /// `ending_before` is an object ID that defines your place in the list.
+    /// For instance, if you make a list request and receive 100 objects, starting with `obj_bar`, your subsequent call can include `ending_before=obj_bar` in order to fetch the previous page of the list.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ending_before: Option<TreasuryDebitReversalId>,
+
+    /// Specifies which fields in the response should be expanded.
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+
+    /// Returns objects associated with this FinancialAccount.
+    pub financial_account: &'a str,
+
+    /// A limit on the number of objects to be returned.
+    ///
+    /// Limit can range between 1 and 100, and the default is 10.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u64>,
+
+    /// Only return DebitReversals for the ReceivedDebit ID.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub received_debit: Option<&'a str>,
+
+    /// Only return DebitReversals for a given resolution.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub resolution: Option<TreasuryDebitReversalResolution>,
+
+    /// A cursor for use in pagination.
+    ///
+    /// `starting_after` is an object ID that defines your place in the list.
+    /// For instance, if you make a list request and receive 100 objects, ending with `obj_foo`, your subsequent call can include `starting_after=obj_foo` in order to fetch the next page of the list.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub starting_after: Option<TreasuryDebitReversalId>,
+
+    /// Only return DebitReversals for a given status.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<TreasuryDebitReversalStatusFilter>,
+}
+
+impl<'a> ListTreasuryDebitReversals<'a> {
+    pub fn new(financial_account: &'a str) -> Self {
+        ListTreasuryDebitReversals {
+            ending_before: Default::default(),
+            expand: Default::default(),
+            financial_account,
+            limit: Default::default(),
+            received_debit: Default::default(),
+            resolution: Default::default(),
+            starting_after: Default::default(),
+            status: Default::default(),
+        }
+    }
+}
+impl Paginable for ListTreasuryDebitReversals<'_> {
+    type O = TreasuryDebitReversal;
+    fn set_last(&mut self, item: Self::O) {
+        self.starting_after = Some(item.id());
+    }
+}
+
 /// An enum representing the possible values of an `TreasuryDebitReversal`'s `network` field.
 #[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
 #[serde(rename_all = "snake_case")]
@@ -151,3 +271,73 @@ impl std::default::Default for TreasuryDebitReversalStatus {
         Self::Failed
     }
 }
+
+/// An enum representing the possible values of an `ListTreasuryDebitReversals`'s `resolution` field.
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum TreasuryDebitReversalResolution {
+    Lost,
+    Won,
+}
+
+impl TreasuryDebitReversalResolution {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            TreasuryDebitReversalResolution::Lost => "lost",
+            TreasuryDebitReversalResolution::Won => "won",
+        }
+    }
+}
+
+impl AsRef<str> for TreasuryDebitReversalResolution {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::fmt::Display for TreasuryDebitReversalResolution {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
+impl std::default::Default for TreasuryDebitReversalResolution {
+    fn default() -> Self {
+        Self::Lost
+    }
+}
+
+/// An enum representing the possible values of an `ListTreasuryDebitReversals`'s `status` field.
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum TreasuryDebitReversalStatusFilter {
+    Canceled,
+    Completed,
+    Processing,
+}
+
+impl TreasuryDebitReversalStatusFilter {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            TreasuryDebitReversalStatusFilter::Canceled => "canceled",
+            TreasuryDebitReversalStatusFilter::Completed => "completed",
+            TreasuryDebitReversalStatusFilter::Processing => "processing",
+        }
+    }
+}
+
+impl AsRef<str> for TreasuryDebitReversalStatusFilter {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::fmt::Display for TreasuryDebitReversalStatusFilter {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
+impl std::default::Default for TreasuryDebitReversalStatusFilter {
+    fn default() -> Self {
+        Self::Canceled
+    }
+}
@@ -2,11 +2,13 @@
 // This file was automatically generated.
 // ======================================
 
-use crate::ids::{TreasuryFinancialAccountId};
-use crate::params::{Metadata, Object, Timestamp};
-use crate::resources::{TreasuryFinancialAccountFeatures};
 use serde::{Deserialize, Serialize};
 
+use crate::client::{Client, Response};
+use crate::ids::TreasuryFinancialAccountId;
+use crate::params::{Expand, List, Metadata, Object, Paginable, RangeQuery, Timestamp};
+use crate::resources::TreasuryFinancialAccountFeatures;
+
 /// The resource representing a Stripe "TreasuryFinancialAccountsResourceFinancialAccount".
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct TreasuryFinancialAccount {
@@ -64,6 +66,62 @@ pub struct TreasuryFinancialAccount {
     pub supported_currencies: Vec<String>,
 }
 
+impl TreasuryFinancialAccount {
+    /// Returns a list of FinancialAccounts.
+    pub fn list(
+        client: &Client,
+        params: &ListTreasuryFinancialAccounts<'_>,
+    ) -> Response<List<TreasuryFinancialAccount>> {
+        client.get_query("/treasury/financial_accounts", &params)
+    }
+
+    /// Creates a new FinancialAccount.
+    ///
+    /// For now, each connected account can only have one FinancialAccount.
+    pub fn create(
+        client: &Client,
+        params: CreateTreasuryFinancialAccount<'_>,
+    ) -> Response<TreasuryFinancialAccount> {
+        client.post_form("/treasury/financial_accounts", &params)
+    }
+
+    /// Retrieves the details of a FinancialAccount.
+    pub fn retrieve(
+        client: &Client,
+        id: &TreasuryFinancialAccountId,
+        expand: &[&str],
+    ) -> Response<TreasuryFinancialAccount> {
+        client.get_query(&format!("/treasury/financial_accounts/{}", id), &Expand { expand })
+    }
+
+    /// Updates the details of a FinancialAccount.
+    pub fn update(
+        client: &Client,
+        id: &TreasuryFinancialAccountId,
+        params: UpdateTreasuryFinancialAccount<'_>,
+    ) -> Response<TreasuryFinancialAccount> {
+        client.post_form(&format!("/treasury/financial_accounts/{}", id), &params)
+    }
+
+    /// Retrieves Features information associated with the FinancialAccount.
+    pub fn retrieve_features(
+        client: &Client,
+        id: &TreasuryFinancialAccountId,
+        params: &RetrieveTreasuryFinancialAccountFeatures<'_>,
+    ) -> Response<TreasuryFinancialAccountFeatures> {
+        client.get_query(&format!("/treasury/financial_accounts/{}/features", id), &params)
+    }
+
+    /// Updates the Features associated with a FinancialAccount.
+    pub fn update_features(
+        client: &Client,
+        id: &TreasuryFinancialAccountId,
+        params: UpdateTreasuryFinancialAccountFeatures<'_>,
+    ) -> Response<TreasuryFinancialAccountFeatures> {
+        client.post_form(&format!("/treasury/financial_accounts/{}/features", id), &params)
+    }
+}
+
 impl Object for TreasuryFinancialAccount {
     type Id = TreasuryFinancialAccountId;
     fn id(&self) -> Self::Id {
@@ -76,7 +134,6 @@ impl Object for TreasuryFinancialAccount {
 
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct TreasuryFinancialAccountsResourceBalance {
-
     /// Funds the user can spend right now.
     pub cash: i64,
 
@@ -89,13 +146,13 @@ pub struct TreasuryFinancialAccountsResourceBalance {
 
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct TreasuryFinancialAccountsResourceFinancialAddress {
-
     #[serde(skip_serializing_if = "Option::is_none")]
     pub aba: Option<TreasuryFinancialAccountsResourceAbaRecord>,
 
     /// The list of networks that the address supports.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub supported_networks: Option<Vec<TreasuryFinancialAccountsResourceFinancialAddressSupportedNetworks>>,
+    pub supported_networks:
+        Option<Vec<TreasuryFinancialAccountsResourceFinancialAddressSupportedNetworks>>,
 
     /// The type of financial address.
     #[serde(rename = "type")]
@@ -104,7 +161,6 @@ pub struct TreasuryFinancialAccountsResourceFinancialAddress {
 
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct TreasuryFinancialAccountsResourceAbaRecord {
-
     /// The name of the person or business that owns the bank account.
     pub account_holder_name: String,
 
@@ -123,7 +179,6 @@ pub struct TreasuryFinancialAccountsResourceAbaRecord {
 
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct TreasuryFinancialAccountsResourcePlatformRestrictions {
-
     /// Restricts all inbound money movement.
     pub inbound_flows: Option<TreasuryFinancialAccountsResourcePlatformRestrictionsInboundFlows>,
 
@@ -133,18 +188,286 @@ pub struct TreasuryFinancialAccountsResourcePlatformRestrictions {
 
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct TreasuryFinancialAccountsResourceStatusDetails {
-
     /// Details related to the closure of this FinancialAccount.
     pub closed: Option<TreasuryFinancialAccountsResourceClosedStatusDetails>,
 }
 
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct TreasuryFinancialAccountsResourceClosedStatusDetails {
-
     /// The array that contains reasons for a FinancialAccount closure.
     pub reasons: Vec<TreasuryFinancialAccountsResourceClosedStatusDetailsReasons>,
 }
 
+/// The parameters for `TreasuryFinancialAccount::create`.
+#[derive(Clone, Debug, Serialize)]
+pub struct CreateTreasuryFinancialAccount<'a> {
+    /// Specifies which fields in the response should be expanded.
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+
+    /// Encodes whether a FinancialAccount has access to a particular feature.
+    ///
+    /// Stripe or the platform can control features via the requested field.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub features: Option<TreasuryFinancialAccountFeaturesParams>,
+
+    /// Set of [key-value pairs](https://stripe.com/docs/api/metadata) that you can attach to an object.
+    ///
+    /// This can be useful for storing additional information about the object in a structured format.
+    /// Individual keys can be unset by posting an empty value to them.
+    /// All keys can be unset by posting an empty value to `metadata`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+
+    /// The set of functionalities that the platform can restrict on the FinancialAccount.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub platform_restrictions: Option<TreasuryFinancialAccountPlatformRestrictionsParams>,
+
+    /// The currencies the FinancialAccount can hold a balance in.
+    pub supported_currencies: Vec<String>,
+}
+
+impl<'a> CreateTreasuryFinancialAccount<'a> {
+    pub fn new(supported_currencies: Vec<String>) -> Self {
+        CreateTreasuryFinancialAccount {
+            expand: Default::default(),
+            features: Default::default(),
+            metadata: Default::default(),
+            platform_restrictions: Default::default(),
+            supported_currencies,
+        }
+    }
+}
+
+/// The parameters for `TreasuryFinancialAccount::list`.
+#[derive(Clone, Debug, Serialize, Default)]
+pub struct ListTreasuryFinancialAccounts<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created: Option<RangeQuery<Timestamp>>,
+
+    /// A cursor for use in pagination.
+    ///
+    /// `ending_before` is an object ID that defines your place in the list.
+    /// For instance, if you make a list request and receive 100 objects, starting with `obj_bar`, your subsequent call can include `ending_before=obj_bar` in order to fetch the previous page of the list.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ending_before: Option<TreasuryFinancialAccountId>,
+
+    /// Specifies which fields in the response should be expanded.
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+
+    /// A limit on the number of objects to be returned.
+    ///
+    /// Limit can range between 1 and 100, and the default is 10.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u64>,
+
+    /// A cursor for use in pagination.
+    ///
+    /// `starting_after` is an object ID that defines your place in the list.
+    /// For instance, if you make a list request and receive 100 objects, ending with `obj_foo`, your subsequent call can include `starting_after=obj_foo` in order to fetch the next page of the list.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub starting_after: Option<TreasuryFinancialAccountId>,
+}
+
+impl<'a> ListTreasuryFinancialAccounts<'a> {
+    pub fn new() -> Self {
+        ListTreasuryFinancialAccounts {
+            created: Default::default(),
+            ending_before: Default::default(),
+            expand: Default::default(),
+            limit: Default::default(),
+            starting_after: Default::default(),
+        }
+    }
+}
+impl Paginable for ListTreasuryFinancialAccounts<'_> {
+    type O = TreasuryFinancialAccount;
+    fn set_last(&mut self, item: Self::O) {
+        self.starting_after = Some(item.id());
+    }
+}
+/// The parameters for `TreasuryFinancialAccount::update`.
+#[derive(Clone, Debug, Serialize, Default)]
+pub struct UpdateTreasuryFinancialAccount<'a> {
+    /// Specifies which fields in the response should be expanded.
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+
+    /// Encodes whether a FinancialAccount has access to a particular feature, with a status enum and associated `status_details`.
+    ///
+    /// Stripe or the platform may control features via the requested field.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub features: Option<TreasuryFinancialAccountFeaturesParams>,
+
+    /// Set of [key-value pairs](https://stripe.com/docs/api/metadata) that you can attach to an object.
+    ///
+    /// This can be useful for storing additional information about the object in a structured format.
+    /// Individual keys can be unset by posting an empty value to them.
+    /// All keys can be unset by posting an empty value to `metadata`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+
+    /// The set of functionalities that the platform can restrict on the FinancialAccount.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub platform_restrictions: Option<TreasuryFinancialAccountPlatformRestrictionsParams>,
+}
+
+impl<'a> UpdateTreasuryFinancialAccount<'a> {
+    pub fn new() -> Self {
+        UpdateTreasuryFinancialAccount {
+            expand: Default::default(),
+            features: Default::default(),
+            metadata: Default::default(),
+            platform_restrictions: Default::default(),
+        }
+    }
+}
+
+/// The parameters for `TreasuryFinancialAccount::retrieve_features`.
+#[derive(Clone, Debug, Serialize, Default)]
+pub struct RetrieveTreasuryFinancialAccountFeatures<'a> {
+    /// Specifies which fields in the response should be expanded.
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+}
+
+impl<'a> RetrieveTreasuryFinancialAccountFeatures<'a> {
+    pub fn new() -> Self {
+        RetrieveTreasuryFinancialAccountFeatures { expand: Default::default() }
+    }
+}
+
+/// The parameters for `TreasuryFinancialAccount::update_features`.
+#[derive(Clone, Debug, Serialize, Default)]
+pub struct UpdateTreasuryFinancialAccountFeatures<'a> {
+    /// Encodes the FinancialAccount's ability to be used with the Issuing product, including attaching cards to and drawing funds from the FinancialAccount.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub card_issuing: Option<TreasuryFeatureAccess>,
+
+    /// Represents whether this FinancialAccount is eligible for deposit insurance.
+    ///
+    /// Various factors determine the insurance amount.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub deposit_insurance: Option<TreasuryFeatureAccess>,
+
+    /// Specifies which fields in the response should be expanded.
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+
+    /// Contains Features that add FinancialAddresses to the FinancialAccount.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub financial_addresses: Option<TreasuryFinancialAddressesFeatureAccess>,
+
+    /// Contains settings related to adding funds to a FinancialAccount from another Account with the same owner.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub inbound_transfers: Option<TreasuryInboundTransfersFeatureAccess>,
+
+    /// Represents the ability for the FinancialAccount to send money to, or receive money from other FinancialAccounts (for example, via OutboundPayment).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub intra_stripe_flows: Option<TreasuryFeatureAccess>,
+
+    /// Includes Features related to initiating money movement out of the FinancialAccount to someone else's bucket of money.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub outbound_payments: Option<TreasuryOutboundFlowsFeatureAccess>,
+
+    /// Contains a Feature and settings related to moving money out of the FinancialAccount into another Account with the same owner.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub outbound_transfers: Option<TreasuryOutboundFlowsFeatureAccess>,
+}
+
+impl<'a> UpdateTreasuryFinancialAccountFeatures<'a> {
+    pub fn new() -> Self {
+        UpdateTreasuryFinancialAccountFeatures {
+            card_issuing: Default::default(),
+            deposit_insurance: Default::default(),
+            expand: Default::default(),
+            financial_addresses: Default::default(),
+            inbound_transfers: Default::default(),
+            intra_stripe_flows: Default::default(),
+            outbound_payments: Default::default(),
+            outbound_transfers: Default::default(),
+        }
+    }
+}
+
+/// The features to request for a FinancialAccount.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct TreasuryFinancialAccountFeaturesParams {
+    /// Encodes the FinancialAccount's ability to be used with the Issuing product, including attaching cards to and drawing funds from the FinancialAccount.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub card_issuing: Option<TreasuryFeatureAccess>,
+
+    /// Represents whether this FinancialAccount is eligible for deposit insurance.
+    ///
+    /// Various factors determine the insurance amount.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub deposit_insurance: Option<TreasuryFeatureAccess>,
+
+    /// Contains Features that add FinancialAddresses to the FinancialAccount.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub financial_addresses: Option<TreasuryFinancialAddressesFeatureAccess>,
+
+    /// Contains settings related to adding funds to a FinancialAccount from another Account with the same owner.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub inbound_transfers: Option<TreasuryInboundTransfersFeatureAccess>,
+
+    /// Represents the ability for the FinancialAccount to send money to, or receive money from other FinancialAccounts (for example, via OutboundPayment).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub intra_stripe_flows: Option<TreasuryFeatureAccess>,
+
+    /// Includes Features related to initiating money movement out of the FinancialAccount to someone else's bucket of money.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub outbound_payments: Option<TreasuryOutboundFlowsFeatureAccess>,
+
+    /// Contains a Feature and settings related to moving money out of the FinancialAccount into another Account with the same owner.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub outbound_transfers: Option<TreasuryOutboundFlowsFeatureAccess>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct TreasuryFinancialAccountPlatformRestrictionsParams {
+    /// Restricts all inbound money movement.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub inbound_flows: Option<TreasuryFinancialAccountsResourcePlatformRestrictionsInboundFlows>,
+
+    /// Restricts all outbound money movement.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub outbound_flows: Option<TreasuryFinancialAccountsResourcePlatformRestrictionsOutboundFlows>,
+}
+
+/// Whether to request a FinancialAccount feature.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct TreasuryFeatureAccess {
+    /// Whether the FinancialAccount should have the Feature.
+    pub requested: bool,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct TreasuryFinancialAddressesFeatureAccess {
+    /// Adds an ABA FinancialAddress to the FinancialAccount.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub aba: Option<TreasuryFeatureAccess>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct TreasuryInboundTransfersFeatureAccess {
+    /// Enables ACH Debits via the InboundTransfers API.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ach: Option<TreasuryFeatureAccess>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct TreasuryOutboundFlowsFeatureAccess {
+    /// Enables ACH transfers.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ach: Option<TreasuryFeatureAccess>,
+
+    /// Enables US domestic wire transfers.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub us_domestic_wire: Option<TreasuryFeatureAccess>,
+}
+
 /// An enum representing the possible values of an `TreasuryFinancialAccount`'s `active_features` field.
 #[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
 #[serde(rename_all = "snake_case")]
@@ -172,14 +495,24 @@ impl TreasuryFinancialAccountActiveFeatures {
         match self {
             TreasuryFinancialAccountActiveFeatures::CardIssuing => "card_issuing",
             TreasuryFinancialAccountActiveFeatures::DepositInsurance => "deposit_insurance",
-            TreasuryFinancialAccountActiveFeatures::FinancialAddressesAba => "financial_addresses.aba",
+            TreasuryFinancialAccountActiveFeatures::FinancialAddressesAba => {
+                "financial_addresses.aba"
+            }
             TreasuryFinancialAccountActiveFeatures::InboundTransfersAch => "inbound_transfers.ach",
             TreasuryFinancialAccountActiveFeatures::IntraStripeFlows => "intra_stripe_flows",
             TreasuryFinancialAccountActiveFeatures::OutboundPaymentsAch => "outbound_payments.ach",
-            TreasuryFinancialAccountActiveFeatures::OutboundPaymentsUsDomesticWire => "outbound_payments.us_domestic_wire",
-            TreasuryFinancialAccountActiveFeatures::OutboundTransfersAch => "outbound_transfers.ach",
-            TreasuryFinancialAccountActiveFeatures::OutboundTransfersUsDomesticWire => "outbound_transfers.us_domestic_wire",
-            TreasuryFinancialAccountActiveFeatures::RemoteDepositCapture => "remote_deposit_capture",
+            TreasuryFinancialAccountActiveFeatures::OutboundPaymentsUsDomesticWire => {
+                "outbound_payments.us_domestic_wire"
+            }
+            TreasuryFinancialAccountActiveFeatures::OutboundTransfersAch => {
+                "outbound_transfers.ach"
+            }
+            TreasuryFinancialAccountActiveFeatures::OutboundTransfersUsDomesticWire => {
+                "outbound_transfers.us_domestic_wire"
+            }
+            TreasuryFinancialAccountActiveFeatures::RemoteDepositCapture => {
+                "remote_deposit_capture"
+            }
         }
     }
 }
@@ -228,14 +561,24 @@ impl TreasuryFinancialAccountPendingFeatures {
         match self {
             TreasuryFinancialAccountPendingFeatures::CardIssuing => "card_issuing",
             TreasuryFinancialAccountPendingFeatures::DepositInsurance => "deposit_insurance",
-            TreasuryFinancialAccountPendingFeatures::FinancialAddressesAba => "financial_addresses.aba",
+            TreasuryFinancialAccountPendingFeatures::FinancialAddressesAba => {
+                "financial_addresses.aba"
+            }
             TreasuryFinancialAccountPendingFeatures::InboundTransfersAch => "inbound_transfers.ach",
             TreasuryFinancialAccountPendingFeatures::IntraStripeFlows => "intra_stripe_flows",
             TreasuryFinancialAccountPendingFeatures::OutboundPaymentsAch => "outbound_payments.ach",
-            TreasuryFinancialAccountPendingFeatures::OutboundPaymentsUsDomesticWire => "outbound_payments.us_domestic_wire",
-            TreasuryFinancialAccountPendingFeatures::OutboundTransfersAch => "outbound_transfers.ach",
-            TreasuryFinancialAccountPendingFeatures::OutboundTransfersUsDomesticWire => "outbound_transfers.us_domestic_wire",
-            TreasuryFinancialAccountPendingFeatures::RemoteDepositCapture => "remote_deposit_capture",
+            TreasuryFinancialAccountPendingFeatures::OutboundPaymentsUsDomesticWire => {
+                "outbound_payments.us_domestic_wire"
+            }
+            TreasuryFinancialAccountPendingFeatures::OutboundTransfersAch => {
+                "outbound_transfers.ach"
+            }
+            TreasuryFinancialAccountPendingFeatures::OutboundTransfersUsDomesticWire => {
+                "outbound_transfers.us_domestic_wire"
+            }
+            TreasuryFinancialAccountPendingFeatures::RemoteDepositCapture => {
+                "remote_deposit_capture"
+            }
         }
     }
 }
@@ -284,14 +627,28 @@ impl TreasuryFinancialAccountRestrictedFeatures {
         match self {
             TreasuryFinancialAccountRestrictedFeatures::CardIssuing => "card_issuing",
             TreasuryFinancialAccountRestrictedFeatures::DepositInsurance => "deposit_insurance",
-            TreasuryFinancialAccountRestrictedFeatures::FinancialAddressesAba => "financial_addresses.aba",
-            TreasuryFinancialAccountRestrictedFeatures::InboundTransfersAch => "inbound_transfers.ach",
+            TreasuryFinancialAccountRestrictedFeatures::FinancialAddressesAba => {
+                "financial_addresses.aba"
+            }
+            TreasuryFinancialAccountRestrictedFeatures::InboundTransfersAch => {
+                "inbound_transfers.ach"
+            }
             TreasuryFinancialAccountRestrictedFeatures::IntraStripeFlows => "intra_stripe_flows",
-            TreasuryFinancialAccountRestrictedFeatures::OutboundPaymentsAch => "outbound_payments.ach",
-            TreasuryFinancialAccountRestrictedFeatures::OutboundPaymentsUsDomesticWire => "outbound_payments.us_domestic_wire",
-            TreasuryFinancialAccountRestrictedFeatures::OutboundTransfersAch => "outbound_transfers.ach",
-            TreasuryFinancialAccountRestrictedFeatures::OutboundTransfersUsDomesticWire => "outbound_transfers.us_domestic_wire",
-            TreasuryFinancialAccountRestrictedFeatures::RemoteDepositCapture => "remote_deposit_capture",
+            TreasuryFinancialAccountRestrictedFeatures::OutboundPaymentsAch => {
+                "outbound_payments.ach"
+            }
+            TreasuryFinancialAccountRestrictedFeatures::OutboundPaymentsUsDomesticWire => {
+                "outbound_payments.us_domestic_wire"
+            }
+            TreasuryFinancialAccountRestrictedFeatures::OutboundTransfersAch => {
+                "outbound_transfers.ach"
+            }
+            TreasuryFinancialAccountRestrictedFeatures::OutboundTransfersUsDomesticWire => {
+                "outbound_transfers.us_domestic_wire"
+            }
+            TreasuryFinancialAccountRestrictedFeatures::RemoteDepositCapture => {
+                "remote_deposit_capture"
+            }
         }
     }
 }
@@ -359,8 +716,12 @@ pub enum TreasuryFinancialAccountsResourceClosedStatusDetailsReasons {
 impl TreasuryFinancialAccountsResourceClosedStatusDetailsReasons {
     pub fn as_str(self) -> &'static str {
         match self {
-            TreasuryFinancialAccountsResourceClosedStatusDetailsReasons::AccountRejected => "account_rejected",
-            TreasuryFinancialAccountsResourceClosedStatusDetailsReasons::ClosedByPlatform => "closed_by_platform",
+            TreasuryFinancialAccountsResourceClosedStatusDetailsReasons::AccountRejected => {
+                "account_rejected"
+            }
+            TreasuryFinancialAccountsResourceClosedStatusDetailsReasons::ClosedByPlatform => {
+                "closed_by_platform"
+            }
             TreasuryFinancialAccountsResourceClosedStatusDetailsReasons::Other => "other",
         }
     }
@@ -395,7 +756,9 @@ impl TreasuryFinancialAccountsResourceFinancialAddressSupportedNetworks {
     pub fn as_str(self) -> &'static str {
         match self {
             TreasuryFinancialAccountsResourceFinancialAddressSupportedNetworks::Ach => "ach",
-            TreasuryFinancialAccountsResourceFinancialAddressSupportedNetworks::UsDomesticWire => "us_domestic_wire",
+            TreasuryFinancialAccountsResourceFinancialAddressSupportedNetworks::UsDomesticWire => {
+                "us_domestic_wire"
+            }
         }
     }
 }
@@ -460,8 +823,12 @@ pub enum TreasuryFinancialAccountsResourcePlatformRestrictionsInboundFlows {
 impl TreasuryFinancialAccountsResourcePlatformRestrictionsInboundFlows {
     pub fn as_str(self) -> &'static str {
         match self {
-            TreasuryFinancialAccountsResourcePlatformRestrictionsInboundFlows::Restricted => "restricted",
-            TreasuryFinancialAccountsResourcePlatformRestrictionsInboundFlows::Unrestricted => "unrestricted",
+            TreasuryFinancialAccountsResourcePlatformRestrictionsInboundFlows::Restricted => {
+                "restricted"
+            }
+            TreasuryFinancialAccountsResourcePlatformRestrictionsInboundFlows::Unrestricted => {
+                "unrestricted"
+            }
         }
     }
 }
@@ -494,8 +861,12 @@ pub enum TreasuryFinancialAccountsResourcePlatformRestrictionsOutboundFlows {
 impl TreasuryFinancialAccountsResourcePlatformRestrictionsOutboundFlows {
     pub fn as_str(self) -> &'static str {
         match self {
-            TreasuryFinancialAccountsResourcePlatformRestrictionsOutboundFlows::Restricted => "restricted",
-            TreasuryFinancialAccountsResourcePlatformRestrictionsOutboundFlows::Unrestricted => "unrestricted",
+            TreasuryFinancialAccountsResourcePlatformRestrictionsOutboundFlows::Restricted => {
+                "restricted"
+            }
+            TreasuryFinancialAccountsResourcePlatformRestrictionsOutboundFlows::Unrestricted => {
+                "unrestricted"
+            }
         }
     }
 }
@@ -2,11 +2,13 @@
 // This file was automatically generated.
 // ======================================
 
-use crate::ids::{TreasuryInboundTransferId};
-use crate::params::{Expandable, Metadata, Object, Timestamp};
-use crate::resources::{Currency, TreasurySharedResourceBillingDetails, TreasuryTransaction};
 use serde::{Deserialize, Serialize};
 
+use crate::client::{Client, Response};
+use crate::ids::TreasuryInboundTransferId;
+use crate::params::{Expand, Expandable, List, Metadata, Object, Paginable, Timestamp};
+use crate::resources::{Currency, TreasurySharedResourceBillingDetails, TreasuryTransaction};
+
 /// The resource representing a Stripe "TreasuryInboundTransfersResourceInboundTransfer".
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct TreasuryInboundTransfer {
@@ -76,12 +78,92 @@ pub struct TreasuryInboundTransfer {
     /// The status changes to `failed` if the transfer fails.
     pub status: TreasuryInboundTransferStatus,
 
-    pub status_transitions: TreasuryInboundTransfersResourceInboundTransferResourceStatusTransitions,
+    pub status_transitions:
+        TreasuryInboundTransfersResourceInboundTransferResourceStatusTransitions,
 
     /// The Transaction associated with this object.
     pub transaction: Option<Expandable<TreasuryTransaction>>,
 }
 
+impl TreasuryInboundTransfer {
+    /// Returns a list of InboundTransfers sent from the specified FinancialAccount.
+    pub fn list(
+        client: &Client,
+        params: &ListTreasuryInboundTransfers<'_>,
+    ) -> Response<List<TreasuryInboundTransfer>> {
+        client.get_query("/treasury/inbound_transfers", &params)
+    }
+
+    /// Creates an InboundTransfer.
+    pub fn create(
+        client: &Client,
+        params: CreateTreasuryInboundTransfer<'_>,
+    ) -> Response<TreasuryInboundTransfer> {
+        client.post_form("/treasury/inbound_transfers", &params)
+    }
+
+    /// Retrieves the details of an existing InboundTransfer.
+    pub fn retrieve(
+        client: &Client,
+        id: &TreasuryInboundTransferId,
+        expand: &[&str],
+    ) -> Response<TreasuryInboundTransfer> {
+        client.get_query(&format!("/treasury/inbound_transfers/{}", id), &Expand { expand })
+    }
+
+    /// Cancels an InboundTransfer.
+    pub fn cancel(
+        client: &Client,
+        id: &TreasuryInboundTransferId,
+        params: CancelTreasuryInboundTransfer<'_>,
+    ) -> Response<TreasuryInboundTransfer> {
+        client.post_form(&format!("/treasury/inbound_transfers/{}/cancel", id), &params)
+    }
+}
+
+/// Test mode requests that simulate the lifecycle of a [`TreasuryInboundTransfer`].
+///
+/// Stripe rejects these requests in live mode.
+#[derive(Clone, Copy, Debug)]
+pub struct TestHelpersTreasuryInboundTransfer;
+
+impl TestHelpersTreasuryInboundTransfer {
+    /// Transitions a test mode created InboundTransfer to the `failed` status.
+    ///
+    /// The InboundTransfer must already be in the `processing` state.
+    pub fn fail(
+        client: &Client,
+        id: &TreasuryInboundTransferId,
+        params: FailTreasuryInboundTransfer<'_>,
+    ) -> Response<TreasuryInboundTransfer> {
+        client.post_form(&format!("/test_helpers/treasury/inbound_transfers/{}/fail", id), &params)
+    }
+
+    /// Marks the test mode InboundTransfer object as returned and links the InboundTransfer to a ReceivedDebit.
+    ///
+    /// The InboundTransfer must already be in the `succeeded` state.
+    pub fn return_inbound_transfer(
+        client: &Client,
+        id: &TreasuryInboundTransferId,
+        params: ReturnTreasuryInboundTransfer<'_>,
+    ) -> Response<TreasuryInboundTransfer> {
+        client
+            .post_form(&format!("/test_helpers/treasury/inbound_transfers/{}/return", id), &params)
+    }
+
+    /// Transitions a test mode created InboundTransfer to the `succeeded` status.
+    ///
+    /// The InboundTransfer must already be in the `processing` state.
+    pub fn succeed(
+        client: &Client,
+        id: &TreasuryInboundTransferId,
+        params: SucceedTreasuryInboundTransfer<'_>,
+    ) -> Response<TreasuryInboundTransfer> {
+        client
+            .post_form(&format!("/test_helpers/treasury/inbound_transfers/{}/succeed", id), &params)
+    }
+}
+
 impl Object for TreasuryInboundTransfer {
     type Id = TreasuryInboundTransferId;
     fn id(&self) -> Self::Id {
@@ -94,7 +176,6 @@ impl Object for TreasuryInboundTransfer {
 
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct InboundTransfers {
-
     pub billing_details: TreasurySharedResourceBillingDetails,
 
     /// The type of the payment method used in the InboundTransfer.
@@ -107,9 +188,9 @@ pub struct InboundTransfers {
 
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct InboundTransfersPaymentMethodDetailsUsBankAccount {
-
     /// Account holder type: individual or company.
-    pub account_holder_type: Option<InboundTransfersPaymentMethodDetailsUsBankAccountAccountHolderType>,
+    pub account_holder_type:
+        Option<InboundTransfersPaymentMethodDetailsUsBankAccountAccountHolderType>,
 
     /// Account type: checkings or savings.
     ///
@@ -136,21 +217,18 @@ pub struct InboundTransfersPaymentMethodDetailsUsBankAccount {
 
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct TreasuryInboundTransfersResourceFailureDetails {
-
     /// Reason for the failure.
     pub code: TreasuryInboundTransfersResourceFailureDetailsCode,
 }
 
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct TreasuryInboundTransfersResourceInboundTransferResourceLinkedFlows {
-
     /// If funds for this flow were returned after the flow went to the `succeeded` state, this field contains a reference to the ReceivedDebit return.
     pub received_debit: Option<String>,
 }
 
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct TreasuryInboundTransfersResourceInboundTransferResourceStatusTransitions {
-
     /// Timestamp describing when an InboundTransfer changed status to `canceled`.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub canceled_at: Option<Timestamp>,
@@ -162,6 +240,191 @@ pub struct TreasuryInboundTransfersResourceInboundTransferResourceStatusTransiti
     pub succeeded_at: Option<Timestamp>,
 }
 
+/// The parameters for `TreasuryInboundTransfer::create`.
+#[derive(Clone, Debug, Serialize)]
+pub struct CreateTreasuryInboundTransfer<'a> {
+    /// Amount (in cents) to be transferred.
+    pub amount: i64,
+
+    /// Three-letter [ISO currency code](https://www.iso.org/iso-4217-currency-codes.html), in lowercase.
+    ///
+    /// Must be a [supported currency](https://stripe.com/docs/currencies).
+    pub currency: Currency,
+
+    /// An arbitrary string attached to the object.
+    ///
+    /// Often useful for displaying to users.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<&'a str>,
+
+    /// Specifies which fields in the response should be expanded.
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+
+    /// The FinancialAccount to send funds to.
+    pub financial_account: &'a str,
+
+    /// Set of [key-value pairs](https://stripe.com/docs/api/metadata) that you can attach to an object.
+    ///
+    /// This can be useful for storing additional information about the object in a structured format.
+    /// Individual keys can be unset by posting an empty value to them.
+    /// All keys can be unset by posting an empty value to `metadata`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+
+    /// The origin payment method to be debited for the InboundTransfer.
+    pub origin_payment_method: &'a str,
+
+    /// The complete description that appears on your customers' statements.
+    ///
+    /// Maximum 10 characters.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub statement_descriptor: Option<&'a str>,
+}
+
+impl<'a> CreateTreasuryInboundTransfer<'a> {
+    pub fn new(
+        amount: i64,
+        currency: Currency,
+        financial_account: &'a str,
+        origin_payment_method: &'a str,
+    ) -> Self {
+        CreateTreasuryInboundTransfer {
+            amount,
+            currency,
+            description: Default::default(),
+            expand: Default::default(),
+            financial_account,
+            metadata: Default::default(),
+            origin_payment_method,
+            statement_descriptor: Default::default(),
+        }
+    }
+}
+
+/// The parameters for `TreasuryInboundTransfer::list`.
+#[derive(Clone, Debug, Serialize)]
+pub struct ListTreasuryInboundTransfers<'a> {
+    /// A cursor for use in pagination.
+    ///
+    /// `ending_before` is an object ID that defines your place in the list.
+    /// For instance, if you make a list request and receive 100 objects, starting with `obj_bar`, your subsequent call can include `ending_before=obj_bar` in order to fetch the previous page of the list.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ending_before: Option<TreasuryInboundTransferId>,
+
+    /// Specifies which fields in the response should be expanded.
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+
+    /// Returns objects associated with this FinancialAccount.
+    pub financial_account: &'a str,
+
+    /// A limit on the number of objects to be returned.
+    ///
+    /// Limit can range between 1 and 100, and the default is 10.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u64>,
+
+    /// A cursor for use in pagination.
+    ///
+    /// `starting_after` is an object ID that defines your place in the list.
+    /// For instance, if you make a list request and receive 100 objects, ending with `obj_foo`, your subsequent call can include `starting_after=obj_foo` in order to fetch the next page of the list.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub starting_after: Option<TreasuryInboundTransferId>,
+
+    /// Only return InboundTransfers that have the given status: `processing`, `succeeded`, `failed` or `canceled`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<TreasuryInboundTransferStatus>,
+}
+
+impl<'a> ListTreasuryInboundTransfers<'a> {
+    pub fn new(financial_account: &'a str) -> Self {
+        ListTreasuryInboundTransfers {
+            ending_before: Default::default(),
+            expand: Default::default(),
+            financial_account,
+            limit: Default::default(),
+            starting_after: Default::default(),
+            status: Default::default(),
+        }
+    }
+}
+impl Paginable for ListTreasuryInboundTransfers<'_> {
+    type O = TreasuryInboundTransfer;
+    fn set_last(&mut self, item: Self::O) {
+        self.starting_after = Some(item.id());
+    }
+}
+/// The parameters for `TreasuryInboundTransfer::cancel`.
+#[derive(Clone, Debug, Serialize, Default)]
+pub struct CancelTreasuryInboundTransfer<'a> {
+    /// Specifies which fields in the response should be expanded.
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+}
+
+impl<'a> CancelTreasuryInboundTransfer<'a> {
+    pub fn new() -> Self {
+        CancelTreasuryInboundTransfer { expand: Default::default() }
+    }
+}
+
+/// The parameters for `TestHelpersTreasuryInboundTransfer::fail`.
+#[derive(Clone, Debug, Serialize, Default)]
+pub struct FailTreasuryInboundTransfer<'a> {
+    /// Specifies which fields in the response should be expanded.
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+
+    /// Details about a failed InboundTransfer.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub failure_details: Option<FailTreasuryInboundTransferFailureDetails>,
+}
+
+impl<'a> FailTreasuryInboundTransfer<'a> {
+    pub fn new() -> Self {
+        FailTreasuryInboundTransfer {
+            expand: Default::default(),
+            failure_details: Default::default(),
+        }
+    }
+}
+
+/// The parameters for `TestHelpersTreasuryInboundTransfer::return_inbound_transfer`.
+#[derive(Clone, Debug, Serialize, Default)]
+pub struct ReturnTreasuryInboundTransfer<'a> {
+    /// Specifies which fields in the response should be expanded.
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+}
+
+impl<'a> ReturnTreasuryInboundTransfer<'a> {
+    pub fn new() -> Self {
+        ReturnTreasuryInboundTransfer { expand: Default::default() }
+    }
+}
+
+/// The parameters for `TestHelpersTreasuryInboundTransfer::succeed`.
+#[derive(Clone, Debug, Serialize, Default)]
+pub struct SucceedTreasuryInboundTransfer<'a> {
+    /// Specifies which fields in the response should be expanded.
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+}
+
+impl<'a> SucceedTreasuryInboundTransfer<'a> {
+    pub fn new() -> Self {
+        SucceedTreasuryInboundTransfer { expand: Default::default() }
+    }
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct FailTreasuryInboundTransferFailureDetails {
+    /// Reason for the failure.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub code: Option<TreasuryInboundTransfersResourceFailureDetailsCode>,
+}
+
 /// An enum representing the possible values of an `InboundTransfersPaymentMethodDetailsUsBankAccount`'s `account_holder_type` field.
 #[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
 #[serde(rename_all = "snake_case")]
@@ -173,8 +436,12 @@ pub enum InboundTransfersPaymentMethodDetailsUsBankAccountAccountHolderType {
 impl InboundTransfersPaymentMethodDetailsUsBankAccountAccountHolderType {
     pub fn as_str(self) -> &'static str {
         match self {
-            InboundTransfersPaymentMethodDetailsUsBankAccountAccountHolderType::Company => "company",
-            InboundTransfersPaymentMethodDetailsUsBankAccountAccountHolderType::Individual => "individual",
+            InboundTransfersPaymentMethodDetailsUsBankAccountAccountHolderType::Company => {
+                "company"
+            }
+            InboundTransfersPaymentMethodDetailsUsBankAccountAccountHolderType::Individual => {
+                "individual"
+            }
         }
     }
 }
@@ -356,15 +623,33 @@ impl TreasuryInboundTransfersResourceFailureDetailsCode {
         match self {
             TreasuryInboundTransfersResourceFailureDetailsCode::AccountClosed => "account_closed",
             TreasuryInboundTransfersResourceFailureDetailsCode::AccountFrozen => "account_frozen",
-            TreasuryInboundTransfersResourceFailureDetailsCode::BankAccountRestricted => "bank_account_restricted",
-            TreasuryInboundTransfersResourceFailureDetailsCode::BankOwnershipChanged => "bank_ownership_changed",
-            TreasuryInboundTransfersResourceFailureDetailsCode::DebitNotAuthorized => "debit_not_authorized",
-            TreasuryInboundTransfersResourceFailureDetailsCode::IncorrectAccountHolderAddress => "incorrect_account_holder_address",
-            TreasuryInboundTransfersResourceFailureDetailsCode::IncorrectAccountHolderName => "incorrect_account_holder_name",
-            TreasuryInboundTransfersResourceFailureDetailsCode::IncorrectAccountHolderTaxId => "incorrect_account_holder_tax_id",
-            TreasuryInboundTransfersResourceFailureDetailsCode::InsufficientFunds => "insufficient_funds",
-            TreasuryInboundTransfersResourceFailureDetailsCode::InvalidAccountNumber => "invalid_account_number",
-            TreasuryInboundTransfersResourceFailureDetailsCode::InvalidCurrency => "invalid_currency",
+            TreasuryInboundTransfersResourceFailureDetailsCode::BankAccountRestricted => {
+                "bank_account_restricted"
+            }
+            TreasuryInboundTransfersResourceFailureDetailsCode::BankOwnershipChanged => {
+                "bank_ownership_changed"
+            }
+            TreasuryInboundTransfersResourceFailureDetailsCode::DebitNotAuthorized => {
+                "debit_not_authorized"
+            }
+            TreasuryInboundTransfersResourceFailureDetailsCode::IncorrectAccountHolderAddress => {
+                "incorrect_account_holder_address"
+            }
+            TreasuryInboundTransfersResourceFailureDetailsCode::IncorrectAccountHolderName => {
+                "incorrect_account_holder_name"
+            }
+            TreasuryInboundTransfersResourceFailureDetailsCode::IncorrectAccountHolderTaxId => {
+                "incorrect_account_holder_tax_id"
+            }
+            TreasuryInboundTransfersResourceFailureDetailsCode::InsufficientFunds => {
+                "insufficient_funds"
+            }
+            TreasuryInboundTransfersResourceFailureDetailsCode::InvalidAccountNumber => {
+                "invalid_account_number"
+            }
+            TreasuryInboundTransfersResourceFailureDetailsCode::InvalidCurrency => {
+                "invalid_currency"
+            }
             TreasuryInboundTransfersResourceFailureDetailsCode::NoAccount => "no_account",
             TreasuryInboundTransfersResourceFailureDetailsCode::Other => "other",
         }
@@ -2,11 +2,15 @@
 // This file was automatically generated.
 // ======================================
 
-use crate::ids::{TreasuryOutboundPaymentId};
-use crate::params::{Expandable, Metadata, Object, Timestamp};
-use crate::resources::{Currency, TreasurySharedResourceBillingDetails, TreasuryTransaction};
 use serde::{Deserialize, Serialize};
 
+use crate::client::{Client, Response};
+use crate::ids::{CustomerId, TreasuryOutboundPaymentId};
+use crate::params::{Expand, Expandable, List, Metadata, Object, Paginable, Timestamp};
+use crate::resources::{
+    BillingDetails, Currency, TreasurySharedResourceBillingDetails, TreasuryTransaction,
+};
+
 /// The resource representing a Stripe "TreasuryOutboundPaymentsResourceOutboundPayment".
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct TreasuryOutboundPayment {
@@ -46,7 +50,8 @@ pub struct TreasuryOutboundPayment {
     pub destination_payment_method_details: Option<OutboundPaymentsPaymentMethodDetails>,
 
     /// Details about the end user.
-    pub end_user_details: Option<TreasuryOutboundPaymentsResourceOutboundPaymentResourceEndUserDetails>,
+    pub end_user_details:
+        Option<TreasuryOutboundPaymentsResourceOutboundPaymentResourceEndUserDetails>,
 
     /// The date when funds are expected to arrive in the destination account.
     pub expected_arrival_date: Timestamp,
@@ -80,12 +85,91 @@ pub struct TreasuryOutboundPayment {
     /// If an OutboundPayment fails to arrive at its destination, its status will change to `returned`.
     pub status: TreasuryOutboundPaymentStatus,
 
-    pub status_transitions: TreasuryOutboundPaymentsResourceOutboundPaymentResourceStatusTransitions,
+    pub status_transitions:
+        TreasuryOutboundPaymentsResourceOutboundPaymentResourceStatusTransitions,
 
     /// The Transaction associated with this object.
     pub transaction: Expandable<TreasuryTransaction>,
 }
 
+impl TreasuryOutboundPayment {
+    /// Returns a list of OutboundPayments sent from the specified FinancialAccount.
+    pub fn list(
+        client: &Client,
+        params: &ListTreasuryOutboundPayments<'_>,
+    ) -> Response<List<TreasuryOutboundPayment>> {
+        client.get_query("/treasury/outbound_payments", &params)
+    }
+
+    /// Creates an OutboundPayment.
+    pub fn create(
+        client: &Client,
+        params: CreateTreasuryOutboundPayment<'_>,
+    ) -> Response<TreasuryOutboundPayment> {
+        client.post_form("/treasury/outbound_payments", &params)
+    }
+
+    /// Retrieves the details of an existing OutboundPayment by passing the unique OutboundPayment ID from either the OutboundPayment creation request or OutboundPayment list.
+    pub fn retrieve(
+        client: &Client,
+        id: &TreasuryOutboundPaymentId,
+        expand: &[&str],
+    ) -> Response<TreasuryOutboundPayment> {
+        client.get_query(&format!("/treasury/outbound_payments/{}", id), &Expand { expand })
+    }
+
+    /// Cancel an OutboundPayment.
+    pub fn cancel(
+        client: &Client,
+        id: &TreasuryOutboundPaymentId,
+        params: CancelTreasuryOutboundPayment<'_>,
+    ) -> Response<TreasuryOutboundPayment> {
+        client.post_form(&format!("/treasury/outbound_payments/{}/cancel", id), &params)
+    }
+}
+
+/// Test mode requests that simulate the lifecycle of a [`TreasuryOutboundPayment`].
+///
+/// Stripe rejects these requests in live mode.
+#[derive(Clone, Copy, Debug)]
+pub struct TestHelpersTreasuryOutboundPayment;
+
+impl TestHelpersTreasuryOutboundPayment {
+    /// Transitions a test mode created OutboundPayment to the `failed` status.
+    ///
+    /// The OutboundPayment must already be in the `processing` state.
+    pub fn fail(
+        client: &Client,
+        id: &TreasuryOutboundPaymentId,
+        params: FailTreasuryOutboundPayment<'_>,
+    ) -> Response<TreasuryOutboundPayment> {
+        client.post_form(&format!("/test_helpers/treasury/outbound_payments/{}/fail", id), &params)
+    }
+
+    /// Transitions a test mode created OutboundPayment to the `posted` status.
+    ///
+    /// The OutboundPayment must already be in the `processing` state.
+    pub fn post(
+        client: &Client,
+        id: &TreasuryOutboundPaymentId,
+        params: PostTreasuryOutboundPayment<'_>,
+    ) -> Response<TreasuryOutboundPayment> {
+        client.post_form(&format!("/test_helpers/treasury/outbound_payments/{}/post", id), &params)
+    }
+
+    /// Transitions a test mode created OutboundPayment to the `returned` status.
+    ///
+    /// The OutboundPayment must already be in the `processing` state.
+    pub fn return_outbound_payment(
+        client: &Client,
+        id: &TreasuryOutboundPaymentId,
+        params: ReturnTreasuryOutboundPayment<'_>,
+    ) -> Response<TreasuryOutboundPayment> {
+        client
+            .post_form(&format!("/test_helpers/treasury/outbound_payments/{}/return", id), &params)
+    }
+}
+
 impl Object for TreasuryOutboundPayment {
     type Id = TreasuryOutboundPaymentId;
     fn id(&self) -> Self::Id {
@@ -98,7 +182,6 @@ impl Object for TreasuryOutboundPayment {
 
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct OutboundPaymentsPaymentMethodDetails {
-
     pub billing_details: TreasurySharedResourceBillingDetails,
 
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -114,7 +197,6 @@ pub struct OutboundPaymentsPaymentMethodDetails {
 
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct OutboundPaymentsPaymentMethodDetailsFinancialAccount {
-
     /// Token of the FinancialAccount.
     pub id: String,
 
@@ -124,9 +206,9 @@ pub struct OutboundPaymentsPaymentMethodDetailsFinancialAccount {
 
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct OutboundPaymentsPaymentMethodDetailsUsBankAccount {
-
     /// Account holder type: individual or company.
-    pub account_holder_type: Option<OutboundPaymentsPaymentMethodDetailsUsBankAccountAccountHolderType>,
+    pub account_holder_type:
+        Option<OutboundPaymentsPaymentMethodDetailsUsBankAccountAccountHolderType>,
 
     /// Account type: checkings or savings.
     ///
@@ -153,7 +235,6 @@ pub struct OutboundPaymentsPaymentMethodDetailsUsBankAccount {
 
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct TreasuryOutboundPaymentsResourceOutboundPaymentResourceEndUserDetails {
-
     /// IP address of the user initiating the OutboundPayment.
     ///
     /// Set if `present` is set to `true`.
@@ -169,7 +250,6 @@ pub struct TreasuryOutboundPaymentsResourceOutboundPaymentResourceEndUserDetails
 
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct TreasuryOutboundPaymentsResourceOutboundPaymentResourceStatusTransitions {
-
     /// Timestamp describing when an OutboundPayment changed status to `canceled`.
     pub canceled_at: Option<Timestamp>,
 
@@ -185,7 +265,6 @@ pub struct TreasuryOutboundPaymentsResourceOutboundPaymentResourceStatusTransiti
 
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct TreasuryOutboundPaymentsResourceReturnedStatus {
-
     /// Reason for the return.
     pub code: TreasuryOutboundPaymentsResourceReturnedStatusCode,
 
@@ -193,6 +272,313 @@ pub struct TreasuryOutboundPaymentsResourceReturnedStatus {
     pub transaction: Expandable<TreasuryTransaction>,
 }
 
+/// The parameters for `TreasuryOutboundPayment::create`.
+#[derive(Clone, Debug, Serialize)]
+pub struct CreateTreasuryOutboundPayment<'a> {
+    /// Amount (in cents) to be transferred.
+    pub amount: i64,
+
+    /// Three-letter [ISO currency code](https://www.iso.org/iso-4217-currency-codes.html), in lowercase.
+    ///
+    /// Must be a [supported currency](https://stripe.com/docs/currencies).
+    pub currency: Currency,
+
+    /// ID of the customer to whom the OutboundPayment is sent.
+    ///
+    /// Must match the Customer attached to the `destination_payment_method` passed in.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub customer: Option<CustomerId>,
+
+    /// An arbitrary string attached to the object.
+    ///
+    /// Often useful for displaying to users.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<&'a str>,
+
+    /// The PaymentMethod to use as the payment instrument for the OutboundPayment.
+    ///
+    /// Exclusive with `destination_payment_method_data`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub destination_payment_method: Option<&'a str>,
+
+    /// Hash used to generate the PaymentMethod to be used for this OutboundPayment.
+    ///
+    /// Exclusive with `destination_payment_method`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub destination_payment_method_data:
+        Option<CreateTreasuryOutboundPaymentDestinationPaymentMethodData>,
+
+    /// Payment method-specific configuration for this OutboundPayment.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub destination_payment_method_options:
+        Option<CreateTreasuryOutboundPaymentDestinationPaymentMethodOptions>,
+
+    /// End user details.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_user_details: Option<CreateTreasuryOutboundPaymentEndUserDetails>,
+
+    /// Specifies which fields in the response should be expanded.
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+
+    /// The FinancialAccount to pull funds from.
+    pub financial_account: &'a str,
+
+    /// Set of [key-value pairs](https://stripe.com/docs/api/metadata) that you can attach to an object.
+    ///
+    /// This can be useful for storing additional information about the object in a structured format.
+    /// Individual keys can be unset by posting an empty value to them.
+    /// All keys can be unset by posting an empty value to `metadata`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+
+    /// The description that appears on the receiving end for this OutboundPayment (for example, bank statement for external bank transfer).
+    ///
+    /// Maximum 10 characters for `ach` payments, 140 characters for `wire` payments, or 500 characters for `stripe` network transfers.
+    /// The default value is `payment`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub statement_descriptor: Option<&'a str>,
+}
+
+impl<'a> CreateTreasuryOutboundPayment<'a> {
+    pub fn new(amount: i64, currency: Currency, financial_account: &'a str) -> Self {
+        CreateTreasuryOutboundPayment {
+            amount,
+            currency,
+            customer: Default::default(),
+            description: Default::default(),
+            destination_payment_method: Default::default(),
+            destination_payment_method_data: Default::default(),
+            destination_payment_method_options: Default::default(),
+            end_user_details: Default::default(),
+            expand: Default::default(),
+            financial_account,
+            metadata: Default::default(),
+            statement_descriptor: Default::default(),
+        }
+    }
+}
+
+/// The parameters for `TreasuryOutboundPayment::list`.
+#[derive(Clone, Debug, Serialize)]
+pub struct ListTreasuryOutboundPayments<'a> {
+    /// Only return OutboundPayments sent to this customer.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub customer: Option<&'a str>,
+
+    /// A cursor for use in pagination.
+    ///
+    /// `ending_before` is an object ID that defines your place in the list.
+    /// For instance, if you make a list request and receive 100 objects, starting with `obj_bar`, your subsequent call can include `ending_before=obj_bar` in order to fetch the previous page of the list.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ending_before: Option<TreasuryOutboundPaymentId>,
+
+    /// Specifies which fields in the response should be expanded.
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+
+    /// Returns objects associated with this FinancialAccount.
+    pub financial_account: &'a str,
+
+    /// A limit on the number of objects to be returned.
+    ///
+    /// Limit can range between 1 and 100, and the default is 10.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u64>,
+
+    /// A cursor for use in pagination.
+    ///
+    /// `starting_after` is an object ID that defines your place in the list.
+    /// For instance, if you make a list request and receive 100 objects, ending with `obj_foo`, your subsequent call can include `starting_after=obj_foo` in order to fetch the next page of the list.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub starting_after: Option<TreasuryOutboundPaymentId>,
+
+    /// Only return OutboundPayments that have the given status: `processing`, `failed`, `posted`, `returned`, or `canceled`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<TreasuryOutboundPaymentStatus>,
+}
+
+impl<'a> ListTreasuryOutboundPayments<'a> {
+    pub fn new(financial_account: &'a str) -> Self {
+        ListTreasuryOutboundPayments {
+            customer: Default::default(),
+            ending_before: Default::default(),
+            expand: Default::default(),
+            financial_account,
+            limit: Default::default(),
+            starting_after: Default::default(),
+            status: Default::default(),
+        }
+    }
+}
+impl Paginable for ListTreasuryOutboundPayments<'_> {
+    type O = TreasuryOutboundPayment;
+    fn set_last(&mut self, item: Self::O) {
+        self.starting_after = Some(item.id());
+    }
+}
+/// The parameters for `TreasuryOutboundPayment::cancel`.
+#[derive(Clone, Debug, Serialize, Default)]
+pub struct CancelTreasuryOutboundPayment<'a> {
+    /// Specifies which fields in the response should be expanded.
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+}
+
+impl<'a> CancelTreasuryOutboundPayment<'a> {
+    pub fn new() -> Self {
+        CancelTreasuryOutboundPayment { expand: Default::default() }
+    }
+}
+
+/// The parameters for `TestHelpersTreasuryOutboundPayment::fail`.
+#[derive(Clone, Debug, Serialize, Default)]
+pub struct FailTreasuryOutboundPayment<'a> {
+    /// Specifies which fields in the response should be expanded.
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+}
+
+impl<'a> FailTreasuryOutboundPayment<'a> {
+    pub fn new() -> Self {
+        FailTreasuryOutboundPayment { expand: Default::default() }
+    }
+}
+
+/// The parameters for `TestHelpersTreasuryOutboundPayment::post`.
+#[derive(Clone, Debug, Serialize, Default)]
+pub struct PostTreasuryOutboundPayment<'a> {
+    /// Specifies which fields in the response should be expanded.
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+}
+
+impl<'a> PostTreasuryOutboundPayment<'a> {
+    pub fn new() -> Self {
+        PostTreasuryOutboundPayment { expand: Default::default() }
+    }
+}
+
+/// The parameters for `TestHelpersTreasuryOutboundPayment::return_outbound_payment`.
+#[derive(Clone, Debug, Serialize, Default)]
+pub struct ReturnTreasuryOutboundPayment<'a> {
+    /// Specifies which fields in the response should be expanded.
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+
+    /// Optional hash to set the the return code.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub returned_details: Option<ReturnTreasuryOutboundPaymentReturnedDetails>,
+}
+
+impl<'a> ReturnTreasuryOutboundPayment<'a> {
+    pub fn new() -> Self {
+        ReturnTreasuryOutboundPayment {
+            expand: Default::default(),
+            returned_details: Default::default(),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct CreateTreasuryOutboundPaymentDestinationPaymentMethodData {
+    /// Billing information associated with the PaymentMethod that may be used or required by particular types of payment methods.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub billing_details: Option<BillingDetails>,
+
+    /// Required if type is set to `financial_account`.
+    ///
+    /// The FinancialAccount ID to send funds to.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub financial_account: Option<String>,
+
+    /// Set of [key-value pairs](https://stripe.com/docs/api/metadata) that you can attach to an object.
+    ///
+    /// This can be useful for storing additional information about the object in a structured format.
+    /// Individual keys can be unset by posting an empty value to them.
+    /// All keys can be unset by posting an empty value to `metadata`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+
+    /// The type of the PaymentMethod.
+    ///
+    /// An additional hash is included on the PaymentMethod with a name matching this value.
+    /// It contains additional information specific to the PaymentMethod type.
+    #[serde(rename = "type")]
+    pub type_: CreateTreasuryOutboundPaymentDestinationPaymentMethodDataType,
+
+    /// Required hash if type is set to `us_bank_account`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub us_bank_account:
+        Option<CreateTreasuryOutboundPaymentDestinationPaymentMethodDataUsBankAccount>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct CreateTreasuryOutboundPaymentDestinationPaymentMethodDataUsBankAccount {
+    /// Account holder type: individual or company.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub account_holder_type: Option<
+        CreateTreasuryOutboundPaymentDestinationPaymentMethodDataUsBankAccountAccountHolderType,
+    >,
+
+    /// Account number of the bank account.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub account_number: Option<String>,
+
+    /// Account type: checkings or savings.
+    ///
+    /// Defaults to checking if omitted.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub account_type:
+        Option<CreateTreasuryOutboundPaymentDestinationPaymentMethodDataUsBankAccountAccountType>,
+
+    /// The ID of a Financial Connections Account to use as a payment method.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub financial_connections_account: Option<String>,
+
+    /// Routing number of the bank account.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub routing_number: Option<String>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct CreateTreasuryOutboundPaymentDestinationPaymentMethodOptions {
+    /// Optional fields for `us_bank_account`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub us_bank_account:
+        Option<CreateTreasuryOutboundPaymentDestinationPaymentMethodOptionsUsBankAccount>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct CreateTreasuryOutboundPaymentDestinationPaymentMethodOptionsUsBankAccount {
+    /// The US bank account network that must be used for this OutboundPayment.
+    ///
+    /// If not set, we will default to the PaymentMethod's preferred network.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub network: Option<OutboundPaymentsPaymentMethodDetailsUsBankAccountNetwork>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct CreateTreasuryOutboundPaymentEndUserDetails {
+    /// IP address of the user initiating the OutboundPayment.
+    ///
+    /// Must be supplied if `present` is set to `true`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ip_address: Option<String>,
+
+    /// `True` if the OutboundPayment creation request is being made on behalf of an end user by a platform.
+    ///
+    /// Otherwise, `false`.
+    pub present: bool,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct ReturnTreasuryOutboundPaymentReturnedDetails {
+    /// The return code to be set on the OutboundPayment object.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub code: Option<TreasuryOutboundPaymentsResourceReturnedStatusCode>,
+}
+
 /// An enum representing the possible values of an `OutboundPaymentsPaymentMethodDetailsFinancialAccount`'s `network` field.
 #[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
 #[serde(rename_all = "snake_case")]
@@ -270,8 +656,12 @@ pub enum OutboundPaymentsPaymentMethodDetailsUsBankAccountAccountHolderType {
 impl OutboundPaymentsPaymentMethodDetailsUsBankAccountAccountHolderType {
     pub fn as_str(self) -> &'static str {
         match self {
-            OutboundPaymentsPaymentMethodDetailsUsBankAccountAccountHolderType::Company => "company",
-            OutboundPaymentsPaymentMethodDetailsUsBankAccountAccountHolderType::Individual => "individual",
+            OutboundPaymentsPaymentMethodDetailsUsBankAccountAccountHolderType::Company => {
+                "company"
+            }
+            OutboundPaymentsPaymentMethodDetailsUsBankAccountAccountHolderType::Individual => {
+                "individual"
+            }
         }
     }
 }
@@ -339,7 +729,9 @@ impl OutboundPaymentsPaymentMethodDetailsUsBankAccountNetwork {
     pub fn as_str(self) -> &'static str {
         match self {
             OutboundPaymentsPaymentMethodDetailsUsBankAccountNetwork::Ach => "ach",
-            OutboundPaymentsPaymentMethodDetailsUsBankAccountNetwork::UsDomesticWire => "us_domestic_wire",
+            OutboundPaymentsPaymentMethodDetailsUsBankAccountNetwork::UsDomesticWire => {
+                "us_domestic_wire"
+            }
         }
     }
 }
@@ -422,12 +814,22 @@ impl TreasuryOutboundPaymentsResourceReturnedStatusCode {
         match self {
             TreasuryOutboundPaymentsResourceReturnedStatusCode::AccountClosed => "account_closed",
             TreasuryOutboundPaymentsResourceReturnedStatusCode::AccountFrozen => "account_frozen",
-            TreasuryOutboundPaymentsResourceReturnedStatusCode::BankAccountRestricted => "bank_account_restricted",
-            TreasuryOutboundPaymentsResourceReturnedStatusCode::BankOwnershipChanged => "bank_ownership_changed",
+            TreasuryOutboundPaymentsResourceReturnedStatusCode::BankAccountRestricted => {
+                "bank_account_restricted"
+            }
+            TreasuryOutboundPaymentsResourceReturnedStatusCode::BankOwnershipChanged => {
+                "bank_ownership_changed"
+            }
             TreasuryOutboundPaymentsResourceReturnedStatusCode::Declined => "declined",
-            TreasuryOutboundPaymentsResourceReturnedStatusCode::IncorrectAccountHolderName => "incorrect_account_holder_name",
-            TreasuryOutboundPaymentsResourceReturnedStatusCode::InvalidAccountNumber => "invalid_account_number",
-            TreasuryOutboundPaymentsResourceReturnedStatusCode::InvalidCurrency => "invalid_currency",
+            TreasuryOutboundPaymentsResourceReturnedStatusCode::IncorrectAccountHolderName => {
+                "incorrect_account_holder_name"
+            }
+            TreasuryOutboundPaymentsResourceReturnedStatusCode::InvalidAccountNumber => {
+                "invalid_account_number"
+            }
+            TreasuryOutboundPaymentsResourceReturnedStatusCode::InvalidCurrency => {
+                "invalid_currency"
+            }
             TreasuryOutboundPaymentsResourceReturnedStatusCode::NoAccount => "no_account",
             TreasuryOutboundPaymentsResourceReturnedStatusCode::Other => "other",
         }
@@ -450,3 +852,121 @@ impl std::default::Default for TreasuryOutboundPaymentsResourceReturnedStatusCod
         Self::AccountClosed
     }
 }
+
+/// An enum representing the possible values of an `CreateTreasuryOutboundPaymentDestinationPaymentMethodData`'s `type` field.
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum CreateTreasuryOutboundPaymentDestinationPaymentMethodDataType {
+    FinancialAccount,
+    UsBankAccount,
+}
+
+impl CreateTreasuryOutboundPaymentDestinationPaymentMethodDataType {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            CreateTreasuryOutboundPaymentDestinationPaymentMethodDataType::FinancialAccount => {
+                "financial_account"
+            }
+            CreateTreasuryOutboundPaymentDestinationPaymentMethodDataType::UsBankAccount => {
+                "us_bank_account"
+            }
+        }
+    }
+}
+
+impl AsRef<str> for CreateTreasuryOutboundPaymentDestinationPaymentMethodDataType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::fmt::Display for CreateTreasuryOutboundPaymentDestinationPaymentMethodDataType {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
+impl std::default::Default for CreateTreasuryOutboundPaymentDestinationPaymentMethodDataType {
+    fn default() -> Self {
+        Self::FinancialAccount
+    }
+}
+
+/// An enum representing the possible values of an `CreateTreasuryOutboundPaymentDestinationPaymentMethodDataUsBankAccount`'s `account_holder_type` field.
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum CreateTreasuryOutboundPaymentDestinationPaymentMethodDataUsBankAccountAccountHolderType {
+    Company,
+    Individual,
+}
+
+impl CreateTreasuryOutboundPaymentDestinationPaymentMethodDataUsBankAccountAccountHolderType {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            CreateTreasuryOutboundPaymentDestinationPaymentMethodDataUsBankAccountAccountHolderType::Company => "company",
+            CreateTreasuryOutboundPaymentDestinationPaymentMethodDataUsBankAccountAccountHolderType::Individual => "individual",
+        }
+    }
+}
+
+impl AsRef<str>
+    for CreateTreasuryOutboundPaymentDestinationPaymentMethodDataUsBankAccountAccountHolderType
+{
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::fmt::Display
+    for CreateTreasuryOutboundPaymentDestinationPaymentMethodDataUsBankAccountAccountHolderType
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
+impl std::default::Default
+    for CreateTreasuryOutboundPaymentDestinationPaymentMethodDataUsBankAccountAccountHolderType
+{
+    fn default() -> Self {
+        Self::Company
+    }
+}
+
+/// An enum representing the possible values of an `CreateTreasuryOutboundPaymentDestinationPaymentMethodDataUsBankAccount`'s `account_type` field.
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum CreateTreasuryOutboundPaymentDestinationPaymentMethodDataUsBankAccountAccountType {
+    Checking,
+    Savings,
+}
+
+impl CreateTreasuryOutboundPaymentDestinationPaymentMethodDataUsBankAccountAccountType {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            CreateTreasuryOutboundPaymentDestinationPaymentMethodDataUsBankAccountAccountType::Checking => "checking",
+            CreateTreasuryOutboundPaymentDestinationPaymentMethodDataUsBankAccountAccountType::Savings => "savings",
+        }
+    }
+}
+
+impl AsRef<str>
+    for CreateTreasuryOutboundPaymentDestinationPaymentMethodDataUsBankAccountAccountType
+{
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::fmt::Display
+    for CreateTreasuryOutboundPaymentDestinationPaymentMethodDataUsBankAccountAccountType
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
+impl std::default::Default
+    for CreateTreasuryOutboundPaymentDestinationPaymentMethodDataUsBankAccountAccountType
+{
+    fn default() -> Self {
+        Self::Checking
+    }
+}
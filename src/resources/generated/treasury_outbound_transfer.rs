@@ -2,11 +2,13 @@
 // This file was automatically generated.
 // ======================================
 
-use crate::ids::{TreasuryOutboundTransferId};
-use crate::params::{Expandable, Metadata, Object, Timestamp};
-use crate::resources::{Currency, TreasurySharedResourceBillingDetails, TreasuryTransaction};
 use serde::{Deserialize, Serialize};
 
+use crate::client::{Client, Response};
+use crate::ids::TreasuryOutboundTransferId;
+use crate::params::{Expand, Expandable, List, Metadata, Object, Paginable, Timestamp};
+use crate::resources::{Currency, TreasurySharedResourceBillingDetails, TreasuryTransaction};
+
 /// The resource representing a Stripe "TreasuryOutboundTransfersResourceOutboundTransfer".
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct TreasuryOutboundTransfer {
@@ -77,6 +79,84 @@ pub struct TreasuryOutboundTransfer {
     pub transaction: Expandable<TreasuryTransaction>,
 }
 
+impl TreasuryOutboundTransfer {
+    /// Returns a list of OutboundTransfers sent from the specified FinancialAccount.
+    pub fn list(
+        client: &Client,
+        params: &ListTreasuryOutboundTransfers<'_>,
+    ) -> Response<List<TreasuryOutboundTransfer>> {
+        client.get_query("/treasury/outbound_transfers", &params)
+    }
+
+    /// Creates an OutboundTransfer.
+    pub fn create(
+        client: &Client,
+        params: CreateTreasuryOutboundTransfer<'_>,
+    ) -> Response<TreasuryOutboundTransfer> {
+        client.post_form("/treasury/outbound_transfers", &params)
+    }
+
+    /// Retrieves the details of an existing OutboundTransfer by passing the unique OutboundTransfer ID from either the OutboundTransfer creation request or OutboundTransfer list.
+    pub fn retrieve(
+        client: &Client,
+        id: &TreasuryOutboundTransferId,
+        expand: &[&str],
+    ) -> Response<TreasuryOutboundTransfer> {
+        client.get_query(&format!("/treasury/outbound_transfers/{}", id), &Expand { expand })
+    }
+
+    /// An OutboundTransfer can be canceled if the funds have not yet been paid out.
+    pub fn cancel(
+        client: &Client,
+        id: &TreasuryOutboundTransferId,
+        params: CancelTreasuryOutboundTransfer<'_>,
+    ) -> Response<TreasuryOutboundTransfer> {
+        client.post_form(&format!("/treasury/outbound_transfers/{}/cancel", id), &params)
+    }
+}
+
+/// Test mode requests that simulate the lifecycle of a [`TreasuryOutboundTransfer`].
+///
+/// Stripe rejects these requests in live mode.
+#[derive(Clone, Copy, Debug)]
+pub struct TestHelpersTreasuryOutboundTransfer;
+
+impl TestHelpersTreasuryOutboundTransfer {
+    /// Transitions a test mode created OutboundTransfer to the `failed` status.
+    ///
+    /// The OutboundTransfer must already be in the `processing` state.
+    pub fn fail(
+        client: &Client,
+        id: &TreasuryOutboundTransferId,
+        params: FailTreasuryOutboundTransfer<'_>,
+    ) -> Response<TreasuryOutboundTransfer> {
+        client.post_form(&format!("/test_helpers/treasury/outbound_transfers/{}/fail", id), &params)
+    }
+
+    /// Transitions a test mode created OutboundTransfer to the `posted` status.
+    ///
+    /// The OutboundTransfer must already be in the `processing` state.
+    pub fn post(
+        client: &Client,
+        id: &TreasuryOutboundTransferId,
+        params: PostTreasuryOutboundTransfer<'_>,
+    ) -> Response<TreasuryOutboundTransfer> {
+        client.post_form(&format!("/test_helpers/treasury/outbound_transfers/{}/post", id), &params)
+    }
+
+    /// Transitions a test mode created OutboundTransfer to the `returned` status.
+    ///
+    /// The OutboundTransfer must already be in the `processing` state.
+    pub fn return_outbound_transfer(
+        client: &Client,
+        id: &TreasuryOutboundTransferId,
+        params: ReturnTreasuryOutboundTransfer<'_>,
+    ) -> Response<TreasuryOutboundTransfer> {
+        client
+            .post_form(&format!("/test_helpers/treasury/outbound_transfers/{}/return", id), &params)
+    }
+}
+
 impl Object for TreasuryOutboundTransfer {
     type Id = TreasuryOutboundTransferId;
     fn id(&self) -> Self::Id {
@@ -89,7 +169,6 @@ impl Object for TreasuryOutboundTransfer {
 
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct OutboundTransfersPaymentMethodDetails {
-
     pub billing_details: TreasurySharedResourceBillingDetails,
 
     /// The type of the payment method used in the OutboundTransfer.
@@ -102,9 +181,9 @@ pub struct OutboundTransfersPaymentMethodDetails {
 
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct OutboundTransfersPaymentMethodDetailsUsBankAccount {
-
     /// Account holder type: individual or company.
-    pub account_holder_type: Option<OutboundTransfersPaymentMethodDetailsUsBankAccountAccountHolderType>,
+    pub account_holder_type:
+        Option<OutboundTransfersPaymentMethodDetailsUsBankAccountAccountHolderType>,
 
     /// Account type: checkings or savings.
     ///
@@ -131,7 +210,6 @@ pub struct OutboundTransfersPaymentMethodDetailsUsBankAccount {
 
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct TreasuryOutboundTransfersResourceReturnedDetails {
-
     /// Reason for the return.
     pub code: TreasuryOutboundTransfersResourceReturnedDetailsCode,
 
@@ -141,7 +219,6 @@ pub struct TreasuryOutboundTransfersResourceReturnedDetails {
 
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct TreasuryOutboundTransfersResourceStatusTransitions {
-
     /// Timestamp describing when an OutboundTransfer changed status to `canceled`.
     pub canceled_at: Option<Timestamp>,
 
@@ -155,6 +232,211 @@ pub struct TreasuryOutboundTransfersResourceStatusTransitions {
     pub returned_at: Option<Timestamp>,
 }
 
+/// The parameters for `TreasuryOutboundTransfer::create`.
+#[derive(Clone, Debug, Serialize)]
+pub struct CreateTreasuryOutboundTransfer<'a> {
+    /// Amount (in cents) to be transferred.
+    pub amount: i64,
+
+    /// Three-letter [ISO currency code](https://www.iso.org/iso-4217-currency-codes.html), in lowercase.
+    ///
+    /// Must be a [supported currency](https://stripe.com/docs/currencies).
+    pub currency: Currency,
+
+    /// An arbitrary string attached to the object.
+    ///
+    /// Often useful for displaying to users.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<&'a str>,
+
+    /// The PaymentMethod to use as the payment instrument for the OutboundTransfer.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub destination_payment_method: Option<&'a str>,
+
+    /// Hash describing payment method configuration details.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub destination_payment_method_options:
+        Option<CreateTreasuryOutboundTransferDestinationPaymentMethodOptions>,
+
+    /// Specifies which fields in the response should be expanded.
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+
+    /// The FinancialAccount to pull funds from.
+    pub financial_account: &'a str,
+
+    /// Set of [key-value pairs](https://stripe.com/docs/api/metadata) that you can attach to an object.
+    ///
+    /// This can be useful for storing additional information about the object in a structured format.
+    /// Individual keys can be unset by posting an empty value to them.
+    /// All keys can be unset by posting an empty value to `metadata`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+
+    /// Statement descriptor to be shown on the receiving end of an OutboundTransfer.
+    ///
+    /// Maximum 10 characters for `ach` transfers or 140 characters for `wire` transfers.
+    /// The default value is `transfer`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub statement_descriptor: Option<&'a str>,
+}
+
+impl<'a> CreateTreasuryOutboundTransfer<'a> {
+    pub fn new(amount: i64, currency: Currency, financial_account: &'a str) -> Self {
+        CreateTreasuryOutboundTransfer {
+            amount,
+            currency,
+            description: Default::default(),
+            destination_payment_method: Default::default(),
+            destination_payment_method_options: Default::default(),
+            expand: Default::default(),
+            financial_account,
+            metadata: Default::default(),
+            statement_descriptor: Default::default(),
+        }
+    }
+}
+
+/// The parameters for `TreasuryOutboundTransfer::list`.
+#[derive(Clone, Debug, Serialize)]
+pub struct ListTreasuryOutboundTransfers<'a> {
+    /// A cursor for use in pagination.
+    ///
+    /// `ending_before` is an object ID that defines your place in the list.
+    /// For instance, if you make a list request and receive 100 objects, starting with `obj_bar`, your subsequent call can include `ending_before=obj_bar` in order to fetch the previous page of the list.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ending_before: Option<TreasuryOutboundTransferId>,
+
+    /// Specifies which fields in the response should be expanded.
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+
+    /// Returns objects associated with this FinancialAccount.
+    pub financial_account: &'a str,
+
+    /// A limit on the number of objects to be returned.
+    ///
+    /// Limit can range between 1 and 100, and the default is 10.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u64>,
+
+    /// A cursor for use in pagination.
+    ///
+    /// `starting_after` is an object ID that defines your place in the list.
+    /// For instance, if you make a list request and receive 100 objects, ending with `obj_foo`, your subsequent call can include `starting_after=obj_foo` in order to fetch the next page of the list.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub starting_after: Option<TreasuryOutboundTransferId>,
+
+    /// Only return OutboundTransfers that have the given status: `processing`, `canceled`, `failed`, `posted`, or `returned`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<TreasuryOutboundTransferStatus>,
+}
+
+impl<'a> ListTreasuryOutboundTransfers<'a> {
+    pub fn new(financial_account: &'a str) -> Self {
+        ListTreasuryOutboundTransfers {
+            ending_before: Default::default(),
+            expand: Default::default(),
+            financial_account,
+            limit: Default::default(),
+            starting_after: Default::default(),
+            status: Default::default(),
+        }
+    }
+}
+impl Paginable for ListTreasuryOutboundTransfers<'_> {
+    type O = TreasuryOutboundTransfer;
+    fn set_last(&mut self, item: Self::O) {
+        self.starting_after = Some(item.id());
+    }
+}
+/// The parameters for `TreasuryOutboundTransfer::cancel`.
+#[derive(Clone, Debug, Serialize, Default)]
+pub struct CancelTreasuryOutboundTransfer<'a> {
+    /// Specifies which fields in the response should be expanded.
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+}
+
+impl<'a> CancelTreasuryOutboundTransfer<'a> {
+    pub fn new() -> Self {
+        CancelTreasuryOutboundTransfer { expand: Default::default() }
+    }
+}
+
+/// The parameters for `TestHelpersTreasuryOutboundTransfer::fail`.
+#[derive(Clone, Debug, Serialize, Default)]
+pub struct FailTreasuryOutboundTransfer<'a> {
+    /// Specifies which fields in the response should be expanded.
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+}
+
+impl<'a> FailTreasuryOutboundTransfer<'a> {
+    pub fn new() -> Self {
+        FailTreasuryOutboundTransfer { expand: Default::default() }
+    }
+}
+
+/// The parameters for `TestHelpersTreasuryOutboundTransfer::post`.
+#[derive(Clone, Debug, Serialize, Default)]
+pub struct PostTreasuryOutboundTransfer<'a> {
+    /// Specifies which fields in the response should be expanded.
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+}
+
+impl<'a> PostTreasuryOutboundTransfer<'a> {
+    pub fn new() -> Self {
+        PostTreasuryOutboundTransfer { expand: Default::default() }
+    }
+}
+
+/// The parameters for `TestHelpersTreasuryOutboundTransfer::return_outbound_transfer`.
+#[derive(Clone, Debug, Serialize, Default)]
+pub struct ReturnTreasuryOutboundTransfer<'a> {
+    /// Specifies which fields in the response should be expanded.
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+
+    /// Details about a returned OutboundTransfer.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub returned_details: Option<ReturnTreasuryOutboundTransferReturnedDetails>,
+}
+
+impl<'a> ReturnTreasuryOutboundTransfer<'a> {
+    pub fn new() -> Self {
+        ReturnTreasuryOutboundTransfer {
+            expand: Default::default(),
+            returned_details: Default::default(),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct CreateTreasuryOutboundTransferDestinationPaymentMethodOptions {
+    /// Optional fields for `us_bank_account`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub us_bank_account:
+        Option<CreateTreasuryOutboundTransferDestinationPaymentMethodOptionsUsBankAccount>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct CreateTreasuryOutboundTransferDestinationPaymentMethodOptionsUsBankAccount {
+    /// The US bank account network that must be used for this OutboundTransfer.
+    ///
+    /// If not set, we will default to the PaymentMethod's preferred network.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub network: Option<OutboundTransfersPaymentMethodDetailsUsBankAccountNetwork>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct ReturnTreasuryOutboundTransferReturnedDetails {
+    /// Reason for the return.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub code: Option<TreasuryOutboundTransfersResourceReturnedDetailsCode>,
+}
+
 /// An enum representing the possible values of an `OutboundTransfersPaymentMethodDetails`'s `type` field.
 #[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
 #[serde(rename_all = "snake_case")]
@@ -198,8 +480,12 @@ pub enum OutboundTransfersPaymentMethodDetailsUsBankAccountAccountHolderType {
 impl OutboundTransfersPaymentMethodDetailsUsBankAccountAccountHolderType {
     pub fn as_str(self) -> &'static str {
         match self {
-            OutboundTransfersPaymentMethodDetailsUsBankAccountAccountHolderType::Company => "company",
-            OutboundTransfersPaymentMethodDetailsUsBankAccountAccountHolderType::Individual => "individual",
+            OutboundTransfersPaymentMethodDetailsUsBankAccountAccountHolderType::Company => {
+                "company"
+            }
+            OutboundTransfersPaymentMethodDetailsUsBankAccountAccountHolderType::Individual => {
+                "individual"
+            }
         }
     }
 }
@@ -267,7 +553,9 @@ impl OutboundTransfersPaymentMethodDetailsUsBankAccountNetwork {
     pub fn as_str(self) -> &'static str {
         match self {
             OutboundTransfersPaymentMethodDetailsUsBankAccountNetwork::Ach => "ach",
-            OutboundTransfersPaymentMethodDetailsUsBankAccountNetwork::UsDomesticWire => "us_domestic_wire",
+            OutboundTransfersPaymentMethodDetailsUsBankAccountNetwork::UsDomesticWire => {
+                "us_domestic_wire"
+            }
         }
     }
 }
@@ -350,12 +638,22 @@ impl TreasuryOutboundTransfersResourceReturnedDetailsCode {
         match self {
             TreasuryOutboundTransfersResourceReturnedDetailsCode::AccountClosed => "account_closed",
             TreasuryOutboundTransfersResourceReturnedDetailsCode::AccountFrozen => "account_frozen",
-            TreasuryOutboundTransfersResourceReturnedDetailsCode::BankAccountRestricted => "bank_account_restricted",
-            TreasuryOutboundTransfersResourceReturnedDetailsCode::BankOwnershipChanged => "bank_ownership_changed",
+            TreasuryOutboundTransfersResourceReturnedDetailsCode::BankAccountRestricted => {
+                "bank_account_restricted"
+            }
+            TreasuryOutboundTransfersResourceReturnedDetailsCode::BankOwnershipChanged => {
+                "bank_ownership_changed"
+            }
             TreasuryOutboundTransfersResourceReturnedDetailsCode::Declined => "declined",
-            TreasuryOutboundTransfersResourceReturnedDetailsCode::IncorrectAccountHolderName => "incorrect_account_holder_name",
-            TreasuryOutboundTransfersResourceReturnedDetailsCode::InvalidAccountNumber => "invalid_account_number",
-            TreasuryOutboundTransfersResourceReturnedDetailsCode::InvalidCurrency => "invalid_currency",
+            TreasuryOutboundTransfersResourceReturnedDetailsCode::IncorrectAccountHolderName => {
+                "incorrect_account_holder_name"
+            }
+            TreasuryOutboundTransfersResourceReturnedDetailsCode::InvalidAccountNumber => {
+                "invalid_account_number"
+            }
+            TreasuryOutboundTransfersResourceReturnedDetailsCode::InvalidCurrency => {
+                "invalid_currency"
+            }
             TreasuryOutboundTransfersResourceReturnedDetailsCode::NoAccount => "no_account",
             TreasuryOutboundTransfersResourceReturnedDetailsCode::Other => "other",
         }
@@ -2,11 +2,17 @@
 // This file was automatically generated.
 // ======================================
 
-use crate::ids::{TreasuryReceivedCreditId};
-use crate::params::{Expandable, Object, Timestamp};
-use crate::resources::{Currency, Payout, TreasuryCreditReversal, TreasuryOutboundPayment, TreasurySharedResourceInitiatingPaymentMethodDetailsInitiatingPaymentMethodDetails, TreasuryTransaction};
 use serde::{Deserialize, Serialize};
 
+use crate::client::{Client, Response};
+use crate::ids::TreasuryReceivedCreditId;
+use crate::params::{Expand, Expandable, List, Object, Paginable, Timestamp};
+use crate::resources::{
+    Currency, Payout, TreasuryCreditReversal, TreasuryOutboundPayment,
+    TreasurySharedResourceInitiatingPaymentMethodDetailsInitiatingPaymentMethodDetails,
+    TreasuryTransaction,
+};
+
 /// The resource representing a Stripe "TreasuryReceivedCreditsResourceReceivedCredit".
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct TreasuryReceivedCredit {
@@ -42,7 +48,8 @@ pub struct TreasuryReceivedCredit {
     /// A [hosted transaction receipt](https://stripe.com/docs/treasury/moving-money/regulatory-receipts) URL that is provided when money movement is considered regulated under Stripe's money transmission licenses.
     pub hosted_regulatory_receipt_url: Option<String>,
 
-    pub initiating_payment_method_details: TreasurySharedResourceInitiatingPaymentMethodDetailsInitiatingPaymentMethodDetails,
+    pub initiating_payment_method_details:
+        TreasurySharedResourceInitiatingPaymentMethodDetailsInitiatingPaymentMethodDetails,
 
     pub linked_flows: TreasuryReceivedCreditsResourceLinkedFlows,
 
@@ -65,6 +72,43 @@ pub struct TreasuryReceivedCredit {
     pub transaction: Option<Expandable<TreasuryTransaction>>,
 }
 
+impl TreasuryReceivedCredit {
+    /// Returns a list of ReceivedCredits.
+    pub fn list(
+        client: &Client,
+        params: &ListTreasuryReceivedCredits<'_>,
+    ) -> Response<List<TreasuryReceivedCredit>> {
+        client.get_query("/treasury/received_credits", &params)
+    }
+
+    /// Retrieves the details of an existing ReceivedCredit by passing the unique ReceivedCredit ID from the ReceivedCredit list.
+    pub fn retrieve(
+        client: &Client,
+        id: &TreasuryReceivedCreditId,
+        expand: &[&str],
+    ) -> Response<TreasuryReceivedCredit> {
+        client.get_query(&format!("/treasury/received_credits/{}", id), &Expand { expand })
+    }
+}
+
+/// Test mode requests that simulate the lifecycle of a [`TreasuryReceivedCredit`].
+///
+/// Stripe rejects these requests in live mode.
+#[derive(Clone, Copy, Debug)]
+pub struct TestHelpersTreasuryReceivedCredit;
+
+impl TestHelpersTreasuryReceivedCredit {
+    /// Use this endpoint to simulate a test mode ReceivedCredit initiated by a third party.
+    ///
+    /// In live mode, you can't directly create ReceivedCredits initiated by third parties.
+    pub fn create(
+        client: &Client,
+        params: CreateTreasuryReceivedCredit<'_>,
+    ) -> Response<TreasuryReceivedCredit> {
+        client.post_form("/test_helpers/treasury/received_credits", &params)
+    }
+}
+
 impl Object for TreasuryReceivedCredit {
     type Id = TreasuryReceivedCreditId;
     fn id(&self) -> Self::Id {
@@ -77,7 +121,6 @@ impl Object for TreasuryReceivedCredit {
 
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct TreasuryReceivedCreditsResourceLinkedFlows {
-
     /// The CreditReversal created as a result of this ReceivedCredit being reversed.
     pub credit_reversal: Option<String>,
 
@@ -102,7 +145,6 @@ pub struct TreasuryReceivedCreditsResourceLinkedFlows {
 
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct TreasuryReceivedCreditsResourceReversalDetails {
-
     /// Time before which a ReceivedCredit can be reversed.
     pub deadline: Option<Timestamp>,
 
@@ -112,7 +154,6 @@ pub struct TreasuryReceivedCreditsResourceReversalDetails {
 
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct TreasuryReceivedCreditsResourceSourceFlowsDetails {
-
     #[serde(skip_serializing_if = "Option::is_none")]
     pub credit_reversal: Option<TreasuryCreditReversal>,
 
@@ -127,6 +168,149 @@ pub struct TreasuryReceivedCreditsResourceSourceFlowsDetails {
     pub type_: TreasuryReceivedCreditsResourceSourceFlowsDetailsType,
 }
 
+/// The parameters for `TestHelpersTreasuryReceivedCredit::create`.
+#[derive(Clone, Debug, Serialize)]
+pub struct CreateTreasuryReceivedCredit<'a> {
+    /// Amount (in cents) to be transferred.
+    pub amount: i64,
+
+    /// Three-letter [ISO currency code](https://www.iso.org/iso-4217-currency-codes.html), in lowercase.
+    ///
+    /// Must be a [supported currency](https://stripe.com/docs/currencies).
+    pub currency: Currency,
+
+    /// An arbitrary string attached to the object.
+    ///
+    /// Often useful for displaying to users.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<&'a str>,
+
+    /// Specifies which fields in the response should be expanded.
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+
+    /// The FinancialAccount to send funds to.
+    pub financial_account: &'a str,
+
+    /// Initiating payment method details for the object.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub initiating_payment_method_details:
+        Option<CreateTreasuryReceivedCreditInitiatingPaymentMethodDetails>,
+
+    /// The rails used for the object.
+    pub network: CreateTreasuryReceivedCreditNetwork,
+}
+
+impl<'a> CreateTreasuryReceivedCredit<'a> {
+    pub fn new(
+        amount: i64,
+        currency: Currency,
+        financial_account: &'a str,
+        network: CreateTreasuryReceivedCreditNetwork,
+    ) -> Self {
+        CreateTreasuryReceivedCredit {
+            amount,
+            currency,
+            description: Default::default(),
+            expand: Default::default(),
+            financial_account,
+            initiating_payment_method_details: Default::default(),
+            network,
+        }
+    }
+}
+
+/// The parameters for `TreasuryReceivedCredit::list`.
+#[derive(Clone, Debug, Serialize)]
+pub struct ListTreasuryReceivedCredits<'a> {
+    /// A cursor for use in pagination.
+    ///
+    /// `ending_before` is an object ID that defines your place in the list.
+    /// For instance, if you make a list request and receive 100 objects, starting with `obj_bar`, your subsequent call can include `ending_before=obj_bar` in order to fetch the previous page of the list.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ending_before: Option<TreasuryReceivedCreditId>,
+
+    /// Specifies which fields in the response should be expanded.
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+
+    /// Returns objects associated with this FinancialAccount.
+    pub financial_account: &'a str,
+
+    /// A limit on the number of objects to be returned.
+    ///
+    /// Limit can range between 1 and 100, and the default is 10.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u64>,
+
+    /// Only return ReceivedCredits described by the flow.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub linked_flows: Option<ListTreasuryReceivedCreditsLinkedFlows>,
+
+    /// A cursor for use in pagination.
+    ///
+    /// `starting_after` is an object ID that defines your place in the list.
+    /// For instance, if you make a list request and receive 100 objects, ending with `obj_foo`, your subsequent call can include `starting_after=obj_foo` in order to fetch the next page of the list.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub starting_after: Option<TreasuryReceivedCreditId>,
+
+    /// Only return ReceivedCredits that have the given status: `succeeded` or `failed`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<TreasuryReceivedCreditStatus>,
+}
+
+impl<'a> ListTreasuryReceivedCredits<'a> {
+    pub fn new(financial_account: &'a str) -> Self {
+        ListTreasuryReceivedCredits {
+            ending_before: Default::default(),
+            expand: Default::default(),
+            financial_account,
+            limit: Default::default(),
+            linked_flows: Default::default(),
+            starting_after: Default::default(),
+            status: Default::default(),
+        }
+    }
+}
+impl Paginable for ListTreasuryReceivedCredits<'_> {
+    type O = TreasuryReceivedCredit;
+    fn set_last(&mut self, item: Self::O) {
+        self.starting_after = Some(item.id());
+    }
+}
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct CreateTreasuryReceivedCreditInitiatingPaymentMethodDetails {
+    /// The source type.
+    #[serde(rename = "type")]
+    pub type_: CreateTreasuryReceivedCreditInitiatingPaymentMethodDetailsType,
+
+    /// Optional fields for `us_bank_account`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub us_bank_account:
+        Option<CreateTreasuryReceivedCreditInitiatingPaymentMethodDetailsUsBankAccount>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct CreateTreasuryReceivedCreditInitiatingPaymentMethodDetailsUsBankAccount {
+    /// The bank account holder's name.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub account_holder_name: Option<String>,
+
+    /// The bank account number.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub account_number: Option<String>,
+
+    /// The bank account's routing number.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub routing_number: Option<String>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct ListTreasuryReceivedCreditsLinkedFlows {
+    /// The source flow type.
+    pub source_flow_type: TreasuryReceivedCreditsResourceSourceFlowsDetailsType,
+}
+
 /// An enum representing the possible values of an `TreasuryReceivedCredit`'s `failure_code` field.
 #[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
 #[serde(rename_all = "snake_case")]
@@ -288,9 +472,13 @@ pub enum TreasuryReceivedCreditsResourceSourceFlowsDetailsType {
 impl TreasuryReceivedCreditsResourceSourceFlowsDetailsType {
     pub fn as_str(self) -> &'static str {
         match self {
-            TreasuryReceivedCreditsResourceSourceFlowsDetailsType::CreditReversal => "credit_reversal",
+            TreasuryReceivedCreditsResourceSourceFlowsDetailsType::CreditReversal => {
+                "credit_reversal"
+            }
             TreasuryReceivedCreditsResourceSourceFlowsDetailsType::Other => "other",
-            TreasuryReceivedCreditsResourceSourceFlowsDetailsType::OutboundPayment => "outbound_payment",
+            TreasuryReceivedCreditsResourceSourceFlowsDetailsType::OutboundPayment => {
+                "outbound_payment"
+            }
             TreasuryReceivedCreditsResourceSourceFlowsDetailsType::Payout => "payout",
         }
     }
@@ -312,3 +500,71 @@ impl std::default::Default for TreasuryReceivedCreditsResourceSourceFlowsDetails
         Self::CreditReversal
     }
 }
+
+/// An enum representing the possible values of an `CreateTreasuryReceivedCredit`'s `network` field.
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum CreateTreasuryReceivedCreditNetwork {
+    Ach,
+    UsDomesticWire,
+}
+
+impl CreateTreasuryReceivedCreditNetwork {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            CreateTreasuryReceivedCreditNetwork::Ach => "ach",
+            CreateTreasuryReceivedCreditNetwork::UsDomesticWire => "us_domestic_wire",
+        }
+    }
+}
+
+impl AsRef<str> for CreateTreasuryReceivedCreditNetwork {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::fmt::Display for CreateTreasuryReceivedCreditNetwork {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
+impl std::default::Default for CreateTreasuryReceivedCreditNetwork {
+    fn default() -> Self {
+        Self::Ach
+    }
+}
+
+/// An enum representing the possible values of an `CreateTreasuryReceivedCreditInitiatingPaymentMethodDetails`'s `type` field.
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum CreateTreasuryReceivedCreditInitiatingPaymentMethodDetailsType {
+    UsBankAccount,
+}
+
+impl CreateTreasuryReceivedCreditInitiatingPaymentMethodDetailsType {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            CreateTreasuryReceivedCreditInitiatingPaymentMethodDetailsType::UsBankAccount => {
+                "us_bank_account"
+            }
+        }
+    }
+}
+
+impl AsRef<str> for CreateTreasuryReceivedCreditInitiatingPaymentMethodDetailsType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::fmt::Display for CreateTreasuryReceivedCreditInitiatingPaymentMethodDetailsType {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
+impl std::default::Default for CreateTreasuryReceivedCreditInitiatingPaymentMethodDetailsType {
+    fn default() -> Self {
+        Self::UsBankAccount
+    }
+}
@@ -2,11 +2,16 @@
 // This file was automatically generated.
 // ======================================
 
-use crate::ids::{TreasuryReceivedDebitId};
-use crate::params::{Expandable, Object, Timestamp};
-use crate::resources::{Currency, TreasurySharedResourceInitiatingPaymentMethodDetailsInitiatingPaymentMethodDetails, TreasuryTransaction};
 use serde::{Deserialize, Serialize};
 
+use crate::client::{Client, Response};
+use crate::ids::TreasuryReceivedDebitId;
+use crate::params::{Expand, Expandable, List, Object, Paginable, Timestamp};
+use crate::resources::{
+    Currency, TreasurySharedResourceInitiatingPaymentMethodDetailsInitiatingPaymentMethodDetails,
+    TreasuryTransaction,
+};
+
 /// The resource representing a Stripe "TreasuryReceivedDebitsResourceReceivedDebit".
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct TreasuryReceivedDebit {
@@ -43,7 +48,8 @@ pub struct TreasuryReceivedDebit {
     pub hosted_regulatory_receipt_url: Option<String>,
 
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub initiating_payment_method_details: Option<TreasurySharedResourceInitiatingPaymentMethodDetailsInitiatingPaymentMethodDetails>,
+    pub initiating_payment_method_details:
+        Option<TreasurySharedResourceInitiatingPaymentMethodDetailsInitiatingPaymentMethodDetails>,
 
     pub linked_flows: TreasuryReceivedDebitsResourceLinkedFlows,
 
@@ -66,6 +72,43 @@ pub struct TreasuryReceivedDebit {
     pub transaction: Option<Expandable<TreasuryTransaction>>,
 }
 
+impl TreasuryReceivedDebit {
+    /// Returns a list of ReceivedDebits.
+    pub fn list(
+        client: &Client,
+        params: &ListTreasuryReceivedDebits<'_>,
+    ) -> Response<List<TreasuryReceivedDebit>> {
+        client.get_query("/treasury/received_debits", &params)
+    }
+
+    /// Retrieves the details of an existing ReceivedDebit by passing the unique ReceivedDebit ID from the ReceivedDebit list.
+    pub fn retrieve(
+        client: &Client,
+        id: &TreasuryReceivedDebitId,
+        expand: &[&str],
+    ) -> Response<TreasuryReceivedDebit> {
+        client.get_query(&format!("/treasury/received_debits/{}", id), &Expand { expand })
+    }
+}
+
+/// Test mode requests that simulate the lifecycle of a [`TreasuryReceivedDebit`].
+///
+/// Stripe rejects these requests in live mode.
+#[derive(Clone, Copy, Debug)]
+pub struct TestHelpersTreasuryReceivedDebit;
+
+impl TestHelpersTreasuryReceivedDebit {
+    /// Use this endpoint to simulate a test mode ReceivedDebit initiated by a third party.
+    ///
+    /// In live mode, you can't directly create ReceivedDebits initiated by third parties.
+    pub fn create(
+        client: &Client,
+        params: CreateTreasuryReceivedDebit<'_>,
+    ) -> Response<TreasuryReceivedDebit> {
+        client.post_form("/test_helpers/treasury/received_debits", &params)
+    }
+}
+
 impl Object for TreasuryReceivedDebit {
     type Id = TreasuryReceivedDebitId;
     fn id(&self) -> Self::Id {
@@ -78,7 +121,6 @@ impl Object for TreasuryReceivedDebit {
 
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct TreasuryReceivedDebitsResourceLinkedFlows {
-
     /// The DebitReversal created as a result of this ReceivedDebit being reversed.
     pub debit_reversal: Option<String>,
 
@@ -94,7 +136,6 @@ pub struct TreasuryReceivedDebitsResourceLinkedFlows {
 
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct TreasuryReceivedDebitsResourceReversalDetails {
-
     /// Time before which a ReceivedDebit can be reversed.
     pub deadline: Option<Timestamp>,
 
@@ -102,6 +143,138 @@ pub struct TreasuryReceivedDebitsResourceReversalDetails {
     pub restricted_reason: Option<TreasuryReceivedDebitsResourceReversalDetailsRestrictedReason>,
 }
 
+/// The parameters for `TestHelpersTreasuryReceivedDebit::create`.
+#[derive(Clone, Debug, Serialize)]
+pub struct CreateTreasuryReceivedDebit<'a> {
+    /// Amount (in cents) to be transferred.
+    pub amount: i64,
+
+    /// Three-letter [ISO currency code](https://www.iso.org/iso-4217-currency-codes.html), in lowercase.
+    ///
+    /// Must be a [supported currency](https://stripe.com/docs/currencies).
+    pub currency: Currency,
+
+    /// An arbitrary string attached to the object.
+    ///
+    /// Often useful for displaying to users.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<&'a str>,
+
+    /// Specifies which fields in the response should be expanded.
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+
+    /// The FinancialAccount to pull funds from.
+    pub financial_account: &'a str,
+
+    /// Initiating payment method details for the object.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub initiating_payment_method_details:
+        Option<CreateTreasuryReceivedDebitInitiatingPaymentMethodDetails>,
+
+    /// The rails used for the object.
+    pub network: CreateTreasuryReceivedDebitNetwork,
+}
+
+impl<'a> CreateTreasuryReceivedDebit<'a> {
+    pub fn new(
+        amount: i64,
+        currency: Currency,
+        financial_account: &'a str,
+        network: CreateTreasuryReceivedDebitNetwork,
+    ) -> Self {
+        CreateTreasuryReceivedDebit {
+            amount,
+            currency,
+            description: Default::default(),
+            expand: Default::default(),
+            financial_account,
+            initiating_payment_method_details: Default::default(),
+            network,
+        }
+    }
+}
+
+/// The parameters for `TreasuryReceivedDebit::list`.
+#[derive(Clone, Debug, Serialize)]
+pub struct ListTreasuryReceivedDebits<'a> {
+    /// A cursor for use in pagination.
+    ///
+    /// `ending_before` is an object ID that defines your place in the list.
+    /// For instance, if you make a list request and receive 100 objects, starting with `obj_bar`, your subsequent call can include `ending_before=obj_bar` in order to fetch the previous page of the list.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ending_before: Option<TreasuryReceivedDebitId>,
+
+    /// Specifies which fields in the response should be expanded.
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+
+    /// Returns objects associated with this FinancialAccount.
+    pub financial_account: &'a str,
+
+    /// A limit on the number of objects to be returned.
+    ///
+    /// Limit can range between 1 and 100, and the default is 10.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u64>,
+
+    /// A cursor for use in pagination.
+    ///
+    /// `starting_after` is an object ID that defines your place in the list.
+    /// For instance, if you make a list request and receive 100 objects, ending with `obj_foo`, your subsequent call can include `starting_after=obj_foo` in order to fetch the next page of the list.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub starting_after: Option<TreasuryReceivedDebitId>,
+
+    /// Only return ReceivedDebits that have the given status: `succeeded` or `failed`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<TreasuryReceivedDebitStatus>,
+}
+
+impl<'a> ListTreasuryReceivedDebits<'a> {
+    pub fn new(financial_account: &'a str) -> Self {
+        ListTreasuryReceivedDebits {
+            ending_before: Default::default(),
+            expand: Default::default(),
+            financial_account,
+            limit: Default::default(),
+            starting_after: Default::default(),
+            status: Default::default(),
+        }
+    }
+}
+impl Paginable for ListTreasuryReceivedDebits<'_> {
+    type O = TreasuryReceivedDebit;
+    fn set_last(&mut self, item: Self::O) {
+        self.starting_after = Some(item.id());
+    }
+}
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct CreateTreasuryReceivedDebitInitiatingPaymentMethodDetails {
+    /// The source type.
+    #[serde(rename = "type")]
+    pub type_: CreateTreasuryReceivedDebitInitiatingPaymentMethodDetailsType,
+
+    /// Optional fields for `us_bank_account`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub us_bank_account:
+        Option<CreateTreasuryReceivedDebitInitiatingPaymentMethodDetailsUsBankAccount>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct CreateTreasuryReceivedDebitInitiatingPaymentMethodDetailsUsBankAccount {
+    /// The bank account holder's name.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub account_holder_name: Option<String>,
+
+    /// The bank account number.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub account_number: Option<String>,
+
+    /// The bank account's routing number.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub routing_number: Option<String>,
+}
+
 /// An enum representing the possible values of an `TreasuryReceivedDebit`'s `failure_code` field.
 #[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
 #[serde(rename_all = "snake_case")]
@@ -224,11 +397,19 @@ pub enum TreasuryReceivedDebitsResourceReversalDetailsRestrictedReason {
 impl TreasuryReceivedDebitsResourceReversalDetailsRestrictedReason {
     pub fn as_str(self) -> &'static str {
         match self {
-            TreasuryReceivedDebitsResourceReversalDetailsRestrictedReason::AlreadyReversed => "already_reversed",
-            TreasuryReceivedDebitsResourceReversalDetailsRestrictedReason::DeadlinePassed => "deadline_passed",
-            TreasuryReceivedDebitsResourceReversalDetailsRestrictedReason::NetworkRestricted => "network_restricted",
+            TreasuryReceivedDebitsResourceReversalDetailsRestrictedReason::AlreadyReversed => {
+                "already_reversed"
+            }
+            TreasuryReceivedDebitsResourceReversalDetailsRestrictedReason::DeadlinePassed => {
+                "deadline_passed"
+            }
+            TreasuryReceivedDebitsResourceReversalDetailsRestrictedReason::NetworkRestricted => {
+                "network_restricted"
+            }
             TreasuryReceivedDebitsResourceReversalDetailsRestrictedReason::Other => "other",
-            TreasuryReceivedDebitsResourceReversalDetailsRestrictedReason::SourceFlowRestricted => "source_flow_restricted",
+            TreasuryReceivedDebitsResourceReversalDetailsRestrictedReason::SourceFlowRestricted => {
+                "source_flow_restricted"
+            }
         }
     }
 }
@@ -249,3 +430,69 @@ impl std::default::Default for TreasuryReceivedDebitsResourceReversalDetailsRest
         Self::AlreadyReversed
     }
 }
+
+/// An enum representing the possible values of an `CreateTreasuryReceivedDebit`'s `network` field.
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum CreateTreasuryReceivedDebitNetwork {
+    Ach,
+}
+
+impl CreateTreasuryReceivedDebitNetwork {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            CreateTreasuryReceivedDebitNetwork::Ach => "ach",
+        }
+    }
+}
+
+impl AsRef<str> for CreateTreasuryReceivedDebitNetwork {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::fmt::Display for CreateTreasuryReceivedDebitNetwork {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
+impl std::default::Default for CreateTreasuryReceivedDebitNetwork {
+    fn default() -> Self {
+        Self::Ach
+    }
+}
+
+/// An enum representing the possible values of an `CreateTreasuryReceivedDebitInitiatingPaymentMethodDetails`'s `type` field.
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum CreateTreasuryReceivedDebitInitiatingPaymentMethodDetailsType {
+    UsBankAccount,
+}
+
+impl CreateTreasuryReceivedDebitInitiatingPaymentMethodDetailsType {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            CreateTreasuryReceivedDebitInitiatingPaymentMethodDetailsType::UsBankAccount => {
+                "us_bank_account"
+            }
+        }
+    }
+}
+
+impl AsRef<str> for CreateTreasuryReceivedDebitInitiatingPaymentMethodDetailsType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::fmt::Display for CreateTreasuryReceivedDebitInitiatingPaymentMethodDetailsType {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
+impl std::default::Default for CreateTreasuryReceivedDebitInitiatingPaymentMethodDetailsType {
+    fn default() -> Self {
+        Self::UsBankAccount
+    }
+}
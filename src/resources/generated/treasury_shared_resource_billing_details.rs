@@ -2,13 +2,12 @@
 // This file was automatically generated.
 // ======================================
 
-use crate::resources::{Address};
+use crate::resources::Address;
 use serde::{Deserialize, Serialize};
 
 /// The resource representing a Stripe "TreasurySharedResourceBillingDetails".
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct TreasurySharedResourceBillingDetails {
-
     pub address: Address,
 
     /// Email address.
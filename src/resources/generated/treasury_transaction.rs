@@ -2,11 +2,16 @@
 // This file was automatically generated.
 // ======================================
 
-use crate::ids::{TreasuryTransactionId};
-use crate::params::{List, Object, Timestamp};
-use crate::resources::{Currency, TreasuryTransactionEntry, TreasuryTransactionsResourceBalanceImpact, TreasuryTransactionsResourceFlowDetails};
 use serde::{Deserialize, Serialize};
 
+use crate::client::{Client, Response};
+use crate::ids::TreasuryTransactionId;
+use crate::params::{Expand, List, Object, Paginable, RangeQuery, Timestamp};
+use crate::resources::{
+    Currency, TreasuryTransactionEntry, TreasuryTransactionsResourceBalanceImpact,
+    TreasuryTransactionsResourceFlowDetails,
+};
+
 /// The resource representing a Stripe "TreasuryTransactionsResourceTransaction".
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct TreasuryTransaction {
@@ -56,7 +61,27 @@ pub struct TreasuryTransaction {
     /// Status of the Transaction.
     pub status: TreasuryTransactionStatus,
 
-    pub status_transitions: TreasuryTransactionsResourceAbstractTransactionResourceStatusTransitions,
+    pub status_transitions:
+        TreasuryTransactionsResourceAbstractTransactionResourceStatusTransitions,
+}
+
+impl TreasuryTransaction {
+    /// Retrieves a list of Transaction objects.
+    pub fn list(
+        client: &Client,
+        params: &ListTreasuryTransactions<'_>,
+    ) -> Response<List<TreasuryTransaction>> {
+        client.get_query("/treasury/transactions", &params)
+    }
+
+    /// Retrieves the details of an existing Transaction.
+    pub fn retrieve(
+        client: &Client,
+        id: &TreasuryTransactionId,
+        expand: &[&str],
+    ) -> Response<TreasuryTransaction> {
+        client.get_query(&format!("/treasury/transactions/{}", id), &Expand { expand })
+    }
 }
 
 impl Object for TreasuryTransaction {
@@ -71,7 +96,6 @@ impl Object for TreasuryTransaction {
 
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct TreasuryTransactionsResourceAbstractTransactionResourceStatusTransitions {
-
     /// Timestamp describing when the Transaction changed status to `posted`.
     pub posted_at: Option<Timestamp>,
 
@@ -79,6 +103,84 @@ pub struct TreasuryTransactionsResourceAbstractTransactionResourceStatusTransiti
     pub void_at: Option<Timestamp>,
 }
 
+/// The parameters for `TreasuryTransaction::list`.
+#[derive(Clone, Debug, Serialize)]
+pub struct ListTreasuryTransactions<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created: Option<RangeQuery<Timestamp>>,
+
+    /// A cursor for use in pagination.
+    ///
+    /// `ending_before` is an object ID that defines your place in the list.
+    /// For instance, if you make a list request and receive 100 objects, starting with `obj_bar`, your subsequent call can include `ending_before=obj_bar` in order to fetch the previous page of the list.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ending_before: Option<TreasuryTransactionId>,
+
+    /// Specifies which fields in the response should be expanded.
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+
+    /// Returns objects associated with this FinancialAccount.
+    pub financial_account: &'a str,
+
+    /// A limit on the number of objects to be returned.
+    ///
+    /// Limit can range between 1 and 100, and the default is 10.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u64>,
+
+    /// The results are in reverse chronological order by `created` or `posted_at`.
+    ///
+    /// The default is `created`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub order_by: Option<TreasuryTransactionOrderBy>,
+
+    /// A cursor for use in pagination.
+    ///
+    /// `starting_after` is an object ID that defines your place in the list.
+    /// For instance, if you make a list request and receive 100 objects, ending with `obj_foo`, your subsequent call can include `starting_after=obj_foo` in order to fetch the next page of the list.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub starting_after: Option<TreasuryTransactionId>,
+
+    /// Only return Transactions that have the given status: `open`, `posted`, or `void`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<TreasuryTransactionStatus>,
+
+    /// A filter for the `status_transitions.posted_at` timestamp.
+    ///
+    /// When using this filter, `status=posted` and `order_by=posted_at` must also be specified.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status_transitions: Option<ListTreasuryTransactionsStatusTransitions>,
+}
+
+impl<'a> ListTreasuryTransactions<'a> {
+    pub fn new(financial_account: &'a str) -> Self {
+        ListTreasuryTransactions {
+            created: Default::default(),
+            ending_before: Default::default(),
+            expand: Default::default(),
+            financial_account,
+            limit: Default::default(),
+            order_by: Default::default(),
+            starting_after: Default::default(),
+            status: Default::default(),
+            status_transitions: Default::default(),
+        }
+    }
+}
+impl Paginable for ListTreasuryTransactions<'_> {
+    type O = TreasuryTransaction;
+    fn set_last(&mut self, item: Self::O) {
+        self.starting_after = Some(item.id());
+    }
+}
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct ListTreasuryTransactionsStatusTransitions {
+    /// Returns Transactions with `posted_at` within the specified range.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub posted_at: Option<RangeQuery<Timestamp>>,
+}
+
 /// An enum representing the possible values of an `TreasuryTransaction`'s `flow_type` field.
 #[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
 #[serde(rename_all = "snake_case")]
@@ -162,3 +264,37 @@ impl std::default::Default for TreasuryTransactionStatus {
         Self::Open
     }
 }
+
+/// An enum representing the possible values of an `ListTreasuryTransactions`'s `order_by` field.
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum TreasuryTransactionOrderBy {
+    Created,
+    PostedAt,
+}
+
+impl TreasuryTransactionOrderBy {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            TreasuryTransactionOrderBy::Created => "created",
+            TreasuryTransactionOrderBy::PostedAt => "posted_at",
+        }
+    }
+}
+
+impl AsRef<str> for TreasuryTransactionOrderBy {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::fmt::Display for TreasuryTransactionOrderBy {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
+impl std::default::Default for TreasuryTransactionOrderBy {
+    fn default() -> Self {
+        Self::Created
+    }
+}
@@ -2,11 +2,16 @@
 // This file was automatically generated.
 // ======================================
 
-use crate::ids::{TreasuryTransactionEntryId};
-use crate::params::{Expandable, Object, Timestamp};
-use crate::resources::{Currency, TreasuryTransaction, TreasuryTransactionsResourceBalanceImpact, TreasuryTransactionsResourceFlowDetails};
 use serde::{Deserialize, Serialize};
 
+use crate::client::{Client, Response};
+use crate::ids::TreasuryTransactionEntryId;
+use crate::params::{Expand, Expandable, List, Object, Paginable, RangeQuery, Timestamp};
+use crate::resources::{
+    Currency, TreasuryTransaction, TreasuryTransactionsResourceBalanceImpact,
+    TreasuryTransactionsResourceFlowDetails,
+};
+
 /// The resource representing a Stripe "TreasuryTransactionsResourceTransactionEntry".
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct TreasuryTransactionEntry {
@@ -51,6 +56,25 @@ pub struct TreasuryTransactionEntry {
     pub type_: TreasuryTransactionEntryType,
 }
 
+impl TreasuryTransactionEntry {
+    /// Retrieves a list of TransactionEntry objects.
+    pub fn list(
+        client: &Client,
+        params: &ListTreasuryTransactionEntries<'_>,
+    ) -> Response<List<TreasuryTransactionEntry>> {
+        client.get_query("/treasury/transaction_entries", &params)
+    }
+
+    /// Retrieves a TransactionEntry object.
+    pub fn retrieve(
+        client: &Client,
+        id: &TreasuryTransactionEntryId,
+        expand: &[&str],
+    ) -> Response<TreasuryTransactionEntry> {
+        client.get_query(&format!("/treasury/transaction_entries/{}", id), &Expand { expand })
+    }
+}
+
 impl Object for TreasuryTransactionEntry {
     type Id = TreasuryTransactionEntryId;
     fn id(&self) -> Self::Id {
@@ -61,6 +85,75 @@ impl Object for TreasuryTransactionEntry {
     }
 }
 
+/// The parameters for `TreasuryTransactionEntry::list`.
+#[derive(Clone, Debug, Serialize)]
+pub struct ListTreasuryTransactionEntries<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created: Option<RangeQuery<Timestamp>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub effective_at: Option<RangeQuery<Timestamp>>,
+
+    /// A cursor for use in pagination.
+    ///
+    /// `ending_before` is an object ID that defines your place in the list.
+    /// For instance, if you make a list request and receive 100 objects, starting with `obj_bar`, your subsequent call can include `ending_before=obj_bar` in order to fetch the previous page of the list.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ending_before: Option<TreasuryTransactionEntryId>,
+
+    /// Specifies which fields in the response should be expanded.
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+
+    /// Returns objects associated with this FinancialAccount.
+    pub financial_account: &'a str,
+
+    /// A limit on the number of objects to be returned.
+    ///
+    /// Limit can range between 1 and 100, and the default is 10.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u64>,
+
+    /// The results are in reverse chronological order by `created` or `effective_at`.
+    ///
+    /// The default is `created`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub order_by: Option<TreasuryTransactionEntryOrderBy>,
+
+    /// A cursor for use in pagination.
+    ///
+    /// `starting_after` is an object ID that defines your place in the list.
+    /// For instance, if you make a list request and receive 100 objects, ending with `obj_foo`, your subsequent call can include `starting_after=obj_foo` in order to fetch the next page of the list.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub starting_after: Option<TreasuryTransactionEntryId>,
+
+    /// Only return TransactionEntries associated with this Transaction.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub transaction: Option<&'a str>,
+}
+
+impl<'a> ListTreasuryTransactionEntries<'a> {
+    pub fn new(financial_account: &'a str) -> Self {
+        ListTreasuryTransactionEntries {
+            created: Default::default(),
+            effective_at: Default::default(),
+            ending_before: Default::default(),
+            expand: Default::default(),
+            financial_account,
+            limit: Default::default(),
+            order_by: Default::default(),
+            starting_after: Default::default(),
+            transaction: Default::default(),
+        }
+    }
+}
+impl Paginable for ListTreasuryTransactionEntries<'_> {
+    type O = TreasuryTransactionEntry;
+    fn set_last(&mut self, item: Self::O) {
+        self.starting_after = Some(item.id());
+    }
+}
+
 /// An enum representing the possible values of an `TreasuryTransactionEntry`'s `flow_type` field.
 #[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
 #[serde(rename_all = "snake_case")]
@@ -144,15 +237,21 @@ impl TreasuryTransactionEntryType {
             TreasuryTransactionEntryType::InboundTransfer => "inbound_transfer",
             TreasuryTransactionEntryType::InboundTransferReturn => "inbound_transfer_return",
             TreasuryTransactionEntryType::IssuingAuthorizationHold => "issuing_authorization_hold",
-            TreasuryTransactionEntryType::IssuingAuthorizationRelease => "issuing_authorization_release",
+            TreasuryTransactionEntryType::IssuingAuthorizationRelease => {
+                "issuing_authorization_release"
+            }
             TreasuryTransactionEntryType::Other => "other",
             TreasuryTransactionEntryType::OutboundPayment => "outbound_payment",
-            TreasuryTransactionEntryType::OutboundPaymentCancellation => "outbound_payment_cancellation",
+            TreasuryTransactionEntryType::OutboundPaymentCancellation => {
+                "outbound_payment_cancellation"
+            }
             TreasuryTransactionEntryType::OutboundPaymentFailure => "outbound_payment_failure",
             TreasuryTransactionEntryType::OutboundPaymentPosting => "outbound_payment_posting",
             TreasuryTransactionEntryType::OutboundPaymentReturn => "outbound_payment_return",
             TreasuryTransactionEntryType::OutboundTransfer => "outbound_transfer",
-            TreasuryTransactionEntryType::OutboundTransferCancellation => "outbound_transfer_cancellation",
+            TreasuryTransactionEntryType::OutboundTransferCancellation => {
+                "outbound_transfer_cancellation"
+            }
             TreasuryTransactionEntryType::OutboundTransferFailure => "outbound_transfer_failure",
             TreasuryTransactionEntryType::OutboundTransferPosting => "outbound_transfer_posting",
             TreasuryTransactionEntryType::OutboundTransferReturn => "outbound_transfer_return",
@@ -178,3 +277,37 @@ impl std::default::Default for TreasuryTransactionEntryType {
         Self::CreditReversal
     }
 }
+
+/// An enum representing the possible values of an `ListTreasuryTransactionEntries`'s `order_by` field.
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum TreasuryTransactionEntryOrderBy {
+    Created,
+    EffectiveAt,
+}
+
+impl TreasuryTransactionEntryOrderBy {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            TreasuryTransactionEntryOrderBy::Created => "created",
+            TreasuryTransactionEntryOrderBy::EffectiveAt => "effective_at",
+        }
+    }
+}
+
+impl AsRef<str> for TreasuryTransactionEntryOrderBy {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::fmt::Display for TreasuryTransactionEntryOrderBy {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
+impl std::default::Default for TreasuryTransactionEntryOrderBy {
+    fn default() -> Self {
+        Self::Created
+    }
+}
@@ -1,9 +1,11 @@
 use serde::Serialize;
 
 use crate::client::{Client, Response};
-use crate::ids::{CouponId, CustomerId, InvoiceId, PlanId, SubscriptionId, SubscriptionItemId};
-use crate::params::{Metadata, Timestamp};
-use crate::resources::{CollectionMethod, Invoice};
+use crate::ids::{
+    CouponId, CustomerId, InvoiceLineItemId, PlanId, PriceId, SubscriptionId, SubscriptionItemId,
+};
+use crate::params::{List, Metadata, Object, Paginable, Timestamp};
+use crate::resources::{CollectionMethod, Invoice, InvoiceLineItem, SubscriptionProrationBehavior};
 
 #[deprecated(since = "0.12.0")]
 pub type InvoiceCollectionMethod = CollectionMethod;
@@ -16,11 +18,14 @@ impl Invoice {
         client.get_query("/invoices/upcoming", &params)
     }
 
-    /// Pays an invoice.
+    /// Retrieves the line items of an upcoming invoice, a page at a time.
     ///
-    /// For more details see <https://stripe.com/docs/api#pay_invoice.>.
-    pub fn pay(client: &Client, invoice_id: &InvoiceId) -> Response<Invoice> {
-        client.post(&format!("/invoices/{}/pay", invoice_id))
+    /// For more details see <https://stripe.com/docs/api/invoices/upcoming_invoice_lines>.
+    pub fn upcoming_lines(
+        client: &Client,
+        params: &ListUpcomingInvoiceLines,
+    ) -> Response<List<InvoiceLineItem>> {
+        client.get_query("/invoices/upcoming/lines", &params)
     }
 }
 
@@ -32,10 +37,12 @@ pub struct RetrieveUpcomingInvoice {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub subscription: Option<SubscriptionId>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub subscription_items: Option<SubscriptionItemFilter>,
+    pub subscription_items: Option<Vec<SubscriptionItemFilter>>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub subscription_prorate: Option<bool>,
     #[serde(skip_serializing_if = "Option::is_none")]
+    pub subscription_proration_behavior: Option<SubscriptionProrationBehavior>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub subscription_proration_date: Option<Timestamp>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub subscription_tax_percent: Option<f64>,
@@ -51,6 +58,7 @@ impl RetrieveUpcomingInvoice {
             subscription: None,
             subscription_items: None,
             subscription_prorate: None,
+            subscription_proration_behavior: None,
             subscription_proration_date: None,
             subscription_tax_percent: None,
             subscription_trial_end: None,
@@ -59,6 +67,32 @@ impl RetrieveUpcomingInvoice {
 }
 
 #[derive(Clone, Debug, Serialize)]
+pub struct ListUpcomingInvoiceLines {
+    /// Which upcoming invoice to list the lines of.
+    #[serde(flatten)]
+    pub invoice: RetrieveUpcomingInvoice,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ending_before: Option<InvoiceLineItemId>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub starting_after: Option<InvoiceLineItemId>,
+}
+
+impl ListUpcomingInvoiceLines {
+    pub fn new(invoice: RetrieveUpcomingInvoice) -> Self {
+        ListUpcomingInvoiceLines { invoice, ending_before: None, limit: None, starting_after: None }
+    }
+}
+
+impl Paginable for ListUpcomingInvoiceLines {
+    type O = InvoiceLineItem;
+    fn set_last(&mut self, item: Self::O) {
+        self.starting_after = Some(item.id());
+    }
+}
+
+#[derive(Clone, Debug, Default, Serialize)]
 pub struct SubscriptionItemFilter {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub id: Option<SubscriptionItemId>,
@@ -69,5 +103,7 @@ pub struct SubscriptionItemFilter {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub plan: Option<PlanId>,
     #[serde(skip_serializing_if = "Option::is_none")]
+    pub price: Option<PriceId>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub quantity: Option<u64>,
 }
@@ -0,0 +1,423 @@
+use serde::{Deserialize, Serialize};
+
+use crate::client::{Client, Response};
+use crate::error::StripeError;
+use crate::ids::{AccountId, ApplicationId};
+use crate::resources::Account;
+
+/// The parameters of a link to the Connect OAuth authorize page.
+///
+/// For more details see <https://stripe.com/docs/connect/oauth-reference#get-authorize>.
+#[derive(Clone, Debug, Serialize)]
+pub struct OAuthAuthorizeUrl<'a> {
+    /// The unique identifier provided to your application, found in your application settings.
+    pub client_id: &'a ApplicationId,
+
+    /// Send the user to the Express onboarding flow rather than the Standard one.
+    #[serde(skip)]
+    pub express: bool,
+
+    /// Always show the account selection screen, even if the user is already connected.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub always_prompt: Option<bool>,
+
+    /// The URL the user will be redirected to after connecting their account.
+    ///
+    /// It must be one of the redirect URIs in your application settings.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub redirect_uri: Option<&'a str>,
+
+    /// The scope of the access granted to your platform.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub scope: Option<OAuthScope>,
+
+    /// An arbitrary string that is returned to your redirect uri, used to prevent CSRF attacks.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub state: Option<&'a str>,
+
+    /// Whether the user is shown the login or the registration page.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stripe_landing: Option<OAuthStripeLanding>,
+
+    /// Details about the user, prefilled in the registration form.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stripe_user: Option<OAuthStripeUser>,
+
+    /// The capabilities requested for an Express account, eg. `card_payments`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub suggested_capabilities: Option<Vec<String>>,
+}
+
+impl<'a> OAuthAuthorizeUrl<'a> {
+    pub fn new(client_id: &'a ApplicationId) -> Self {
+        OAuthAuthorizeUrl {
+            client_id,
+            express: false,
+            always_prompt: Default::default(),
+            redirect_uri: Default::default(),
+            scope: Default::default(),
+            state: Default::default(),
+            stripe_landing: Default::default(),
+            stripe_user: Default::default(),
+            suggested_capabilities: Default::default(),
+        }
+    }
+
+    /// The URL to send the user to, on the Connect OAuth host of `client`.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the parameters cannot be encoded.
+    pub fn url(&self, client: &Client) -> Result<String, StripeError> {
+        let path = if self.express { "/express/oauth/authorize" } else { "/oauth/authorize" };
+        let params = AuthorizeParams { response_type: "code", params: self };
+        client.connect_url_with_params(path, params).map(String::from)
+    }
+}
+
+#[derive(Serialize)]
+struct AuthorizeParams<'a> {
+    response_type: &'static str,
+    #[serde(flatten)]
+    params: &'a OAuthAuthorizeUrl<'a>,
+}
+
+/// Details about the user, prefilled in the Connect OAuth registration form.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct OAuthStripeUser {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub business_name: Option<String>,
+
+    /// One of `individual`, `company` or `non_profit`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub business_type: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub city: Option<String>,
+
+    /// Two-letter country code ([ISO 3166-1 alpha-2](https://en.wikipedia.org/wiki/ISO_3166-1_alpha-2)).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub country: Option<String>,
+
+    /// Three-letter ISO code representing the default currency for the account.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub currency: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dob_day: Option<u8>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dob_month: Option<u8>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dob_year: Option<u16>,
+
+    /// The user's email address, which is also used as the login.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub email: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub first_name: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_name: Option<String>,
+
+    /// The user's phone number, without the country code.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub phone_number: Option<String>,
+
+    /// Whether the business sells physical products.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub physical_product: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub product_description: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub state: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub street_address: Option<String>,
+
+    /// The URL of the business's website.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub zip: Option<String>,
+}
+
+/// The access token of an account connected through Connect OAuth.
+///
+/// For more details see <https://stripe.com/docs/connect/oauth-reference#post-token>.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct OAuthToken {
+    /// An access token that can be used to make requests on behalf of the connected account.
+    ///
+    /// Prefer the `Stripe-Account` header and your platform's key instead, see [`Client::with_stripe_account`].
+    pub access_token: String,
+
+    pub livemode: bool,
+
+    /// A token that can be used to request a new access token with a different scope.
+    pub refresh_token: Option<String>,
+
+    /// The scope granted to the access token.
+    pub scope: OAuthScope,
+
+    /// A publishable key that can be used with Stripe.js on behalf of the connected account.
+    pub stripe_publishable_key: Option<String>,
+
+    /// The id of the connected account.
+    pub stripe_user_id: AccountId,
+
+    /// Always `bearer`.
+    pub token_type: String,
+}
+
+impl OAuthToken {
+    /// Exchanges an authorization code, or a refresh token, for an access token.
+    pub fn create(client: &Client, params: CreateOAuthToken<'_>) -> Response<OAuthToken> {
+        client.post_connect_form("/oauth/token", &params)
+    }
+}
+
+/// The parameters for `OAuthToken::create`.
+#[derive(Clone, Debug, Serialize)]
+pub struct CreateOAuthToken<'a> {
+    /// Whether `code` or `refresh_token` is exchanged.
+    pub grant_type: OAuthGrantType,
+
+    /// The code returned to your redirect uri, if `grant_type` is `authorization_code`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub code: Option<&'a str>,
+
+    /// A refresh token from a previous exchange, if `grant_type` is `refresh_token`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub refresh_token: Option<&'a str>,
+
+    /// The scope of the new access token, when refreshing.
+    ///
+    /// It must be the same as, or narrower than, the scope that was granted.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub scope: Option<OAuthScope>,
+}
+
+impl<'a> CreateOAuthToken<'a> {
+    /// Exchanges the code returned to your redirect uri.
+    pub fn authorization_code(code: &'a str) -> Self {
+        CreateOAuthToken {
+            grant_type: OAuthGrantType::AuthorizationCode,
+            code: Some(code),
+            refresh_token: None,
+            scope: None,
+        }
+    }
+
+    /// Exchanges the refresh token of a previous exchange.
+    pub fn refresh_token(refresh_token: &'a str) -> Self {
+        CreateOAuthToken {
+            grant_type: OAuthGrantType::RefreshToken,
+            code: None,
+            refresh_token: Some(refresh_token),
+            scope: None,
+        }
+    }
+}
+
+/// The response to `Account::deauthorize`.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct OAuthDeauthorization {
+    /// The id of the account that was disconnected.
+    pub stripe_user_id: AccountId,
+}
+
+#[derive(Clone, Debug, Serialize)]
+struct DeauthorizeAccount<'a> {
+    client_id: &'a ApplicationId,
+    stripe_user_id: &'a AccountId,
+}
+
+impl Account {
+    /// Disconnects an account connected through Connect OAuth from your platform.
+    ///
+    /// For more details see <https://stripe.com/docs/connect/oauth-reference#post-deauthorize>.
+    pub fn deauthorize(
+        client: &Client,
+        id: &AccountId,
+        client_id: &ApplicationId,
+    ) -> Response<OAuthDeauthorization> {
+        let params = DeauthorizeAccount { client_id, stripe_user_id: id };
+        client.post_connect_form("/oauth/deauthorize", &params)
+    }
+}
+
+/// An enum representing the scopes of a Connect OAuth access token.
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum OAuthScope {
+    ReadOnly,
+    ReadWrite,
+}
+
+impl OAuthScope {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            OAuthScope::ReadOnly => "read_only",
+            OAuthScope::ReadWrite => "read_write",
+        }
+    }
+}
+
+impl AsRef<str> for OAuthScope {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::fmt::Display for OAuthScope {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
+
+impl std::default::Default for OAuthScope {
+    fn default() -> Self {
+        Self::ReadOnly
+    }
+}
+
+/// An enum representing the possible values of an `CreateOAuthToken`'s `grant_type` field.
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum OAuthGrantType {
+    AuthorizationCode,
+    RefreshToken,
+}
+
+impl OAuthGrantType {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            OAuthGrantType::AuthorizationCode => "authorization_code",
+            OAuthGrantType::RefreshToken => "refresh_token",
+        }
+    }
+}
+
+impl AsRef<str> for OAuthGrantType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::fmt::Display for OAuthGrantType {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
+
+/// An enum representing the possible values of an `OAuthAuthorizeUrl`'s `stripe_landing` field.
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum OAuthStripeLanding {
+    Login,
+    Register,
+}
+
+impl OAuthStripeLanding {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            OAuthStripeLanding::Login => "login",
+            OAuthStripeLanding::Register => "register",
+        }
+    }
+}
+
+impl AsRef<str> for OAuthStripeLanding {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::fmt::Display for OAuthStripeLanding {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{OAuthAuthorizeUrl, OAuthScope, OAuthStripeUser};
+    use crate::{ApplicationId, Client};
+
+    #[test]
+    fn builds_authorize_url() {
+        let client = Client::new("sk_test_12345");
+        let client_id: ApplicationId = "ca_123".parse().unwrap();
+        let params = OAuthAuthorizeUrl {
+            scope: Some(OAuthScope::ReadWrite),
+            state: Some("csrf"),
+            stripe_user: Some(OAuthStripeUser {
+                email: Some("jenny@example.com".to_string()),
+                ..Default::default()
+            }),
+            ..OAuthAuthorizeUrl::new(&client_id)
+        };
+        assert_eq!(
+            params.url(&client).unwrap(),
+            "https://connect.stripe.com/oauth/authorize?response_type=code&client_id=ca_123\
+             &scope=read_write&state=csrf&stripe_user[email]=jenny%40example.com"
+        );
+
+        let params = OAuthAuthorizeUrl { express: true, ..OAuthAuthorizeUrl::new(&client_id) };
+        assert_eq!(
+            params.url(&client).unwrap(),
+            "https://connect.stripe.com/express/oauth/authorize?response_type=code&client_id=ca_123"
+        );
+    }
+
+    #[cfg(feature = "async")]
+    #[tokio::test]
+    async fn exchanges_codes_and_maps_errors() {
+        use httpmock::{Method::POST, MockServer};
+
+        use super::{CreateOAuthToken, OAuthToken};
+        use crate::{OAuthErrorCode, StripeError};
+
+        let server = MockServer::start_async().await;
+        let client = Client::from_url(&*server.url("/"), "sk_test_12345");
+
+        let ok = server.mock(|when, then| {
+            when.method(POST)
+                .path("/oauth/token")
+                .body("grant_type=authorization_code&code=ac_123");
+            then.status(200).body(
+                r#"{"access_token": "sk_test_456", "livemode": false, "refresh_token": "rt_123",
+                "scope": "read_write", "stripe_publishable_key": "pk_test_456",
+                "stripe_user_id": "acct_123", "token_type": "bearer"}"#,
+            );
+        });
+        let token = OAuthToken::create(&client, CreateOAuthToken::authorization_code("ac_123"))
+            .await
+            .unwrap();
+        ok.assert_hits(1);
+        assert_eq!(token.stripe_user_id.as_str(), "acct_123");
+        assert_eq!(token.scope, OAuthScope::ReadWrite);
+
+        let err = server.mock(|when, then| {
+            when.method(POST).path("/oauth/token").body_contains("refresh_token=rt_old");
+            then.status(400).body(
+                r#"{"error": "invalid_grant", "error_description": "Refresh token rt_old does not exist"}"#,
+            );
+        });
+        let res = OAuthToken::create(&client, CreateOAuthToken::refresh_token("rt_old")).await;
+        err.assert_hits(1);
+        match res {
+            Err(StripeError::OAuth(err)) => {
+                assert_eq!(err.error, OAuthErrorCode::InvalidGrant);
+                assert_eq!(err.http_status, 400);
+            }
+            res => panic!("expected an oauth error, got {:?}", res),
+        }
+    }
+}
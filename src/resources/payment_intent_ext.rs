@@ -1,44 +1,11 @@
 use serde::{Deserialize, Serialize};
 
-use crate::client::{Client, Response};
 use crate::params::{Expandable, Metadata};
-use crate::resources::{Currency, PaymentIntent, PaymentSource, Shipping};
-use crate::PaymentIntentCancellationReason;
+use crate::resources::{ConfirmPaymentIntent, Currency, PaymentSource, Shipping};
 
-impl PaymentIntent {
-    /// Confirm that customer intends to pay with current or provided source. Upon confirmation, the PaymentIntent will attempt to initiate a payment.
-    ///
-    /// For more details see <https://stripe.com/docs/api/payment_intents/confirm>.
-    pub fn confirm(
-        client: &Client,
-        payment_intent_id: &str,
-        params: PaymentIntentConfirmParams<'_>,
-    ) -> Response<PaymentIntent> {
-        client.post_form(&format!("/payment_intents/{}/confirm", payment_intent_id), params)
-    }
+#[deprecated(note = "use `ConfirmPaymentIntent` instead")]
+pub type PaymentIntentConfirmParams<'a> = ConfirmPaymentIntent<'a>;
 
-    /// Capture the funds of an existing uncaptured PaymentIntent where required_action="requires_capture".
-    ///
-    /// For more details see <https://stripe.com/docs/api/payment_intents/capture>.
-    pub fn capture(
-        client: &Client,
-        payment_intent_id: &str,
-        params: CapturePaymentIntent,
-    ) -> Response<PaymentIntent> {
-        client.post_form(&format!("/payment_intents/{}/capture", payment_intent_id), params)
-    }
-
-    /// A PaymentIntent object can be canceled when it is in one of these statuses: requires_source, requires_capture, requires_confirmation, requires_source_action.
-    ///
-    /// For more details see <https://stripe.com/docs/api/payment_intents/cancel>.
-    pub fn cancel(
-        client: &Client,
-        payment_intent_id: &str,
-        params: CancelPaymentIntent,
-    ) -> Response<PaymentIntent> {
-        client.post_form(&format!("/payment_intents/{}/cancel", payment_intent_id), params)
-    }
-}
 /// The resource representing a Stripe PaymentError object.
 ///
 /// For more details see <https://stripe.com/docs/api/payment_intents/object#payment_intent_object-last_payment_error>.
@@ -168,40 +135,3 @@ pub struct PaymentIntentUpdateParams<'a> {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub transfer_group: Option<&'a str>,
 }
-
-/// The set of parameters that can be used when confirming a payment_intent object.
-///
-/// For more details see <https://stripe.com/docs/api/payment_intents/confirm>
-#[derive(Clone, Debug, Default, Serialize)]
-pub struct PaymentIntentConfirmParams<'a> {
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub receipt_email: Option<&'a str>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub return_url: Option<&'a str>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub save_source_to_customer: Option<bool>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub shipping: Option<Shipping>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub source: Option<&'a str>,
-}
-
-/// The set of parameters that can be used when capturing a payment_intent object.
-///
-/// For more details see <https://stripe.com/docs/api/payment_intents/capture>
-#[derive(Clone, Debug, Default, Serialize)]
-pub struct CapturePaymentIntent {
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub amount_to_capture: Option<u64>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub application_fee_amount: Option<u64>,
-}
-
-/// The set of parameters that can be used when canceling a payment_intent object.
-///
-/// For more details see <https://stripe.com/docs/api/payment_intents/cancel>
-#[derive(Clone, Debug, Default, Serialize)]
-pub struct CancelPaymentIntent {
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub cancellation_reason: Option<PaymentIntentCancellationReason>,
-}
@@ -24,9 +24,6 @@ pub struct PaymentMethodDetailsCardPresent {}
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct PaymentMethodDetailsCardWallet {}
 
-#[derive(Clone, Debug, Default, Deserialize, Serialize)]
-pub struct RadarValueListItem {}
-
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct SubscriptionTransferData {}
 
@@ -0,0 +1,12 @@
+use crate::client::{Client, Response};
+use crate::ids::QuoteId;
+use crate::resources::Quote;
+
+impl Quote {
+    /// Download the PDF for a finalized quote.
+    ///
+    /// For more details see <https://stripe.com/docs/api/quotes/pdf>.
+    pub fn pdf(client: &Client, id: &QuoteId) -> Response<Vec<u8>> {
+        client.get_file_bytes(&format!("/quotes/{}/pdf", id))
+    }
+}
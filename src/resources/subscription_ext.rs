@@ -1,8 +1,16 @@
+use std::time::{SystemTime, UNIX_EPOCH};
+
 use serde::Serialize;
 
 use crate::client::{Client, Response};
-use crate::ids::SubscriptionId;
-use crate::resources::{CreateSubscriptionItems, Subscription};
+use crate::ids::{PriceId, SubscriptionId, SubscriptionItemId};
+use crate::params::Timestamp;
+use crate::resources::{
+    CreateSubscriptionItems, Invoice, RetrieveUpcomingInvoice, Subscription,
+    SubscriptionItemFilter, SubscriptionProrationBehavior, UpdateSubscription,
+    UpdateSubscriptionItems, UpdateSubscriptionPauseCollection,
+    UpdateSubscriptionPauseCollectionBehavior,
+};
 
 #[derive(Clone, Debug, Default, Serialize)]
 pub struct CancelSubscription {
@@ -16,6 +24,11 @@ impl CancelSubscription {
     }
 }
 
+#[derive(Serialize)]
+struct UnpauseCollection {
+    pause_collection: &'static str,
+}
+
 impl Subscription {
     /// Cancels a subscription.
     ///
@@ -27,6 +40,31 @@ impl Subscription {
     ) -> Response<Subscription> {
         client.delete_query(&format!("/subscriptions/{}", subscription_id), params)
     }
+
+    /// Pauses payment collection, handling the subscription's invoices with `behavior` until `resumes_at`, if set.
+    ///
+    /// For more details see <https://stripe.com/docs/billing/subscriptions/pause>.
+    pub fn pause_collection(
+        client: &Client,
+        subscription_id: &SubscriptionId,
+        behavior: UpdateSubscriptionPauseCollectionBehavior,
+        resumes_at: Option<Timestamp>,
+    ) -> Response<Subscription> {
+        let mut params = UpdateSubscription::new();
+        params.pause_collection = Some(UpdateSubscriptionPauseCollection { behavior, resumes_at });
+        Subscription::update(client, subscription_id, params)
+    }
+
+    /// Resumes payment collection of a subscription paused with `pause_collection`.
+    pub fn unpause_collection(
+        client: &Client,
+        subscription_id: &SubscriptionId,
+    ) -> Response<Subscription> {
+        client.post_form(
+            &format!("/subscriptions/{}", subscription_id),
+            UnpauseCollection { pause_collection: "" },
+        )
+    }
 }
 
 impl CreateSubscriptionItems {
@@ -34,3 +72,131 @@ impl CreateSubscriptionItems {
         Default::default()
     }
 }
+
+/// Moves one of a subscription's items to a new price, with prorations.
+///
+/// The proration date is fixed when the change is created, so that `apply` bills
+/// exactly what `preview` showed.
+///
+/// ```rust,ignore
+/// let change = SubscriptionPriceChange::new(item_id, price_id);
+/// let invoice = change.preview(&client, &subscription).await?;
+/// // confirm `invoice.amount_due` with the customer, then
+/// let subscription = change.apply(&client, &subscription.id).await?;
+/// ```
+#[derive(Clone, Debug)]
+pub struct SubscriptionPriceChange {
+    /// The subscription item whose price is changed.
+    pub item: SubscriptionItemId,
+
+    /// The new price of the item.
+    pub price: PriceId,
+
+    /// The new quantity of the item, which is reset to 1 if not set.
+    pub quantity: Option<u64>,
+
+    /// How the change is prorated, `create_prorations` by default.
+    pub proration_behavior: SubscriptionProrationBehavior,
+
+    /// The time the prorations are calculated at.
+    pub proration_date: Timestamp,
+}
+
+impl SubscriptionPriceChange {
+    /// Changes the price of `item` to `price`, prorated as of now.
+    pub fn new(item: SubscriptionItemId, price: PriceId) -> Self {
+        let now = SystemTime::now().duration_since(UNIX_EPOCH).map_or(0, |d| d.as_secs() as i64);
+        SubscriptionPriceChange {
+            item,
+            price,
+            quantity: None,
+            proration_behavior: SubscriptionProrationBehavior::CreateProrations,
+            proration_date: now,
+        }
+    }
+
+    /// Previews the upcoming invoice of `subscription` with the change applied.
+    pub fn preview(&self, client: &Client, subscription: &Subscription) -> Response<Invoice> {
+        let mut params = RetrieveUpcomingInvoice::new(subscription.customer.id());
+        params.subscription = Some(subscription.id.clone());
+        params.subscription_items = Some(vec![SubscriptionItemFilter {
+            id: Some(self.item.clone()),
+            price: Some(self.price.clone()),
+            quantity: self.quantity,
+            ..Default::default()
+        }]);
+        params.subscription_proration_behavior = Some(self.proration_behavior);
+        params.subscription_proration_date = Some(self.proration_date);
+        Invoice::upcoming(client, params)
+    }
+
+    /// Applies the change to the subscription.
+    pub fn apply(
+        &self,
+        client: &Client,
+        subscription_id: &SubscriptionId,
+    ) -> Response<Subscription> {
+        let mut params = UpdateSubscription::new();
+        params.items = Some(vec![UpdateSubscriptionItems {
+            id: Some(self.item.to_string()),
+            price: Some(self.price.to_string()),
+            quantity: self.quantity,
+            ..Default::default()
+        }]);
+        params.proration_behavior = Some(self.proration_behavior);
+        params.proration_date = Some(self.proration_date);
+        Subscription::update(client, subscription_id, params)
+    }
+}
+
+#[cfg(all(test, feature = "async"))]
+mod tests {
+    use httpmock::Method::{GET, POST};
+    use httpmock::MockServer;
+
+    use super::SubscriptionPriceChange;
+    use crate::{Client, Expandable, Subscription};
+
+    #[tokio::test]
+    async fn previews_and_applies_price_changes() {
+        let server = MockServer::start_async().await;
+        let client = Client::from_url(&*server.url("/"), "fake_key");
+
+        let subscription = Subscription {
+            id: "sub_123".parse().unwrap(),
+            customer: Expandable::Id("cus_123".parse().unwrap()),
+            ..Default::default()
+        };
+        let change = SubscriptionPriceChange {
+            proration_date: 1680000000,
+            ..SubscriptionPriceChange::new("si_123".parse().unwrap(), "price_456".parse().unwrap())
+        };
+
+        let preview = server.mock(|when, then| {
+            when.method(GET)
+                .path("/v1/invoices/upcoming")
+                .query_param("customer", "cus_123")
+                .query_param("subscription", "sub_123")
+                .query_param("subscription_items[0][id]", "si_123")
+                .query_param("subscription_items[0][price]", "price_456")
+                .query_param("subscription_proration_date", "1680000000");
+            then.status(200).body(r#"{"object": "invoice", "amount_due": 1250}"#);
+        });
+        let invoice = change.preview(&client, &subscription).await.unwrap();
+        preview.assert_hits(1);
+        assert_eq!(invoice.amount_due, Some(1250));
+
+        let apply = server.mock(|when, then| {
+            when.method(POST)
+                .path("/v1/subscriptions/sub_123")
+                .body_contains("items[0][id]=si_123")
+                .body_contains("items[0][price]=price_456")
+                .body_contains("proration_behavior=create_prorations")
+                .body_contains("proration_date=1680000000");
+            then.status(200).body(serde_json::to_string(&subscription).unwrap());
+        });
+        let updated = change.apply(&client, &subscription.id).await.unwrap();
+        apply.assert_hits(1);
+        assert_eq!(updated.id, subscription.id);
+    }
+}
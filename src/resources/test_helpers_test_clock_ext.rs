@@ -0,0 +1,187 @@
+use std::time::Duration;
+
+use crate::client::config::sleep;
+use crate::client::Client;
+use crate::error::{StripeError, TestClockError, TestClockErrorKind};
+use crate::ids::TestHelpersTestClockId;
+use crate::params::Timestamp;
+use crate::resources::{
+    AdvanceTestHelpersTestClock, TestHelpersTestClock, TestHelpersTestClockStatus,
+};
+
+/// How [`TestHelpersTestClock::advance_and_wait`] polls a clock while it advances.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct TestClockPolling {
+    /// The delay between two retrievals of the clock.
+    pub interval: Duration,
+    /// The most retrievals before giving up with a [`TestClockErrorKind::StillAdvancing`] error.
+    pub max_attempts: u32,
+}
+
+impl Default for TestClockPolling {
+    fn default() -> Self {
+        TestClockPolling { interval: Duration::from_secs(1), max_attempts: 60 }
+    }
+}
+
+impl TestHelpersTestClock {
+    /// Advances the clock to `frozen_time`, then retrieves it until it is `ready` again.
+    ///
+    /// # Errors
+    ///
+    /// This function will return a [`StripeError::TestClock`] if stripe failed to advance
+    /// the clock, or if it is still advancing after `polling.max_attempts` retrievals.
+    /// The error's `kind` tells the two apart.
+    #[cfg(feature = "async")]
+    pub async fn advance_and_wait(
+        client: &Client,
+        id: &TestHelpersTestClockId,
+        frozen_time: Timestamp,
+        polling: TestClockPolling,
+    ) -> Result<TestHelpersTestClock, StripeError> {
+        let params = AdvanceTestHelpersTestClock::new(frozen_time);
+        let mut clock = TestHelpersTestClock::advance(client, id, params).await?;
+        for _ in 0..polling.max_attempts {
+            if is_ready(&clock)? {
+                return Ok(clock);
+            }
+            sleep(polling.interval).await;
+            clock = TestHelpersTestClock::retrieve(client, id, &[]).await?;
+        }
+        if is_ready(&clock)? {
+            Ok(clock)
+        } else {
+            Err(still_advancing(clock))
+        }
+    }
+
+    /// Advances the clock to `frozen_time`, then retrieves it until it is `ready` again.
+    ///
+    /// # Errors
+    ///
+    /// This function will return a [`StripeError::TestClock`] if stripe failed to advance
+    /// the clock, or if it is still advancing after `polling.max_attempts` retrievals.
+    /// The error's `kind` tells the two apart.
+    #[cfg(feature = "blocking")]
+    pub fn advance_and_wait(
+        client: &Client,
+        id: &TestHelpersTestClockId,
+        frozen_time: Timestamp,
+        polling: TestClockPolling,
+    ) -> Result<TestHelpersTestClock, StripeError> {
+        let params = AdvanceTestHelpersTestClock::new(frozen_time);
+        let mut clock = TestHelpersTestClock::advance(client, id, params)?;
+        for _ in 0..polling.max_attempts {
+            if is_ready(&clock)? {
+                return Ok(clock);
+            }
+            sleep(polling.interval);
+            clock = TestHelpersTestClock::retrieve(client, id, &[])?;
+        }
+        if is_ready(&clock)? {
+            Ok(clock)
+        } else {
+            Err(still_advancing(clock))
+        }
+    }
+}
+
+fn is_ready(clock: &TestHelpersTestClock) -> Result<bool, StripeError> {
+    match clock.status {
+        Some(TestHelpersTestClockStatus::Ready) => Ok(true),
+        Some(TestHelpersTestClockStatus::InternalFailure) => Err(TestClockError {
+            id: clock.id.clone(),
+            kind: TestClockErrorKind::Failed,
+            status: TestHelpersTestClockStatus::InternalFailure,
+        }
+        .into()),
+        _ => Ok(false),
+    }
+}
+
+fn still_advancing(clock: TestHelpersTestClock) -> StripeError {
+    TestClockError {
+        id: clock.id,
+        kind: TestClockErrorKind::StillAdvancing,
+        status: clock.status.unwrap_or(TestHelpersTestClockStatus::Advancing),
+    }
+    .into()
+}
+
+#[cfg(all(test, feature = "async"))]
+mod tests {
+    use std::time::Duration;
+
+    use httpmock::Method::{GET, POST};
+    use httpmock::MockServer;
+
+    use super::TestClockPolling;
+    use crate::{
+        Client, StripeError, TestClockErrorKind, TestHelpersTestClock, TestHelpersTestClockStatus,
+    };
+
+    fn clock(status: &str) -> String {
+        format!(
+            r#"{{"id": "clock_123", "object": "test_helpers.test_clock", "created": 1,
+            "frozen_time": 100, "livemode": false, "status": "{}"}}"#,
+            status
+        )
+    }
+
+    #[tokio::test]
+    async fn waits_until_ready() {
+        let server = MockServer::start_async().await;
+        let client = Client::from_url(&*server.url("/"), "fake_key");
+        let id = "clock_123".parse().unwrap();
+        let polling = TestClockPolling { interval: Duration::from_millis(1), max_attempts: 3 };
+
+        let mut advance = server.mock(|when, then| {
+            when.method(POST).path("/v1/test_helpers/test_clocks/clock_123/advance");
+            then.status(200).body(clock("advancing"));
+        });
+        let mut retrieve = server.mock(|when, then| {
+            when.method(GET).path("/v1/test_helpers/test_clocks/clock_123");
+            then.status(200).body(clock("ready"));
+        });
+
+        let ready = TestHelpersTestClock::advance_and_wait(&client, &id, 100, polling).await;
+        assert_eq!(ready.unwrap().status, Some(TestHelpersTestClockStatus::Ready));
+        advance.assert_hits(1);
+        retrieve.assert_hits(1);
+        retrieve.delete();
+
+        // a clock that never finishes advancing is given up on
+        retrieve = server.mock(|when, then| {
+            when.method(GET).path("/v1/test_helpers/test_clocks/clock_123");
+            then.status(200).body(clock("advancing"));
+        });
+        let advancing = TestHelpersTestClock::advance_and_wait(&client, &id, 100, polling).await;
+        match advancing {
+            Err(StripeError::TestClock(err)) => {
+                assert_eq!(err.kind, TestClockErrorKind::StillAdvancing);
+                assert_eq!(err.status, TestHelpersTestClockStatus::Advancing);
+                let err = StripeError::TestClock(err);
+                assert!(!err.is_retryable() && !err.is_network());
+            }
+            other => panic!("expected a test clock error, got {:?}", other),
+        }
+        retrieve.assert_hits(3);
+        advance.delete();
+
+        // and one that fails is an error straight away
+        advance = server.mock(|when, then| {
+            when.method(POST).path("/v1/test_helpers/test_clocks/clock_123/advance");
+            then.status(200).body(clock("internal_failure"));
+        });
+        let failed = TestHelpersTestClock::advance_and_wait(&client, &id, 100, polling).await;
+        match failed {
+            Err(StripeError::TestClock(err)) => {
+                assert_eq!(err.id.as_str(), "clock_123");
+                assert_eq!(err.kind, TestClockErrorKind::Failed);
+                assert_eq!(err.status, TestHelpersTestClockStatus::InternalFailure);
+            }
+            other => panic!("expected a test clock error, got {:?}", other),
+        }
+        advance.assert_hits(1);
+    }
+}
@@ -0,0 +1,346 @@
+use thiserror::Error;
+
+use crate::error::StripeError;
+use crate::ids::WebhookEndpointId;
+use crate::resources::{
+    ApiVersion, EventFilter, EventType, WebhookEndpoint, WebhookEndpointStatus,
+};
+#[cfg(feature = "async")]
+use crate::resources::{CreateWebhookEndpoint, UpdateWebhookEndpoint};
+
+/// A webhook endpoint as it should exist in stripe.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DesiredWebhookEndpoint {
+    pub url: String,
+    pub enabled_events: Vec<EventType>,
+    /// The api version to render events with, or the account default if `None`.
+    pub api_version: Option<ApiVersion>,
+    /// The description of the endpoint, left untouched if `None`.
+    pub description: Option<String>,
+    /// Whether the endpoint receives events from connected accounts.
+    pub connect: bool,
+}
+
+impl DesiredWebhookEndpoint {
+    pub fn new(
+        url: impl Into<String>,
+        enabled_events: impl IntoIterator<Item = EventType>,
+    ) -> Self {
+        DesiredWebhookEndpoint {
+            url: url.into(),
+            enabled_events: enabled_events.into_iter().collect(),
+            api_version: None,
+            description: None,
+            connect: false,
+        }
+    }
+
+    pub fn with_api_version(mut self, api_version: ApiVersion) -> Self {
+        self.api_version = Some(api_version);
+        self
+    }
+
+    pub fn with_description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    pub fn with_connect(mut self, connect: bool) -> Self {
+        self.connect = connect;
+        self
+    }
+
+    fn event_filters(&self) -> Result<Vec<EventFilter>, ReconcileError> {
+        let mut filters = self
+            .enabled_events
+            .iter()
+            .map(|event_type| {
+                serde_json::to_value(event_type)
+                    .and_then(serde_json::from_value)
+                    .map_err(|_| ReconcileError::UnsupportedEventType(*event_type))
+            })
+            .collect::<Result<Vec<EventFilter>, _>>()?;
+        sort_filters(&mut filters);
+        Ok(filters)
+    }
+}
+
+/// A change needed to bring stripe in line with the desired endpoints.
+#[derive(Clone, Debug, PartialEq)]
+pub enum WebhookEndpointAction {
+    /// Create a new endpoint.
+    Create { endpoint: DesiredWebhookEndpoint, enabled_events: Vec<EventFilter> },
+    /// Update the events or description of an endpoint, enabling it if it was disabled.
+    Update {
+        id: WebhookEndpointId,
+        endpoint: DesiredWebhookEndpoint,
+        enabled_events: Vec<EventFilter>,
+    },
+    /// The api version of an endpoint cannot be changed, so a new endpoint is created and the old one disabled.
+    Replace {
+        id: WebhookEndpointId,
+        endpoint: DesiredWebhookEndpoint,
+        enabled_events: Vec<EventFilter>,
+    },
+    /// Disable an endpoint that is not desired.
+    Disable { id: WebhookEndpointId },
+}
+
+/// An error encountered while reconciling webhook endpoints.
+#[derive(Debug, Error)]
+pub enum ReconcileError {
+    #[error("event type {0:?} cannot be enabled on a webhook endpoint")]
+    UnsupportedEventType(EventType),
+    #[error("error reconciling webhook endpoints: {0}")]
+    Stripe(#[from] StripeError),
+}
+
+/// The actions needed to reconcile the webhook endpoints in stripe with a desired set.
+///
+/// Endpoints are matched by url and whether they receive connect events. Matched
+/// endpoints are updated in place where possible, and endpoints that are not desired
+/// are disabled rather than deleted. Building a plan does not change anything, so it
+/// can be inspected as a dry run before calling [`WebhookEndpointPlan::apply`].
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct WebhookEndpointPlan {
+    pub actions: Vec<WebhookEndpointAction>,
+}
+
+impl WebhookEndpointPlan {
+    /// Diff the desired endpoints against the existing ones.
+    ///
+    /// # Errors
+    ///
+    /// This function will return a ReconcileError if a desired endpoint enables
+    /// an event type that cannot be subscribed to.
+    pub fn new(
+        desired: &[DesiredWebhookEndpoint],
+        existing: &[WebhookEndpoint],
+    ) -> Result<Self, ReconcileError> {
+        let mut unmatched = existing.iter().filter(|e| !e.deleted).collect::<Vec<_>>();
+        // prefer matching enabled endpoints when there are duplicates
+        unmatched.sort_by_key(|e| e.status == Some(WebhookEndpointStatus::Disabled));
+
+        let mut actions = Vec::new();
+        for endpoint in desired {
+            let enabled_events = endpoint.event_filters()?;
+            let position = unmatched.iter().position(|e| {
+                e.url.as_deref() == Some(endpoint.url.as_str())
+                    && e.application.is_some() == endpoint.connect
+            });
+            let current = match position {
+                Some(position) => unmatched.remove(position),
+                None => {
+                    actions.push(WebhookEndpointAction::Create {
+                        endpoint: endpoint.clone(),
+                        enabled_events,
+                    });
+                    continue;
+                }
+            };
+
+            let id = current.id.clone();
+            let endpoint = endpoint.clone();
+            if endpoint.api_version.is_some() && current.api_version != endpoint.api_version {
+                actions.push(WebhookEndpointAction::Replace { id, endpoint, enabled_events });
+                continue;
+            }
+
+            let mut current_events = current.enabled_events.clone().unwrap_or_default();
+            sort_filters(&mut current_events);
+            let disabled = current.status == Some(WebhookEndpointStatus::Disabled);
+            let description_changed = endpoint.description.is_some()
+                && endpoint.description.as_deref() != current.description.as_deref();
+            if disabled || description_changed || current_events != enabled_events {
+                actions.push(WebhookEndpointAction::Update { id, endpoint, enabled_events });
+            }
+        }
+
+        actions.extend(
+            unmatched
+                .into_iter()
+                .filter(|e| e.status != Some(WebhookEndpointStatus::Disabled))
+                .map(|e| WebhookEndpointAction::Disable { id: e.id.clone() }),
+        );
+
+        Ok(WebhookEndpointPlan { actions })
+    }
+
+    /// Whether stripe already matches the desired endpoints.
+    pub fn is_empty(&self) -> bool {
+        self.actions.is_empty()
+    }
+
+    /// Fetch all existing endpoints and diff them against the desired ones.
+    ///
+    /// # Errors
+    ///
+    /// This function will return a ReconcileError if the endpoints could not be
+    /// listed, or a desired endpoint enables an event type that cannot be subscribed to.
+    #[cfg(feature = "async")]
+    pub async fn fetch(
+        client: &crate::Client,
+        desired: &[DesiredWebhookEndpoint],
+    ) -> Result<Self, ReconcileError> {
+        let mut params = crate::resources::ListWebhookEndpoints::new();
+        params.limit = Some(100);
+        let mut existing = Vec::new();
+        loop {
+            let page = WebhookEndpoint::list(client, &params).await?;
+            params.starting_after = page.data.last().map(|e| e.id.clone());
+            let has_more = page.has_more && params.starting_after.is_some();
+            existing.extend(page.data);
+            if !has_more {
+                break;
+            }
+        }
+        Self::new(desired, &existing)
+    }
+
+    /// Apply the plan, returning the endpoints that were created.
+    ///
+    /// Stripe only returns the signing secret of an endpoint when it is created,
+    /// so store the `secret` of the returned endpoints before discarding them.
+    ///
+    /// # Errors
+    ///
+    /// This function will return a ReconcileError if any request fails. Actions
+    /// are applied in order, so the ones before the failure will have been applied.
+    #[cfg(feature = "async")]
+    pub async fn apply(
+        &self,
+        client: &crate::Client,
+    ) -> Result<Vec<WebhookEndpoint>, ReconcileError> {
+        let mut created = Vec::new();
+        for action in &self.actions {
+            match action {
+                WebhookEndpointAction::Create { endpoint, enabled_events } => {
+                    created.push(create(client, endpoint, enabled_events).await?);
+                }
+                WebhookEndpointAction::Update { id, endpoint, enabled_events } => {
+                    let mut params = UpdateWebhookEndpoint::new();
+                    params.description = endpoint.description.as_deref();
+                    params.disabled = Some(false);
+                    params.enabled_events = Some(enabled_events.clone());
+                    WebhookEndpoint::update(client, id, params).await?;
+                }
+                WebhookEndpointAction::Replace { id, endpoint, enabled_events } => {
+                    created.push(create(client, endpoint, enabled_events).await?);
+                    disable(client, id).await?;
+                }
+                WebhookEndpointAction::Disable { id } => {
+                    disable(client, id).await?;
+                }
+            }
+        }
+        Ok(created)
+    }
+}
+
+#[cfg(feature = "async")]
+async fn create(
+    client: &crate::Client,
+    endpoint: &DesiredWebhookEndpoint,
+    enabled_events: &[EventFilter],
+) -> Result<WebhookEndpoint, StripeError> {
+    let mut params = CreateWebhookEndpoint::new(enabled_events.to_vec(), &endpoint.url);
+    params.api_version = endpoint.api_version;
+    params.connect = Some(endpoint.connect);
+    params.description = endpoint.description.as_deref();
+    WebhookEndpoint::create(client, params).await
+}
+
+#[cfg(feature = "async")]
+async fn disable(client: &crate::Client, id: &WebhookEndpointId) -> Result<(), StripeError> {
+    let mut params = UpdateWebhookEndpoint::new();
+    params.disabled = Some(true);
+    WebhookEndpoint::update(client, id, params).await.map(|_| ())
+}
+
+fn sort_filters(filters: &mut Vec<EventFilter>) {
+    filters.sort_by_key(|filter| filter.as_str());
+    filters.dedup();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{DesiredWebhookEndpoint, WebhookEndpointAction, WebhookEndpointPlan};
+    use crate::{ApiVersion, EventFilter, EventType, WebhookEndpoint, WebhookEndpointStatus};
+
+    fn existing(id: &str, url: &str, events: Vec<EventFilter>) -> WebhookEndpoint {
+        WebhookEndpoint {
+            id: id.parse().unwrap(),
+            url: Some(url.to_string()),
+            enabled_events: Some(events),
+            api_version: Some(ApiVersion::V2022_11_15),
+            status: Some(WebhookEndpointStatus::Enabled),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn matching_endpoints_need_no_changes() {
+        let desired = [DesiredWebhookEndpoint::new(
+            "https://example.com/hooks",
+            [EventType::CustomerUpdated, EventType::CustomerCreated],
+        )];
+        let current = [existing(
+            "we_1",
+            "https://example.com/hooks",
+            vec![EventFilter::CustomerCreated, EventFilter::CustomerUpdated],
+        )];
+
+        let plan = WebhookEndpointPlan::new(&desired, &current).unwrap();
+        assert!(plan.is_empty());
+    }
+
+    #[test]
+    fn plans_create_update_replace_and_disable() {
+        let desired = [
+            DesiredWebhookEndpoint::new("https://example.com/new", [EventType::ChargeSucceeded]),
+            DesiredWebhookEndpoint::new("https://example.com/hooks", [EventType::ChargeFailed]),
+            DesiredWebhookEndpoint::new("https://example.com/pinned", [EventType::ChargeFailed])
+                .with_api_version(ApiVersion::V2020_08_27),
+        ];
+        let current = [
+            existing("we_1", "https://example.com/hooks", vec![EventFilter::ChargeSucceeded]),
+            existing("we_2", "https://example.com/pinned", vec![EventFilter::ChargeFailed]),
+            existing("we_3", "https://example.com/old", vec![EventFilter::All]),
+        ];
+
+        let plan = WebhookEndpointPlan::new(&desired, &current).unwrap();
+        assert_eq!(
+            plan.actions,
+            vec![
+                WebhookEndpointAction::Create {
+                    endpoint: desired[0].clone(),
+                    enabled_events: vec![EventFilter::ChargeSucceeded],
+                },
+                WebhookEndpointAction::Update {
+                    id: "we_1".parse().unwrap(),
+                    endpoint: desired[1].clone(),
+                    enabled_events: vec![EventFilter::ChargeFailed],
+                },
+                WebhookEndpointAction::Replace {
+                    id: "we_2".parse().unwrap(),
+                    endpoint: desired[2].clone(),
+                    enabled_events: vec![EventFilter::ChargeFailed],
+                },
+                WebhookEndpointAction::Disable { id: "we_3".parse().unwrap() },
+            ]
+        );
+    }
+
+    #[test]
+    fn connect_endpoints_are_matched_separately() {
+        let desired =
+            [DesiredWebhookEndpoint::new("https://example.com/hooks", [EventType::AccountUpdated])
+                .with_connect(true)];
+        let current =
+            [existing("we_1", "https://example.com/hooks", vec![EventFilter::AccountUpdated])];
+
+        let plan = WebhookEndpointPlan::new(&desired, &current).unwrap();
+        assert!(matches!(plan.actions[0], WebhookEndpointAction::Create { .. }));
+        assert!(matches!(plan.actions[1], WebhookEndpointAction::Disable { .. }));
+    }
+}
@@ -125,6 +125,16 @@ pub enum EventType {
     CustomerUpdated,
     #[serde(rename = "file.created")]
     FileCreated,
+    #[serde(rename = "financial_connections.account.created")]
+    FinancialConnectionsAccountCreated,
+    #[serde(rename = "financial_connections.account.deactivated")]
+    FinancialConnectionsAccountDeactivated,
+    #[serde(rename = "financial_connections.account.disconnected")]
+    FinancialConnectionsAccountDisconnected,
+    #[serde(rename = "financial_connections.account.reactivated")]
+    FinancialConnectionsAccountReactivated,
+    #[serde(rename = "financial_connections.account.refreshed_balance")]
+    FinancialConnectionsAccountRefreshedBalance,
     #[serde(rename = "identity.verification_session.canceled")]
     IdentityVerificationSessionCanceled,
     #[serde(rename = "identity.verification_session.created")]
@@ -435,6 +445,10 @@ pub enum EventObject {
     Discount(Discount),
     Dispute(Dispute),
     File(File),
+    #[serde(rename = "financial_connections.account")]
+    FinancialConnectionsAccount(FinancialConnectionsAccount),
+    #[serde(rename = "identity.verification_session")]
+    IdentityVerificationSession(IdentityVerificationSession),
     Invoice(Invoice),
     #[serde(rename = "invoiceitem")]
     InvoiceItem(InvoiceItem),
@@ -459,6 +473,8 @@ pub enum EventObject {
     Product(Product),
     PromotionCode(PromotionCode),
     Quote(Quote),
+    #[serde(rename = "radar.early_fraud_warning")]
+    RadarEarlyFraudWarning(RadarEarlyFraudWarning),
     Refund(Refund),
     Review(Review),
     SetupIntent(SetupIntent),
@@ -466,10 +482,28 @@ pub enum EventObject {
     SubscriptionSchedule(SubscriptionSchedule),
     TaxId(TaxId),
     TaxRate(TaxRate),
+    #[serde(rename = "terminal.reader")]
+    TerminalReader(TerminalReader),
     #[serde(rename = "test_helpers.test_clock")]
     TestHelpersTestClock(TestHelpersTestClock),
     Topup(Topup),
     Transfer(Transfer),
+    #[serde(rename = "treasury.credit_reversal")]
+    TreasuryCreditReversal(TreasuryCreditReversal),
+    #[serde(rename = "treasury.debit_reversal")]
+    TreasuryDebitReversal(TreasuryDebitReversal),
+    #[serde(rename = "treasury.financial_account")]
+    TreasuryFinancialAccount(TreasuryFinancialAccount),
+    #[serde(rename = "treasury.inbound_transfer")]
+    TreasuryInboundTransfer(TreasuryInboundTransfer),
+    #[serde(rename = "treasury.outbound_payment")]
+    TreasuryOutboundPayment(TreasuryOutboundPayment),
+    #[serde(rename = "treasury.outbound_transfer")]
+    TreasuryOutboundTransfer(TreasuryOutboundTransfer),
+    #[serde(rename = "treasury.received_credit")]
+    TreasuryReceivedCredit(TreasuryReceivedCredit),
+    #[serde(rename = "treasury.received_debit")]
+    TreasuryReceivedDebit(TreasuryReceivedDebit),
 }
 
 impl Default for EventObject {
@@ -478,6 +512,71 @@ impl Default for EventObject {
     }
 }
 
+impl EventObject {
+    /// The id of the object, if it has one.
+    pub fn id(&self) -> Option<String> {
+        use crate::params::Object;
+
+        match self {
+            EventObject::Account(object) => Some(object.id().to_string()),
+            EventObject::Application(object) => Some(object.id().to_string()),
+            EventObject::ApplicationFee(object) => Some(object.id().to_string()),
+            EventObject::ApplicationFeeRefund(object) => Some(object.id().to_string()),
+            EventObject::BankAccount(object) => Some(object.id().to_string()),
+            EventObject::BillingPortalConfiguration(object) => Some(object.id().to_string()),
+            EventObject::Card(object) => Some(object.id().to_string()),
+            EventObject::Charge(object) => Some(object.id().to_string()),
+            EventObject::CheckoutSession(object) => Some(object.id().to_string()),
+            EventObject::Coupon(object) => Some(object.id().to_string()),
+            EventObject::Customer(object) => Some(object.id().to_string()),
+            EventObject::Discount(object) => Some(object.id().to_string()),
+            EventObject::Dispute(object) => Some(object.id().to_string()),
+            EventObject::File(object) => Some(object.id().to_string()),
+            EventObject::FinancialConnectionsAccount(object) => Some(object.id().to_string()),
+            EventObject::IdentityVerificationSession(object) => Some(object.id().to_string()),
+            EventObject::Invoice(object) => Some(object.id().to_string()),
+            EventObject::InvoiceItem(object) => Some(object.id().to_string()),
+            EventObject::IssuingAuthorization(object) => Some(object.id().to_string()),
+            EventObject::IssuingCard(object) => Some(object.id().to_string()),
+            EventObject::IssuingCardholder(object) => Some(object.id().to_string()),
+            EventObject::IssuingDispute(object) => Some(object.id().to_string()),
+            EventObject::IssuingTransaction(object) => Some(object.id().to_string()),
+            EventObject::Mandate(object) => Some(object.id().to_string()),
+            EventObject::PaymentIntent(object) => Some(object.id().to_string()),
+            EventObject::PaymentLink(object) => Some(object.id().to_string()),
+            EventObject::PaymentMethod(object) => Some(object.id().to_string()),
+            EventObject::Payout(object) => Some(object.id().to_string()),
+            EventObject::Person(object) => Some(object.id().to_string()),
+            EventObject::Plan(object) => Some(object.id().to_string()),
+            EventObject::Price(object) => Some(object.id().to_string()),
+            EventObject::Product(object) => Some(object.id().to_string()),
+            EventObject::PromotionCode(object) => Some(object.id().to_string()),
+            EventObject::Quote(object) => Some(object.id().to_string()),
+            EventObject::RadarEarlyFraudWarning(object) => Some(object.id().to_string()),
+            EventObject::Refund(object) => Some(object.id().to_string()),
+            EventObject::Review(object) => Some(object.id().to_string()),
+            EventObject::SetupIntent(object) => Some(object.id().to_string()),
+            EventObject::Subscription(object) => Some(object.id().to_string()),
+            EventObject::SubscriptionSchedule(object) => Some(object.id().to_string()),
+            EventObject::TaxId(object) => Some(object.id().to_string()),
+            EventObject::TaxRate(object) => Some(object.id().to_string()),
+            EventObject::TerminalReader(object) => Some(object.id().to_string()),
+            EventObject::TestHelpersTestClock(object) => Some(object.id().to_string()),
+            EventObject::Topup(object) => Some(object.id().to_string()),
+            EventObject::Transfer(object) => Some(object.id().to_string()),
+            EventObject::TreasuryCreditReversal(object) => Some(object.id().to_string()),
+            EventObject::TreasuryDebitReversal(object) => Some(object.id().to_string()),
+            EventObject::TreasuryFinancialAccount(object) => Some(object.id().to_string()),
+            EventObject::TreasuryInboundTransfer(object) => Some(object.id().to_string()),
+            EventObject::TreasuryOutboundPayment(object) => Some(object.id().to_string()),
+            EventObject::TreasuryOutboundTransfer(object) => Some(object.id().to_string()),
+            EventObject::TreasuryReceivedCredit(object) => Some(object.id().to_string()),
+            EventObject::TreasuryReceivedDebit(object) => Some(object.id().to_string()),
+            EventObject::AccountCapabilities(_) | EventObject::Balance(_) => None,
+        }
+    }
+}
+
 #[cfg(feature = "webhook-events")]
 pub struct Webhook {
     current_timestamp: i64,
@@ -491,8 +590,24 @@ impl Webhook {
     ///  - the provided signature is invalid
     ///  - the provided secret is invalid
     ///  - the signature timestamp is older than 5 minutes
+    ///  - the event could not be parsed, see [`EventParser::parse`]
     pub fn construct_event(payload: &str, sig: &str, secret: &str) -> Result<Event, WebhookError> {
-        Self { current_timestamp: Utc::now().timestamp() }.do_construct_event(payload, sig, secret)
+        Self::construct_event_with(payload, sig, secret, &EventParser::new())
+    }
+
+    /// Like [`construct_event`](Webhook::construct_event), deserializing the event with `parser`.
+    ///
+    /// # Errors
+    ///
+    /// See [`construct_event`](Webhook::construct_event).
+    pub fn construct_event_with(
+        payload: &str,
+        sig: &str,
+        secret: &str,
+        parser: &EventParser,
+    ) -> Result<Event, WebhookError> {
+        Self { current_timestamp: Utc::now().timestamp() }
+            .do_construct_event(payload, sig, secret, parser)
     }
 
     fn do_construct_event(
@@ -500,6 +615,7 @@ impl Webhook {
         payload: &str,
         sig: &str,
         secret: &str,
+        parser: &EventParser,
     ) -> Result<Event, WebhookError> {
         // Get Stripe signature from header
         let signature = Signature::parse(sig)?;
@@ -520,7 +636,7 @@ impl Webhook {
             return Err(WebhookError::BadTimestamp(signature.t));
         }
 
-        Ok(serde_json::from_str(payload)?)
+        parser.parse(payload)
     }
 }
 
@@ -625,7 +741,7 @@ mod tests {
         let webhook = super::Webhook { current_timestamp: event_timestamp };
 
         let event = webhook
-            .do_construct_event(payload, &signature, &secret)
+            .do_construct_event(payload, &signature, &secret, &super::EventParser::new())
             .expect("Failed to construct event");
 
         assert_eq!(event.type_, super::EventType::InvoiceItemCreated);
@@ -0,0 +1,88 @@
+use std::future::Future;
+use std::pin::Pin;
+
+use actix_web::dev::Payload;
+use actix_web::http::StatusCode;
+use actix_web::{web, FromRequest, HttpRequest, ResponseError};
+use futures_util::StreamExt;
+
+use super::{StripeEvent, WebhookConfig, WebhookRejection, SIGNATURE_HEADER};
+
+impl FromRequest for StripeEvent {
+    type Error = WebhookRejection;
+    type Future = Pin<Box<dyn Future<Output = Result<Self, Self::Error>>>>;
+
+    fn from_request(req: &HttpRequest, payload: &mut Payload) -> Self::Future {
+        let config = req
+            .app_data::<WebhookConfig>()
+            .or_else(|| req.app_data::<web::Data<WebhookConfig>>().map(|data| data.as_ref()))
+            .cloned();
+        let signature =
+            req.headers().get(SIGNATURE_HEADER).and_then(|v| v.to_str().ok()).map(str::to_owned);
+        let mut payload = payload.take();
+
+        Box::pin(async move {
+            let config = config.ok_or(WebhookRejection::MissingConfig)?;
+            let mut bytes = Vec::new();
+            while let Some(chunk) = payload.next().await {
+                let chunk = chunk.map_err(|e| WebhookRejection::InvalidBody(e.to_string()))?;
+                if bytes.len() + chunk.len() > config.max_body_size() {
+                    return Err(WebhookRejection::PayloadTooLarge);
+                }
+                bytes.extend_from_slice(&chunk);
+            }
+            config.verify(&bytes, signature.as_deref()).map(StripeEvent)
+        })
+    }
+}
+
+impl ResponseError for WebhookRejection {
+    fn status_code(&self) -> StatusCode {
+        StatusCode::from_u16(WebhookRejection::status_code(self))
+            .unwrap_or(StatusCode::INTERNAL_SERVER_ERROR)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use actix_web::test::TestRequest;
+    use actix_web::{FromRequest, ResponseError};
+
+    use super::super::test_util::{sign, PAYLOAD, SECRET};
+    use super::super::{StripeEvent, WebhookConfig, WebhookRejection};
+
+    #[actix_web::test]
+    async fn extracts_verified_event() {
+        let (req, mut payload) = TestRequest::post()
+            .insert_header(("Stripe-Signature", sign(PAYLOAD, SECRET)))
+            .app_data(WebhookConfig::new(SECRET))
+            .set_payload(PAYLOAD)
+            .to_http_parts();
+
+        let StripeEvent(event) = StripeEvent::from_request(&req, &mut payload).await.unwrap();
+        assert_eq!(event.id.as_str(), "evt_123");
+    }
+
+    #[actix_web::test]
+    async fn rejects_invalid_requests() {
+        let (req, mut payload) = TestRequest::post()
+            .insert_header(("Stripe-Signature", sign(PAYLOAD, SECRET)))
+            .app_data(WebhookConfig::new(SECRET).with_max_body_size(16))
+            .set_payload(PAYLOAD)
+            .to_http_parts();
+        let err = StripeEvent::from_request(&req, &mut payload).await.unwrap_err();
+        assert_eq!(err.error_response().status(), 413);
+
+        let (req, mut payload) = TestRequest::post().set_payload(PAYLOAD).to_http_parts();
+        let err = StripeEvent::from_request(&req, &mut payload).await.unwrap_err();
+        assert!(matches!(err, WebhookRejection::MissingConfig));
+        assert_eq!(err.error_response().status(), 500);
+
+        let (req, mut payload) = TestRequest::post()
+            .app_data(WebhookConfig::new(SECRET))
+            .set_payload(PAYLOAD)
+            .to_http_parts();
+        let err = StripeEvent::from_request(&req, &mut payload).await.unwrap_err();
+        assert_eq!(err.error_response().status(), 400);
+    }
+}
@@ -0,0 +1,89 @@
+use axum::extract::{FromRef, FromRequest};
+use axum::http::{Request, StatusCode};
+use axum::response::{IntoResponse, Response};
+use axum::{async_trait, BoxError};
+
+use super::{read_body, StripeEvent, WebhookConfig, WebhookRejection, SIGNATURE_HEADER};
+
+#[async_trait]
+impl<S, B> FromRequest<S, B> for StripeEvent
+where
+    WebhookConfig: FromRef<S>,
+    S: Send + Sync,
+    B: http_body::Body + Send + 'static,
+    B::Data: Send,
+    B::Error: Into<BoxError>,
+{
+    type Rejection = WebhookRejection;
+
+    async fn from_request(req: Request<B>, state: &S) -> Result<Self, Self::Rejection> {
+        let config = WebhookConfig::from_ref(state);
+        let (parts, body) = req.into_parts();
+        let signature = parts.headers.get(SIGNATURE_HEADER).and_then(|v| v.to_str().ok());
+        let body = http_body::Body::map_err(body, |e| e.into());
+        let payload = read_body(body, config.max_body_size()).await?;
+        config.verify(&payload, signature).map(StripeEvent)
+    }
+}
+
+impl IntoResponse for WebhookRejection {
+    fn into_response(self) -> Response {
+        let status =
+            StatusCode::from_u16(self.status_code()).unwrap_or(StatusCode::INTERNAL_SERVER_ERROR);
+        (status, self.to_string()).into_response()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::body::Body;
+    use axum::http::{Request, StatusCode};
+    use axum::routing::post;
+    use axum::Router;
+    use tower::ServiceExt;
+
+    use super::super::test_util::{sign, PAYLOAD, SECRET};
+    use super::super::{read_body, StripeEvent, WebhookConfig};
+
+    fn app(config: WebhookConfig) -> Router {
+        Router::new()
+            .route(
+                "/webhooks",
+                post(|StripeEvent(event): StripeEvent| async move { event.id.to_string() }),
+            )
+            .with_state(config)
+    }
+
+    fn request(payload: &str, signature: Option<String>) -> Request<Body> {
+        let mut req = Request::post("/webhooks");
+        if let Some(signature) = signature {
+            req = req.header("Stripe-Signature", signature);
+        }
+        req.body(Body::from(payload.to_string())).unwrap()
+    }
+
+    #[tokio::test]
+    async fn extracts_verified_event() {
+        let req = request(PAYLOAD, Some(sign(PAYLOAD, SECRET)));
+        let res = app(WebhookConfig::new(SECRET)).oneshot(req).await.unwrap();
+        assert_eq!(res.status(), StatusCode::OK);
+        let body = read_body(res.into_body(), usize::MAX).await.unwrap();
+        assert_eq!(body, b"evt_123");
+    }
+
+    #[tokio::test]
+    async fn rejects_invalid_requests() {
+        let req = request(PAYLOAD, None);
+        let res = app(WebhookConfig::new(SECRET)).oneshot(req).await.unwrap();
+        assert_eq!(res.status(), StatusCode::BAD_REQUEST);
+
+        let req = request(PAYLOAD, Some(sign(PAYLOAD, "whsec_other_secret")));
+        let res = app(WebhookConfig::new(SECRET)).oneshot(req).await.unwrap();
+        assert_eq!(res.status(), StatusCode::BAD_REQUEST);
+
+        let req = request(PAYLOAD, Some(sign(PAYLOAD, SECRET)));
+        let res =
+            app(WebhookConfig::new(SECRET).with_max_body_size(16)).oneshot(req).await.unwrap();
+        assert_eq!(res.status(), StatusCode::PAYLOAD_TOO_LARGE);
+    }
+}
@@ -0,0 +1,356 @@
+use std::sync::Mutex;
+
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+use super::processor::{BoxFuture, EventHandler, EventStore, ProcessError, ProcessOutcome};
+use super::WebhookProcessor;
+use crate::client::Client;
+use crate::error::StripeError;
+use crate::ids::EventId;
+use crate::params::{List, RangeBounds, RangeQuery, Timestamp};
+use crate::resources::{Event, EventType, ListEvents};
+
+/// The last event handled by an [`EventBackfill`], persisted between runs.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct Checkpoint {
+    pub event_id: EventId,
+    /// The time at which the event was created.
+    pub created: Timestamp,
+}
+
+/// Storage for the [`Checkpoint`] of an [`EventBackfill`].
+pub trait CheckpointStore: Send + Sync {
+    type Error: std::error::Error + Send + Sync + 'static;
+
+    /// Load the last saved checkpoint, if any.
+    fn load(&self) -> BoxFuture<'_, Result<Option<Checkpoint>, Self::Error>>;
+
+    /// Save a checkpoint, replacing the previous one.
+    fn save(&self, checkpoint: Checkpoint) -> BoxFuture<'_, Result<(), Self::Error>>;
+}
+
+/// A [`CheckpointStore`] that keeps the checkpoint in memory.
+#[derive(Debug, Default)]
+pub struct InMemoryCheckpointStore {
+    checkpoint: Mutex<Option<Checkpoint>>,
+}
+
+impl InMemoryCheckpointStore {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl CheckpointStore for InMemoryCheckpointStore {
+    type Error = std::convert::Infallible;
+
+    fn load(&self) -> BoxFuture<'_, Result<Option<Checkpoint>, Self::Error>> {
+        let checkpoint = self.checkpoint.lock().unwrap_or_else(|e| e.into_inner()).clone();
+        Box::pin(std::future::ready(Ok(checkpoint)))
+    }
+
+    fn save(&self, checkpoint: Checkpoint) -> BoxFuture<'_, Result<(), Self::Error>> {
+        *self.checkpoint.lock().unwrap_or_else(|e| e.into_inner()) = Some(checkpoint);
+        Box::pin(std::future::ready(Ok(())))
+    }
+}
+
+/// An error encountered while backfilling events.
+#[derive(Debug, Error)]
+pub enum BackfillError<C, S, H> {
+    #[error("error listing events: {0}")]
+    Stripe(#[from] StripeError),
+    #[error("error accessing the checkpoint store: {0}")]
+    Checkpoint(C),
+    #[error("{0}")]
+    Process(ProcessError<S, H>),
+}
+
+/// The result of a single [`EventBackfill::run`].
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct BackfillSummary {
+    /// Events that were handed to the handler.
+    pub processed: usize,
+    /// Events that had already been handled, for example when delivered by webhook.
+    pub duplicates: usize,
+    /// Events that were being handled by a concurrent webhook delivery.
+    pub in_progress: usize,
+    /// Events that were skipped because a newer event for their object had been handled.
+    pub stale: usize,
+    /// The checkpoint after the run, if any event has ever been handled.
+    pub checkpoint: Option<Checkpoint>,
+}
+
+/// Replays events from `Event::list` through a [`WebhookProcessor`], oldest first.
+///
+/// Events are read from the saved [`Checkpoint`] onwards, or from
+/// [`with_since`](EventBackfill::with_since) on the first run, and the checkpoint is
+/// advanced as events are handled. Since events go through the same processor as
+/// webhooks, events that were already delivered are skipped as duplicates.
+///
+/// Calling [`run`](EventBackfill::run) on an interval turns this into a poller for
+/// environments that cannot receive webhooks.
+///
+/// Stripe only keeps events for 30 days, so a backfill cannot reach further back than that.
+pub struct EventBackfill<'a, S, C> {
+    client: &'a Client,
+    processor: &'a WebhookProcessor<S>,
+    checkpoints: &'a C,
+    types: Vec<EventType>,
+    since: Option<Timestamp>,
+    until: Option<Timestamp>,
+    page_size: u64,
+}
+
+impl<'a, S: EventStore, C: CheckpointStore> EventBackfill<'a, S, C> {
+    pub fn new(client: &'a Client, processor: &'a WebhookProcessor<S>, checkpoints: &'a C) -> Self {
+        EventBackfill {
+            client,
+            processor,
+            checkpoints,
+            types: Vec::new(),
+            since: None,
+            until: None,
+            page_size: 100,
+        }
+    }
+
+    /// Only replay events of these types. Stripe accepts up to 20 types.
+    pub fn with_types(mut self, types: impl IntoIterator<Item = EventType>) -> Self {
+        self.types = types.into_iter().collect();
+        self
+    }
+
+    /// Where to start when no checkpoint has been saved yet.
+    ///
+    /// Without this, the first run starts from the oldest event stripe still has.
+    pub fn with_since(mut self, since: Timestamp) -> Self {
+        self.since = Some(since);
+        self
+    }
+
+    /// Ignore events created after this time.
+    pub fn with_until(mut self, until: Timestamp) -> Self {
+        self.until = Some(until);
+        self
+    }
+
+    /// Set the number of events requested per page, clamped to stripe's limits of 1 to 100.
+    pub fn with_page_size(mut self, page_size: u64) -> Self {
+        self.page_size = page_size.clamp(1, 100);
+        self
+    }
+
+    /// Handle all events after the checkpoint, oldest first.
+    ///
+    /// Each page is handled as soon as it is fetched, so only one page is held in memory.
+    /// On the first run without a checkpoint, stripe's newest-first ordering means the
+    /// whole range is listed once to find the oldest page before any event is handled,
+    /// which costs one request per page of the range; narrow it with
+    /// [`with_since`](EventBackfill::with_since) to keep that short.
+    ///
+    /// # Errors
+    ///
+    /// This function will return a BackfillError if listing events, the checkpoint
+    /// store or processing an event failed. Events handled before the failure are
+    /// checkpointed, so the next run resumes with the event that failed.
+    pub async fn run<H: EventHandler>(
+        &self,
+        handler: &H,
+    ) -> Result<BackfillSummary, BackfillError<C::Error, S::Error, H::Error>> {
+        let mut summary = BackfillSummary {
+            checkpoint: self.checkpoints.load().await.map_err(BackfillError::Checkpoint)?,
+            ..Default::default()
+        };
+
+        let types = self.types.iter().map(event_type_str).collect::<Vec<_>>();
+        let mut params = ListEvents::new();
+        params.limit = Some(self.page_size);
+        params.types = if types.is_empty() { None } else { Some(types) };
+        // once there is a checkpoint, the cursor is the lower bound
+        let since = if summary.checkpoint.is_none() { self.since } else { None };
+        if since.is_some() || self.until.is_some() {
+            params.created = Some(RangeQuery::Bounds(RangeBounds {
+                gte: since,
+                lte: self.until,
+                ..Default::default()
+            }));
+        }
+
+        if summary.checkpoint.is_none() {
+            // stripe lists events newest first, so to start with the
+            // oldest we walk to the last page before handling anything
+            let mut page = Event::list(self.client, &params).await?;
+            while page.has_more {
+                params.starting_after = page.data.last().map(|event| event.id.clone());
+                page = Event::list(self.client, &params).await?;
+            }
+            params.starting_after = None;
+            self.handle_page(page, handler, &mut summary).await?;
+        }
+
+        while let Some(checkpoint) = &summary.checkpoint {
+            // `ending_before` returns the page of events directly after the cursor
+            params.ending_before = Some(checkpoint.event_id.clone());
+            let page = Event::list(self.client, &params).await?;
+            if !self.handle_page(page, handler, &mut summary).await? {
+                break;
+            }
+        }
+
+        Ok(summary)
+    }
+
+    /// Handle a page of events oldest first, returning whether there are more pages.
+    async fn handle_page<H: EventHandler>(
+        &self,
+        page: List<Event>,
+        handler: &H,
+        summary: &mut BackfillSummary,
+    ) -> Result<bool, BackfillError<C::Error, S::Error, H::Error>> {
+        let has_more = page.has_more && !page.data.is_empty();
+        let mut checkpoint = None;
+
+        for event in page.data.into_iter().rev() {
+            let next = Checkpoint { event_id: event.id.clone(), created: event.created };
+            match self.processor.process(event, handler).await {
+                Ok(ProcessOutcome::Processed) => summary.processed += 1,
+                Ok(ProcessOutcome::Duplicate) => summary.duplicates += 1,
+                Ok(ProcessOutcome::InProgress) => summary.in_progress += 1,
+                Ok(ProcessOutcome::Stale) => summary.stale += 1,
+                Err(e) => {
+                    self.save(checkpoint, summary).await?;
+                    return Err(BackfillError::Process(e));
+                }
+            }
+            checkpoint = Some(next);
+        }
+
+        self.save(checkpoint, summary).await?;
+        Ok(has_more)
+    }
+
+    async fn save<H>(
+        &self,
+        checkpoint: Option<Checkpoint>,
+        summary: &mut BackfillSummary,
+    ) -> Result<(), BackfillError<C::Error, S::Error, H>> {
+        if let Some(checkpoint) = checkpoint {
+            self.checkpoints.save(checkpoint.clone()).await.map_err(BackfillError::Checkpoint)?;
+            summary.checkpoint = Some(checkpoint);
+        }
+        Ok(())
+    }
+}
+
+/// The wire name of an event type, such as `customer.created`.
+fn event_type_str(event_type: &EventType) -> String {
+    match serde_json::to_value(event_type) {
+        Ok(serde_json::Value::String(name)) => name,
+        _ => event_type.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{Arc, Mutex};
+
+    use httpmock::Method::GET;
+    use httpmock::MockServer;
+
+    use super::{
+        BackfillSummary, Checkpoint, CheckpointStore, EventBackfill, InMemoryCheckpointStore,
+    };
+    use crate::{Client, Event, EventType, InMemoryEventStore, WebhookProcessor};
+
+    fn page(ids: &[(&str, i64)], has_more: bool) -> String {
+        let events = ids
+            .iter()
+            .map(|(id, created)| {
+                format!(
+                    r#"{{"id": "{}", "object": "event", "created": {}, "livemode": false,
+                    "pending_webhooks": 0, "type": "customer.updated",
+                    "data": {{"object": {{"id": "cus_123", "object": "customer", "created": 1}}}}}}"#,
+                    id, created
+                )
+            })
+            .collect::<Vec<_>>()
+            .join(",");
+        format!(
+            r#"{{"object": "list", "data": [{}], "has_more": {}, "url": "/v1/events"}}"#,
+            events, has_more
+        )
+    }
+
+    #[tokio::test]
+    async fn replays_events_oldest_first() {
+        let server = MockServer::start_async().await;
+        let client = Client::from_url(&*server.url("/"), "fake_key");
+
+        let after_newest = server.mock(|when, then| {
+            when.method(GET).path("/v1/events").query_param("ending_before", "evt_3");
+            then.status(200).body(page(&[], false));
+        });
+        let after_oldest = server.mock(|when, then| {
+            when.method(GET).path("/v1/events").query_param("ending_before", "evt_1");
+            then.status(200).body(page(&[("evt_3", 30), ("evt_2", 20)], false));
+        });
+        let last_page = server.mock(|when, then| {
+            when.method(GET).path("/v1/events").query_param("starting_after", "evt_2");
+            then.status(200).body(page(&[("evt_1", 10)], false));
+        });
+        let first_page = server.mock(|when, then| {
+            when.method(GET)
+                .path("/v1/events")
+                .query_param("created[gte]", "5")
+                .query_param("types[0]", "customer.updated");
+            then.status(200).body(page(&[("evt_3", 30), ("evt_2", 20)], true));
+        });
+
+        let handled = Arc::new(Mutex::new(Vec::new()));
+        let handler = |event: Event| {
+            handled.lock().unwrap().push(event.id.to_string());
+            async { Ok::<_, String>(()) }
+        };
+
+        let processor = WebhookProcessor::new(InMemoryEventStore::new());
+        let checkpoints = InMemoryCheckpointStore::new();
+        let backfill = EventBackfill::new(&client, &processor, &checkpoints)
+            .with_types([EventType::CustomerUpdated])
+            .with_since(5);
+
+        let summary = backfill.run(&handler).await.unwrap();
+        let checkpoint = Checkpoint { event_id: "evt_3".parse().unwrap(), created: 30 };
+        assert_eq!(
+            summary,
+            BackfillSummary {
+                processed: 3,
+                checkpoint: Some(checkpoint.clone()),
+                ..Default::default()
+            }
+        );
+        assert_eq!(*handled.lock().unwrap(), vec!["evt_1", "evt_2", "evt_3"]);
+        assert_eq!(checkpoints.load().await.unwrap(), Some(checkpoint));
+
+        // a second run only asks for events after the checkpoint
+        let summary = backfill.run(&handler).await.unwrap();
+        assert_eq!(summary.processed, 0);
+
+        first_page.assert_hits_async(1).await;
+        last_page.assert_hits_async(1).await;
+        after_oldest.assert_hits_async(1).await;
+        after_newest.assert_hits_async(1).await;
+    }
+
+    #[test]
+    fn clamps_page_size() {
+        let client = Client::new("sk_test_123");
+        let processor = WebhookProcessor::new(InMemoryEventStore::new());
+        let checkpoints = InMemoryCheckpointStore::new();
+        let backfill = EventBackfill::new(&client, &processor, &checkpoints);
+        assert_eq!(backfill.with_page_size(0).page_size, 1);
+        let backfill = EventBackfill::new(&client, &processor, &checkpoints);
+        assert_eq!(backfill.with_page_size(1000).page_size, 100);
+    }
+}
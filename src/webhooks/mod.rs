@@ -0,0 +1,249 @@
+//! Webhook integrations for web frameworks.
+//!
+//! Each integration reads the raw request body, pulls the `Stripe-Signature`
+//! header and hands both to [`Webhook::construct_event`](crate::Webhook::construct_event),
+//! rejecting requests that are too large or fail verification.
+//!
+//! - `webhook-axum`: [`StripeEvent`] implements axum's `FromRequest`
+//! - `webhook-actix`: [`StripeEvent`] implements actix-web's `FromRequest`
+//! - `webhook-tower`: [`StripeWebhookLayer`] verifies requests in front of any service
+//!
+//! Verified events can then be deduplicated with a [`WebhookProcessor`], which
+//! is also used to replay missed events from `Event::list` with an `EventBackfill`.
+
+use thiserror::Error;
+
+use crate::error::WebhookError;
+use crate::resources::{Event, EventParser, Webhook};
+
+#[cfg(feature = "webhook-actix")]
+mod actix;
+#[cfg(feature = "webhook-axum")]
+mod axum;
+#[cfg(feature = "async")]
+mod backfill;
+mod processor;
+#[cfg(feature = "webhook-tower")]
+mod tower;
+
+#[cfg(feature = "async")]
+pub use self::backfill::*;
+pub use self::processor::*;
+#[cfg(feature = "webhook-tower")]
+pub use self::tower::{StripeWebhookLayer, StripeWebhookService};
+
+/// The name of the header stripe uses to sign webhook payloads.
+const SIGNATURE_HEADER: &str = "Stripe-Signature";
+
+/// The default maximum size of a webhook payload, in bytes.
+const DEFAULT_MAX_BODY_SIZE: usize = 1024 * 1024;
+
+/// The configuration used to verify incoming webhooks.
+///
+/// With axum, this must be reachable from the router state via `FromRef`.
+/// With actix-web, register it with `App::app_data`.
+#[derive(Clone, Debug)]
+pub struct WebhookConfig {
+    secret: String,
+    max_body_size: usize,
+    parser: EventParser,
+}
+
+impl WebhookConfig {
+    /// Create a new config with the endpoint's signing secret (`whsec_...`).
+    ///
+    /// Payloads are limited to 1 MiB unless changed with [`WebhookConfig::with_max_body_size`].
+    pub fn new(secret: impl Into<String>) -> Self {
+        WebhookConfig {
+            secret: secret.into(),
+            max_body_size: DEFAULT_MAX_BODY_SIZE,
+            parser: EventParser::new(),
+        }
+    }
+
+    /// Set the maximum accepted payload size, in bytes.
+    pub fn with_max_body_size(mut self, max_body_size: usize) -> Self {
+        self.max_body_size = max_body_size;
+        self
+    }
+
+    /// Set the parser used to deserialize events, for example to add migrations.
+    pub fn with_parser(mut self, parser: EventParser) -> Self {
+        self.parser = parser;
+        self
+    }
+
+    /// The maximum accepted payload size, in bytes.
+    pub fn max_body_size(&self) -> usize {
+        self.max_body_size
+    }
+
+    /// Verify a raw payload against its signature header and parse the event.
+    ///
+    /// # Errors
+    ///
+    /// This function will return a WebhookRejection if:
+    ///  - the signature header is missing
+    ///  - the payload is larger than the configured maximum or is not valid utf-8
+    ///  - the event could not be verified or parsed, see [`Webhook::construct_event`]
+    pub fn verify(
+        &self,
+        payload: &[u8],
+        signature: Option<&str>,
+    ) -> Result<Event, WebhookRejection> {
+        if payload.len() > self.max_body_size {
+            return Err(WebhookRejection::PayloadTooLarge);
+        }
+        let signature = signature.ok_or(WebhookRejection::MissingSignature)?;
+        let payload = std::str::from_utf8(payload)
+            .map_err(|_| WebhookRejection::InvalidBody("payload is not valid utf-8".into()))?;
+        Ok(Webhook::construct_event_with(payload, signature, &self.secret, &self.parser)?)
+    }
+}
+
+/// The reason an incoming webhook request was rejected.
+#[derive(Debug, Error)]
+pub enum WebhookRejection {
+    #[error("missing {} header", SIGNATURE_HEADER)]
+    MissingSignature,
+    #[error("payload exceeds the maximum body size")]
+    PayloadTooLarge,
+    #[error("error reading payload: {0}")]
+    InvalidBody(String),
+    #[error("no webhook config registered")]
+    MissingConfig,
+    #[error("error verifying webhook: {0}")]
+    Webhook(#[from] WebhookError),
+}
+
+impl WebhookRejection {
+    /// The HTTP status code to respond with.
+    ///
+    /// Errors caused by the request are reported as client errors, so that
+    /// they show up as failed deliveries in the stripe dashboard, while a
+    /// missing config or unusable secret is reported as a server error.
+    pub fn status_code(&self) -> u16 {
+        match self {
+            WebhookRejection::PayloadTooLarge => 413,
+            WebhookRejection::MissingConfig | WebhookRejection::Webhook(WebhookError::BadKey) => {
+                500
+            }
+            WebhookRejection::MissingSignature
+            | WebhookRejection::InvalidBody(_)
+            | WebhookRejection::Webhook(_) => 400,
+        }
+    }
+}
+
+/// A verified event extracted from an incoming webhook request.
+#[derive(Clone, Debug)]
+pub struct StripeEvent(pub Event);
+
+impl std::ops::Deref for StripeEvent {
+    type Target = Event;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl From<StripeEvent> for Event {
+    fn from(event: StripeEvent) -> Self {
+        event.0
+    }
+}
+
+/// Read an `http_body::Body` to the end, bailing out as soon as it exceeds `limit`.
+#[cfg(any(feature = "webhook-axum", feature = "webhook-tower"))]
+async fn read_body<B>(body: B, limit: usize) -> Result<Vec<u8>, WebhookRejection>
+where
+    B: http_body::Body,
+    B::Error: std::fmt::Display,
+{
+    use bytes::Buf;
+
+    if body.size_hint().lower() > limit as u64 {
+        return Err(WebhookRejection::PayloadTooLarge);
+    }
+
+    let mut body = Box::pin(body);
+    let mut bytes = Vec::new();
+    while let Some(chunk) = http_body::Body::data(&mut body).await {
+        let mut chunk = chunk.map_err(|e| WebhookRejection::InvalidBody(e.to_string()))?;
+        if bytes.len() + chunk.remaining() > limit {
+            return Err(WebhookRejection::PayloadTooLarge);
+        }
+        bytes.extend_from_slice(&chunk.copy_to_bytes(chunk.remaining()));
+    }
+
+    Ok(bytes)
+}
+
+#[cfg(test)]
+pub(crate) mod test_util {
+    use hmac::{Hmac, Mac};
+    use sha2::Sha256;
+
+    pub const SECRET: &str = "whsec_test_secret";
+
+    pub const PAYLOAD: &str = r#"{
+  "id": "evt_123",
+  "object": "event",
+  "api_version": "2022-11-15",
+  "created": 1533204620,
+  "data": {
+    "object": {
+      "id": "cus_123",
+      "object": "customer",
+      "created": 1533204620,
+      "livemode": false
+    }
+  },
+  "livemode": false,
+  "pending_webhooks": 1,
+  "type": "customer.created"
+}"#;
+
+    /// Sign a payload the same way stripe does, at the current time.
+    pub fn sign(payload: &str, secret: &str) -> String {
+        let timestamp = chrono::Utc::now().timestamp();
+        let mut mac = Hmac::<Sha256>::new_from_slice(secret.as_bytes()).unwrap();
+        mac.update(format!("{}.{}", timestamp, payload).as_bytes());
+        format!("t={},v1={}", timestamp, hex::encode(mac.finalize().into_bytes()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::test_util::{sign, PAYLOAD, SECRET};
+    use super::{WebhookConfig, WebhookRejection};
+    use crate::{EventType, WebhookError};
+
+    #[test]
+    fn verifies_signed_payload() {
+        let config = WebhookConfig::new(SECRET);
+        let signature = sign(PAYLOAD, SECRET);
+        let event = config.verify(PAYLOAD.as_bytes(), Some(&signature)).unwrap();
+        assert_eq!(event.type_, EventType::CustomerCreated);
+    }
+
+    #[test]
+    fn rejects_bad_requests() {
+        let config = WebhookConfig::new(SECRET).with_max_body_size(16);
+        let signature = sign(PAYLOAD, SECRET);
+
+        let err = config.verify(PAYLOAD.as_bytes(), Some(&signature)).unwrap_err();
+        assert!(matches!(err, WebhookRejection::PayloadTooLarge));
+        assert_eq!(err.status_code(), 413);
+
+        let config = WebhookConfig::new(SECRET);
+        let err = config.verify(PAYLOAD.as_bytes(), None).unwrap_err();
+        assert!(matches!(err, WebhookRejection::MissingSignature));
+        assert_eq!(err.status_code(), 400);
+
+        let signature = sign(PAYLOAD, "whsec_other_secret");
+        let err = config.verify(PAYLOAD.as_bytes(), Some(&signature)).unwrap_err();
+        assert!(matches!(err, WebhookRejection::Webhook(WebhookError::BadSignature)));
+        assert_eq!(err.status_code(), 400);
+    }
+}
@@ -0,0 +1,402 @@
+use std::collections::HashMap;
+use std::convert::Infallible;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Mutex;
+use std::time::Duration;
+
+use chrono::Utc;
+use thiserror::Error;
+
+use crate::error::WebhookError;
+use crate::ids::EventId;
+use crate::params::Timestamp;
+use crate::resources::{Event, Webhook};
+
+/// A boxed future returned by [`EventStore`] and [`EventHandler`] implementations.
+pub type BoxFuture<'a, T> = Pin<Box<dyn Future<Output = T> + Send + 'a>>;
+
+/// The outcome of handling an event, as recorded in an [`EventStore`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum EventStatus {
+    /// A handler claimed the event at the given time and has not finished yet.
+    InProgress {
+        claimed_at: Timestamp,
+    },
+    Succeeded,
+    /// The handler failed with the given message. Failed events
+    /// are handled again when stripe retries the delivery.
+    Failed(String),
+}
+
+/// A record of an event that has been handled.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct EventRecord {
+    pub id: EventId,
+    /// The id of the object in `data.object`, if it has one.
+    pub object_id: Option<String>,
+    /// The time at which the event was created.
+    pub created: Timestamp,
+    pub status: EventStatus,
+}
+
+/// Storage for the events that a [`WebhookProcessor`] has handled.
+///
+/// Implement this for your database or cache to deduplicate across processes.
+/// For a single process, [`InMemoryEventStore`] is provided.
+pub trait EventStore: Send + Sync {
+    type Error: std::error::Error + Send + Sync + 'static;
+
+    /// Look up the last recorded outcome for an event.
+    fn get<'a>(
+        &'a self,
+        id: &'a EventId,
+    ) -> BoxFuture<'a, Result<Option<EventRecord>, Self::Error>>;
+
+    /// Atomically store an [`EventStatus::InProgress`] record, unless the event has already
+    /// succeeded or another handler holds a claim made at or after `abandoned_before`.
+    ///
+    /// Returns whether the claim was taken. This must be a single insert-if-absent
+    /// operation (eg. a conditional write), otherwise two concurrent deliveries of the
+    /// same event may both be handled.
+    fn try_claim(
+        &self,
+        record: EventRecord,
+        abandoned_before: Timestamp,
+    ) -> BoxFuture<'_, Result<bool, Self::Error>>;
+
+    /// Record the outcome for an event, replacing any previous record.
+    fn record(&self, record: EventRecord) -> BoxFuture<'_, Result<(), Self::Error>>;
+
+    /// The `created` time of the most recent event successfully handled for an object.
+    fn last_created<'a>(
+        &'a self,
+        object_id: &'a str,
+    ) -> BoxFuture<'a, Result<Option<Timestamp>, Self::Error>>;
+}
+
+/// An [`EventStore`] that keeps its records in memory.
+#[derive(Debug, Default)]
+pub struct InMemoryEventStore {
+    inner: Mutex<InMemoryEvents>,
+}
+
+#[derive(Debug, Default)]
+struct InMemoryEvents {
+    events: HashMap<EventId, EventRecord>,
+    last_created: HashMap<String, Timestamp>,
+}
+
+impl InMemoryEventStore {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, InMemoryEvents> {
+        // a poisoned lock only means another handler panicked while holding
+        // it, the maps themselves are always left in a consistent state
+        self.inner.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+impl EventStore for InMemoryEventStore {
+    type Error = Infallible;
+
+    fn get<'a>(
+        &'a self,
+        id: &'a EventId,
+    ) -> BoxFuture<'a, Result<Option<EventRecord>, Self::Error>> {
+        let record = self.lock().events.get(id).cloned();
+        Box::pin(std::future::ready(Ok(record)))
+    }
+
+    fn try_claim(
+        &self,
+        record: EventRecord,
+        abandoned_before: Timestamp,
+    ) -> BoxFuture<'_, Result<bool, Self::Error>> {
+        let mut inner = self.lock();
+        let claimable = match inner.events.get(&record.id).map(|r| &r.status) {
+            None | Some(EventStatus::Failed(_)) => true,
+            Some(EventStatus::InProgress { claimed_at }) => *claimed_at < abandoned_before,
+            Some(EventStatus::Succeeded) => false,
+        };
+        if claimable {
+            inner.events.insert(record.id.clone(), record);
+        }
+        Box::pin(std::future::ready(Ok(claimable)))
+    }
+
+    fn record(&self, record: EventRecord) -> BoxFuture<'_, Result<(), Self::Error>> {
+        let mut inner = self.lock();
+        if let (EventStatus::Succeeded, Some(object_id)) = (&record.status, &record.object_id) {
+            let last = inner.last_created.entry(object_id.clone()).or_insert(record.created);
+            *last = (*last).max(record.created);
+        }
+        inner.events.insert(record.id.clone(), record);
+        Box::pin(std::future::ready(Ok(())))
+    }
+
+    fn last_created<'a>(
+        &'a self,
+        object_id: &'a str,
+    ) -> BoxFuture<'a, Result<Option<Timestamp>, Self::Error>> {
+        let created = self.lock().last_created.get(object_id).copied();
+        Box::pin(std::future::ready(Ok(created)))
+    }
+}
+
+/// Something that handles stripe events.
+///
+/// This is implemented for any `Fn(Event) -> impl Future<Output = Result<(), E>>`.
+pub trait EventHandler: Send + Sync {
+    type Error: std::fmt::Display;
+
+    fn handle(&self, event: Event) -> BoxFuture<'static, Result<(), Self::Error>>;
+}
+
+impl<F, Fut, E> EventHandler for F
+where
+    F: Fn(Event) -> Fut + Send + Sync,
+    Fut: Future<Output = Result<(), E>> + Send + 'static,
+    E: std::fmt::Display,
+{
+    type Error = E;
+
+    fn handle(&self, event: Event) -> BoxFuture<'static, Result<(), Self::Error>> {
+        Box::pin(self(event))
+    }
+}
+
+/// What a [`WebhookProcessor`] did with an event.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ProcessOutcome {
+    /// The handler ran and succeeded.
+    Processed,
+    /// The event had already been handled successfully and was skipped.
+    Duplicate,
+    /// Another delivery of the event is being handled, so this one was skipped.
+    InProgress,
+    /// A newer event for the same object had already been handled, so this one was skipped.
+    Stale,
+}
+
+/// An error encountered while processing an event.
+#[derive(Debug, Error)]
+pub enum ProcessError<S, H> {
+    #[error("error verifying webhook: {0}")]
+    Webhook(#[from] WebhookError),
+    #[error("error accessing the event store: {0}")]
+    Store(S),
+    #[error("error handling event: {0}")]
+    Handler(H),
+}
+
+/// Deduplicates events before handing them to an [`EventHandler`].
+///
+/// Stripe delivers webhooks at least once, and not necessarily in order.
+/// The processor claims each event in the store before handing it to the handler,
+/// skipping events that have already been handled successfully or are being handled
+/// by a concurrent delivery, and records the outcome of every event it handles.
+pub struct WebhookProcessor<S> {
+    store: S,
+    skip_stale: bool,
+    claim_timeout: Duration,
+}
+
+impl<S: EventStore> WebhookProcessor<S> {
+    pub fn new(store: S) -> Self {
+        WebhookProcessor { store, skip_stale: false, claim_timeout: Duration::from_secs(300) }
+    }
+
+    /// How long a claim on an event is honoured before another delivery may take it over,
+    /// 5 minutes by default.
+    ///
+    /// A claim is only left behind if the handler future is dropped or the process exits
+    /// before the outcome is recorded, so this should exceed the slowest handler.
+    pub fn with_claim_timeout(mut self, claim_timeout: Duration) -> Self {
+        self.claim_timeout = claim_timeout;
+        self
+    }
+
+    /// Skip events created before the last event successfully handled for the same object.
+    ///
+    /// Useful when a handler syncs the full state of `data.object`, so that an older
+    /// delivery arriving late does not overwrite newer state.
+    pub fn with_skip_stale(mut self, skip_stale: bool) -> Self {
+        self.skip_stale = skip_stale;
+        self
+    }
+
+    /// The underlying event store.
+    pub fn store(&self) -> &S {
+        &self.store
+    }
+
+    /// Verify a webhook payload, and then [`process`](WebhookProcessor::process) it.
+    ///
+    /// # Errors
+    ///
+    /// This function will return a ProcessError if the webhook could not be
+    /// verified (see [`Webhook::construct_event`]), the store failed or the handler failed.
+    pub async fn process_payload<H: EventHandler>(
+        &self,
+        payload: &str,
+        sig: &str,
+        secret: &str,
+        handler: &H,
+    ) -> Result<ProcessOutcome, ProcessError<S::Error, H::Error>> {
+        let event = Webhook::construct_event(payload, sig, secret)?;
+        self.process(event, handler).await
+    }
+
+    /// Hand an event to the handler, unless it has already been handled.
+    ///
+    /// # Errors
+    ///
+    /// This function will return a ProcessError if the store or the handler failed.
+    /// Handler failures are recorded, so that the event is handled again on redelivery.
+    pub async fn process<H: EventHandler>(
+        &self,
+        event: Event,
+        handler: &H,
+    ) -> Result<ProcessOutcome, ProcessError<S::Error, H::Error>> {
+        let id = event.id.clone();
+        let created = event.created;
+        let object_id = event.data.object.id();
+
+        if let (true, Some(object_id)) = (self.skip_stale, &object_id) {
+            let last = self.store.last_created(object_id).await.map_err(ProcessError::Store)?;
+            if matches!(last, Some(last) if created < last) {
+                return Ok(ProcessOutcome::Stale);
+            }
+        }
+
+        let now = Utc::now().timestamp();
+        let claim = EventRecord {
+            id: id.clone(),
+            object_id: object_id.clone(),
+            created,
+            status: EventStatus::InProgress { claimed_at: now },
+        };
+        let abandoned_before = now - self.claim_timeout.as_secs() as i64;
+        if !self.store.try_claim(claim, abandoned_before).await.map_err(ProcessError::Store)? {
+            let record = self.store.get(&id).await.map_err(ProcessError::Store)?;
+            return Ok(match record.map(|r| r.status) {
+                Some(EventStatus::InProgress { .. }) => ProcessOutcome::InProgress,
+                _ => ProcessOutcome::Duplicate,
+            });
+        }
+
+        let result = handler.handle(event).await;
+        let status = match &result {
+            Ok(()) => EventStatus::Succeeded,
+            Err(e) => EventStatus::Failed(e.to_string()),
+        };
+        self.store
+            .record(EventRecord { id, object_id, created, status })
+            .await
+            .map_err(ProcessError::Store)?;
+
+        result.map(|_| ProcessOutcome::Processed).map_err(ProcessError::Handler)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use super::super::test_util::{sign, PAYLOAD, SECRET};
+    use super::WebhookProcessor;
+    use super::{
+        EventRecord, EventStatus, EventStore, InMemoryEventStore, ProcessError, ProcessOutcome,
+    };
+    use crate::Event;
+
+    fn event(id: &str, created: i64) -> Event {
+        let mut event: Event = serde_json::from_str(PAYLOAD).unwrap();
+        event.id = id.parse().unwrap();
+        event.created = created;
+        event
+    }
+
+    #[tokio::test]
+    async fn skips_duplicate_events() {
+        let processor = WebhookProcessor::new(InMemoryEventStore::new());
+        let calls = AtomicUsize::new(0);
+        let handler = |_: Event| {
+            calls.fetch_add(1, Ordering::SeqCst);
+            async { Ok::<_, String>(()) }
+        };
+
+        let signature = sign(PAYLOAD, SECRET);
+        let outcome = processor.process_payload(PAYLOAD, &signature, SECRET, &handler).await;
+        assert_eq!(outcome.unwrap(), ProcessOutcome::Processed);
+        let outcome = processor.process_payload(PAYLOAD, &signature, SECRET, &handler).await;
+        assert_eq!(outcome.unwrap(), ProcessOutcome::Duplicate);
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn retries_failed_events() {
+        let processor = WebhookProcessor::new(InMemoryEventStore::new());
+        let failing = |_: Event| async { Err("database unavailable") };
+        let working = |_: Event| async { Ok::<_, String>(()) };
+
+        let result = processor.process(event("evt_1", 10), &failing).await;
+        assert!(matches!(result, Err(ProcessError::Handler("database unavailable"))));
+        let record = processor.store().get(&"evt_1".parse().unwrap()).await.unwrap().unwrap();
+        assert_eq!(record.status, EventStatus::Failed("database unavailable".to_string()));
+
+        let result = processor.process(event("evt_1", 10), &working).await;
+        assert_eq!(result.unwrap(), ProcessOutcome::Processed);
+    }
+
+    #[tokio::test]
+    async fn skips_stale_events() {
+        let processor = WebhookProcessor::new(InMemoryEventStore::new()).with_skip_stale(true);
+        let handler = |_: Event| async { Ok::<_, String>(()) };
+
+        let result = processor.process(event("evt_2", 20), &handler).await;
+        assert_eq!(result.unwrap(), ProcessOutcome::Processed);
+        let result = processor.process(event("evt_1", 10), &handler).await;
+        assert_eq!(result.unwrap(), ProcessOutcome::Stale);
+        assert_eq!(processor.store().last_created("cus_123").await.unwrap(), Some(20));
+    }
+
+    #[tokio::test]
+    async fn claims_concurrent_deliveries() {
+        let processor = WebhookProcessor::new(InMemoryEventStore::new());
+        let calls = AtomicUsize::new(0);
+        let handler = |_: Event| {
+            calls.fetch_add(1, Ordering::SeqCst);
+            async {
+                tokio::task::yield_now().await;
+                Ok::<_, String>(())
+            }
+        };
+
+        let (first, second) = tokio::join!(
+            processor.process(event("evt_1", 10), &handler),
+            processor.process(event("evt_1", 10), &handler),
+        );
+        assert_eq!(first.unwrap(), ProcessOutcome::Processed);
+        assert_eq!(second.unwrap(), ProcessOutcome::InProgress);
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn takes_over_abandoned_claims() {
+        let store = InMemoryEventStore::new();
+        let claim = |claimed_at| EventRecord {
+            id: "evt_1".parse().unwrap(),
+            object_id: None,
+            created: 10,
+            status: EventStatus::InProgress { claimed_at },
+        };
+
+        assert!(store.try_claim(claim(100), 0).await.unwrap());
+        assert!(!store.try_claim(claim(200), 100).await.unwrap());
+        assert!(store.try_claim(claim(500), 101).await.unwrap());
+    }
+}
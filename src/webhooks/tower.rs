@@ -0,0 +1,150 @@
+use std::future::Future;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+use bytes::Bytes;
+use http::{Request, Response, StatusCode};
+use tower_layer::Layer;
+use tower_service::Service;
+
+use super::{read_body, WebhookConfig, SIGNATURE_HEADER};
+
+/// A [`Layer`] that verifies incoming webhooks before they reach the inner service.
+///
+/// Verified requests are passed on with the parsed [`Event`](crate::Event) stored in
+/// the request extensions and the original payload as the body. Rejected requests
+/// never reach the inner service and are answered with an empty response carrying
+/// the [`WebhookRejection::status_code`](super::WebhookRejection::status_code).
+#[derive(Clone, Debug)]
+pub struct StripeWebhookLayer {
+    config: WebhookConfig,
+}
+
+impl StripeWebhookLayer {
+    pub fn new(config: WebhookConfig) -> Self {
+        StripeWebhookLayer { config }
+    }
+}
+
+impl<S> Layer<S> for StripeWebhookLayer {
+    type Service = StripeWebhookService<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        StripeWebhookService { inner, config: self.config.clone() }
+    }
+}
+
+/// The [`Service`] produced by [`StripeWebhookLayer`].
+#[derive(Clone, Debug)]
+pub struct StripeWebhookService<S> {
+    inner: S,
+    config: WebhookConfig,
+}
+
+impl<S, ReqBody, ResBody> Service<Request<ReqBody>> for StripeWebhookService<S>
+where
+    S: Service<Request<ReqBody>, Response = Response<ResBody>> + Clone + Send + 'static,
+    S::Future: Send + 'static,
+    ReqBody: http_body::Body + From<Bytes> + Send + 'static,
+    ReqBody::Data: Send,
+    ReqBody::Error: std::fmt::Display,
+    ResBody: Default,
+{
+    type Response = Response<ResBody>;
+    type Error = S::Error;
+    type Future = Pin<Box<dyn Future<Output = Result<Self::Response, Self::Error>> + Send>>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, req: Request<ReqBody>) -> Self::Future {
+        // the service that was polled ready is the one we must call
+        let clone = self.inner.clone();
+        let mut inner = std::mem::replace(&mut self.inner, clone);
+        let config = self.config.clone();
+
+        Box::pin(async move {
+            let (mut parts, body) = req.into_parts();
+            let signature = parts
+                .headers
+                .get(SIGNATURE_HEADER)
+                .and_then(|v| v.to_str().ok())
+                .map(str::to_owned);
+
+            let verified = match read_body(body, config.max_body_size()).await {
+                Ok(payload) => config.verify(&payload, signature.as_deref()).map(|e| (payload, e)),
+                Err(rejection) => Err(rejection),
+            };
+
+            match verified {
+                Ok((payload, event)) => {
+                    parts.extensions.insert(event);
+                    inner
+                        .call(Request::from_parts(parts, ReqBody::from(Bytes::from(payload))))
+                        .await
+                }
+                Err(rejection) => {
+                    let mut res = Response::new(ResBody::default());
+                    *res.status_mut() = StatusCode::from_u16(rejection.status_code())
+                        .unwrap_or(StatusCode::INTERNAL_SERVER_ERROR);
+                    Ok(res)
+                }
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::Infallible;
+
+    use bytes::Bytes;
+    use http::{Request, Response, StatusCode};
+    use http_body::Full;
+    use tower::{service_fn, ServiceExt};
+    use tower_layer::Layer;
+
+    use super::super::test_util::{sign, PAYLOAD, SECRET};
+    use super::super::WebhookConfig;
+    use super::StripeWebhookLayer;
+    use crate::Event;
+
+    async fn handler(req: Request<Full<Bytes>>) -> Result<Response<Full<Bytes>>, Infallible> {
+        let event = req.extensions().get::<Event>().expect("event is verified");
+        Ok(Response::new(Full::from(Bytes::from(event.id.to_string()))))
+    }
+
+    fn request(signature: Option<String>) -> Request<Full<Bytes>> {
+        let mut req = Request::post("/webhooks");
+        if let Some(signature) = signature {
+            req = req.header("Stripe-Signature", signature);
+        }
+        req.body(Full::from(Bytes::from(PAYLOAD))).unwrap()
+    }
+
+    #[tokio::test]
+    async fn passes_verified_event_to_inner_service() {
+        let service =
+            StripeWebhookLayer::new(WebhookConfig::new(SECRET)).layer(service_fn(handler));
+        let res = service.oneshot(request(Some(sign(PAYLOAD, SECRET)))).await.unwrap();
+        assert_eq!(res.status(), StatusCode::OK);
+    }
+
+    #[tokio::test]
+    async fn rejects_invalid_requests() {
+        let layer = StripeWebhookLayer::new(WebhookConfig::new(SECRET));
+
+        let res = layer.layer(service_fn(handler)).oneshot(request(None)).await.unwrap();
+        assert_eq!(res.status(), StatusCode::BAD_REQUEST);
+
+        let signature = sign(PAYLOAD, "whsec_other_secret");
+        let res = layer.layer(service_fn(handler)).oneshot(request(Some(signature))).await.unwrap();
+        assert_eq!(res.status(), StatusCode::BAD_REQUEST);
+
+        let layer = StripeWebhookLayer::new(WebhookConfig::new(SECRET).with_max_body_size(16));
+        let signature = sign(PAYLOAD, SECRET);
+        let res = layer.layer(service_fn(handler)).oneshot(request(Some(signature))).await.unwrap();
+        assert_eq!(res.status(), StatusCode::PAYLOAD_TOO_LARGE);
+    }
+}
@@ -313,3 +313,99 @@ fn deserialize_checkout_event() {
     let result = serde_json::from_value::<Event>(example);
     assert!(result.is_ok(), "expected ok; was {:?}", result);
 }
+
+#[test]
+#[cfg(feature = "billing")]
+fn serialize_upcoming_invoice_lines() {
+    use stripe::{ListUpcomingInvoiceLines, RetrieveUpcomingInvoice};
+
+    let mut invoice = RetrieveUpcomingInvoice::new("cus_123".parse().unwrap());
+    invoice.subscription = Some("sub_123".parse().unwrap());
+    let mut params = ListUpcomingInvoiceLines::new(invoice);
+    params.limit = Some(3);
+    params.starting_after = Some("il_123".parse().unwrap());
+    assert_eq!(
+        urldecode(serde_qs::to_string(&params).unwrap()),
+        "customer=cus_123&subscription=sub_123&limit=3&starting_after=il_123"
+    );
+}
+
+#[test]
+#[cfg(feature = "billing")]
+fn serialize_portal_configuration_features() {
+    use stripe::{
+        CreateBillingPortalConfiguration, CreateBillingPortalConfigurationFeatures,
+        CreateBillingPortalConfigurationFeaturesSubscriptionUpdate,
+        CreateBillingPortalConfigurationFeaturesSubscriptionUpdateProducts,
+        PortalSubscriptionUpdateDefaultAllowedUpdates,
+    };
+
+    let features = CreateBillingPortalConfigurationFeatures {
+        subscription_update: Some(CreateBillingPortalConfigurationFeaturesSubscriptionUpdate {
+            default_allowed_updates: vec![PortalSubscriptionUpdateDefaultAllowedUpdates::Price],
+            enabled: true,
+            products: vec![CreateBillingPortalConfigurationFeaturesSubscriptionUpdateProducts {
+                prices: vec!["price_123".to_string(), "price_456".to_string()],
+                product: "prod_123".to_string(),
+            }],
+            proration_behavior: None,
+        }),
+        ..Default::default()
+    };
+    let params = CreateBillingPortalConfiguration::new(Default::default(), features);
+    assert_eq!(
+        urldecode(serde_qs::to_string(&params).unwrap()),
+        "features[subscription_update][default_allowed_updates][0]=price\
+         &features[subscription_update][enabled]=true\
+         &features[subscription_update][products][0][prices][0]=price_123\
+         &features[subscription_update][products][0][prices][1]=price_456\
+         &features[subscription_update][products][0][product]=prod_123"
+    );
+}
+
+#[test]
+fn serialize_confirm_payment_intent_with_multicapture() {
+    use stripe::{
+        ConfirmPaymentIntent, CreatePaymentIntentPaymentMethodOptions,
+        CreatePaymentIntentPaymentMethodOptionsCard,
+        CreatePaymentIntentPaymentMethodOptionsCardRequestMulticapture,
+    };
+
+    let mut params = ConfirmPaymentIntent::new();
+    params.payment_method = Some("pm_card_visa".parse().unwrap());
+    params.payment_method_options = Some(CreatePaymentIntentPaymentMethodOptions {
+        card: Some(CreatePaymentIntentPaymentMethodOptionsCard {
+            request_multicapture: Some(
+                CreatePaymentIntentPaymentMethodOptionsCardRequestMulticapture::IfAvailable,
+            ),
+            ..Default::default()
+        }),
+        ..Default::default()
+    });
+    assert_eq!(
+        urldecode(serde_qs::to_string(&params).unwrap()),
+        "payment_method=pm_card_visa\
+         &payment_method_options[card][request_multicapture]=if_available"
+    );
+}
+
+#[test]
+#[cfg(feature = "billing")]
+fn serialize_credit_note_preview_lines() {
+    use stripe::{PreviewCreditNote, PreviewCreditNoteLines, PreviewCreditNoteLinesType};
+
+    let mut params = PreviewCreditNote::new("in_123".parse().unwrap());
+    params.lines = Some(vec![PreviewCreditNoteLines {
+        invoice_line_item: Some("il_123".to_string()),
+        quantity: Some(1),
+        type_: PreviewCreditNoteLinesType::InvoiceLineItem,
+        ..Default::default()
+    }]);
+    assert_eq!(
+        urldecode(serde_qs::to_string(&params).unwrap()),
+        "invoice=in_123\
+         &lines[0][invoice_line_item]=il_123\
+         &lines[0][quantity]=1\
+         &lines[0][type]=invoice_line_item"
+    );
+}